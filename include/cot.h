@@ -3,6 +3,102 @@
 #include <cot/basedefs.h>
 #include <cot/logging.h>
 #include <cot/effects.h>
+#include <cot/tile_grid.h>
+#include <cot/geometry.h>
+#include <cot/dungeon_dialogue.h>
+#include <cot/zones.h>
+#include <cot/script_vars.h>
+#include <cot/terrain_mechanics.h>
+#include <cot/script_calls.h>
+#include <cot/script_opcodes.h>
+#include <cot/puzzles.h>
+#include <cot/locks.h>
+#include <cot/special_process.h>
+#include <cot/personality_quiz.h>
+#include <cot/treasure_boxes.h>
+#include <cot/defeat_rules.h>
+#include <cot/unlocks.h>
+#include <cot/dojo.h>
+#include <cot/cafe.h>
+#include <cot/stats.h>
+#include <cot/minigames.h>
+#include <cot/day_events.h>
+#include <cot/swap_shop.h>
+#include <cot/move_links.h>
+#include <cot/gummis.h>
+#include <cot/sealed_chambers.h>
+#include <cot/move_tutor.h>
+#include <cot/gen_grid.h>
+#include <cot/learnsets.h>
+#include <cot/floor_gen.h>
+#include <cot/floor_snapshot.h>
+#include <cot/species.h>
+#include <cot/data_tables.h>
+#include <cot/bench.h>
+#include <cot/fixed_rooms.h>
+#include <cot/fixed_bin.h>
+#include <cot/weights.h>
+#include <cot/entity_slots.h>
+#include <cot/hidden_power.h>
+#include <cot/extended_moves.h>
+#include <cot/despawn.h>
+#include <cot/entity_iter.h>
+#include <cot/illusions.h>
+#include <cot/extended_items.h>
+#include <cot/item_transmute.h>
+#include <cot/spawn_builder.h>
+#include <cot/extended_species.h>
+#include <cot/ground_items.h>
+#include <cot/transform.h>
+#include <cot/traps.h>
+#include <cot/fixed_room_entities.h>
+#include <cot/damage.h>
+#include <cot/flavor_events.h>
+#include <cot/fixed_room_builder.h>
+#include <cot/fs.h>
+#include <cot/statuses.h>
+#include <cot/ally_items.h>
+#include <cot/compression.h>
+#include <cot/escorts.h>
+#include <cot/weather.h>
+#include <cot/outlaws.h>
+#include <cot/random.h>
+#include <cot/mission_types.h>
+#include <cot/str_buf.h>
+#include <cot/messages.h>
+#include <cot/bytes.h>
+#include <cot/collections.h>
+#include <cot/fixed_point.h>
+#include <cot/wan.h>
+#include <cot/floor_share.h>
+#include <cot/menus.h>
+#include <cot/localization.h>
+#include <cot/daily.h>
+#include <cot/script_queue.h>
+#include <cot/language.h>
+#include <cot/floor_mutations.h>
+#include <cot/signatures.h>
+#include <cot/save_data.h>
+#include <cot/room_decorators.h>
+#include <cot/biomes.h>
+#include <cot/layout.h>
+#include <cot/iq.h>
+#include <cot/vaults.h>
+#include <cot/arena.h>
+#include <cot/missions.h>
+#include <cot/bridges.h>
+#include <cot/kecleon_shops.h>
+#include <cot/spawn_weights.h>
+#include <cot/hidden_stairs.h>
+#include <cot/scratch.h>
+#include <cot/spawn_constraints.h>
+#include <cot/wall_movement.h>
+#include <cot/pathfinding.h>
+#include <cot/ai_hooks.h>
+#include <cot/minimap.h>
+#include <cot/weather_terrain.h>
+#include <cot/trap_visibility.h>
+#include <cot/faint.h>
 
 #ifdef COT_RUST
 #include <cot/rust.h>
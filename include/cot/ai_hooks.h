@@ -0,0 +1,33 @@
+#pragma once
+
+// Turn and AI hooks for dungeon mode.
+//
+// Turn hooks run at the start and end of every turn. Action hooks run for every monster after
+// the AI has chosen its action and may change it, e.g. to stop partners from using a move or
+// to make enemies flee at low HP. The hooks run in the order they were added. Hooks added or
+// removed while the hooks run take effect the next time.
+//
+// The hooks run from wrappers in hooks.c: turn hooks around the game's RunFractionalTurn and
+// action hooks after its RunMonsterAi. The game splits every turn into fractional turns to let
+// faster monsters act more often, so turn hooks run several times per turn. Illusions
+// (illusions.h) and escorts (escorts.h) are checked for damage and Kecleon shops
+// (kecleon_shops.h) for thefts before the turn end hooks run, and terrain mechanics
+// (terrain_mechanics.h) are applied to the monsters that moved. Escorts that follow the leader
+// get their action before the action hooks run.
+
+#define COT_MAX_TURN_HOOKS 8
+#define COT_MAX_ACTION_HOOKS 8
+
+typedef void (*turn_hook_fn)(void);
+// `action` is the monster's chosen action and may be modified.
+typedef void (*action_hook_fn)(struct entity* monster, struct action_data* action);
+
+bool CotAddTurnStartHook(turn_hook_fn hook);
+bool CotAddTurnEndHook(turn_hook_fn hook);
+void CotRemoveTurnHook(turn_hook_fn hook);
+bool CotAddActionHook(action_hook_fn hook);
+void CotRemoveActionHook(action_hook_fn hook);
+
+void cotInternalOnTurnStart(void);
+void cotInternalOnTurnEnd(void);
+void cotInternalOnMonsterActionChosen(struct entity* monster);
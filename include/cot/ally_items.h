@@ -0,0 +1,37 @@
+#pragma once
+
+// Rules for allies using and picking up items.
+//
+// Team members and non-team allies (escort clients, guests) can get different rules, e.g. to
+// keep an escorted client from eating the Reviver Seed it was given. A hook can refine the rule
+// per monster and item. The rules are applied by wrappers in hooks.c: use rules around the
+// game's GetAiUseItemProbability, so they only affect items the AI uses on its own, and pickup
+// rules around PickUpItem. Since the game already picks up every item an ally walks over,
+// ALLY_ITEMS_ALWAYS has no effect on pickups.
+
+typedef enum ally_item_rule {
+  ALLY_ITEMS_VANILLA = 0,
+  ALLY_ITEMS_NEVER,
+  ALLY_ITEMS_ALWAYS,
+} ally_item_rule;
+
+typedef enum ally_item_action {
+  ALLY_ITEM_USE = 0,
+  ALLY_ITEM_PICK_UP,
+} ally_item_action;
+
+// Returns whether `ally` may perform `action` with `item`. `allowed` is the decision after
+// applying the configured rule.
+typedef bool (*ally_item_hook_fn)(struct entity* ally, struct item* item, ally_item_action action,
+                                  bool allowed);
+
+// `team` applies to team members, `guests` to non-team allies.
+void CotSetAllyItemUseRule(ally_item_rule team, ally_item_rule guests);
+void CotSetAllyItemPickUpRule(ally_item_rule team, ally_item_rule guests);
+void CotSetAllyItemHook(ally_item_hook_fn hook);
+
+// Returns the chance in percent that `ally` uses `item`, given the game's chance `vanilla`.
+// Enemies always get `vanilla`.
+int cotInternalGetAllyItemUseChance(struct entity* ally, struct item* item, int vanilla);
+// Returns whether `ally` may pick up `item`. Enemies always may.
+bool cotInternalCanAllyPickUpItem(struct entity* ally, struct item* item);
@@ -0,0 +1,88 @@
+#pragma once
+
+// Memory budgets on top of the game's allocators.
+//
+// A cot_arena allocates from one of the game's memory arenas, or from the default heap, and
+// keeps track of how much of its budget is used, so patches can check the remaining space
+// before allocating instead of running the game out of memory.
+//
+// Scopes free all their allocations when they go out of scope:
+//
+//   COT_ARENA_SCOPE(scope, &arena);
+//   struct position* path = COT_NEW_ARRAY(&scope, struct position, 64);
+//   if (path == NULL) {
+//     return;
+//   }
+//   ... // `path` is freed when `scope` goes out of scope
+//
+// Every allocation has an 8-byte header, which counts towards the budget. CotGetArenaStats
+// reports how much of the budget is used, and how much space is left in the underlying game
+// arena, including what other users of that arena allocated. With leak tracking enabled,
+// every live allocation is recorded with the address of its caller, and CotArenaReportLeaks
+// logs the ones that haven't been freed.
+
+#define COT_ARENA_SCOPE_MAX_ALLOCS 16
+#define COT_ARENA_HEADER_SIZE 8
+// Live allocations recorded by leak tracking, shared by all arenas.
+#define COT_ARENA_MAX_TRACKED 64
+
+typedef struct cot_arena {
+  // NULL for the default heap used by MemAlloc.
+  struct mem_arena* arena;
+  uint32_t budget;
+  uint32_t used;
+  uint32_t peak;
+  int n_allocs;
+  bool track_leaks;
+} cot_arena;
+
+typedef struct arena_stats {
+  // Budget of the cot_arena. `used` includes the allocation headers.
+  uint32_t total;
+  uint32_t used;
+  uint32_t free;
+  // Highest `used` since the arena was initialized.
+  uint32_t peak;
+  int n_allocs;
+  // Size of the underlying game arena and the bytes in use by all its users.
+  uint32_t arena_total;
+  uint32_t arena_used;
+  // Largest free block of the underlying game arena.
+  uint32_t largest_free_block;
+  // Largest allocation that currently fits, both in the budget and in the largest free block.
+  uint32_t headroom;
+} arena_stats;
+
+typedef struct arena_scope {
+  cot_arena* arena;
+  int n_allocs;
+  void* allocs[COT_ARENA_SCOPE_MAX_ALLOCS];
+} arena_scope;
+
+void CotArenaInit(cot_arena* arena, struct mem_arena* mem_arena, uint32_t budget);
+// Returns NULL if the allocation and its header exceed the remaining budget or the game is out
+// of memory.
+void* CotArenaAlloc(cot_arena* arena, uint32_t size);
+void CotArenaFree(cot_arena* arena, void* ptr);
+uint32_t CotArenaRemaining(const cot_arena* arena);
+arena_stats CotGetArenaStats(const cot_arena* arena);
+
+// Enable before the first allocation, allocations made earlier aren't tracked.
+void CotArenaSetLeakTracking(cot_arena* arena, bool enabled);
+// Logs every tracked allocation of the arena that's still live. Returns their number.
+int CotArenaReportLeaks(const cot_arena* arena);
+
+#define COT_ARENA_NEW(arena, type) ((type*) CotArenaAlloc((arena), sizeof(type)))
+#define COT_ARENA_NEW_ARRAY(arena, type, n) ((type*) CotArenaAlloc((arena), sizeof(type) * (n)))
+
+#define COT_ARENA_SCOPE(name, cot_arena) \
+  __attribute__((cleanup(CotArenaScopeEnd))) arena_scope name = { .arena = (cot_arena) }
+
+// Returns NULL if the allocation fails or the scope already holds
+// COT_ARENA_SCOPE_MAX_ALLOCS allocations.
+void* CotScopeAlloc(arena_scope* scope, uint32_t size);
+// Frees all allocations of the scope. Called automatically by COT_ARENA_SCOPE.
+void CotArenaScopeEnd(arena_scope* scope);
+
+#define COT_NEW(scope, type) ((type*) CotScopeAlloc((scope), sizeof(type)))
+#define COT_NEW_ARRAY(scope, type, n) ((type*) CotScopeAlloc((scope), sizeof(type) * (n)))
@@ -0,0 +1,23 @@
+#pragma once
+
+// Benchmarks for the floor generation primitives.
+//
+// Timing uses hardware timers 2 and 3 in cascade mode, which the game doesn't use. Results are
+// reported through the debug log, so the benchmarks are only available in debug builds.
+// The benchmarks overwrite the current floor and must only be run in dungeon mode, e.g. from
+// a debug special process, on a floor that is regenerated afterwards.
+
+#ifndef NDEBUG
+
+// Timer ticks per second (bus clock / 64).
+#define COT_BENCH_TICKS_PER_SECOND 523656
+
+void CotBenchStart(void);
+// Returns the ticks elapsed since the last CotBenchStart.
+uint32_t CotBenchStop(void);
+
+// Runs every generator benchmark `iterations` times and logs the average time per call. The
+// builtin generator and COT_ROOMS_GENERATOR are compared on the same `iterations` seeds.
+void CotRunFloorGenBenchmarks(int iterations);
+
+#endif
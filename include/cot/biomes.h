@@ -0,0 +1,52 @@
+#pragma once
+
+// Floor themes ("biomes").
+//
+// A biome bundles the look and feel of a floor in one declarative struct: its tileset and
+// music, a weather bias, extra monster spawns, floor mutations (see floor_mutations.h) and
+// room decorators (see room_decorators.h). Rules map dungeons and floor ranges to biomes; the
+// first matching rule picks the biome of a floor when it's generated.
+//
+// CotInstallBiomes adds the pre- and post-generate hooks (see floor_gen.h) that apply the
+// biome. Tileset, music and weather are written into the floor properties before generation;
+// mutations, decorators and spawns run once the floor is complete, in that order.
+
+#define COT_MAX_BIOME_RULES 16
+#define COT_MAX_BIOME_SPAWNS 16
+#define COT_BIOME_KEEP -1
+
+typedef struct biome_spawn {
+  enum monster_id species;
+  int level;
+  // Relative chance of this entry being picked.
+  int weight;
+} biome_spawn;
+
+typedef struct biome {
+  const char* name;
+  // COT_BIOME_KEEP to keep the floor's own tileset or music.
+  int tileset;
+  int music_table_idx;
+  // Chance in percent that the floor's weather is replaced by `weather`.
+  enum weather_id weather;
+  int weather_chance;
+  // Number of monsters picked from `spawns` by weight and placed on random tiles like CotSpawn
+  // does, so never on the stairs or the team spawn. Only the first COT_MAX_BIOME_SPAWNS entries
+  // of `spawns` are used.
+  int n_extra_spawns;
+  const biome_spawn* spawns;
+  int n_spawns;
+  const floor_mutation* mutations;
+  int n_mutations;
+  const room_decorator* decorators;
+  int n_decorators;
+} biome;
+
+// Floors `first_floor` to `last_floor` of `dungeon` use `biome`. Use COT_ANY_DUNGEON and
+// COT_ANY_FLOOR as wildcards. Rules are checked in the order they were added.
+bool CotAddBiomeRule(enum dungeon_id dungeon, int first_floor, int last_floor, const biome* biome);
+void CotClearBiomeRules(void);
+
+// Returns the biome of the current floor, or NULL if it has none.
+const biome* CotGetCurrentBiome(void);
+bool CotInstallBiomes(void);
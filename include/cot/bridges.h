@@ -0,0 +1,23 @@
+#pragma once
+
+// Crossings over secondary terrain.
+//
+// Floors with lots of water or lava can cut off parts of the floor for monsters that can't
+// cross it. Bridges and stepping stones turn secondary terrain tiles back into floor tiles.
+// CotConnectAcrossSecondaryTerrain finds the areas that can't be reached on foot from the team
+// spawn and builds the shortest bridges that connect them.
+//
+// Bridges are drawn with the floor graphics of the floor's tileset. Dungeon tilesets have no
+// bridge graphics, and the format of their tiles isn't documented in pmdsky-debug, so
+// c-of-time can't draw dedicated bridge tiles.
+
+// Turns the secondary terrain on a straight line between two tiles into floor, first
+// horizontally, then vertically. Returns the number of tiles changed.
+int CotBuildBridge(int x0, int y0, int x1, int y1);
+// Turns every secondary terrain tile in the area whose coordinates sum to a multiple of
+// `spacing` into floor. A spacing of 2 results in diagonally connected stones, which can be
+// crossed on foot.
+int CotPlaceSteppingStones(int x, int y, int w, int h, int spacing);
+// Builds up to `max_bridges` bridges until all floor tiles can be reached on foot from the
+// team spawn. Returns the number of bridges built.
+int CotConnectAcrossSecondaryTerrain(int max_bridges);
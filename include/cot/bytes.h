@@ -0,0 +1,25 @@
+#pragma once
+
+// Little-endian reads and writes for unaligned data, e.g. in files loaded from the ROM, and
+// checksums.
+
+static inline uint16_t CotReadU16(const uint8_t* p) {
+  return p[0] | (p[1] << 8);
+}
+
+static inline uint32_t CotReadU32(const uint8_t* p) {
+  return p[0] | (p[1] << 8) | (p[2] << 16) | ((uint32_t) p[3] << 24);
+}
+
+static inline void CotWriteU16(uint8_t* p, uint16_t value) {
+  p[0] = value & 0xFF;
+  p[1] = value >> 8;
+}
+
+static inline void CotWriteU32(uint8_t* p, uint32_t value) {
+  CotWriteU16(p, value & 0xFFFF);
+  CotWriteU16(p + 2, value >> 16);
+}
+
+// Standard CRC-32 (as used by zlib) of `size` bytes.
+uint32_t CotCrc32(const void* data, int size);
@@ -0,0 +1,70 @@
+#pragma once
+
+// Spinda's Café drink and event customization.
+//
+// This module is only compiled with the COT_FEATURE_SPINDA_CAFE feature flag, e.g.
+// `make patch EXTRA_CFLAGS=-DCOT_FEATURE_SPINDA_CAFE`.
+//
+// Drink outcomes are configured per ingredient item, and the configured outcomes can be read
+// back with CotGetCafeDrinkOutcome or enumerated by index. The café is run by scripts, so once
+// CotInstallCafe was called, the café's scripts apply them with two extension opcodes (see
+// script_opcodes.h):
+//
+// - COT_SCRIPT_OPCODE_CAFE_DRINK(ingredient, member) applies the outcome for `ingredient` to the
+//   team member at index `member` of the Chimecho Assembly. $CONDITION is 1 if an outcome was
+//   configured, or 0 if the script should fall back to the game's own effect.
+// - COT_SCRIPT_OPCODE_CAFE_EVENT(event_id) asks the event hook whether to trigger an event.
+//   $CONDITION is COT_CAFE_EVENT_KEEP without a hook, so the script can use the game's own
+//   logic.
+//
+// Define COT_CAFE_OPCODE_BASE if the opcode IDs collide with other extension opcodes.
+
+#ifdef COT_FEATURE_SPINDA_CAFE
+
+#define COT_MAX_CAFE_DRINKS 16
+
+#ifndef COT_CAFE_OPCODE_BASE
+#define COT_CAFE_OPCODE_BASE 0x1F3
+#endif
+#define COT_SCRIPT_OPCODE_CAFE_DRINK (COT_CAFE_OPCODE_BASE + 0)
+#define COT_SCRIPT_OPCODE_CAFE_EVENT (COT_CAFE_OPCODE_BASE + 1)
+
+// $CONDITION values of COT_SCRIPT_OPCODE_CAFE_EVENT.
+#define COT_CAFE_EVENT_KEEP 0
+#define COT_CAFE_EVENT_TRIGGER 1
+#define COT_CAFE_EVENT_SKIP 2
+
+// Applies a custom drink effect. Return true if the effect was applied.
+typedef bool (*cafe_drink_fn)(enum item_id ingredient, struct ground_monster* member);
+// Called when the café can trigger an event. Return true to trigger event `event_id`.
+typedef bool (*cafe_event_fn)(int event_id);
+
+typedef struct cafe_drink_outcome {
+  int iq;
+  int max_hp;
+  // Attack, Special Attack
+  int offensive_stats[2];
+  // Defense, Special Defense
+  int defensive_stats[2];
+  // Runs after the stat changes above. May be NULL.
+  cafe_drink_fn custom;
+} cafe_drink_outcome;
+
+// Registers the café opcodes. Returns false if their IDs already have handlers.
+bool CotInstallCafe(void);
+
+bool CotSetCafeDrinkOutcome(enum item_id ingredient, cafe_drink_outcome outcome);
+// Returns the outcome configured for `ingredient`, or NULL if there's none.
+const cafe_drink_outcome* CotGetCafeDrinkOutcome(enum item_id ingredient);
+int CotGetCafeDrinkCount(void);
+// Returns the ingredient of the configured outcome at `index`, or ITEM_NOTHING if out of range.
+enum item_id CotGetCafeDrinkIngredient(int index);
+void CotSetCafeEventHook(cafe_event_fn hook);
+
+// Applies the outcome configured for `ingredient` to `member`. Returns false if there's none and
+// the game's own effect should be used.
+bool CotServeCafeDrink(enum item_id ingredient, struct ground_monster* member);
+// Returns true if the hook decided the event, with the decision stored in `trigger`.
+bool CotDecideCafeEvent(int event_id, bool* trigger);
+
+#endif
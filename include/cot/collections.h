@@ -0,0 +1,95 @@
+#pragma once
+
+// Fixed-capacity collections that don't allocate.
+//
+// - COT_VEC: a vector with its capacity fixed at compile time
+// - floor_bitset: one bit per tile of the floor
+// - COT_MONSTER_MAP: a value per monster slot of the entity table
+//
+//   COT_VEC(struct position, 16) path = { 0 };
+//   if (!COT_VEC_PUSH(&path, pos)) { ... } // full
+//   for (int i = 0; i < path.len; i++) { ... path.items[i] ... }
+
+#define COT_VEC(type, capacity) \
+  struct { \
+    int len; \
+    type items[capacity]; \
+  }
+
+#define COT_VEC_CAPACITY(vec) ((int) (sizeof((vec)->items) / sizeof((vec)->items[0])))
+#define COT_VEC_IS_FULL(vec) ((vec)->len >= COT_VEC_CAPACITY(vec))
+// Evaluates to false if the vector is full.
+#define COT_VEC_PUSH(vec, value) \
+  (COT_VEC_IS_FULL(vec) ? false : ((vec)->items[(vec)->len++] = (value), true))
+// The vector must not be empty.
+#define COT_VEC_POP(vec) ((vec)->items[--(vec)->len])
+// Removes item `i` by moving the last item into its place.
+#define COT_VEC_SWAP_REMOVE(vec, i) ((vec)->items[(i)] = (vec)->items[--(vec)->len])
+// Removes item `i`, keeping the order of the remaining items.
+#define COT_VEC_REMOVE(vec, i) \
+  do { \
+    for (int _cot_j = (i) + 1; _cot_j < (vec)->len; _cot_j++) { \
+      (vec)->items[_cot_j - 1] = (vec)->items[_cot_j]; \
+    } \
+    (vec)->len--; \
+  } while (0)
+#define COT_VEC_CLEAR(vec) ((vec)->len = 0)
+
+#define COT_FLOOR_BITSET_WORDS ((COT_FLOOR_WIDTH * COT_FLOOR_HEIGHT + 31) / 32)
+
+typedef struct floor_bitset {
+  uint32_t words[COT_FLOOR_BITSET_WORDS];
+} floor_bitset;
+
+static inline void CotBitsetClear(floor_bitset* set) {
+  for (int i = 0; i < COT_FLOOR_BITSET_WORDS; i++) {
+    set->words[i] = 0;
+  }
+}
+
+static inline void CotBitsetSet(floor_bitset* set, int x, int y) {
+  int i = y * COT_FLOOR_WIDTH + x;
+  set->words[i / 32] |= 1u << (i % 32);
+}
+
+static inline void CotBitsetUnset(floor_bitset* set, int x, int y) {
+  int i = y * COT_FLOOR_WIDTH + x;
+  set->words[i / 32] &= ~(1u << (i % 32));
+}
+
+// Out-of-bounds tiles are never set.
+static inline bool CotBitsetTest(const floor_bitset* set, int x, int y) {
+  if (x < 0 || x >= COT_FLOOR_WIDTH || y < 0 || y >= COT_FLOOR_HEIGHT) {
+    return false;
+  }
+  int i = y * COT_FLOOR_WIDTH + x;
+  return (set->words[i / 32] >> (i % 32)) & 1;
+}
+
+int CotBitsetCount(const floor_bitset* set);
+
+// Returns the monster slot (0 to COT_N_MONSTER_SLOTS - 1) of a monster entity, or -1.
+int CotMonsterSlot(struct entity* entity);
+
+#define COT_MONSTER_MAP(type) \
+  struct { \
+    uint32_t present; \
+    type values[COT_N_MONSTER_SLOTS]; \
+  }
+
+// Evaluates to a pointer to the value of `entity`, or NULL if it has none.
+#define COT_MONSTER_MAP_GET(map, entity) \
+  (CotMonsterMapHas((map)->present, (entity)) ? &(map)->values[CotMonsterSlot(entity)] : NULL)
+// Evaluates to false if `entity` isn't a monster.
+#define COT_MONSTER_MAP_SET(map, entity, value) \
+  (CotMonsterSlot(entity) < 0 ? false \
+    : ((map)->values[CotMonsterSlot(entity)] = (value), \
+       (map)->present |= 1u << CotMonsterSlot(entity), true))
+#define COT_MONSTER_MAP_REMOVE(map, entity) \
+  ((map)->present &= CotMonsterSlot(entity) < 0 ? ~0u : ~(1u << CotMonsterSlot(entity)))
+#define COT_MONSTER_MAP_CLEAR(map) ((map)->present = 0)
+
+static inline bool CotMonsterMapHas(uint32_t present, struct entity* entity) {
+  int slot = CotMonsterSlot(entity);
+  return slot >= 0 && ((present >> slot) & 1);
+}
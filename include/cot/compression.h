@@ -0,0 +1,28 @@
+#pragma once
+
+// Encoders and decoders for the game's compression formats.
+//
+// PX compression is used by the AT4PX and PKDPX containers. Both share the same compressed
+// stream and only differ in their headers: AT4PX stores the decompressed size as 16 bits,
+// PKDPX as 32 bits. NRL is a simple run-length encoding of zeros, repeated bytes and literals.
+//
+// The encoders aim for valid output rather than the best ratio; the decoders handle data
+// produced by the game's tools as well.
+
+typedef enum px_format {
+  PX_FORMAT_AT4PX = 0,
+  PX_FORMAT_PKDPX,
+} px_format;
+
+// Returns the decompressed size of a PX container, or -1 if `src` isn't one.
+int CotPxGetDecompressedSize(const void* src);
+// Decompresses a PX container. Returns the decompressed size, or -1 if `src` is invalid or
+// `dst` is too small.
+int CotPxDecompress(const void* src, void* dst, int dst_capacity);
+// Compresses `size` bytes into a PX container. Returns the container's size, or -1 if `dst` is
+// too small or the container would exceed the format's limits.
+int CotPxCompress(const void* src, int size, void* dst, int dst_capacity, px_format format);
+
+// Return the number of bytes written, or -1 if `dst` is too small or `src` is invalid.
+int CotNrlDecompress(const void* src, int src_size, void* dst, int dst_capacity);
+int CotNrlCompress(const void* src, int size, void* dst, int dst_capacity);
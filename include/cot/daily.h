@@ -0,0 +1,46 @@
+#pragma once
+
+// Daily run mode.
+//
+// A daily run derives its seed from a date and a salt chosen by the mod, so every player gets
+// the same seed on the same day. CotDailyBegin reads today's date from the DS's real-time
+// clock with the NitroSDK function RTC_GetDate, which isn't in pmdsky-debug yet; add its
+// address to symbols/custom_[region].ld. Without it, CotDailyBegin fails, but a date can still
+// be passed to CotDailyBeginOnDate directly, e.g. one received from a server.
+//
+// While a daily run is active, the dungeon RNG is reseeded before every floor of the daily
+// dungeon is generated, which makes floor layouts independent of what the player did on
+// previous floors. The general RNG of random.h is locked to the dungeon RNG while the daily
+// dungeon is loaded, so the rest of the game isn't affected. The run's statistics are recorded
+// with the stats module.
+
+typedef struct daily_date {
+  // Years since 2000
+  uint32_t year;
+  uint32_t month;
+  uint32_t day;
+} daily_date;
+
+typedef struct daily_result {
+  daily_date date;
+  uint32_t seed;
+  enum dungeon_id dungeon;
+  int floors_reached;
+  bool cleared;
+  uint32_t damage_dealt;
+  uint32_t damage_taken;
+} daily_result;
+
+// Writes today's date from the real-time clock to `out`. Returns false if the clock can't be
+// read.
+bool CotGetTodaysDate(daily_date* out);
+uint32_t CotDailySeed(const daily_date* date, uint32_t salt);
+// Starts a daily run of `dungeon` with today's seed. Returns false if the real-time clock
+// can't be read.
+bool CotDailyBegin(enum dungeon_id dungeon, uint32_t salt);
+// Starts a daily run of `dungeon` with the seed of `date`.
+bool CotDailyBeginOnDate(enum dungeon_id dungeon, const daily_date* date, uint32_t salt);
+void CotDailyEnd(bool cleared);
+bool CotIsDailyRunActive(void);
+// Returns the result of the last finished daily run, or NULL.
+const daily_result* CotGetLastDailyResult(void);
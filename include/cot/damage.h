@@ -0,0 +1,37 @@
+#pragma once
+
+// Wrappers around the game's damage calculation and a hook to modify its results.
+//
+// The wrappers only calculate damage; applying it is left to the caller. The damage hook runs
+// for every calculation, including the game's own, from wrappers around CalcDamage and
+// CalcDamageFixed in hooks.c. Fixed damage, e.g. from Sonic Boom or Dragon Rage, is passed to
+// the hook with MOVE_NOTHING, since the game doesn't tell which move caused it.
+
+// Damage multiplier of 1.0 in the game's fixed-point format.
+#define COT_DAMAGE_MULT_ONE 0x100
+
+typedef struct damage_result {
+  int damage;
+  bool critical;
+  enum type_id type;
+  enum move_category category;
+  enum type_matchup type_matchup;
+  // The defender is fully immune to the move's type.
+  bool immune;
+} damage_result;
+
+// Calculates the damage `move` would deal with the given type and power.
+void CotCalcDamage(struct entity* attacker, struct entity* defender, enum move_id move,
+                   enum type_id type, int power, int crit_chance, damage_result* out);
+// Calculates fixed damage, e.g. from Sonic Boom, with type immunities applied.
+void CotCalcFixedDamage(struct entity* attacker, struct entity* defender, int amount,
+                        enum type_id type, enum move_category category, damage_result* out);
+
+// May change the calculated damage in `data`.
+typedef void (*damage_hook_fn)(struct entity* attacker, struct entity* defender,
+                               enum move_id move, struct damage_data* data);
+
+void CotSetDamageHook(damage_hook_fn hook);
+
+void cotInternalOnDamageCalculated(struct entity* attacker, struct entity* defender,
+                                   enum move_id move, struct damage_data* data);
@@ -0,0 +1,58 @@
+#pragma once
+
+// Accessors for the move and item data tables.
+//
+// Like the species accessors, these read the tables through the game's own functions. The move
+// functions take a struct move, so a temporary one is set up for the queried move ID.
+//
+// Moves and items can also be patched in RAM, e.g. to apply balance tweaks at boot. Patches write
+// to the data tables loaded from waza_p.bin and item_p.bin, so they last until the game reloads
+// them.
+
+typedef struct move_info {
+  enum move_id id;
+  enum type_id type;
+  enum move_category category;
+  int base_power;
+  int accuracy;
+  int max_pp;
+  int crit_chance;
+} move_info;
+
+typedef struct item_info {
+  enum item_id id;
+  enum item_category category;
+  int buy_price;
+  int sell_price;
+  bool thrown;
+  bool edible;
+  // The move taught by a TM/HM, MOVE_NOTHING for other items.
+  enum move_id tm_move;
+} item_info;
+
+// Return false if the ID is neither in the game's tables nor an extended move or item.
+bool CotGetMoveInfo(enum move_id id, move_info* out);
+bool CotGetItemInfo(enum item_id id, item_info* out);
+
+#define COT_DATA_KEEP -1
+
+// Fields set to COT_DATA_KEEP keep their current value.
+typedef struct move_patch {
+  int base_power;
+  int accuracy;
+  int max_pp;
+  int crit_chance;
+} move_patch;
+
+// Returns false without changing anything if the move ID or a value is out of range.
+bool CotPatchMoveData(enum move_id id, const move_patch* patch);
+
+// Fields set to COT_DATA_KEEP keep their current value.
+typedef struct item_patch {
+  int buy_price;
+  int sell_price;
+} item_patch;
+
+// Returns false without changing anything if the item ID or a value is out of range. Extended
+// items can't be patched, they use the data of their template.
+bool CotPatchItemData(enum item_id id, const item_patch* patch);
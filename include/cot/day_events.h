@@ -0,0 +1,31 @@
+#pragma once
+
+// Events at the end-of-day (sleep) transition.
+//
+// The sleep transition is run by scripts, so CotRunDayEnd must be called from a special process
+// (see special_process.h) in the script that ends the day. It runs, in order: story beats whose
+// delay has elapsed, the shop restock hook and one random guild event picked by weight among the
+// registered events whose condition holds.
+
+#define COT_MAX_GUILD_EVENTS 16
+#define COT_MAX_STORY_BEATS 8
+
+typedef struct guild_event {
+  // Relative chance of the event being picked. Events with weight 0 are never picked.
+  int weight;
+  // May be NULL if the event is always available.
+  bool (*condition)(void);
+  void (*run)(void);
+} guild_event;
+
+typedef void (*story_beat_fn)(void);
+typedef void (*shop_restock_fn)(void);
+
+bool CotRegisterGuildEvent(guild_event event);
+// Chance in percent that a guild event happens on a given night. Defaults to 100.
+void CotSetGuildEventChance(int percent);
+// Runs `beat` at the end of the day `days` days from now (0 = tonight).
+bool CotScheduleStoryBeat(int days, story_beat_fn beat);
+void CotSetShopRestockHook(shop_restock_fn hook);
+// Ends the day. Call once per sleep transition.
+void CotRunDayEnd(void);
@@ -0,0 +1,35 @@
+#pragma once
+
+// Per-dungeon rules for what the team keeps after being defeated.
+//
+// The bag and money are snapshotted when the leader faints, before the game's defeat penalty
+// runs. The penalty is only applied once the team is back in ground mode, so restoring the parts
+// the rule keeps is up to the script that runs after a defeat: call CotApplyDefeatRule from a
+// special process there (see special_process.h).
+
+#define COT_MAX_DEFEAT_RULES 16
+#define COT_BAG_MAX_ITEMS 50
+
+typedef enum defeat_item_rule {
+  // Vanilla behavior: money and items are lost.
+  DEFEAT_LOSE_ALL = 0,
+  // Only money is lost, items are kept.
+  DEFEAT_LOSE_MONEY_ONLY,
+  // Nothing is lost.
+  DEFEAT_KEEP_ALL,
+} defeat_item_rule;
+
+// Overrides the rule for a dungeon, for example to change it based on story progress.
+// Return `configured` to keep the configured rule.
+typedef defeat_item_rule (*defeat_rule_fn)(enum dungeon_id dungeon, defeat_item_rule configured);
+
+bool CotSetDefeatItemRule(enum dungeon_id dungeon, defeat_item_rule rule);
+void CotSetDefeatItemRuleHook(defeat_rule_fn hook);
+// Returns the effective rule for `dungeon`, including the hook.
+defeat_item_rule CotGetDefeatItemRule(enum dungeon_id dungeon);
+
+// Restores the parts of the snapshotted inventory the rule for `dungeon` keeps. Does nothing if
+// there's no snapshot, i.e. the leader didn't faint since the last call.
+void CotApplyDefeatRule(enum dungeon_id dungeon);
+
+void cotInternalOnLeaderFainted(void);
@@ -0,0 +1,29 @@
+#pragma once
+
+// Mass despawn and floor clearing utilities.
+//
+// Despawned entities are removed from their tile and their entity slot is freed. Items and
+// traps are removed through the game's own functions, which also clear their tile state and
+// update the floor's graphics. Monsters are hidden and removed without fainting: no experience
+// is awarded, no items are dropped and no faint animation plays.
+//
+//   // Clear all items from room 3
+//   int room = 3;
+//   CotDespawnItems(CotFilterInRoom, &room);
+
+// Return true to despawn `entity`.
+typedef bool (*despawn_filter_fn)(struct entity* entity, void* ctx);
+
+void CotDespawnEntity(struct entity* entity);
+
+// Filter that selects entities standing in a room. `ctx` points to the room index, an int.
+bool CotFilterInRoom(struct entity* entity, void* ctx);
+
+// Despawns every monster that passes `filter` (NULL despawns all of them). Team members are
+// never despawned. Returns the number of despawned monsters.
+int CotDespawnMonsters(despawn_filter_fn filter, void* ctx);
+int CotDespawnItems(despawn_filter_fn filter, void* ctx);
+int CotDespawnTraps(despawn_filter_fn filter, void* ctx);
+
+// Removes all non-team monsters, items and traps from the floor.
+void CotClearFloor(void);
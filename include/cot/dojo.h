@@ -0,0 +1,52 @@
+#pragma once
+
+// Marowak Dojo hooks and custom training courses.
+//
+// Custom courses point at any dungeon configuration. A wrapper around the game's
+// IsMarowakTrainingMaze in hooks.c reports them as training mazes, so the game treats them like
+// the vanilla mazes wherever it checks whether a dungeon is one.
+//
+// Rewards are handed out by the Dojo's script after returning from a maze. Once CotInstallDojo
+// was called, the reward opcode (COT_SCRIPT_OPCODE_DOJO_REWARD, see script_opcodes.h) runs the
+// reward for the last training maze entered: the course's reward callback or the global reward
+// hook. It sets $CONDITION to 1 if the game's own reward should be skipped, so the script can
+// branch around it. Define COT_DOJO_OPCODE_BASE if the opcode ID collides with other extension
+// opcodes.
+
+#define COT_MAX_TRAINING_COURSES 8
+
+#ifndef COT_DOJO_OPCODE_BASE
+#define COT_DOJO_OPCODE_BASE 0x1F2
+#endif
+// Takes no parameters. Runs the reward for the last training maze entered.
+#define COT_SCRIPT_OPCODE_DOJO_REWARD (COT_DOJO_OPCODE_BASE + 0)
+
+// Hands out the reward for clearing `dungeon`. Return true to skip the game's own reward.
+typedef bool (*dojo_reward_fn)(enum dungeon_id dungeon);
+
+typedef struct training_course {
+  enum dungeon_id dungeon;
+  // Text string ID of the course name, for use in custom menus.
+  int name_string_id;
+  // May be NULL to fall back to the global reward hook.
+  dojo_reward_fn reward;
+} training_course;
+
+// Registers the reward opcode and the hook keeping track of the training maze entered. Returns
+// false if the opcode already has a handler.
+bool CotInstallDojo(void);
+
+bool CotRegisterTrainingCourse(training_course course);
+int CotGetTrainingCourseCount(void);
+const training_course* CotGetTrainingCourse(int index);
+
+// Returns true for the vanilla Dojo mazes and registered courses.
+bool CotIsTrainingMaze(enum dungeon_id dungeon);
+// Reward hook used for vanilla mazes and courses without their own callback.
+void CotSetDojoRewardHook(dojo_reward_fn hook);
+
+// Runs the reward for clearing `dungeon`. Returns true if the game's own reward should be skipped.
+bool CotRewardDojoClear(enum dungeon_id dungeon);
+
+// Returns true for registered courses. Called by the IsMarowakTrainingMaze wrapper.
+bool cotInternalIsTrainingCourse(enum dungeon_id dungeon);
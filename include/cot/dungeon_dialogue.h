@@ -0,0 +1,47 @@
+#pragma once
+
+// Scripted dialogue sequences for dungeon mode.
+//
+// A sequence is an array of steps terminated by DIALOGUE_STEP_END. While a sequence runs,
+// the turn loop is suspended: every step runs its own frame loop until it completes, so no
+// monster acts until CotRunDungeonDialogue returns.
+
+typedef enum dialogue_step_kind {
+  DIALOGUE_STEP_END = 0,
+  // Show the text string `message_id` in a dialogue box. If `speaker` is set, the portrait
+  // of `portrait_monster` (or the speaker's species if MONSTER_NONE) is shown with `emotion`.
+  DIALOGUE_STEP_MESSAGE,
+  // Move `entity` to `target`.
+  DIALOGUE_STEP_MOVE,
+  // Wait for `frames` frames.
+  DIALOGUE_STEP_WAIT,
+} dialogue_step_kind;
+
+typedef struct dialogue_step {
+  dialogue_step_kind kind;
+  struct entity* entity;
+  enum monster_id portrait_monster;
+  enum portrait_emotion emotion;
+  int message_id;
+  struct position target;
+  int frames;
+} dialogue_step;
+
+#define DIALOGUE_MESSAGE(message_id_) \
+  { .kind = DIALOGUE_STEP_MESSAGE, .message_id = (message_id_) }
+#define DIALOGUE_SAY(speaker_, emotion_, message_id_) \
+  { .kind = DIALOGUE_STEP_MESSAGE, .entity = (speaker_), .portrait_monster = MONSTER_NONE, \
+    .emotion = (emotion_), .message_id = (message_id_) }
+#define DIALOGUE_MOVE(entity_, x_, y_) \
+  { .kind = DIALOGUE_STEP_MOVE, .entity = (entity_), .target = { .x = (x_), .y = (y_) } }
+#define DIALOGUE_WAIT(frames_) \
+  { .kind = DIALOGUE_STEP_WAIT, .frames = (frames_) }
+#define DIALOGUE_END() \
+  { .kind = DIALOGUE_STEP_END }
+
+// Runs a dialogue sequence to completion. Must only be called in dungeon mode, outside of
+// another dialogue sequence. Steps referring to invalid entities are skipped.
+void CotRunDungeonDialogue(const dialogue_step* steps);
+
+// Returns true while a dialogue sequence is being played.
+bool CotIsDungeonDialogueRunning(void);
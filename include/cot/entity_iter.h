@@ -0,0 +1,54 @@
+#pragma once
+
+// Iteration over the dungeon entity table.
+//
+// Iterators only yield valid entities, so callers don't need to check EntityIsValid
+// themselves. Despawning the current entity is safe, but the active monster list must not be
+// compacted or extended while iterating over ITER_MONSTERS.
+//
+//   struct entity* entity;
+//   entity_iter it = CotIterEntities(ITER_MONSTERS);
+//   while (CotIterNext(&it, &entity)) {
+//     ...
+//   }
+//
+// The typed accessors below check the entity's type, so they can be used on any entity. They
+// return NULL or a neutral value for entities of a different type.
+
+typedef enum entity_iter_kind {
+  // All active monsters.
+  ITER_MONSTERS = 0,
+  ITER_TEAM,
+  // Enemies and non-team allies.
+  ITER_NON_TEAM,
+  ITER_ITEMS,
+  ITER_TRAPS,
+} entity_iter_kind;
+
+typedef struct entity_iter {
+  struct entity** slots;
+  int index;
+  int end;
+} entity_iter;
+
+entity_iter CotIterEntities(entity_iter_kind kind);
+bool CotIterNext(entity_iter* it, struct entity** out);
+
+#define COT_FOR_EACH_ENTITY(kind, var) \
+  for (entity_iter _cot_it_##var = CotIterEntities(kind); CotIterNext(&_cot_it_##var, &var);)
+
+// Returns the entity's data, or NULL if the entity has a different type.
+struct monster* CotGetMonster(struct entity* entity);
+struct item* CotGetItem(struct entity* entity);
+struct trap* CotGetTrap(struct entity* entity);
+
+struct position CotGetEntityPos(struct entity* entity);
+// Returns MONSTER_NONE for entities that aren't monsters.
+enum monster_id CotGetMonsterSpecies(struct entity* entity);
+// Return 0 for entities that aren't monsters.
+int CotGetMonsterHp(struct entity* entity);
+int CotGetMonsterMaxHp(struct entity* entity);
+struct statuses* CotGetMonsterStatuses(struct entity* entity);
+bool CotIsTeamMember(struct entity* entity);
+// Returns ITEM_NOTHING for entities that aren't items.
+enum item_id CotGetItemId(struct entity* entity);
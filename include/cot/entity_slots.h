@@ -0,0 +1,26 @@
+#pragma once
+
+// Entity table slot management.
+//
+// The entity table has fixed slots for monsters (team members first, then enemies and
+// allies), items and traps. A slot is free when its entity has the type ENTITY_NOTHING.
+
+#define COT_N_MONSTER_SLOTS 20
+#define COT_N_TEAM_SLOTS 4
+#define COT_N_ITEM_SLOTS 64
+#define COT_N_TRAP_SLOTS 64
+
+typedef enum entity_slot_kind {
+  SLOT_TEAM = 0,
+  SLOT_NON_TEAM,
+  SLOT_ITEM,
+  SLOT_TRAP,
+} entity_slot_kind;
+
+// Returns the index of the first free slot of the given kind, or -1 if they're all taken.
+int CotFindFreeEntitySlot(entity_slot_kind kind);
+int CotCountFreeEntitySlots(entity_slot_kind kind);
+
+// Moves the valid entries of the active monster list to the front, keeping their order,
+// and clears the rest. Returns the number of active monsters.
+int CotCompactActiveMonsters(void);
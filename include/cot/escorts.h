@@ -0,0 +1,40 @@
+#pragma once
+
+// Protected entities, like escort mission clients.
+//
+// A protected entity reports damage and fainting to its callbacks. If it faints, the escort
+// fails: the failure handler runs and CotEscortFailed returns true until the escorts are
+// cleared. CotInstallEscorts adds a pre-generate hook (see floor_gen.h) that clears them
+// before every floor, since the entities of the previous floor are gone; protect the escorts
+// of the new floor from a post-generate hook. Protected entities can also follow the leader:
+// when they're further away than their follow distance, they walk towards the leader instead
+// of acting on their own.
+//
+// Fainting is reported by the faint hooks, see faint.h. Damage is detected at the end of every
+// turn by comparing the escort's HP to the last turn, and following overrides the action the
+// game's AI chose before the action hooks run (see ai_hooks.h).
+
+#define COT_MAX_ESCORTS 4
+
+typedef struct escort_config {
+  // Distance in tiles to the leader at which the escort starts following. 0 to disable.
+  int follow_distance;
+  // All callbacks may be NULL.
+  void (*on_damaged)(struct entity* escort, int damage);
+  void (*on_fainted)(struct entity* escort);
+} escort_config;
+
+typedef void (*escort_failed_fn)(struct entity* escort);
+
+bool CotProtectEntity(struct entity* entity, escort_config config);
+void CotUnprotectEntity(struct entity* entity);
+bool CotIsProtected(struct entity* entity);
+void CotClearEscorts(void);
+bool CotInstallEscorts(void);
+
+void CotSetEscortFailedHandler(escort_failed_fn handler);
+bool CotEscortFailed(void);
+
+void cotInternalCheckEscortsDamaged(void);
+void cotInternalOnEscortFainted(struct entity* entity);
+void cotInternalRunEscortAi(struct entity* entity);
@@ -0,0 +1,39 @@
+#pragma once
+
+// Registration of item IDs beyond the vanilla item table.
+//
+// Like extended moves, an extended item borrows its data (category, prices, whether it can be
+// thrown or eaten) from a vanilla template item and has its own effect handler. Wrappers around
+// the game's item data accessors in hooks.c translate extended IDs to their template; the effect
+// handler is run by the item effect dispatcher before CustomApplyItemEffect. The game's
+// GetItemName and the icon accessors are wrapped too, so the item's own name and icon show up
+// in the game's menus and messages.
+
+#define COT_N_VANILLA_ITEMS 1400
+#define COT_MAX_EXTENDED_ITEMS 32
+
+typedef struct extended_item {
+  enum item_id id;
+  // Vanilla item whose data is used for this item.
+  enum item_id template_item;
+  // Text string ID of the item's name, 0 to use the template's.
+  int name_string_id;
+  // Item whose icon (sprite and palette) is shown, 0 to use the template's.
+  enum item_id icon_item;
+  // Applies the item's effect. May be NULL to use the template's effect.
+  void (*effect)(struct entity* user, struct entity* target, struct item* item, bool is_thrown);
+} extended_item;
+
+bool CotRegisterExtendedItem(const extended_item* item);
+const extended_item* CotGetExtendedItem(enum item_id id);
+
+// Returns the ID to read item data for: the template for extended items, `id` otherwise.
+enum item_id cotInternalResolveItemDataId(enum item_id id);
+// Returns the item whose icon is shown for `id`.
+enum item_id cotInternalResolveItemIconId(enum item_id id);
+// Returns 0 if the item has no name override.
+int cotInternalGetItemNameStringId(enum item_id id);
+// Runs the effect of an extended item. Returns true if the item was handled.
+bool cotInternalApplyExtendedItemEffect(
+  struct entity* user, struct entity* target, struct item* item, bool is_thrown
+);
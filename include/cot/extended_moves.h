@@ -0,0 +1,48 @@
+#pragma once
+
+// Registration of move IDs beyond the vanilla move table.
+//
+// An extended move borrows its data (type, category, power, accuracy, PP, critical hit chance)
+// and animation from a vanilla template move and has its own effect handler. Wrappers around the
+// game's move data and animation accessors in hooks.c translate extended IDs to their template;
+// the effect handler is run by the move effect dispatcher before CustomApplyMoveEffect.
+//
+// The game reads the name and description of move `id` from the text strings `name_base + id`
+// and `description_base + id`. Once the bases of the ROM's text file are set with
+// CotSetMoveStringBases, a wrapper around the game's StringFromId redirects those strings to the
+// extended move's own strings, or to the template's. For extended moves, these strings are past
+// the end of the vanilla blocks, and the wrapper can't tell them apart from lookups of the
+// strings actually stored there: pick IDs that land on strings the ROM doesn't use otherwise.
+
+#define COT_N_VANILLA_MOVES 559
+#define COT_MAX_EXTENDED_MOVES 32
+
+typedef struct extended_move {
+  enum move_id id;
+  // Vanilla move whose data is used for this move.
+  enum move_id template_move;
+  // Text string IDs of the move's name and description, 0 to use the template's.
+  int name_string_id;
+  int description_string_id;
+  // Move whose animation is played, 0 to use the template's.
+  enum move_id animation_move;
+  // Applies the move's effect. Returns whether damage was dealt. May be NULL to use the
+  // template's effect.
+  bool (*effect)(struct entity* user, struct entity* target, struct move* move);
+} extended_move;
+
+bool CotRegisterExtendedMove(const extended_move* move);
+const extended_move* CotGetExtendedMove(enum move_id id);
+// Sets the string IDs of the names and descriptions of move 0 in the ROM's text file.
+void CotSetMoveStringBases(int name_base, int description_base);
+
+// Returns the ID to read move data for: the template for extended moves, `id` otherwise.
+enum move_id cotInternalResolveMoveDataId(enum move_id id);
+// Returns the move whose animation is played for `id`.
+enum move_id cotInternalResolveMoveAnimationId(enum move_id id);
+// Returns the string to read in place of `string_id`, possibly `string_id` itself.
+int cotInternalResolveMoveStringId(int string_id);
+// Runs the effect of an extended move. Returns true if the move was handled.
+bool cotInternalApplyExtendedMoveEffect(
+  move_effect_input* data, struct entity* user, struct entity* target, struct move* move
+);
@@ -0,0 +1,54 @@
+#pragma once
+
+// Registration of species IDs beyond the vanilla monster table ("fakemon").
+//
+// An extended species borrows its data from a vanilla template species and can override its
+// types, abilities, strings and sprite. Inserting the actual sprites and text is left to the
+// ROM's assets. The game's accessors of the monster data and level-up tables that the spawn,
+// battle and display code go through are wrapped to resolve extended IDs, see hooks.c: stats,
+// experience, gender, dex number, sizes, mobility, recruitment, IQ group, spawn requirements,
+// movesets, types, abilities, names and sprites.
+//
+// Extended species can also replace vanilla spawns, rolled when the game picks the species of a
+// newly spawned enemy in GetMonsterIdToSpawn.
+
+#define COT_N_VANILLA_SPECIES 1155
+#define COT_MAX_EXTENDED_SPECIES 32
+// Use as `spawn_dungeon` to replace spawns in every dungeon.
+#define COT_ANY_DUNGEON DUNGEON_DUMMY_0xFF
+
+typedef struct extended_species {
+  enum monster_id id;
+  // Vanilla species whose data is used for this species.
+  enum monster_id template_species;
+  // TYPE_NONE/ABILITY_UNKNOWN keep the template's value.
+  enum type_id types[2];
+  enum ability_id abilities[2];
+  // Text string IDs, 0 to use the template's strings.
+  int name_string_id;
+  int category_string_id;
+  // Species whose sprite and portraits are loaded, 0 to use the template's.
+  enum monster_id sprite_id;
+
+  // Spawn replacement: in `spawn_dungeon`, spawns of `replaces` become this species with a
+  // `replace_chance`% chance. Leave `replaces` at MONSTER_NONE to never spawn naturally.
+  enum monster_id replaces;
+  enum dungeon_id spawn_dungeon;
+  int replace_chance;
+} extended_species;
+
+bool CotRegisterExtendedSpecies(const extended_species* species);
+const extended_species* CotGetExtendedSpecies(enum monster_id id);
+
+// Returns the ID to read monster data for: the template for extended species, `id` otherwise.
+enum monster_id cotInternalResolveSpeciesDataId(enum monster_id id);
+// Returns the species whose sprite should be loaded for `id`.
+enum monster_id cotInternalResolveSpriteId(enum monster_id id);
+// `vanilla` is the value the game read for the resolved data ID.
+enum type_id cotInternalGetSpeciesType(enum monster_id id, int idx, enum type_id vanilla);
+enum ability_id cotInternalGetSpeciesAbility(enum monster_id id, int idx, enum ability_id vanilla);
+// Return 0 if the species has no override.
+int cotInternalGetSpeciesNameStringId(enum monster_id id);
+int cotInternalGetSpeciesCategoryStringId(enum monster_id id);
+// Returns the species to spawn in place of `species`, possibly `species` itself.
+enum monster_id cotInternalRollSpeciesSpawn(enum dungeon_id dungeon, enum monster_id species);
@@ -0,0 +1,23 @@
+#pragma once
+
+// Hooks that run when a monster faints.
+//
+// Faint hooks run from a wrapper around the game's HandleFaint in hooks.c, before the game
+// removes the fainted monster, so its data can still be read. c-of-time's own modules are
+// notified first: escorts (escorts.h), outlaws (outlaws.h) and custom mission types
+// (mission_types.h), plus defeat rules (defeat_rules.h) when the leader faints. The hooks added
+// here run afterwards, in the order they were added. Monsters that are revived, e.g. by a
+// Reviver Seed, don't faint.
+
+#define COT_MAX_FAINT_HOOKS 8
+
+// `killer` is the entity that caused the faint, or NULL if there's none, e.g. for traps or
+// status conditions.
+typedef void (*faint_hook_fn)(struct entity* fainted, struct entity* killer);
+
+bool CotAddFaintHook(faint_hook_fn hook);
+void CotRemoveFaintHook(faint_hook_fn hook);
+// Returns whether `killer` is a member of the team, i.e. whether the team caused the faint.
+bool CotFaintedByTeam(struct entity* killer);
+
+void cotInternalOnMonsterFainted(struct entity* fainted, struct entity* killer);
@@ -0,0 +1,54 @@
+#pragma once
+
+// Parsing of BALANCE/fixed.bin, the layouts of the game's fixed rooms.
+//
+// The file is an SIR0 container. Its content pointer points to a list of 32-bit offsets, one
+// per fixed room ID, each pointing to a room:
+//
+//   +0x0  u16  width
+//   +0x2  u16  height
+//   +0x4  u16  floor properties of the room (unknown meaning)
+//   +0x6  u16  actions, run-length encoded, until width * height tiles are covered
+//
+// An action covers the tile in its low 12 bits and the number of times it's repeated after
+// the first tile in its high 4 bits. Tiles are listed row by row. Actions below
+// COT_N_FIXED_ROOM_ENTITY_CODES are entity codes, see fixed_room_entities.h. The list ends at
+// the first offset that doesn't point into the file, e.g. the SIR0 padding.
+//
+// The parser works on a file loaded into memory, e.g. with CotLoadFile:
+//
+//   int size;
+//   void* data = CotLoadFile("/BALANCE/fixed.bin", &size);
+//   fixed_bin bin;
+//   fixed_bin_room room;
+//   if (data != NULL && CotFixedBinOpen(&bin, data, size) && CotFixedBinGetRoom(&bin, 1, &room)) {
+//     ...
+//   }
+
+#define COT_FIXED_BIN_ROOM_HEADER_SIZE 6
+
+typedef struct fixed_bin {
+  const uint8_t* data;
+  int size;
+  // Start of the room offset list
+  const uint8_t* rooms;
+  int n_rooms;
+} fixed_bin;
+
+typedef struct fixed_bin_room {
+  int width;
+  int height;
+  uint16_t properties;
+  // Encoded actions, see above
+  const uint8_t* actions;
+  // Number of bytes left in the file after `actions`
+  int actions_size;
+} fixed_bin_room;
+
+// Returns false if `data` isn't a valid fixed.bin.
+bool CotFixedBinOpen(fixed_bin* bin, const void* data, int size);
+// Returns false if `fixed_room_id` is out of range or its room is truncated.
+bool CotFixedBinGetRoom(const fixed_bin* bin, int fixed_room_id, fixed_bin_room* out);
+// Decodes the actions of `room` into one action per tile, row by row. Returns the number of
+// tiles written, or -1 if `out` can't hold width * height tiles or the actions are truncated.
+int CotFixedBinDecodeRoom(const fixed_bin_room* room, uint16_t* out, int max);
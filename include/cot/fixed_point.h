@@ -0,0 +1,111 @@
+#pragma once
+
+// Fixed-point numbers in the format the game uses for multipliers (e.g. the weights in
+// weights.h), with 8 fractional bits.
+//
+// cot_fx32 is pmdsky-debug's fx32_8, so it can be passed to and from game functions and structs
+// directly. cot_fx16 stores the same format in 16 bits, for tables and structs where space
+// matters; do the math in cot_fx32 and convert the result back.
+//
+// Like the game, conversions to integers and multiplications round towards negative infinity.
+// Divisions round towards zero and don't need 64-bit division, which the DS has no support for.
+// Results that don't fit, including divisions by zero, saturate and log a warning.
+
+typedef fx32_8 cot_fx32;
+typedef int16_t cot_fx16;
+
+#define COT_FX32_ONE (1 << 8)
+#define COT_FX16_ONE (1 << 8)
+// Range of the integers a cot_fx32 can hold, from -2^23 to 2^23 - 1.
+#define COT_FX32_INT_MIN (INT32_MIN >> 8)
+#define COT_FX32_INT_MAX (INT32_MAX >> 8)
+
+static inline int32_t cotInternalFxSaturate(int64_t value) {
+  if (value > INT32_MAX || value < INT32_MIN) {
+    COT_WARNFMT(COT_LOG_CAT_FIXED_POINT, "Fixed-point overflow, %s saturated",
+                value > 0 ? "positive" : "negative");
+    return value > 0 ? INT32_MAX : INT32_MIN;
+  }
+  return (int32_t) value;
+}
+
+// Computes (a << shift) / b with long division.
+static inline int32_t cotInternalFxDiv(int32_t a, int32_t b, int shift) {
+  if (b == 0) {
+    COT_WARN(COT_LOG_CAT_FIXED_POINT, "Fixed-point division by zero");
+    return a == 0 ? 0 : (a > 0 ? INT32_MAX : INT32_MIN);
+  }
+  bool negative = (a < 0) != (b < 0);
+  uint32_t ua = a < 0 ? -(uint32_t) a : (uint32_t) a;
+  uint32_t ub = b < 0 ? -(uint32_t) b : (uint32_t) b;
+  // INT32_MIN has no positive counterpart
+  uint32_t limit = negative ? 0x80000000u : 0x7FFFFFFFu;
+  uint32_t q = ua / ub;
+  uint32_t r = ua % ub;
+  for (int i = 0; i < shift; i++) {
+    if (q & 0x80000000u) {
+      q = limit + 1;
+      break;
+    }
+    r <<= 1;
+    q <<= 1;
+    if (r >= ub) {
+      r -= ub;
+      q |= 1;
+    }
+  }
+  if (q > limit) {
+    COT_WARN(COT_LOG_CAT_FIXED_POINT, "Fixed-point division overflow, saturated");
+    return negative ? INT32_MIN : INT32_MAX;
+  }
+  return negative ? (int32_t) (0u - q) : (int32_t) q;
+}
+
+static inline cot_fx32 CotFx32FromInt(int value) {
+  if (value > COT_FX32_INT_MAX || value < COT_FX32_INT_MIN) {
+    COT_WARNFMT(COT_LOG_CAT_FIXED_POINT, "%d doesn't fit in a fixed-point number", value);
+    return value > 0 ? INT32_MAX : INT32_MIN;
+  }
+  return value * COT_FX32_ONE;
+}
+
+static inline int CotFx32ToInt(cot_fx32 value) {
+  return value >> 8;
+}
+
+// Rounds to the nearest integer, halves away from zero.
+static inline int CotFx32Round(cot_fx32 value) {
+  int64_t wide = value;
+  return (int) (wide >= 0 ? (wide + COT_FX32_ONE / 2) >> 8 : -((-wide + COT_FX32_ONE / 2) >> 8));
+}
+
+// `num` / `den` as a fixed-point number, e.g. CotFx32FromRatio(3, 2) for 1.5.
+static inline cot_fx32 CotFx32FromRatio(int num, int den) {
+  return cotInternalFxDiv(num, den, 8);
+}
+
+static inline cot_fx32 CotFx32Mul(cot_fx32 a, cot_fx32 b) {
+  return cotInternalFxSaturate(((int64_t) a * b) >> 8);
+}
+
+static inline cot_fx32 CotFx32Div(cot_fx32 a, cot_fx32 b) {
+  return cotInternalFxDiv(a, b, 8);
+}
+
+// Multiplies an integer by a fixed-point factor, e.g. damage by a multiplier.
+static inline int CotFx32Scale(int value, cot_fx32 factor) {
+  return cotInternalFxSaturate(((int64_t) value * factor) >> 8);
+}
+
+// Narrows to 16 bits, saturating outside of the range of cot_fx16 (-128 to just under 128).
+static inline cot_fx16 CotFx32ToFx16(cot_fx32 value) {
+  if (value > INT16_MAX || value < INT16_MIN) {
+    COT_WARN(COT_LOG_CAT_FIXED_POINT, "Fixed-point number doesn't fit in 16 bits, saturated");
+    return value > 0 ? INT16_MAX : INT16_MIN;
+  }
+  return (cot_fx16) value;
+}
+
+static inline cot_fx32 CotFx16ToFx32(cot_fx16 value) {
+  return value;
+}
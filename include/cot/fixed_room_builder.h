@@ -0,0 +1,39 @@
+#pragma once
+
+// Builder for custom fixed rooms.
+//
+// Instead of writing the text layout by hand, a fixed room can be built tile by tile. The
+// builder owns the layout and spawn list, so it must outlive the registered room; keep it in
+// static storage or allocate it with MemAlloc.
+//
+//   static fixed_room_builder builder;
+//   CotFixedRoomBuilderInit(&builder, 170, 11, 9);
+//   CotFixedRoomBuilderFillRect(&builder, 0, 0, 11, 9, '#');
+//   CotFixedRoomBuilderFillRect(&builder, 1, 1, 9, 7, '.');
+//   CotFixedRoomBuilderSetTile(&builder, 5, 7, 'P');
+//   CotFixedRoomBuilderAddMonster(&builder, 5, 2, MONSTER_GROUDON, 50);
+//   CotFixedRoomBuilderRegister(&builder);
+
+#define COT_FIXED_ROOM_MAX_WIDTH (COT_FLOOR_WIDTH - 2)
+#define COT_FIXED_ROOM_MAX_HEIGHT (COT_FLOOR_HEIGHT - 2)
+#define COT_FIXED_ROOM_MAX_SPAWNS 32
+
+typedef struct fixed_room_builder {
+  custom_fixed_room room;
+  char rows[COT_FIXED_ROOM_MAX_HEIGHT][COT_FIXED_ROOM_MAX_WIDTH + 1];
+  const char* row_ptrs[COT_FIXED_ROOM_MAX_HEIGHT];
+  fixed_room_spawn spawns[COT_FIXED_ROOM_MAX_SPAWNS];
+} fixed_room_builder;
+
+// Starts an all-floor layout of the given size.
+bool CotFixedRoomBuilderInit(fixed_room_builder* builder, int fixed_room_id, int width, int height);
+// `c` is one of the layout characters from fixed_rooms.h.
+void CotFixedRoomBuilderSetTile(fixed_room_builder* builder, int x, int y, char c);
+void CotFixedRoomBuilderFillRect(fixed_room_builder* builder, int x, int y, int w, int h, char c);
+bool CotFixedRoomBuilderAddMonster(fixed_room_builder* builder, int x, int y,
+                                   enum monster_id monster, int level);
+bool CotFixedRoomBuilderAddItem(fixed_room_builder* builder, int x, int y, enum item_id item);
+bool CotFixedRoomBuilderAddTrap(fixed_room_builder* builder, int x, int y, enum trap_id trap);
+void CotFixedRoomBuilderSetPopulate(fixed_room_builder* builder, fixed_room_populate_fn populate);
+
+bool CotFixedRoomBuilderRegister(fixed_room_builder* builder);
@@ -0,0 +1,30 @@
+#pragma once
+
+// Overrides for the table mapping fixed room entity codes to monsters, items and traps.
+//
+// Each code in a fixed room's layout refers to an entry of FIXED_ROOM_ENTITY_SPAWN_TABLE, which
+// points to shared monster, item and tile spawn entries. Overrides never modify the shared
+// entries; the entity entry is instead pointed at a copy owned by this module, so changing one
+// code doesn't affect other codes that use the same monster or item. Overrides are kept until
+// they're reset, so a boss room can be rethemed from a floor generation hook and restored later.
+
+#define COT_N_FIXED_ROOM_ENTITY_CODES 269
+#define COT_MAX_FIXED_ROOM_ENTITY_OVERRIDES 16
+
+typedef struct fixed_room_entity {
+  // MONSTER_NONE/ITEM_NOTHING/TRAP_NONE if the code doesn't spawn one.
+  enum monster_id monster;
+  enum monster_behavior behavior;
+  enum item_id item;
+  enum trap_id trap;
+} fixed_room_entity;
+
+bool CotGetFixedRoomEntity(int code, fixed_room_entity* out);
+
+bool CotSetFixedRoomMonster(int code, enum monster_id monster, enum monster_behavior behavior);
+bool CotSetFixedRoomItem(int code, enum item_id item);
+bool CotSetFixedRoomTrap(int code, enum trap_id trap);
+
+// Restores the vanilla entries of one code or of all overridden codes.
+void CotResetFixedRoomEntity(int code);
+void CotResetFixedRoomEntities(void);
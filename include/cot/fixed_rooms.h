@@ -0,0 +1,70 @@
+#pragma once
+
+// Runtime registry of custom fixed rooms.
+//
+// Custom fixed rooms are defined as text layouts, one string per row:
+//   '#' wall            'X' unbreakable wall   '.' floor
+//   '~' secondary terrain (water/lava)         '_' chasm
+//   'S' stairs          'P' team spawn
+// The layout is centered on the floor and surrounded by unbreakable walls. Monsters, items and
+// traps listed in `spawns` are placed once the tiles are in place, followed by an optional
+// callback for anything else. The whole room is validated before the floor is touched, so an
+// invalid room never leaves a half-written floor behind.
+//
+// A room without a layout reuses the layout of a fixed room from BALANCE/fixed.bin instead:
+// the game generates `base_fixed_room_id` and the room's spawns and callback are applied on
+// top. The layouts in fixed.bin can be read with the parser in fixed_bin.h.
+//
+// Custom rooms are generated from the wrapper of the game's GenerateFixedRoom in hooks.c.
+// Unregistered room IDs are left to the game.
+
+#define COT_MAX_CUSTOM_FIXED_ROOMS 16
+
+typedef enum fixed_room_spawn_kind {
+  FIXED_ROOM_SPAWN_MONSTER = 0,
+  FIXED_ROOM_SPAWN_ITEM,
+  FIXED_ROOM_SPAWN_TRAP,
+} fixed_room_spawn_kind;
+
+// Position relative to the top-left tile of the layout, or on the floor for rooms without a
+// layout.
+typedef struct fixed_room_spawn {
+  fixed_room_spawn_kind kind;
+  int x;
+  int y;
+  // monster_id, item_id or trap_id depending on `kind`.
+  int id;
+  // Only used by monsters.
+  int level;
+} fixed_room_spawn;
+
+// Called after the layout has been written. (x, y) is the top-left tile of the layout, (0, 0)
+// for rooms without a layout.
+typedef void (*fixed_room_populate_fn)(int x, int y);
+
+typedef struct custom_fixed_room {
+  int fixed_room_id;
+  int width;
+  int height;
+  // May be NULL to use the layout of `base_fixed_room_id`.
+  const char* const* layout;
+  enum fixed_room_id base_fixed_room_id;
+  const fixed_room_spawn* spawns;
+  int n_spawns;
+  // May be NULL.
+  fixed_room_populate_fn populate;
+} custom_fixed_room;
+
+bool CotRegisterFixedRoom(const custom_fixed_room* room);
+const custom_fixed_room* CotGetCustomFixedRoom(int fixed_room_id);
+
+// Returns whether the layout and spawns of `room` are valid.
+bool CotValidateFixedRoom(const custom_fixed_room* room);
+// Writes `room` onto the floor. Returns false without touching the floor if the room is
+// invalid.
+bool CotGenerateCustomFixedRoom(const custom_fixed_room* room, struct floor_properties* props);
+
+// Returns false for rooms that aren't registered. Otherwise, `result` is set to the result
+// of the generation.
+bool cotInternalDispatchGenerateFixedRoom(enum fixed_room_id fixed_room_id,
+                                          struct floor_properties* props, bool* result);
@@ -0,0 +1,33 @@
+#pragma once
+
+// Random flavor events in dungeons ("A gust of wind blew by...").
+//
+// Every few turns, one of the registered events available in the current dungeon is picked by
+// weight. An event can post a log message, play a sound effect and run a callback.
+// CotInstallFlavorEvents adds a post-generate hook (see floor_gen.h) that schedules the first
+// event of every floor; the countdown runs at the end of every turn (see ai_hooks.h).
+
+#define COT_MAX_FLAVOR_EVENTS 16
+#define COT_MAX_FLAVOR_INTERVALS 8
+
+typedef struct flavor_event {
+  // COT_ANY_DUNGEON for events that can happen anywhere.
+  enum dungeon_id dungeon;
+  // Relative chance of the event being picked.
+  int weight;
+  // May be NULL.
+  const char* message;
+  // 0 to play no sound.
+  int se_id;
+  // May be NULL.
+  void (*run)(void);
+} flavor_event;
+
+bool CotRegisterFlavorEvent(flavor_event event);
+// Sets the range of turns between two events. Applies to all dungeons without their own range.
+void CotSetFlavorEventInterval(int min_turns, int max_turns);
+bool CotSetDungeonFlavorEventInterval(enum dungeon_id dungeon, int min_turns, int max_turns);
+
+bool CotInstallFlavorEvents(void);
+
+void cotInternalUpdateFlavorEvents(void);
@@ -0,0 +1,129 @@
+#pragma once
+
+// Floor generation backends.
+//
+// A backend replaces the builtin floor generator for the floors it's enabled on. It fills the
+// dungeon's tile grid, marks rooms and sets the stairs and team spawn positions in the
+// generation info; the spawn positions of items, traps and monsters are then marked by the
+// game, like after the builtin generator.
+//
+// Pre-generate hooks run before every floor generation and may adjust the floor properties.
+// Override hooks run after the pre-generate hooks and may generate the floor themselves, like
+// a backend; returning GEN_OVERRIDE_REPLACE skips the active backend and the builtin generator.
+// Post-generate hooks run once the floor is complete, no matter which generator built it.
+// All of them run from the wrapper of the game's GenerateFloor in hooks.c.
+//
+// COT_ROOMS_GENERATOR is a backend written entirely in C that lays out rooms on a grid and
+// connects them with corridors.
+
+typedef struct floor_generator {
+  const char* name;
+  // Generates the floor. Return false if generation failed; the builtin generator will run
+  // instead.
+  bool (*generate)(struct floor_properties* props, void* ctx);
+  void* ctx;
+} floor_generator;
+
+// Configuration of COT_ROOMS_GENERATOR. Pass a pointer to it as the backend's `ctx` to change
+// the defaults.
+typedef struct rooms_generator_config {
+  // Number of grid cells, each containing one room. Clamped to 2..4 by 2..3.
+  int grid_x;
+  int grid_y;
+  int min_room_size;
+  // Number of additional dead-end hallways branching off rooms.
+  int extra_hallways;
+  // Chance in percent for each room to have its corners chipped off.
+  int imperfection_chance;
+} rooms_generator_config;
+
+extern const floor_generator COT_ROOMS_GENERATOR;
+
+// Incremental stepping of COT_ROOMS_GENERATOR, e.g. to visualize the generation process or to
+// replace single stages. The generator works on an in-memory tile buffer and doesn't touch the
+// dungeon until CotRoomsGenCommit, so the stages can also run outside of floor generation.
+#define COT_ROOMS_GEN_MAX_ROOMS 12
+// Values of rooms_gen_state::tiles besides room indexes.
+#define COT_ROOMS_GEN_WALL 0xFE
+#define COT_ROOMS_GEN_HALLWAY COT_NO_ROOM
+
+typedef enum rooms_gen_phase {
+  // Carves one room per step.
+  ROOMS_GEN_ROOMS = 0,
+  // Chips off the corners of one room per step.
+  ROOMS_GEN_IMPERFECTIONS,
+  // Connects one room to its neighbors per step.
+  ROOMS_GEN_CORRIDORS,
+  // Carves one extra hallway per step.
+  ROOMS_GEN_EXTRA_HALLWAYS,
+  // Picks the stairs and team spawn positions.
+  ROOMS_GEN_FINALIZE,
+  ROOMS_GEN_DONE,
+} rooms_gen_phase;
+
+typedef struct room_rect {
+  int x0;
+  int y0;
+  int x1;
+  int y1;
+} room_rect;
+
+typedef struct rooms_gen_state {
+  rooms_gen_phase phase;
+  int cursor;
+  int grid_x;
+  int grid_y;
+  int cell_w;
+  int cell_h;
+  int min_size;
+  int extra_hallways;
+  int imperfection_chance;
+  int n_rooms;
+  room_rect rooms[COT_ROOMS_GEN_MAX_ROOMS];
+  struct position stairs_pos;
+  struct position team_spawn_pos;
+  // Room index of every tile, or COT_ROOMS_GEN_WALL/COT_ROOMS_GEN_HALLWAY.
+  uint8_t tiles[COT_FLOOR_HEIGHT][COT_FLOOR_WIDTH];
+} rooms_gen_state;
+
+// Prepares a stepped generation. `config` may be NULL for the defaults. The state is about
+// 2 KB large, so don't keep it on the stack.
+void CotRoomsGenBegin(rooms_gen_state* state, const rooms_generator_config* config);
+// Runs the next step and returns the phase the generator is in afterwards.
+rooms_gen_phase CotRoomsGenStep(rooms_gen_state* state);
+// Replaces the dungeon's floor with the generated one. Must be called during floor generation
+// once the generator is done.
+void CotRoomsGenCommit(const rooms_gen_state* state);
+
+// Return false to reject a generated floor and retry generation.
+typedef bool (*floor_validator_fn)(void);
+
+// Selects the backend for the following floors. Pass NULL to use the builtin generator.
+void CotSetFloorGenerator(const floor_generator* generator);
+// Sets the validator for generated floors and the number of attempts before falling back to
+// the builtin generator. Rejected attempts are rolled back, see floor_snapshot.h.
+void CotSetFloorValidator(floor_validator_fn validator, int max_attempts);
+const floor_generator* CotGetFloorGenerator(void);
+
+#define COT_MAX_FLOOR_GEN_HOOKS 16
+
+typedef void (*floor_gen_hook_fn)(struct floor_properties* props);
+
+typedef enum floor_gen_override {
+  // Let the next override hook, the backend or the builtin generator build the floor.
+  GEN_OVERRIDE_KEEP = 0,
+  // The hook generated the floor, nothing else runs.
+  GEN_OVERRIDE_REPLACE,
+} floor_gen_override;
+
+typedef floor_gen_override (*floor_override_hook_fn)(struct floor_properties* props);
+
+bool CotAddPreGenerateHook(floor_gen_hook_fn hook);
+bool CotAddPostGenerateHook(floor_gen_hook_fn hook);
+void CotRemoveFloorGenHook(floor_gen_hook_fn hook);
+// Override hooks run in the order they were added, until one replaces the generator.
+bool CotAddGenerationOverrideHook(floor_override_hook_fn hook);
+void CotRemoveGenerationOverrideHook(floor_override_hook_fn hook);
+
+bool cotInternalDispatchGenerateFloor(struct floor_properties* props);
+void cotInternalOnFloorGenerated(struct floor_properties* props);
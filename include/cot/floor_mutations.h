@@ -0,0 +1,43 @@
+#pragma once
+
+// Post-processing operators for generated floors.
+//
+// Mutations transform a finished floor, no matter which generator built it. They're applied
+// in order as a pipeline, so a dungeon can stack them, e.g. erode walls, then surround rooms
+// with a moat. The stairs, the team spawn, tiles with an item or trap and tiles marked as spawn
+// positions are never covered up.
+//
+// Pipelines can be applied directly with CotApplyFloorMutations or configured per dungeon;
+// CotInstallFloorMutations adds a post-generate hook (see floor_gen.h) that runs the pipeline
+// of the current dungeon after every floor generation.
+
+#define COT_MAX_MUTATION_PIPELINES 8
+
+typedef enum floor_mutation_kind {
+  // Turns breakable walls next to open floor into floor with an `amount`% chance each.
+  MUTATE_ERODE_WALLS = 0,
+  // Widens corridors to 2 tiles.
+  MUTATE_WIDEN_CORRIDORS,
+  // Turns room tiles into walls with an `amount`% chance each. May cut off parts of rooms, but
+  // is rolled back if the stairs can't be reached anymore.
+  MUTATE_SCATTER_RUBBLE,
+  // Mirrors the whole floor horizontally, along with the room bounds, the Kecleon shop and the
+  // stairs, hidden stairs and team spawn positions.
+  MUTATE_MIRROR,
+  // Turns walls next to room tiles into secondary terrain (water or lava).
+  MUTATE_ROOM_MOAT,
+  // Calls `custom` with `amount`.
+  MUTATE_CUSTOM,
+} floor_mutation_kind;
+
+typedef struct floor_mutation {
+  floor_mutation_kind kind;
+  int amount;
+  void (*custom)(int amount);
+} floor_mutation;
+
+void CotApplyFloorMutations(const floor_mutation* mutations, int n);
+
+bool CotSetDungeonMutations(enum dungeon_id dungeon, const floor_mutation* mutations, int n);
+void CotClearDungeonMutations(enum dungeon_id dungeon);
+bool CotInstallFloorMutations(void);
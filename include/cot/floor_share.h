@@ -0,0 +1,28 @@
+#pragma once
+
+// Compact encoding of a generated floor for sharing.
+//
+// A floor code stores the terrain and flags of every tile, the room index of every open tile,
+// the floor's room data and the stairs and team spawn positions. The data is NRL-compressed
+// and protected by a CRC32, so typical floors fit in about 1 KB. Entities aren't included;
+// they're spawned as usual once a shared floor has been loaded.
+//
+// A floor code is validated completely before the floor is touched, so an invalid code never
+// leaves a half-written floor behind. Besides the checksum, the stairs and the team spawn must
+// be on open tiles, and every room index must refer to an entry of the room data.
+//
+// CotLoadFloorCode can be used as the body of a floor generation backend to play a shared floor.
+
+#define COT_FLOOR_CODE_VERSION 2
+#define COT_FLOOR_CODE_HEADER_SIZE 16
+// Tiles take 2 bits of terrain, 4 bits of flags and a room index byte for open tiles.
+#define COT_FLOOR_CODE_RAW_MAX_SIZE \
+  (COT_FLOOR_WIDTH * COT_FLOOR_HEIGHT * 7 / 4 + (int) sizeof(((struct dungeon*) 0)->room_data))
+// Upper bound of a floor code's size. NRL output is at most 1.5 times as large as its input.
+#define COT_FLOOR_CODE_MAX_SIZE \
+  (COT_FLOOR_CODE_HEADER_SIZE + COT_FLOOR_CODE_RAW_MAX_SIZE * 3 / 2 + 1)
+
+// Encodes the current floor. Returns the code's size, or -1 if `dst` is too small.
+int CotEncodeFloorCode(void* dst, int dst_capacity);
+// Validates a floor code and writes it onto the floor. Returns false if it's invalid.
+bool CotLoadFloorCode(const void* code, int size);
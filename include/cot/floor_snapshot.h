@@ -0,0 +1,30 @@
+#pragma once
+
+// Floor snapshots, to roll back floor generation.
+//
+// A snapshot holds a copy of the dungeon's tile grid and generation info. Backends generate
+// directly into the dungeon, since the game's generation functions only work on the live
+// floor, so this is no double buffering: the state from before an attempt is kept in a
+// snapshot, and a failed or rejected attempt is rolled back before the next one, so no
+// half-generated floor is left behind.
+//
+// Snapshots are large (about 36 KB) and are allocated on the game's heap.
+
+typedef struct floor_snapshot {
+  struct tile (*tiles)[COT_FLOOR_WIDTH];
+  struct dungeon_generation_info gen_info;
+} floor_snapshot;
+
+bool CotFloorSnapshotAlloc(floor_snapshot* snapshot);
+void CotFloorSnapshotFree(floor_snapshot* snapshot);
+// Copies the live floor into `snapshot`.
+void CotFloorSnapshotCapture(floor_snapshot* snapshot);
+// Replaces the live floor with the contents of `snapshot`.
+void CotFloorSnapshotRestore(const floor_snapshot* snapshot);
+
+// Runs `generator` on the live floor until `validator` accepts the result or `max_attempts` is
+// reached, restoring the snapshot taken beforehand after each rejected attempt. `validator` may
+// be NULL. Returns false and restores the previous floor if no attempt succeeded.
+bool CotGenerateFloorWithRollback(const floor_generator* generator,
+                                  struct floor_properties* props, floor_validator_fn validator,
+                                  int max_attempts);
@@ -0,0 +1,55 @@
+#pragma once
+
+// Reading files from the ROM's file system.
+//
+// Thin wrappers around the game's file stream functions, so patches can ship their own data
+// files in the ROM and load them at runtime. Paths are absolute paths into the file system,
+// e.g. "/MYMOD/table.bin". Files can also be opened by their ID in the file system, which
+// skips looking up the path every time:
+//
+//   cot_file file;
+//   if (CotFileOpen(&file, "/MYMOD/table.bin")) {
+//     CotFileRead(&file, buffer, sizeof(buffer));
+//     CotFileClose(&file);
+//   }
+//
+// Checking whether a file exists and opening files by ID need the NitroSDK functions
+// FS_ConvertPathToFileID and FS_OpenFileFast, which aren't in pmdsky-debug yet; add their
+// addresses to symbols/custom_[region].ld. Without them, CotFileOpen falls back to the game's
+// FileOpen, which stops the game if the file doesn't exist, and CotFileOpenById fails.
+
+typedef enum file_seek_origin {
+  FILE_SEEK_SET = 0,
+  FILE_SEEK_CUR = 1,
+  FILE_SEEK_END = 2,
+} file_seek_origin;
+
+// ID of a file in the file system, the NitroSDK's FSFileID.
+typedef struct cot_file_id {
+  void* archive;
+  uint32_t id;
+} cot_file_id;
+
+typedef struct cot_file {
+  struct file_stream stream;
+  bool open;
+} cot_file;
+
+// Returns false if the file doesn't exist, but only if FS_ConvertPathToFileID and
+// FS_OpenFileFast are defined, see above. Otherwise, a missing file stops the game.
+bool CotFileOpen(cot_file* file, const char* path);
+// Returns false if the file can't be opened or opening by ID isn't available.
+bool CotFileOpenById(cot_file* file, cot_file_id id);
+// Looks up the ID of the file at `path`. Returns false if it doesn't exist or looking up IDs
+// isn't available.
+bool CotFileGetId(const char* path, cot_file_id* id_out);
+void CotFileClose(cot_file* file);
+// Returns the number of bytes read.
+int CotFileRead(cot_file* file, void* buf, int size);
+void CotFileSeek(cot_file* file, int offset, file_seek_origin origin);
+int CotFileSize(cot_file* file);
+
+// Reads a whole file into a buffer allocated with MemAlloc. The caller frees it with MemFree.
+// Returns NULL if the file can't be read or the buffer can't be allocated, and if the file
+// doesn't exist under the same conditions as CotFileOpen.
+void* CotLoadFile(const char* path, int* size_out);
@@ -0,0 +1,101 @@
+#pragma once
+
+// Bounds-checked access to the temporary buffers of the builtin floor generator.
+//
+// The generator works on a grid of up to 15x15 cells, stored column by column with a fixed
+// column length of 15 cells, and on two coordinate lists holding the tile positions of the
+// grid lines (size + 1 entries each). A gen_grid bundles these buffers with their sizes so
+// patches on the generator can't silently read or write past them.
+//
+// The buffers only hold meaningful data while a floor is being generated. Outside of floor
+// generation, CotInitGenGrid refuses to wrap them and the accessors of existing grids report
+// every cell as out of bounds.
+
+#define COT_GRID_MAX_SIZE 15
+
+typedef struct gen_grid {
+  struct dungeon_grid_cell* cells;
+  int size_x;
+  int size_y;
+  // Tile X coordinates of the vertical grid lines, size_x + 1 entries.
+  int* list_x;
+  // Tile Y coordinates of the horizontal grid lines, size_y + 1 entries.
+  int* list_y;
+} gen_grid;
+
+// Returns whether a floor is being generated, including the pre- and post-generation hooks.
+bool CotIsGeneratingFloor(void);
+
+// Wraps the generator buffers. Returns false if the sizes are out of range or no floor is
+// being generated.
+bool CotInitGenGrid(gen_grid* grid, struct dungeon_grid_cell* cells, int size_x, int size_y,
+                    int* list_x, int* list_y);
+
+bool CotGenGridInBounds(const gen_grid* grid, int x, int y);
+// Returns the cell at (x, y) or NULL if it's out of bounds.
+struct dungeon_grid_cell* CotGenGridCell(const gen_grid* grid, int x, int y);
+// Returns the tile coordinate of grid line `i`, or -1 if it's out of bounds.
+int CotGenGridLineX(const gen_grid* grid, int i);
+int CotGenGridLineY(const gen_grid* grid, int i);
+
+// Sides of a grid cell, for connections between neighboring cells.
+typedef enum grid_side {
+  GRID_SIDE_TOP = 0,
+  GRID_SIDE_BOTTOM,
+  GRID_SIDE_LEFT,
+  GRID_SIDE_RIGHT,
+} grid_side;
+
+// Kind of content of a grid cell.
+typedef enum grid_cell_kind {
+  // The cell is unused on this floor.
+  GRID_CELL_INVALID = 0,
+  GRID_CELL_ROOM,
+  // A hallway anchor: a single tile that corridors pass through.
+  GRID_CELL_ANCHOR,
+} grid_cell_kind;
+
+grid_cell_kind CotGenGridCellKind(const gen_grid* grid, int x, int y);
+// Returns the tile area covered by the cell's room or anchor. Returns false if the cell is
+// out of bounds or invalid.
+bool CotGenGridCellBounds(const gen_grid* grid, int x, int y, struct position* top_left,
+                          struct position* bottom_right);
+
+bool CotGenGridIsConnected(const gen_grid* grid, int x, int y, grid_side side);
+// Connects the cell to its neighbor on `side`, setting the flags on both cells. Returns false
+// if either cell is out of bounds or invalid.
+bool CotGenGridConnect(const gen_grid* grid, int x, int y, grid_side side);
+
+// Allocates buffers for a grid on the game's heap, e.g. to run the builtin generator's stages
+// from a floor generation backend. Returns false if the sizes are out of range, no floor is
+// being generated or the allocation failed.
+bool CotAllocGenGrid(gen_grid* grid, int size_x, int size_y);
+void CotFreeGenGrid(gen_grid* grid);
+
+// Stages of the builtin generator, in the order the game runs them for standard floors. A patch
+// can run the vanilla pipeline up to a stage, change the grid with the accessors above, e.g. to
+// merge cells into one big room, and resume with the next stage. Each stage checks that the
+// grid is valid and that a floor is being generated, and returns false otherwise.
+
+// Computes the tile positions of the grid lines (GetGridPositions).
+bool CotGenGridComputePositions(const gen_grid* grid);
+// Resets every cell (InitDungeonGrid).
+bool CotGenGridInitCells(const gen_grid* grid);
+// Marks `n_rooms` random cells as rooms and the others as anchors (AssignRooms).
+bool CotGenGridAssignRooms(const gen_grid* grid, int n_rooms);
+// Places the rooms and anchors inside their cells and carves them (CreateRoomsAndAnchors).
+bool CotGenGridCreateRooms(const gen_grid* grid, uint32_t room_flags);
+// Picks connections between cells with a random walk from a random cell
+// (AssignGridCellConnections).
+bool CotGenGridAssignConnections(const gen_grid* grid, struct floor_properties* props);
+// Carves hallways for the connections and merges rooms (CreateGridCellConnections).
+bool CotGenGridCreateConnections(const gen_grid* grid, bool disable_room_merging);
+// Connects cells left unreachable by the previous stages (EnsureConnectedGrid).
+bool CotGenGridEnsureConnected(const gen_grid* grid);
+// Chips off corners of rooms (GenerateRoomImperfections).
+bool CotGenGridAddImperfections(const gen_grid* grid);
+// Adds dead-end hallways (GenerateExtraHallways).
+bool CotGenGridAddExtraHallways(const gen_grid* grid, int n_hallways);
+
+// Called from the GenerateFloor wrapper around floor generation.
+void cotInternalSetGeneratingFloor(bool generating);
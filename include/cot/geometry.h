@@ -0,0 +1,125 @@
+#pragma once
+
+// Tile positions, pixel positions and directions.
+//
+// Tile positions use the game's `struct position`; directions use the game's 8-direction
+// encoding from `enum direction_id`, counter-clockwise starting from DIR_DOWN. New APIs should
+// take and return these types instead of separate x and y arguments.
+//
+// Neighbors, lines and rectangular areas can be iterated over with the COT_FOR_EACH_* macros:
+//
+//   struct position pos;
+//   COT_FOR_EACH_NEIGHBOR(center, pos) {
+//     ...
+//   }
+
+#define COT_N_DIRECTIONS 8
+// Size of a tile in pixels.
+#define COT_TILE_PIXELS 24
+
+// Rectangle of tiles, given by its top-left corner and its size.
+typedef struct tile_area {
+  struct position pos;
+  int w;
+  int h;
+} tile_area;
+
+// Area covering the whole floor.
+#define COT_FLOOR_AREA \
+  ((tile_area) { .pos = { .x = 0, .y = 0 }, .w = COT_FLOOR_WIDTH, .h = COT_FLOOR_HEIGHT })
+
+// Position in pixels from the top-left corner of the floor.
+typedef struct pixel_pos {
+  int x;
+  int y;
+} pixel_pos;
+
+extern const struct position cotDirectionOffsets[COT_N_DIRECTIONS];
+
+static inline struct position CotDirectionOffset(enum direction_id direction) {
+  return cotDirectionOffsets[direction & 7];
+}
+
+static inline enum direction_id CotDirectionOpposite(enum direction_id direction) {
+  return (enum direction_id) ((direction + 4) & 7);
+}
+
+// Rotates counter-clockwise by `steps` eighths of a turn. Negative steps rotate clockwise.
+static inline enum direction_id CotDirectionRotate(enum direction_id direction, int steps) {
+  return (enum direction_id) ((direction + steps) & 7);
+}
+
+static inline bool CotDirectionIsDiagonal(enum direction_id direction) {
+  return (direction & 1) != 0;
+}
+
+static inline bool CotPosEqual(struct position a, struct position b) {
+  return a.x == b.x && a.y == b.y;
+}
+
+static inline struct position CotPosStep(struct position pos, enum direction_id direction) {
+  struct position offset = CotDirectionOffset(direction);
+  return (struct position) { .x = pos.x + offset.x, .y = pos.y + offset.y };
+}
+
+static inline bool CotPosInBounds(struct position pos) {
+  return CotTileInBounds(pos.x, pos.y);
+}
+
+static inline bool CotAreaContains(tile_area area, struct position pos) {
+  return pos.x >= area.pos.x && pos.x < area.pos.x + area.w && pos.y >= area.pos.y
+      && pos.y < area.pos.y + area.h;
+}
+
+// Number of king moves between two tiles.
+int CotChebyshevDistance(struct position a, struct position b);
+int CotManhattanDistance(struct position a, struct position b);
+// Returns the direction to face to look from `from` towards `to`, snapped to the closest of
+// the 8 directions. Returns false if both positions are equal.
+bool CotDirectionTowards(struct position from, struct position to, enum direction_id* out);
+
+// Returns the pixel position of the top-left corner of a tile.
+pixel_pos CotTileToPixel(struct position pos);
+// Returns the pixel position of the center of a tile.
+pixel_pos CotTileCenterToPixel(struct position pos);
+struct position CotPixelToTile(pixel_pos pos);
+
+typedef struct neighbor_iter {
+  struct position center;
+  int direction;
+} neighbor_iter;
+
+// Iterates over the 8 neighbors of a tile in direction order, skipping out-of-bounds tiles.
+neighbor_iter CotIterNeighbors(struct position center);
+bool CotNeighborNext(neighbor_iter* it, struct position* out);
+
+typedef struct line_iter {
+  struct position pos;
+  struct position to;
+  int dx;
+  int dy;
+  int err;
+  bool done;
+} line_iter;
+
+// Iterates over the tiles of a straight line, both ends included.
+line_iter CotIterLine(struct position from, struct position to);
+bool CotLineNext(line_iter* it, struct position* out);
+
+typedef struct area_iter {
+  int x0;
+  int x1;
+  int y1;
+  struct position pos;
+} area_iter;
+
+// Iterates over a rectangle in row order, clipped to the floor.
+area_iter CotIterArea(int x, int y, int w, int h);
+bool CotAreaNext(area_iter* it, struct position* out);
+
+#define COT_FOR_EACH_NEIGHBOR(center, var) \
+  for (neighbor_iter _cot_it_##var = CotIterNeighbors(center); CotNeighborNext(&_cot_it_##var, &var);)
+#define COT_FOR_EACH_ON_LINE(from, to, var) \
+  for (line_iter _cot_it_##var = CotIterLine(from, to); CotLineNext(&_cot_it_##var, &var);)
+#define COT_FOR_EACH_IN_AREA(x, y, w, h, var) \
+  for (area_iter _cot_it_##var = CotIterArea(x, y, w, h); CotAreaNext(&_cot_it_##var, &var);)
@@ -0,0 +1,42 @@
+#pragma once
+
+// Spawning and manipulating items lying on the dungeon floor, and finding items in the bag.
+//
+//   item_spawn spawn;
+//   CotItemSpawnInit(&spawn, ITEM_ORAN_BERRY);
+//   CotItemSpawnSetQuantity(&spawn, 1);
+//   CotSpawnItemAt(&spawn, 10, 12);
+//
+// Floor items can be enumerated with COT_FOR_EACH_ENTITY(ITER_ITEMS, entity). The game has no
+// unidentified item state, so only the sticky and shop flags can be changed.
+
+typedef struct item_spawn {
+  enum item_id id;
+  // Only used by stackable items.
+  int quantity;
+  bool sticky;
+  // Spawns the item as Kecleon shop merchandise.
+  bool in_shop;
+} item_spawn;
+
+void CotItemSpawnInit(item_spawn* spawn, enum item_id id);
+void CotItemSpawnSetQuantity(item_spawn* spawn, int quantity);
+void CotItemSpawnSetSticky(item_spawn* spawn, bool sticky);
+void CotItemSpawnSetInShop(item_spawn* spawn, bool in_shop);
+
+// Spawns the item on (x, y). Fails if the tile is out of bounds, not floor, or already has an
+// object on it.
+bool CotSpawnItemAt(const item_spawn* spawn, int x, int y);
+// Spawns the item on a random free floor tile, see spawn_weights.h.
+bool CotSpawnItemRandom(const item_spawn* spawn);
+
+// Returns the item lying on (x, y), or NULL.
+struct item* CotFloorItemAt(int x, int y);
+int CotCountFloorItems(void);
+
+void CotSetItemSticky(struct item* item, bool sticky);
+// Turns a floor item into Kecleon shop merchandise or back into a free item.
+void CotSetItemInShop(struct item* item, bool in_shop);
+
+// Returns the bag index of the first `item` in the bag, or -1 if there's none.
+int CotFindBagItem(enum item_id item);
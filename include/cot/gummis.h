@@ -0,0 +1,24 @@
+#pragma once
+
+// Runtime overrides for the gummi IQ and stat gains.
+//
+// Overrides are keyed by (gummi type, monster type). IQ gains are written straight into the
+// game's GUMMI_IQ_GAINS table, so they apply in dungeons and in ground mode alike. The vanilla
+// values are restored when the overrides are cleared. Stat boosts replace the amount the game
+// passes to ApplyGummiBoostsDungeonMode, so they only apply to gummis eaten in dungeons; either
+// of the monster's types can match.
+
+#define COT_MAX_GUMMI_OVERRIDES 64
+// Use as `monster_type` to match every monster type.
+#define COT_GUMMI_ANY_TYPE -1
+
+// Sets the IQ gained when a monster of `monster_type` eats a gummi of `gummi_type`. Returns
+// false if a type is out of range or the override table is full.
+bool CotSetGummiIqGain(enum type_id gummi_type, int monster_type, int iq_gain);
+// Sets how much the random stat boost raises a stat when a monster of `monster_type` eats a gummi
+// of `gummi_type` in a dungeon. The chance of getting a boost at all is left to the game.
+bool CotSetGummiStatBoost(enum type_id gummi_type, int monster_type, int stat_boost);
+void CotClearGummiOverrides(void);
+
+// `vanilla` is the stat boost the game was going to apply.
+int cotInternalGetGummiStatBoost(struct entity* target, enum type_id gummi_type, int vanilla);
@@ -0,0 +1,22 @@
+#pragma once
+
+// Hidden Power type and base power.
+//
+// Every dungeon monster stores its own Hidden Power type and base power. The roller decides
+// these values when a monster is spawned, from a wrapper around the game's SpawnMonster in
+// hooks.c; the use hook can adjust them whenever the move deals damage, from the wrapper around
+// CalcDamage (see damage.h). The use hook gets the type and power the game calculated with.
+
+typedef void (*hidden_power_roll_fn)(enum monster_id species, enum type_id* type, int* power);
+typedef void (*hidden_power_use_fn)(struct entity* user, struct entity* target,
+                                    enum type_id* type, int* power);
+
+void CotGetHiddenPower(struct entity* entity, enum type_id* type, int* power);
+void CotSetHiddenPower(struct entity* entity, enum type_id type, int power);
+
+void CotSetHiddenPowerRoller(hidden_power_roll_fn roller);
+void CotSetHiddenPowerUseHook(hidden_power_use_fn hook);
+
+void cotInternalRollHiddenPower(struct entity* entity);
+void cotInternalAdjustHiddenPower(struct entity* user, struct entity* target,
+                                  enum type_id* type, int* power);
@@ -0,0 +1,30 @@
+#pragma once
+
+// Hidden stairs leading to the Secret Bazaar or a Secret Room.
+//
+// The type and position of a floor's hidden stairs are kept in the dungeon generation info.
+// CotSpawnHiddenStairs places new hidden stairs through the game's SpawnStairs, so they
+// behave like naturally generated ones.
+//
+// The entry handler can redirect hidden stairs when the leader steps on them, e.g. to always
+// lead to a Secret Room. It runs from a wrapper around the game's HiddenStairsTrigger in
+// hooks.c, before the leader is asked whether to take the stairs.
+
+// Returns the type the stairs should lead to. HIDDEN_STAIRS_NONE keeps `type`.
+typedef enum hidden_stairs_type (*hidden_stairs_entry_fn)(enum hidden_stairs_type type, int x, int y);
+
+bool CotHiddenStairsPresent(void);
+enum hidden_stairs_type CotGetHiddenStairsType(void);
+// Returns false if the floor has no hidden stairs.
+bool CotGetHiddenStairsPosition(struct position* out);
+// Changes where the floor's hidden stairs lead. Use HIDDEN_STAIRS_SECRET_BAZAAR or
+// HIDDEN_STAIRS_SECRET_ROOM.
+void CotSetHiddenStairsType(enum hidden_stairs_type type);
+// Places hidden stairs of `type` on (x, y), replacing the floor's current hidden stairs.
+// Returns false if `type` is HIDDEN_STAIRS_NONE or the tile isn't a free floor tile.
+bool CotSpawnHiddenStairs(int x, int y, enum hidden_stairs_type type);
+
+// `handler` may be NULL to keep the game's behavior.
+void CotSetHiddenStairsEntryHandler(hidden_stairs_entry_fn handler);
+
+void cotInternalOnEnterHiddenStairs(void);
@@ -0,0 +1,45 @@
+#pragma once
+
+// Illusions: monsters that look like and are announced as a different species until they are
+// revealed.
+//
+// An illusion changes the apparent species of a monster, which the game uses for its sprite,
+// and remembers the real one. Messages naming the monster (SubstitutePlaceholderStringTags) use
+// the illusion's species as well. It's revealed when one of its reveal conditions occurs, or
+// manually with CotRevealIllusion. Illusions only last for the current floor;
+// CotClearIllusions must be called at the start of every floor.
+//
+// Damage is detected at the end of every turn (see ai_hooks.h) by comparing the monster's HP to
+// the last turn, so an illusion is revealed by any HP loss, not only by attacks. There's no reveal
+// on examining the monster: the dungeon's monster info screen isn't documented in pmdsky-debug,
+// so it can't be hooked.
+
+#define COT_MAX_ILLUSIONS 8
+
+typedef enum illusion_reveal {
+  ILLUSION_REVEAL_ON_DAMAGE = 1 << 0,
+} illusion_reveal;
+
+typedef enum illusion_reveal_reason {
+  ILLUSION_REVEALED_MANUALLY = 0,
+  ILLUSION_REVEALED_BY_DAMAGE,
+} illusion_reveal_reason;
+
+// Called after an illusion was revealed, e.g. to log a message.
+typedef void (*illusion_reveal_fn)(struct entity* entity, illusion_reveal_reason reason);
+
+// Disguises `entity` as `species`. `reveal_on` is a combination of illusion_reveal flags, 0 for
+// illusions that can only be revealed manually. Returns false if the illusion table is full.
+bool CotAddIllusion(struct entity* entity, enum monster_id species, unsigned reveal_on);
+bool CotHasIllusion(struct entity* entity);
+// Returns the species `entity` is disguised as, or its apparent species without an illusion.
+enum monster_id CotGetIllusionSpecies(struct entity* entity);
+// Restores the real appearance of `entity`. Returns false if it had no illusion.
+bool CotRevealIllusion(struct entity* entity);
+void CotSetIllusionRevealHandler(illusion_reveal_fn handler);
+// Forgets all illusions without restoring them, for the start of a new floor.
+void CotClearIllusions(void);
+
+// `vanilla` is the species the game would name the monster by.
+enum monster_id cotInternalGetAnnouncedSpecies(struct entity* entity, enum monster_id vanilla);
+void cotInternalCheckIllusionsDamaged(void);
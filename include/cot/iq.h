@@ -0,0 +1,31 @@
+#pragma once
+
+// IQ and IQ skills of dungeon monsters.
+//
+// Monsters can have any IQ skill enabled, not only the ones their IQ would unlock. Overrides
+// replace the result of the game's IQ skill checks for one skill, e.g. to make Course Checker
+// depend on something else than the skill being enabled. Overrides apply to every call of the
+// game's IqSkillIsEnabled, through its wrapper in hooks.c.
+
+#define COT_MAX_IQ_OVERRIDES 16
+#define COT_MAX_IQ 999
+
+// Returns whether the skill counts as enabled for `entity`. `enabled` is the result of the
+// game's own check. Overrides must not call IqSkillIsEnabled, which would run the override
+// again; use CotHasIqSkill instead.
+typedef bool (*iq_skill_override_fn)(struct entity* entity, enum iq_skill_id skill, bool enabled);
+
+int CotGetIq(struct entity* entity);
+// Sets the IQ, clamped to 0..COT_MAX_IQ. Doesn't change which skills are enabled.
+void CotSetIq(struct entity* entity, int iq);
+
+// Checks the skill flags, without overrides.
+bool CotHasIqSkill(struct entity* entity, enum iq_skill_id skill);
+// Checks the skill flags and applies overrides, like the game's own checks.
+bool CotIqSkillIsEnabled(struct entity* entity, enum iq_skill_id skill);
+void CotSetIqSkill(struct entity* entity, enum iq_skill_id skill, bool enabled);
+
+bool CotRegisterIqSkillOverride(enum iq_skill_id skill, iq_skill_override_fn fn);
+void CotUnregisterIqSkillOverride(enum iq_skill_id skill);
+
+bool cotInternalIqSkillIsEnabled(struct entity* entity, enum iq_skill_id skill, bool vanilla);
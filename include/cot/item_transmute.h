@@ -0,0 +1,27 @@
+#pragma once
+
+// Item polymorph and transmutation helpers.
+//
+// Transmuting an item changes its ID in place, wherever the item is stored (bag, held by a
+// monster or lying on the floor). The new item is initialized like a freshly generated one,
+// but keeps its holder and its sticky, shop and set flags, so Kecleon merchandise stays unpaid.
+// Floor items are spawned again in place, so their sprite and minimap icon show the new item.
+//
+// Random and callback-chosen targets are limited to valid vanilla items, skipping ITEM_NOTHING
+// and the unused placeholder entries of the item table.
+
+// Returns the ID an item should be turned into, or the item's own ID to keep it.
+typedef enum item_id (*transmute_fn)(struct item* item, void* ctx);
+
+// Returns whether `id` is a valid vanilla item that items can be turned into.
+bool CotIsTransmuteTarget(enum item_id id);
+void CotTransmuteItem(struct item* item, enum item_id new_id);
+// Turns the item into a random item of the same category. Returns false if none was found.
+bool CotPolymorphItem(struct item* item);
+// Turns the item into a random valid item from `pool`. Returns false if the pool has no valid
+// items.
+bool CotPolymorphItemFromPool(struct item* item, const enum item_id* pool, int n);
+
+// Applies `fn` to every item lying on the floor. Invalid IDs returned by `fn` are ignored.
+// Returns the number of changed items.
+int CotTransmuteFloorItems(transmute_fn fn, void* ctx);
@@ -0,0 +1,82 @@
+#pragma once
+
+// Kecleon shops in dungeons.
+//
+// The shop is found through the tiles the game marks as part of it; its merchandise are the
+// floor items on those tiles flagged as shop items. A stock hook can change the merchandise
+// right after the game stocked the shop, from a wrapper around the game's SpawnNonEnemies in
+// hooks.c. Prices can be scaled globally and overridden per item; they're applied by wrappers
+// around the game's GetActualBuyPrice and GetActualSellPrice.
+//
+// Shop rules force or suppress shops on specific floors. CotInstallShopRules adds a
+// pre-generate hook (see floor_gen.h) that sets the floor's shop chance accordingly.
+//
+// When the player leaves the shop without paying, the shopkeeper becomes hostile and more
+// Kecleon spawn. Thefts are detected at the end of every turn (see ai_hooks.h) by checking for
+// hostile shopkeepers. If aggression is disabled or the theft handler forgives the theft, they
+// go back to being shopkeepers, including the Kecleon the game spawned in response. The
+// theft state is reset on every new floor.
+//
+// The Kecleon the game spawns in response to a theft (the punishment spawns) are filtered from
+// a wrapper around the game's SpawnMonster: spawns past the limit, or rejected by the
+// punishment hook, are removed right away. The Kecleon species can be replaced per dungeon from
+// a wrapper around the game's GetKecleonIdToSpawnByFloor; it applies to both the shopkeeper
+// and the punishment spawns.
+
+#define COT_MAX_SHOP_RULES 16
+#define COT_MAX_SHOPKEEPER_OVERRIDES 8
+
+typedef enum shop_rule_kind {
+  SHOP_RULE_FORCE = 0,
+  SHOP_RULE_SUPPRESS,
+} shop_rule_kind;
+
+// Returns the price of `item`. `price` is the price after scaling.
+typedef int (*shop_price_fn)(struct item* item, bool selling, int price);
+// Called when a theft is detected. Return false to forgive it and keep the shopkeeper peaceful.
+typedef bool (*shop_theft_fn)(void);
+// Called for every item for sale once the game has stocked the shop of a new floor.
+typedef void (*shop_stock_fn)(struct item* item, int x, int y);
+// Called for every punishment spawn. `index` counts the punishment spawns of the floor, from 0.
+// Return false to remove the Kecleon.
+typedef bool (*shop_punishment_fn)(struct entity* kecleon, int index);
+
+bool CotFloorHasShop(void);
+// Writes the inclusive bounds of the shop to `bounds`. Returns false if there's no shop.
+bool CotGetShopBounds(room_rect* bounds);
+// Calls `fn` for every item for sale in the shop.
+void CotForEachShopItem(void (*fn)(struct item* item, int x, int y, void* ctx), void* ctx);
+// Replaces an item for sale with another one. The replacement stays for sale.
+void CotReplaceShopItem(struct item* item, enum item_id id);
+
+// `fn` may be NULL to keep the game's merchandise.
+void CotSetShopStockHook(shop_stock_fn fn);
+
+// Scales buy and sell prices by `percent`. 100 restores the game's prices.
+void CotSetShopPriceScale(int buy_percent, int sell_percent);
+// `fn` may be NULL to remove the override.
+void CotSetShopPriceOverride(shop_price_fn fn);
+
+// Use COT_ANY_FLOOR to match every floor of the dungeon.
+bool CotAddShopRule(enum dungeon_id dungeon, int floor, shop_rule_kind kind);
+bool CotInstallShopRules(void);
+
+void CotSetShopAggression(bool enabled);
+// `handler` may be NULL to keep the game's punishment.
+void CotSetShopTheftHandler(shop_theft_fn handler);
+bool CotShopTheftDetected(void);
+void CotResetShopTheft(void);
+// Limits the number of punishment spawns per floor. A negative limit keeps the game's.
+void CotSetShopPunishmentLimit(int max_spawns);
+// `fn` may be NULL to keep every punishment spawn.
+void CotSetShopPunishmentHook(shop_punishment_fn fn);
+// Replaces the shopkeeper and the punishment spawns in `dungeon`, or in all dungeons with
+// COT_ANY_DUNGEON, with `species`.
+bool CotSetShopkeeperSpecies(enum dungeon_id dungeon, enum monster_id species);
+
+void cotInternalOnShopStocked(void);
+enum monster_id cotInternalGetKecleonSpecies(enum monster_id vanilla);
+// Returns false if the spawned monster was a punishment spawn that got removed.
+bool cotInternalOnPunishmentSpawn(struct entity* entity);
+int cotInternalGetShopPrice(struct item* item, bool selling, int price);
+void cotInternalCheckShopTheft(void);
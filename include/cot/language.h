@@ -0,0 +1,47 @@
+#pragma once
+
+// Region and language detection.
+//
+// The region is fixed at build time by the REGION variable of the Makefile, which defines one
+// of COT_REGION_NA, COT_REGION_EU and COT_REGION_JA. The language is read at runtime, since the
+// EU ROM contains several languages.
+//
+// Patches can register a string table per language; CotLangString picks the string of the
+// current language and falls back to English for languages without a table.
+
+// In the order used by the DS firmware and the game.
+typedef enum cot_language {
+  LANG_JAPANESE = 0,
+  LANG_ENGLISH,
+  LANG_FRENCH,
+  LANG_GERMAN,
+  LANG_ITALIAN,
+  LANG_SPANISH,
+  COT_N_LANGUAGES,
+} cot_language;
+
+typedef enum cot_region {
+  REGION_NA = 0,
+  REGION_EU,
+  REGION_JA,
+} cot_region;
+
+static inline cot_region CotGetRegion(void) {
+#if defined(COT_REGION_EU)
+  return REGION_EU;
+#elif defined(COT_REGION_JA)
+  return REGION_JA;
+#else
+  return REGION_NA;
+#endif
+}
+
+cot_language CotGetLanguage(void);
+// Returns a two-letter code like "en", e.g. for CotSetStringsLanguage.
+const char* CotLanguageCode(cot_language language);
+// Selects the strings file section of the current language, falling back to English.
+void CotUseGameLanguageForStrings(void);
+
+bool CotRegisterLanguageTable(cot_language language, const char* const* strings, int n_strings);
+// Returns string `idx` of the current language's table, or NULL if there's none.
+const char* CotLangString(int idx);
@@ -0,0 +1,15 @@
+#pragma once
+
+// Compile-time checks of struct layouts.
+//
+// c-of-time accesses game structs through the definitions in pmdsky-debug. If those drift from
+// the game's actual layout, e.g. after updating the submodule, fields end up at the wrong
+// offsets and memory is corrupted silently. src/cot/layout.c checks the sizes and offsets that
+// c-of-time relies on, so the build fails instead. The macros can be used for your own
+// assumptions as well.
+
+#define COT_ASSERT_SIZE(type, size) \
+  _Static_assert(sizeof(type) == (size), "Unexpected size of " #type)
+
+#define COT_ASSERT_OFFSET(type, field, offset) \
+  _Static_assert(__builtin_offsetof(type, field) == (offset), "Unexpected offset of " #type "." #field)
@@ -0,0 +1,19 @@
+#pragma once
+
+// Learnset and TM compatibility queries.
+//
+// The movesets are read directly from the game's moveset data (level-up, TM/HM and egg moves)
+// through the GetMoveset*Ptr functions. Those are wrapped to resolve extended species (see
+// extended_species.h), which share the movesets of their template. Teaching and forgetting
+// moves is in move_tutor.h.
+
+// Returns the level at which `species` learns `move` by leveling up, or -1 if it doesn't.
+int CotGetLevelUpMoveLevel(enum monster_id species, enum move_id move);
+// Writes the moves `species` learns up to `max_level` into `out`, without duplicates, and
+// returns their count.
+int CotGetLevelUpMoves(enum monster_id species, int max_level, enum move_id* out, int max);
+
+bool CotCanLearnTmMove(enum monster_id species, enum move_id move);
+// Returns true if `tm` is a TM or HM whose move `species` can learn.
+bool CotCanUseTm(enum monster_id species, enum item_id tm);
+bool CotIsEggMove(enum monster_id species, enum move_id move);
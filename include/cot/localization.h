@@ -0,0 +1,31 @@
+#pragma once
+
+// Custom strings loaded from a file in the ROM, with a fallback language.
+//
+// The strings file holds one section per language, each with "key=value" lines:
+//
+//   [en]
+//   found_item=You found {}!
+//   [fr]
+//   found_item=Vous avez trouvé {} !
+//
+// Lines starting with '#' are comments. The file is read as raw bytes, so it must be saved in
+// the game's text encoding, and values can use the game's text tags and the "{}" placeholders
+// of the messages module:
+//
+//   COT_LOG_MSG(leader, CotGetString("found_item"), MSG_ITEM(ITEM_ORAN_BERRY));
+//
+// Keys missing from the current language are looked up in the fallback language of the same
+// file. Keys missing from both are returned as is, so untranslated strings are easy to spot.
+// There's no fallback to another file: whether a file exists can only be checked with the
+// optional symbols described in fs.h, so the strings file must be in the ROM.
+
+#define COT_MAX_LOCALIZED_STRINGS 256
+
+// Loads the strings file. Replaces previously loaded strings. Without the optional symbols of
+// fs.h, the game stops if the file doesn't exist.
+bool CotLoadStrings(const char* path);
+void CotUnloadStrings(void);
+// Sets the language section to use and the one to fall back to, e.g. "fr" and "en".
+void CotSetStringsLanguage(const char* language, const char* fallback);
+const char* CotGetString(const char* key);
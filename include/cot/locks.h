@@ -0,0 +1,49 @@
+#pragma once
+
+// Key items and locked content.
+//
+// Key types map an item to the locks it opens. Locks are tiles (doors) or arbitrary objects
+// (chests) registered per floor; CotTryUnlock runs the interaction flow for the lock in front
+// of a monster: it checks the bag for a matching key, asks for confirmation and opens the lock.
+// Locks must be reset with CotClearLocks on every floor.
+
+#define COT_MAX_KEY_TYPES 8
+#define COT_MAX_LOCKS 16
+#define COT_KEY_TYPE_INVALID -1
+
+typedef enum lock_kind {
+  // A wall tile that turns into floor when unlocked.
+  LOCK_DOOR = 0,
+  // Any other lockable content. Only the unlock callback is run.
+  LOCK_CHEST,
+} lock_kind;
+
+// Called after a lock has been opened.
+typedef void (*lock_open_fn)(int x, int y, lock_kind kind);
+
+typedef struct key_type {
+  enum item_id key_item;
+  // If true, the key is removed from the bag when used.
+  bool consumed;
+  // Text string IDs for the prompt flow. Set to 0 to skip a message.
+  int prompt_message_id;
+  int no_key_message_id;
+} key_type;
+
+// Registers a new key type. Returns its ID or COT_KEY_TYPE_INVALID if the table is full.
+int CotRegisterKeyType(key_type type);
+// Returns the key type using `item`, or COT_KEY_TYPE_INVALID.
+int CotGetKeyTypeForItem(enum item_id item);
+
+bool CotAddLock(int x, int y, int key_type_id, lock_kind kind, lock_open_fn on_open);
+void CotClearLocks(void);
+bool CotIsLocked(int x, int y);
+
+// Opens the lock at (x, y) without requiring a key.
+bool CotForceUnlock(int x, int y);
+// Runs the unlock flow for the lock on the tile `entity` is facing. Returns true if a lock
+// was opened.
+bool CotTryUnlock(struct entity* entity);
+
+// Opens one of the game's own Key doors at (x, y).
+void CotOpenVanillaKeyDoor(int x, int y);
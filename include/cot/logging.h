@@ -3,6 +3,69 @@
 #define COT_LOG_CAT_DEFAULT "cot"
 #define COT_LOG_CAT_SPECIAL_PROCESS "cot.special_process"
 #define COT_LOG_CAT_EFFECTS "cot.effects"
+#define COT_LOG_CAT_DIALOGUE "cot.dialogue"
+#define COT_LOG_CAT_ZONES "cot.zones"
+#define COT_LOG_CAT_SCRIPT_VARS "cot.script_vars"
+#define COT_LOG_CAT_SCRIPT_CALLS "cot.script_calls"
+#define COT_LOG_CAT_SCRIPT_OPCODES "cot.script_opcodes"
+#define COT_LOG_CAT_PUZZLES "cot.puzzles"
+#define COT_LOG_CAT_LOCKS "cot.locks"
+#define COT_LOG_CAT_QUIZ "cot.quiz"
+#define COT_LOG_CAT_TREASURE_BOXES "cot.treasure_boxes"
+#define COT_LOG_CAT_DEFEAT_RULES "cot.defeat_rules"
+#define COT_LOG_CAT_UNLOCKS "cot.unlocks"
+#define COT_LOG_CAT_DOJO "cot.dojo"
+#define COT_LOG_CAT_CAFE "cot.cafe"
+#define COT_LOG_CAT_STATS "cot.stats"
+#define COT_LOG_CAT_MINIGAMES "cot.minigames"
+#define COT_LOG_CAT_DAY_EVENTS "cot.day_events"
+#define COT_LOG_CAT_SWAP_SHOP "cot.swap_shop"
+#define COT_LOG_CAT_MOVE_LINKS "cot.move_links"
+#define COT_LOG_CAT_GUMMIS "cot.gummis"
+#define COT_LOG_CAT_SEALED_CHAMBERS "cot.sealed_chambers"
+#define COT_LOG_CAT_MOVE_TUTOR "cot.move_tutor"
+#define COT_LOG_CAT_GEN_GRID "cot.gen_grid"
+#define COT_LOG_CAT_FLOOR_GEN "cot.floor_gen"
+#define COT_LOG_CAT_BENCH "cot.bench"
+#define COT_LOG_CAT_FIXED_ROOMS "cot.fixed_rooms"
+#define COT_LOG_CAT_FIXED_BIN "cot.fixed_bin"
+#define COT_LOG_CAT_WEIGHTS "cot.weights"
+#define COT_LOG_CAT_TRAPS "cot.traps"
+#define COT_LOG_CAT_SIGNATURES "cot.signatures"
+#define COT_LOG_CAT_SAVE_DATA "cot.save_data"
+#define COT_LOG_CAT_IQ "cot.iq"
+#define COT_LOG_CAT_DATA_TABLES "cot.data_tables"
+#define COT_LOG_CAT_FIXED_POINT "cot.fixed_point"
+#define COT_LOG_CAT_AI_HOOKS "cot.ai_hooks"
+#define COT_LOG_CAT_ARENA "cot.arena"
+#define COT_LOG_CAT_DAILY "cot.daily"
+#define COT_LOG_CAT_DAMAGE "cot.damage"
+#define COT_LOG_CAT_DESPAWN "cot.despawn"
+#define COT_LOG_CAT_ESCORTS "cot.escorts"
+#define COT_LOG_CAT_SPECIES "cot.species"
+#define COT_LOG_CAT_FAINT "cot.faint"
+#define COT_LOG_CAT_FLAVOR_EVENTS "cot.flavor_events"
+#define COT_LOG_CAT_FS "cot.fs"
+#define COT_LOG_CAT_GROUND_ITEMS "cot.ground_items"
+#define COT_LOG_CAT_HIDDEN_STAIRS "cot.hidden_stairs"
+#define COT_LOG_CAT_ILLUSIONS "cot.illusions"
+#define COT_LOG_CAT_TRANSMUTE "cot.item_transmute"
+#define COT_LOG_CAT_KECLEON_SHOPS "cot.kecleon_shops"
+#define COT_LOG_CAT_LANGUAGE "cot.language"
+#define COT_LOG_CAT_LOCALIZATION "cot.localization"
+#define COT_LOG_CAT_MENUS "cot.menus"
+#define COT_LOG_CAT_MESSAGES "cot.messages"
+#define COT_LOG_CAT_MISSIONS "cot.missions"
+#define COT_LOG_CAT_OUTLAWS "cot.outlaws"
+#define COT_LOG_CAT_SCRATCH "cot.scratch"
+#define COT_LOG_CAT_SPAWN_BUILDER "cot.spawn_builder"
+#define COT_LOG_CAT_STATUSES "cot.statuses"
+#define COT_LOG_CAT_TILE_GRID "cot.tile_grid"
+#define COT_LOG_CAT_WALL_MOVEMENT "cot.wall_movement"
+#define COT_LOG_CAT_WAN "cot.wan"
+#define COT_LOG_CAT_WEATHER "cot.weather"
+#define COT_LOG_CAT_WEATHER_TERRAIN "cot.weather_terrain"
+#define COT_LOG_CAT_MINIMAP "cot.minimap"
 
 // Needs two macros for some reason
 #define _COT_INTERNAL_STRINGIZE_DETAIL(x) #x
@@ -0,0 +1,31 @@
+#pragma once
+
+// Simple option menus built on the game's simple menu windows.
+//
+// CotShowMenu opens a menu, waits until the player picks an option or cancels and closes it
+// again. It advances frames while waiting, so it can only be used where blocking is allowed,
+// e.g. from special processes or from dungeon hooks that run outside of the frame update.
+// Options are text strings from the game's string files.
+
+#define COT_MAX_MENU_OPTIONS 16
+#define COT_MENU_CANCELLED -1
+
+typedef struct menu_option {
+  int string_id;
+  // Runs when the option is picked. May be NULL.
+  void (*on_select)(int idx, void* ctx);
+} menu_option;
+
+typedef struct menu_config {
+  // Position and width in tiles. The height is derived from the number of options.
+  int x;
+  int y;
+  int width;
+  // 0 for no title.
+  int title_string_id;
+  int initial_cursor;
+  bool cancelable;
+} menu_config;
+
+// Returns the index of the picked option, or COT_MENU_CANCELLED.
+int CotShowMenu(const menu_config* config, const menu_option* options, int n_options, void* ctx);
@@ -0,0 +1,61 @@
+#pragma once
+
+// Formatting and showing messages with the game's text tags.
+//
+// Format strings can contain the game's own tags (e.g. "[CS:K]" to start a color and "[CR]" to
+// end it) and "{}" placeholders that are replaced by typed arguments in order:
+//
+//   COT_LOG_MSG(leader, "{} found the {}!", MSG_MONSTER(monster), MSG_ITEM(ITEM_ORAN_BERRY));
+//
+// Monsters are inserted through the game's string placeholders, so they get the same name
+// and coloring as in the game's own messages. Item and species names are colored the way
+// the game colors them.
+
+#define COT_MSG_BUFFER_SIZE 256
+// Number of monster arguments per message.
+#define COT_MSG_MAX_MONSTERS 4
+
+#define COT_TAG_COLOR(c) "[CS:" c "]"
+#define COT_TAG_COLOR_RESET "[CR]"
+
+typedef enum message_arg_kind {
+  MSG_ARG_STRING = 0,
+  MSG_ARG_INT,
+  MSG_ARG_MONSTER,
+  MSG_ARG_SPECIES,
+  MSG_ARG_ITEM,
+} message_arg_kind;
+
+typedef struct message_arg {
+  message_arg_kind kind;
+  union {
+    const char* string;
+    int integer;
+    struct entity* monster;
+    enum monster_id species;
+    enum item_id item;
+  };
+} message_arg;
+
+#define MSG_STRING(s) ((message_arg) { .kind = MSG_ARG_STRING, .string = (s) })
+#define MSG_INT(i) ((message_arg) { .kind = MSG_ARG_INT, .integer = (i) })
+#define MSG_MONSTER(e) ((message_arg) { .kind = MSG_ARG_MONSTER, .monster = (e) })
+#define MSG_SPECIES(id) ((message_arg) { .kind = MSG_ARG_SPECIES, .species = (id) })
+#define MSG_ITEM(id) ((message_arg) { .kind = MSG_ARG_ITEM, .item = (id) })
+
+// Expands to an argument array and its length.
+#define COT_MSG_ARGS(...) \
+  (message_arg[]) { __VA_ARGS__ }, (int) (sizeof((message_arg[]) { __VA_ARGS__ }) / sizeof(message_arg))
+
+// Needs at least one argument; plain strings can be passed to LogMessage directly.
+#define COT_LOG_MSG(entity, fmt, ...) CotLogMessage((entity), (fmt), COT_MSG_ARGS(__VA_ARGS__))
+
+// Formats `fmt` into `buf`. Returns false if the message was truncated, had too few arguments
+// or more than COT_MSG_MAX_MONSTERS monsters. NULL strings are formatted as empty strings.
+bool CotFormatMessage(char* buf, int size, const char* fmt, const message_arg* args, int n_args);
+// Like CotFormatMessage, but appends to a str_buf (see str_buf.h).
+bool CotStrBufAppendMessage(str_buf* sb, const char* fmt, const message_arg* args, int n_args);
+// Posts a formatted message to the dungeon message log.
+void CotLogMessage(struct entity* entity, const char* fmt, const message_arg* args, int n_args);
+// Shows a formatted message in an open dialogue box.
+void CotShowDialogueMessage(int dbox_id, const char* fmt, const message_arg* args, int n_args);
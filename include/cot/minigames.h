@@ -0,0 +1,72 @@
+#pragma once
+
+// Hook framework for the built-in minigames.
+//
+// The minigames are run by scripts. Once CotInstallMinigames was called, a minigame's script
+// hands its decisions to the hooks with extension opcodes (see script_opcodes.h), which keep
+// track of the minigame's state. The state can be queried with CotGetMinigameState. The opcodes,
+// listed here without their COT_SCRIPT_OPCODE_ prefix, are:
+//
+// - MINIGAME_START(kind, n_rounds, time_limit) starts a minigame with the script's number of
+//   rounds and time limit per round, in frames. $CONDITION is the number of rounds after the
+//   difficulty hook.
+// - MINIGAME_TIME_LIMIT() sets $CONDITION to the time limit after the difficulty hook.
+// - MINIGAME_ROUND() starts the next round. $CONDITION is the subject chosen by the
+//   inject_round hook, or 0 to keep the game's choice.
+// - MINIGAME_SCORE(points) adds `points` to the score. $CONDITION is the total score.
+// - MINIGAME_END() ends the minigame. $CONDITION is 1 if the reward hook decided the reward,
+//   which MINIGAME_REWARD_ITEM() and MINIGAME_REWARD_MONEY() then return.
+//
+// The same steps are available as CotMinigame* functions for use from C. Define
+// COT_MINIGAMES_OPCODE_BASE if the opcode IDs collide with other extension opcodes.
+
+#ifndef COT_MINIGAMES_OPCODE_BASE
+#define COT_MINIGAMES_OPCODE_BASE 0x1F5
+#endif
+#define COT_SCRIPT_OPCODE_MINIGAME_START (COT_MINIGAMES_OPCODE_BASE + 0)
+#define COT_SCRIPT_OPCODE_MINIGAME_TIME_LIMIT (COT_MINIGAMES_OPCODE_BASE + 1)
+#define COT_SCRIPT_OPCODE_MINIGAME_ROUND (COT_MINIGAMES_OPCODE_BASE + 2)
+#define COT_SCRIPT_OPCODE_MINIGAME_SCORE (COT_MINIGAMES_OPCODE_BASE + 3)
+#define COT_SCRIPT_OPCODE_MINIGAME_END (COT_MINIGAMES_OPCODE_BASE + 4)
+#define COT_SCRIPT_OPCODE_MINIGAME_REWARD_ITEM (COT_MINIGAMES_OPCODE_BASE + 5)
+#define COT_SCRIPT_OPCODE_MINIGAME_REWARD_MONEY (COT_MINIGAMES_OPCODE_BASE + 6)
+
+typedef enum minigame_kind {
+  MINIGAME_SENTRY_DUTY = 0,
+  COT_N_MINIGAMES,
+} minigame_kind;
+
+typedef struct minigame_state {
+  bool active;
+  minigame_kind kind;
+  int round;
+  int n_rounds;
+  // In frames.
+  int time_limit;
+  int score;
+} minigame_state;
+
+typedef struct minigame_hooks {
+  // Adjusts the number of rounds and the time limit per round (in frames).
+  void (*difficulty)(minigame_kind kind, int* n_rounds, int* time_limit);
+  // Chooses the subject of a round, e.g. the species whose footprint is shown in Sentry Duty.
+  // Return false to keep the game's choice.
+  bool (*inject_round)(minigame_kind kind, int round, int* subject);
+  // Decides the reward for a final score. Return false to keep the game's reward.
+  bool (*reward)(minigame_kind kind, int score, enum item_id* reward_item, int* reward_money);
+} minigame_hooks;
+
+// Registers the minigame opcodes. Returns false if their IDs already have handlers.
+bool CotInstallMinigames(void);
+
+void CotSetMinigameHooks(minigame_kind kind, minigame_hooks hooks);
+const minigame_state* CotGetMinigameState(void);
+
+// `n_rounds`, `time_limit`, `subject` and the rewards start at the game's values and are changed
+// by the hooks.
+void CotMinigameStart(minigame_kind kind, int* n_rounds, int* time_limit);
+void CotMinigameRound(int round, int* subject);
+// Adds `points` to the score.
+void CotMinigameScore(int points);
+// Returns true if the reward hook decided the reward.
+bool CotMinigameEnd(enum item_id* reward_item, int* reward_money);
@@ -0,0 +1,22 @@
+#pragma once
+
+// Overrides for what the minimap shows.
+//
+// Tiles can be revealed or hidden individually on top of what the player has explored, and
+// the whole floor can be forced to be revealed, like with a Luminous Orb. Overrides only apply
+// to the current floor and are reset whenever a new floor is generated.
+//
+// The overrides are applied by a wrapper around the game's DrawMinimapTile in hooks.c, which
+// draws a tile as revealed or hidden accordingly. Changed tiles are redrawn right away.
+
+void CotRevealTile(int x, int y);
+void CotHideTile(int x, int y);
+// Removes the reveal or hide override of a tile.
+void CotResetTileVisibility(int x, int y);
+void CotRevealArea(int x, int y, int w, int h);
+void CotSetMapFullyRevealed(bool revealed);
+
+void CotResetMinimap(void);
+
+bool cotInternalIsTileRevealed(int x, int y, bool vanilla);
+void cotInternalDrawMinimapTile(int x, int y, void (*draw)(int x, int y));
@@ -0,0 +1,69 @@
+#pragma once
+
+// Custom mission types.
+//
+// A custom mission type uses a type ID beyond the vanilla mission types and defines its
+// behavior through callbacks: whether the mission can be accepted, how progress is tracked in
+// the dungeon and when it's complete. Each accepted mission gets a progress counter that the
+// callbacks can use, e.g. to count defeated enemies of a certain type.
+//
+// Missions are identified by their contents rather than their place on the job list, since
+// jobs move when others are removed. Progress and completed missions can be registered as a
+// save block with CotMissionsRegisterSaveBlock, see save_data.h.
+//
+// Missions are accepted from a wrapper around the game's AddMissionToJobList, which is also what
+// CotAddMission (see missions.h) goes through. The game only knows the vanilla mission types and
+// indexes its own tables with them, so a custom mission is stored in the job list with the
+// `vanilla_type` of its type, and its custom type is tracked by c-of-time. Missions with an
+// unregistered type beyond the vanilla ones are rejected.
+//
+// Floor starts are reported after every floor generation and monster defeats by the faint
+// hooks, see faint.h. The game doesn't know about custom mission types, so checking for
+// completion and handing out the reward is up to the scripts: call CotIsMissionComplete and
+// CotClaimMissionReward from special processes (see special_process.h).
+
+#define COT_N_VANILLA_MISSION_TYPES 14
+#define COT_MAX_MISSION_TYPES 8
+#define COT_MAX_ACTIVE_MISSIONS 8
+
+typedef struct mission_progress {
+  // Copy of the accepted mission, as stored in the job list.
+  struct mission mission;
+  // Custom type of the mission. `mission.type` holds the vanilla type it's stored as.
+  int type;
+  int progress;
+  bool complete;
+} mission_progress;
+
+typedef struct custom_mission_type {
+  int id;
+  // Type the mission is stored as in the job list. It decides how the game displays the mission.
+  enum mission_type vanilla_type;
+  // All callbacks may be NULL.
+  bool (*can_accept)(struct mission* mission);
+  void (*on_floor_start)(mission_progress* progress, enum dungeon_id dungeon, int floor);
+  void (*on_monster_fainted)(mission_progress* progress, struct entity* monster);
+  // Sets the reward. Money and item start at the mission's vanilla reward.
+  void (*reward)(mission_progress* progress, int* money, enum item_id* item);
+} custom_mission_type;
+
+bool CotRegisterMissionType(const custom_mission_type* type);
+const custom_mission_type* CotGetMissionType(int id);
+// Returns the progress of an accepted custom mission, or NULL.
+mission_progress* CotGetMissionProgress(struct mission* mission);
+// Marks an accepted mission as complete, no matter whether it's a custom or vanilla type. Its job
+// list entry is marked as done as well, so the game considers it complete too.
+bool CotCompleteMission(struct mission* mission);
+// Registers the progress of accepted missions as a save block, see save_data.h.
+bool CotMissionsRegisterSaveBlock(void);
+// Returns whether `mission` was marked complete, either by its callbacks or CotCompleteMission.
+bool CotIsMissionComplete(struct mission* mission);
+// Runs the reward callback of a custom mission and forgets the mission. `money` and `item`
+// should start at the mission's vanilla reward. Returns false, without touching the reward, if
+// the mission isn't complete.
+bool CotClaimMissionReward(struct mission* mission, int* money, enum item_id* item);
+
+// Stores a custom mission as its vanilla type. Returns false if `mission` can't be accepted.
+bool cotInternalOnMissionAccepted(struct mission* mission);
+void cotInternalOnMissionFloorStart(enum dungeon_id dungeon, int floor);
+void cotInternalOnMissionMonsterFainted(struct entity* monster);
@@ -0,0 +1,41 @@
+#pragma once
+
+// Querying and creating missions on the job list.
+//
+// The job list holds the missions the player has accepted. Missions created with the builder
+// below are added to it directly, as if they had been accepted from a bulletin board, so
+// quest lines can hand out jobs from scripts:
+//
+//   mission_builder builder;
+//   CotMissionInit(&builder, MISSION_RESCUE_CLIENT, DUNGEON_BEACH_CAVE, 3);
+//   CotMissionSetClient(&builder, MONSTER_SHELLOS);
+//   CotMissionSetItemReward(&builder, ITEM_ORAN_BERRY);
+//   CotAddMission(&builder);
+//
+// Missions are completed through CotCompleteMission, which also sets their status in the job
+// list, and checked with CotIsMissionComplete, see mission_types.h.
+//
+// Only the job list can be enumerated: the game reads it through GetAcceptedMission, but
+// pmdsky-debug doesn't document where the bulletin and outlaw boards are stored or any accessor
+// for them.
+
+#define COT_JOB_LIST_SIZE 8
+
+typedef struct mission_builder {
+  struct mission mission;
+} mission_builder;
+
+void CotMissionInit(mission_builder* builder, enum mission_type type, enum dungeon_id dungeon, int floor);
+void CotMissionSetClient(mission_builder* builder, enum monster_id client);
+void CotMissionSetTarget(mission_builder* builder, enum monster_id target);
+void CotMissionSetItemReward(mission_builder* builder, enum item_id item);
+// Adds the mission to the job list. Returns NULL if the list is full or the mission was
+// rejected, see mission_types.h.
+struct mission* CotAddMission(const mission_builder* builder);
+
+// Returns the number of missions on the job list.
+int CotCountJobs(void);
+// Returns job `idx` (0 to COT_JOB_LIST_SIZE - 1), or NULL if the slot is empty.
+struct mission* CotGetJob(int idx);
+// Calls `fn` for every mission on the job list.
+void CotForEachJob(void (*fn)(struct mission* mission, void* ctx), void* ctx);
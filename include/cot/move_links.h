@@ -0,0 +1,31 @@
+#pragma once
+
+// Electivire Link Shop operations on team members.
+//
+// A link chain is a contiguous range of move slots where every move after the first has its
+// `f_linked` flag set. The validator decides which ranges can be linked; it's consulted by
+// CotLinkMoves and whenever the game copies a team member's moves into a dungeon
+// (GroundToDungeonMoveset), which breaks the chains it rejects. The Link Shop's own menu isn't
+// documented in pmdsky-debug, so links made there are checked when the member enters a dungeon
+// rather than when they're confirmed.
+
+#define COT_N_MOVE_SLOTS 4
+
+// Return false to reject linking the moves in slots [first, last] of `member`.
+typedef bool (*move_link_validator_fn)(struct ground_monster* member, int first, int last);
+
+void CotSetMoveLinkValidator(move_link_validator_fn validator);
+
+// Links slots [first, last]. Returns false if the range is invalid or rejected.
+bool CotLinkMoves(struct ground_monster* member, int first, int last);
+// Breaks the chain containing slot `slot`. Does nothing if the slot is invalid.
+void CotDelinkMoves(struct ground_monster* member, int slot);
+// Links all of the member's moves, bypassing the validator. Useful as a reward.
+void CotLinkAllMoves(struct ground_monster* member);
+// Returns the first and last slot of the chain containing `slot`, or false if the slot is
+// invalid.
+bool CotGetMoveChain(struct ground_monster* member, int slot, int* first, int* last);
+
+bool cotInternalValidateMoveLink(struct ground_monster* member, int first, int last);
+// Breaks the chains of `member` that the validator rejects.
+void cotInternalOnMovesetToDungeon(struct ground_monster* member);
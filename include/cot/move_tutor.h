@@ -0,0 +1,67 @@
+#pragma once
+
+// Move reminder and tutor flow for team members.
+//
+// The reminder offers the level-up moves a member could have learned up to its current level.
+// Tutors offer fixed move lists with an optional eligibility check; a tutor without a move list
+// acts as a reminder. Learning a move goes through CotTeachMove in both cases, so custom menus
+// only need to pick a move and a slot.
+//
+// CotRunTutor runs the whole flow a tutor placed in a hub needs: it checks that the move is
+// offered, asks the player to confirm, charges the tutor's cost in money and items and teaches
+// the move.
+
+// Pass as `slot` to CotTeachMove to use the first free slot.
+#define COT_FIRST_FREE_SLOT -1
+// Maximum number of level-up moves considered by the reminder.
+#define COT_MAX_REMINDABLE_MOVES 32
+
+// Return false if `member` can't learn `move` from the tutor.
+typedef bool (*tutor_check_fn)(struct ground_monster* member, enum move_id move);
+// Returns the money charged for teaching `move` to `member`.
+typedef int (*tutor_cost_fn)(struct ground_monster* member, enum move_id move);
+
+typedef struct move_tutor {
+  // NULL to offer the moves the reminder would.
+  const enum move_id* moves;
+  int n_moves;
+  // May be NULL if every member can learn every move.
+  tutor_check_fn can_learn;
+  // May be NULL for tutors that don't charge money.
+  tutor_cost_fn cost;
+  // Taken from the bag for every move taught, ITEM_NOTHING for none.
+  enum item_id cost_item;
+  // Yes/no question asked before teaching, 0 to skip it.
+  int prompt_message_id;
+} move_tutor;
+
+typedef enum tutor_result {
+  TUTOR_TAUGHT = 0,
+  // The tutor doesn't offer the move to the member.
+  TUTOR_NOT_OFFERED,
+  TUTOR_NOT_ENOUGH_MONEY,
+  TUTOR_MISSING_ITEM,
+  // The player declined the prompt.
+  TUTOR_DECLINED,
+  // CotTeachMove failed, e.g. because there's no free slot.
+  TUTOR_NO_SLOT,
+} tutor_result;
+
+bool CotHasMove(struct ground_monster* member, enum move_id move);
+// Teaches `move` in `slot`, replacing the move there. Fails if the member already knows the
+// move or, with COT_FIRST_FREE_SLOT, if all slots are taken.
+bool CotTeachMove(struct ground_monster* member, enum move_id move, int slot);
+// Forgets the move in `slot`. The moves after it move up a slot and the link chain it was part
+// of is broken. Fails if the slot is empty or holds the member's only move.
+bool CotForgetMove(struct ground_monster* member, int slot);
+
+// Writes the moves the reminder can offer to `member` into `out` and returns their count.
+int CotGetRemindableMoves(struct ground_monster* member, enum move_id* out, int max);
+// Writes the moves `tutor` can teach to `member` into `out` and returns their count.
+int CotGetTutorMoves(const move_tutor* tutor, struct ground_monster* member, enum move_id* out, int max);
+// Returns the money `tutor` charges for teaching `move` to `member`.
+int CotGetTutorCost(const move_tutor* tutor, struct ground_monster* member, enum move_id move);
+// Teaches `move` in `slot` through the tutor flow described above. Nothing is charged unless the
+// move is taught.
+tutor_result CotRunTutor(const move_tutor* tutor, struct ground_monster* member, enum move_id move,
+                         int slot);
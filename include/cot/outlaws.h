@@ -0,0 +1,37 @@
+#pragma once
+
+// Outlaw encounters on specific floors.
+//
+// CotInstallOutlaws adds a post-generate hook (see floor_gen.h) that spawns the registered
+// outlaws of every floor. An outlaw announces its arrival in the message log at the start of
+// the floor's first turn, once the team is there, and can be made to flee from the team. When the team defeats it, it counts as captured: its job is
+// completed and the capture callback runs. The job's reward is handed out through the mission
+// reward flow when the job is reported, like the reward of any other mission, see
+// mission_types.h. Outlaws that faint without the team's doing, e.g. from a trap, escape.
+// Defeats are reported by the faint hooks, see faint.h.
+
+#define COT_MAX_OUTLAWS 8
+
+typedef struct outlaw_encounter {
+  enum dungeon_id dungeon;
+  int floor;
+  enum monster_id species;
+  int level;
+  // ITEM_NOTHING for no item.
+  enum item_id held_item;
+  // Flees from the team instead of attacking.
+  bool flees;
+  // May be NULL.
+  const char* announcement;
+  // Job completed by capturing the outlaw, e.g. from CotAddMission. May be NULL.
+  struct mission* mission;
+  // May be NULL.
+  void (*on_captured)(const struct outlaw_encounter* outlaw);
+} outlaw_encounter;
+
+bool CotRegisterOutlaw(const outlaw_encounter* outlaw);
+bool CotInstallOutlaws(void);
+bool CotIsOutlaw(struct entity* entity);
+
+void cotInternalAnnounceOutlaws(void);
+void cotInternalOnOutlawFainted(struct entity* entity, struct entity* killer);
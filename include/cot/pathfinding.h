@@ -0,0 +1,34 @@
+#pragma once
+
+// Path queries on the dungeon floor.
+//
+// CotFindPath searches for the shortest path between two tiles with 8-directional movement,
+// following the game's rules: diagonal moves can't cut the corners of walls. Since every move
+// costs the same, a breadth-first search finds the shortest path. By default only open floor
+// tiles are walkable; a custom predicate can allow secondary terrain, walls for wall passing
+// monsters and so on.
+//
+// CotStairsReachable wraps the game's own reachability check used during floor generation.
+
+#define COT_MAX_PATH_LENGTH 128
+#define COT_UNREACHABLE -1
+
+typedef COT_VEC(struct position, COT_MAX_PATH_LENGTH) tile_path;
+
+// Returns whether (x, y) can be walked on.
+typedef bool (*path_walkable_fn)(int x, int y);
+
+// Writes the path from `from` to `to` into `path`, excluding `from` and including `to`.
+// `walkable` may be NULL for floor tiles only. Returns false if there's no path or it's longer
+// than COT_MAX_PATH_LENGTH.
+bool CotFindPath(struct position from, struct position to, path_walkable_fn walkable, tile_path* path);
+// Returns the number of moves from `from` to `to`, or COT_UNREACHABLE.
+int CotPathDistance(struct position from, struct position to, path_walkable_fn walkable);
+// Fills `distances` with the number of moves from `from` to every tile, COT_UNREACHABLE for
+// tiles that can't be reached.
+void CotDistanceMap(struct position from, path_walkable_fn walkable,
+                    int16_t distances[COT_FLOOR_HEIGHT][COT_FLOOR_WIDTH]);
+
+// Runs the game's check whether the stairs can be reached from every floor tile. With
+// `mark_unreachable`, tiles that can't reach the stairs are flagged in the tile data.
+bool CotStairsReachable(bool mark_unreachable);
@@ -0,0 +1,27 @@
+#pragma once
+
+// Customization of the personality quiz (overlay 13).
+//
+// Overrides are stored by c-of-time and copied into the quiz tables by CotApplyQuizOverrides.
+// The tables live in overlay 13, which the game only loads for the quiz, so call it from a
+// special process (see special_process.h) in the script right before the quiz starts. Entries
+// without an override keep their vanilla values.
+
+#define COT_QUIZ_N_NATURES 16
+#define COT_QUIZ_N_HERO_STARTERS (COT_QUIZ_N_NATURES * 2)
+#define COT_QUIZ_N_PARTNER_STARTERS 21
+#define COT_QUIZ_N_QUESTIONS 66
+#define COT_QUIZ_N_ANSWERS ((int) (sizeof(QUIZ_ANSWER_POINTS) / sizeof(QUIZ_ANSWER_POINTS[0])))
+#define COT_QUIZ_MAX_POINT_OVERRIDES 32
+
+// Starter chosen for a nature. Hero starters are stored as (male, female) pairs per nature.
+void CotSetQuizHeroStarter(int nature, bool female, enum monster_id monster);
+void CotSetQuizPartnerStarter(int index, enum monster_id monster);
+// Text string shown for a question.
+void CotSetQuizQuestionString(int question, int string_id);
+// Points an answer gives to a nature. Setting them again replaces the previous value. Returns
+// false if `answer` or `nature` is out of range or the override table is full.
+bool CotSetQuizAnswerPoints(int answer, int nature, uint8_t points);
+void CotResetQuizOverrides(void);
+// Writes the overrides into overlay 13. Must be called while the overlay is loaded.
+void CotApplyQuizOverrides(void);
@@ -0,0 +1,33 @@
+#pragma once
+
+// Switch and door puzzle primitives.
+//
+// Puzzle elements are wired through circuits: every switch and door belongs to a circuit, and
+// a circuit is powered while at least one of its switches is on. Doors are impassable,
+// unbreakable walls while their circuit is unpowered and open floor otherwise.
+//
+// Elements are placed by custom floor generators and tracked per floor: call
+// CotClearPuzzles on every new floor and CotUpdatePuzzles once per turn.
+
+#define COT_MAX_PUZZLE_SWITCHES 16
+#define COT_MAX_PUZZLE_DOORS 16
+#define COT_MAX_PUZZLE_CIRCUITS 16
+
+typedef enum puzzle_switch_kind {
+  // On while a monster stands on it.
+  PUZZLE_SWITCH_PRESSURE_PLATE = 0,
+  // Toggled every time a monster steps onto it.
+  PUZZLE_SWITCH_TOGGLE,
+} puzzle_switch_kind;
+
+// Called whenever a door opens or closes, e.g. to redraw the tile or play a sound.
+typedef void (*puzzle_door_fn)(int x, int y, bool open);
+
+bool CotPlacePuzzleSwitch(int x, int y, puzzle_switch_kind kind, int circuit);
+bool CotPlacePuzzleDoor(int x, int y, int circuit);
+void CotSetPuzzleDoorCallback(puzzle_door_fn on_door_changed);
+
+void CotClearPuzzles(void);
+void CotUpdatePuzzles(void);
+
+bool CotIsCircuitPowered(int circuit);
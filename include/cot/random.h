@@ -0,0 +1,40 @@
+#pragma once
+
+// Common interface to the game's random number generators.
+//
+// The dungeon RNG is used for everything that happens in dungeon mode and can be seeded, so
+// its state can be saved and restored, e.g. to generate the same floor twice or to replay a
+// seeded run. The general RNG is used everywhere else.
+//
+// While the general RNG is locked, RNG_GENERAL draws from the dungeon RNG too, so everything
+// random in c-of-time follows the dungeon seed. The lock can be limited to one dungeon, like
+// during a daily run (see daily.h), so it only applies while that dungeon is loaded.
+
+typedef enum rng_source {
+  RNG_DUNGEON = 0,
+  RNG_GENERAL,
+} rng_source;
+
+typedef struct rng_snapshot {
+  struct prng_state state;
+  uint32_t secondary_values[5];
+} rng_snapshot;
+
+// Returns a random number in [0, n).
+int CotRandInt(rng_source source, int n);
+// Returns a random number in [min, max).
+int CotRandRange(rng_source source, int min, int max);
+// Returns true with a `percent`% chance.
+bool CotRandChance(rng_source source, int percent);
+// Picks an index into `weights` with probability proportional to its weight.
+// Returns -1 if all weights are 0.
+int CotRandWeighted(rng_source source, const int* weights, int n);
+// Picks a random element of an array.
+#define COT_RAND_CHOOSE(source, array, n) ((array)[CotRandInt((source), (n))])
+
+void CotSaveDungeonRng(rng_snapshot* snapshot);
+void CotRestoreDungeonRng(const rng_snapshot* snapshot);
+void CotSeedDungeonRng(uint32_t seed);
+void CotSetGeneralRngLocked(bool locked);
+// Locks the general RNG while `dungeon` is loaded. Unlock it with CotSetGeneralRngLocked(false).
+void CotLockGeneralRngToDungeon(enum dungeon_id dungeon);
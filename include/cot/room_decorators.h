@@ -0,0 +1,57 @@
+#pragma once
+
+// Per-room decoration of generated floors.
+//
+// Decorators are called once for every room of a finished floor with the room's bounding box
+// and kind, so they can furnish rooms without scanning the whole floor themselves. Each
+// decorator can be limited to some room kinds and given a chance to apply per room.
+//
+// Decorators can be applied directly with CotApplyRoomDecorators or registered globally;
+// CotInstallRoomDecorators adds a post-generate hook (see floor_gen.h) that applies the
+// registered decorators after every floor generation.
+//
+// The builtin decorators never place anything on the stairs, the team spawn, monsters or items.
+// The statue and corner accents also keep off tiles next to corridors, so they don't change how
+// rooms are connected; the item decorator may put its item anywhere in the room.
+
+#define COT_MAX_ROOM_DECORATORS 16
+#define COT_MAX_DECORATED_ROOMS 64
+
+typedef enum room_kind {
+  ROOM_KIND_NORMAL = 0,
+  ROOM_KIND_MONSTER_HOUSE,
+  ROOM_KIND_KECLEON_SHOP,
+} room_kind;
+
+#define COT_ROOM_KIND_MASK(kind) (1 << (kind))
+#define COT_ALL_ROOM_KINDS 0xFF
+
+typedef struct room_info {
+  uint8_t room_id;
+  room_kind kind;
+  // Inclusive bounding box of the room's tiles.
+  room_rect bounds;
+  bool has_stairs;
+} room_info;
+
+typedef struct room_decorator {
+  // Mask of COT_ROOM_KIND_MASK values. 0 means COT_ALL_ROOM_KINDS.
+  uint8_t kinds;
+  // Chance in percent to decorate each room. 0 means always.
+  int chance;
+  void (*decorate)(const room_info* room, int value);
+  int value;
+} room_decorator;
+
+// Places an impassable wall in the middle of rooms at least 5x5 tiles large.
+void CotDecorateStatue(const room_info* room, int value);
+// Turns the corners of rooms at least 5x5 tiles large into secondary terrain.
+void CotDecorateCornerAccents(const room_info* room, int value);
+// Places an item with the ID `value` on a random free tile of the room.
+void CotDecorateItem(const room_info* room, int value);
+
+void CotApplyRoomDecorators(const room_decorator* decorators, int n);
+
+bool CotAddRoomDecorator(const room_decorator* decorator);
+void CotRemoveRoomDecorator(const room_decorator* decorator);
+bool CotInstallRoomDecorators(void);
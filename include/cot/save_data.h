@@ -0,0 +1,52 @@
+#pragma once
+
+// Serialization of custom save data.
+//
+// A mod declares its persistent state as a fixed-size struct and registers it as a save
+// block. All blocks are written into one chunk with a small header and a CRC32; each block is
+// tagged and versioned, so blocks can be added or removed between versions of a mod without
+// invalidating the others.
+//
+// c-of-time doesn't store the chunk: the game's save and load routines aren't hooked, so
+// registering a block doesn't persist anything on its own. This includes the blocks of
+// CotMissionsRegisterSaveBlock, CotGatesRegisterSaveBlock and CotStatsRegisterSaveBlock. A mod
+// that stores the chunk, e.g. from its own patches on the save and load code, serializes it with
+// CotWriteSaveData and restores it with CotReadSaveData.
+//
+// If the chunk is missing or damaged, e.g. on a new save, the blocks keep the contents they
+// had before loading, so initialize them with their defaults.
+
+#define COT_MAX_SAVE_BLOCKS 16
+#define COT_SAVE_DATA_HEADER_SIZE 12
+#define COT_SAVE_BLOCK_HEADER_SIZE 8
+
+#define COT_SAVE_TAG(a, b, c, d) \
+  ((uint32_t) (a) | ((uint32_t) (b) << 8) | ((uint32_t) (c) << 16) | ((uint32_t) (d) << 24))
+
+// Called when a block with a different version or size is loaded. `old` holds the saved
+// contents; `data` still holds the block's current contents.
+typedef void (*save_block_migrate_fn)(void* data, const void* old, uint16_t old_version, uint16_t old_size);
+
+typedef struct save_block {
+  // Unique tag of the block, see COT_SAVE_TAG.
+  uint32_t tag;
+  // Bump this whenever the layout of `data` changes.
+  uint16_t version;
+  uint16_t size;
+  void* data;
+  // May be NULL to discard saved data of other versions.
+  save_block_migrate_fn migrate;
+} save_block;
+
+// Registers a block. `block` must stay valid. Returns false if the tag is already registered
+// or the block table is full.
+bool CotRegisterSaveBlock(const save_block* block);
+void CotUnregisterSaveBlock(uint32_t tag);
+// Size of the chunk with all registered blocks.
+int CotSaveDataSize(void);
+
+// Writes all blocks into `buf`. Returns the number of bytes written, or -1 if `capacity` is
+// too small.
+int CotWriteSaveData(void* buf, int capacity);
+// Restores all blocks found in `buf`. Returns false if there's no valid chunk.
+bool CotReadSaveData(const void* buf, int size);
@@ -0,0 +1,36 @@
+#pragma once
+
+// Named scratch buffers shared between subsystems.
+//
+// Scratch buffers are allocated from one cot_arena (see arena.h) with a fixed budget, so
+// subsystems that need temporary space, like replay capture or map export, don't compete for
+// the game's memory independently. Each buffer has a name, so unrelated code can't clash over
+// the same space, and a lifetime: dungeon buffers are freed by CotScratchEndDungeon, which
+// CotScratchInit hooks into floor generation (see floor_gen.h), so it runs when the first
+// floor of the next dungeon is generated; session buffers live until they're released.
+//
+//   uint8_t* map = CotScratchGet("map_export", 1024, SCRATCH_DUNGEON);
+
+#define COT_MAX_SCRATCH_BUFFERS 16
+
+typedef enum scratch_lifetime {
+  SCRATCH_DUNGEON = 0,
+  SCRATCH_SESSION,
+} scratch_lifetime;
+
+// Sets up the arena the buffers are allocated from and adds the pre-generate hook that frees
+// the dungeon buffers. Must be called before the first buffer is requested. `mem_arena` may be
+// NULL for the default heap.
+void CotScratchInit(struct mem_arena* mem_arena, uint32_t budget);
+
+// Returns the buffer called `name`, allocating it if it doesn't exist yet. `name` must stay
+// valid, e.g. a string literal. Returns NULL if the buffer exists with a different size or
+// there isn't enough space.
+void* CotScratchGet(const char* name, uint32_t size, scratch_lifetime lifetime);
+// Returns the buffer called `name`, or NULL if it doesn't exist.
+void* CotScratchFind(const char* name);
+void CotScratchRelease(const char* name);
+// Frees all buffers with the SCRATCH_DUNGEON lifetime. Can be called early, e.g. right after
+// leaving a dungeon.
+void CotScratchEndDungeon(void);
+arena_stats CotScratchStats(void);
@@ -0,0 +1,51 @@
+#pragma once
+
+// Running script routines from C.
+//
+// The game has no entry point for running an arbitrary routine from native code, so calls are
+// handed over to the script engine through a mailbox: CotCallScriptRoutine stores the request
+// and a polling loop in a script (usually in unionall) picks it up through special processes:
+//
+//   ProcessSpecial(COT_SP_SCRIPT_CALL_POLL, 0, 0)      -> routine ID to run, 0 if none
+//   ProcessSpecial(COT_SP_SCRIPT_CALL_ARG, index, 0)   -> argument `index` of the pending call
+//   ProcessSpecial(COT_SP_SCRIPT_CALL_DONE, result, 0) -> marks the call as finished
+//
+// The script is expected to switch on the polled routine ID and run the matching routine before
+// reporting completion.
+//
+// The special process IDs are reserved and can't be registered with CotRegisterSpecialProcess.
+// Define COT_SP_SCRIPT_CALL_BASE, e.g. with `EXTRA_CFLAGS=-DCOT_SP_SCRIPT_CALL_BASE=300`, if
+// they collide with existing processes.
+
+#ifndef COT_SP_SCRIPT_CALL_BASE
+#define COT_SP_SCRIPT_CALL_BASE 200
+#endif
+#define COT_SP_SCRIPT_CALL_POLL (COT_SP_SCRIPT_CALL_BASE + 0)
+#define COT_SP_SCRIPT_CALL_ARG (COT_SP_SCRIPT_CALL_BASE + 1)
+#define COT_SP_SCRIPT_CALL_DONE (COT_SP_SCRIPT_CALL_BASE + 2)
+
+#define COT_SCRIPT_CALL_MAX_ARGS 4
+
+typedef enum script_call_state {
+  SCRIPT_CALL_IDLE = 0,
+  // Waiting for the script side to poll the request.
+  SCRIPT_CALL_PENDING,
+  // The script side is running the routine.
+  SCRIPT_CALL_RUNNING,
+  // The routine finished. The result can be read with CotGetScriptCallResult.
+  SCRIPT_CALL_DONE,
+} script_call_state;
+
+// Called once the script side reports completion.
+typedef void (*script_call_done_fn)(int routine_id, int result);
+
+// Requests that the routine `routine_id` (must be non-zero) is run with up to
+// COT_SCRIPT_CALL_MAX_ARGS arguments. `on_done` may be NULL. Returns false if another
+// call is still in progress.
+bool CotCallScriptRoutine(int routine_id, const int* args, int n_args, script_call_done_fn on_done);
+script_call_state CotGetScriptCallState(void);
+// Returns the result of the last finished call and resets the mailbox to SCRIPT_CALL_IDLE.
+int CotGetScriptCallResult(void);
+
+// Handles the special processes listed above. Called by the special process dispatcher.
+bool cotInternalHandleScriptCallSpecialProcess(uint32_t special_process_id, short arg1, int* return_val);
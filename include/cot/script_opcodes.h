@@ -0,0 +1,38 @@
+#pragma once
+
+// Handlers for unused or extension script opcodes.
+//
+// A wrapper around the game's RunNextOpcode in hooks.c looks at the opcode a script routine is
+// about to run. If a handler is registered for it, the handler runs instead of the game's
+// implementation, the routine skips over the opcode and its parameters, and the game carries on
+// with the next opcode, or wherever the handler jumped to. Each extension opcode takes a fixed
+// number of parameters, given when its handler is registered.
+//
+// The handler's return value is stored in $CONDITION (VAR_CONDITION), which the script can
+// branch on like on the result of the game's own opcodes.
+
+#define COT_MAX_SCRIPT_OPCODES 16
+
+typedef struct script_opcode_context {
+  // Routine running the opcode.
+  struct script_routine* routine;
+  uint16_t opcode;
+  // Raw opcode parameters as stored in the script.
+  const uint16_t* args;
+  int n_args;
+  // Value stored in $CONDITION. Defaults to 0.
+  int return_val;
+  // Where the routine carries on. Defaults to NULL, for the opcode after this one.
+  const uint16_t* jump_to;
+} script_opcode_context;
+
+typedef void (*script_opcode_fn)(script_opcode_context* ctx);
+
+// Registers a handler for an opcode that takes `n_args` parameters. Returns false if the opcode
+// already has a handler or the handler table is full.
+bool CotRegisterScriptOpcode(uint16_t opcode, int n_args, script_opcode_fn handler);
+void CotUnregisterScriptOpcode(uint16_t opcode);
+
+// Runs the handler for the next opcode of `routine` and moves past it. Returns false if the
+// opcode has no handler.
+bool cotInternalDispatchScriptOpcode(struct script_routine* routine);
@@ -0,0 +1,69 @@
+#pragma once
+
+// Running individual script opcodes from C.
+//
+// Scripts hand control to C code with the queue opcode (COT_SCRIPT_OPCODE_QUEUE_RUN, handled
+// through the opcode registry of script_opcodes.h once CotInstallScriptQueue was called). When
+// a routine reaches it, the next queued opcode is copied into a buffer and the routine jumps
+// there, so the script engine runs it like any opcode of the script, including waiting for its
+// completion. A resume opcode placed after it finishes the command, runs its `on_done`
+// callback and jumps back to the queue opcode, which runs the next command. Once the queue is
+// empty, the routine carries on after the queue opcode. This allows writing the control flow
+// of a cutscene in C, e.g. from a special process the script calls right before the queue
+// opcode:
+//
+//   static const uint16_t move_args[] = { 96, 160, 2 };
+//   CotQueueScriptOpcode(OPCODE_MOVE, move_args, 3, OnMoved);
+//
+// Opcode IDs and parameters are those of the game's opcode table, as listed by SkyTemple.
+// Opcodes run from the buffer rather than from the script file, so opcodes with string or jump
+// parameters, which refer to the script file, can't be queued. Opcodes targeting an actor or
+// object have to run in a routine of that actor or object, since they act on the routine's
+// owner.
+//
+// Once installed, the opcode IDs can't be registered with CotRegisterScriptOpcode. Define
+// COT_SCRIPT_QUEUE_OPCODE_BASE, e.g. with `EXTRA_CFLAGS=-DCOT_SCRIPT_QUEUE_OPCODE_BASE=0x1E0`,
+// if they collide with other extension opcodes.
+
+#ifndef COT_SCRIPT_QUEUE_OPCODE_BASE
+#define COT_SCRIPT_QUEUE_OPCODE_BASE 0x1F0
+#endif
+// Takes no parameters. Runs the queued commands. $CONDITION keeps the result of the last one.
+#define COT_SCRIPT_OPCODE_QUEUE_RUN (COT_SCRIPT_QUEUE_OPCODE_BASE + 0)
+// Placed after each queued opcode. Not meant to be used in scripts.
+#define COT_SCRIPT_OPCODE_QUEUE_RESUME (COT_SCRIPT_QUEUE_OPCODE_BASE + 1)
+
+#define COT_SCRIPT_QUEUE_SIZE 16
+#define COT_SCRIPT_CMD_MAX_ARGS 8
+
+typedef struct script_cmd script_cmd;
+
+// Called once the script engine has finished running `cmd`.
+typedef void (*script_cmd_done_fn)(const script_cmd* cmd);
+
+struct script_cmd {
+  uint16_t opcode;
+  uint16_t args[COT_SCRIPT_CMD_MAX_ARGS];
+  int n_args;
+  // May be NULL.
+  script_cmd_done_fn on_done;
+};
+
+// Registers the queue opcodes. Returns false if their IDs already have handlers.
+bool CotInstallScriptQueue(void);
+
+bool CotQueueScriptCommand(script_cmd cmd);
+// Queues `opcode` with `n_args` raw parameters. `on_done` may be NULL.
+bool CotQueueScriptOpcode(uint16_t opcode, const uint16_t* args, int n_args,
+                          script_cmd_done_fn on_done);
+
+// Returns the command the script engine is running, or NULL if there's none.
+const script_cmd* CotGetRunningScriptCommand(void);
+// Returns the routine running the queued commands, or NULL if there's none.
+struct script_routine* CotGetScriptQueueRoutine(void);
+// Returns true once every queued command has been run.
+bool CotScriptQueueIdle(void);
+// Returns the number of commands not yet finished, including the running one.
+int CotScriptQueueLength(void);
+// Drops the commands that haven't started yet. A running command still finishes.
+void CotClearScriptQueue(void);
@@ -0,0 +1,13 @@
+#pragma once
+
+// Access to the script engine's global variables (enum script_var_id in pmdsky-debug).
+//
+// Only global variables can be accessed this way. Local variables (VAR_LOCAL0 and above)
+// belong to a running script and are rejected.
+
+int CotGetScriptVariableValue(enum script_var_id id);
+void CotSetScriptVariableValue(enum script_var_id id, int value);
+
+// Same as above, for array variables such as VAR_SCENARIO_MAIN.
+int CotGetScriptVariableValueAtIndex(enum script_var_id id, int index);
+void CotSetScriptVariableValueAtIndex(enum script_var_id id, int index, int value);
@@ -0,0 +1,58 @@
+#pragma once
+
+// Collectible gates for multi-part dungeon puzzles, like the Unown relics of Aegis Cave.
+//
+// A gate has a number of pieces and a passage: a rectangle of wall tiles on one floor of a
+// dungeon. Pieces are collected by picking up their item or with CotCollectGatePiece, e.g. from
+// a script or a custom effect. Once every piece is collected the gate opens for good: the
+// passage is carved out right away if the team is on the gate's floor and every time the floor
+// is generated again.
+//
+// Picking up pieces is checked at the end of every turn, see ai_hooks.h. The progress of all
+// gates can be registered as a save block with CotGatesRegisterSaveBlock, see save_data.h.
+
+#define COT_MAX_GATES 8
+#define COT_MAX_GATE_PIECES 16
+#define COT_GATE_INVALID -1
+
+typedef void (*gate_opened_fn)(int gate_id);
+
+typedef struct gate_piece {
+  // Item that collects the piece when it's in the bag. ITEM_NOTHING if the piece can only be
+  // collected with CotCollectGatePiece.
+  enum item_id item;
+  // Whether the item is removed from the bag when the piece is collected.
+  bool consumed;
+} gate_piece;
+
+typedef struct gate {
+  enum dungeon_id dungeon;
+  // Floor with the passage, or COT_ANY_FLOOR to open it on every floor of the dungeon.
+  int floor;
+  int n_pieces;
+  gate_piece pieces[COT_MAX_GATE_PIECES];
+  // Top-left corner and size of the passage. Its tiles are walls until the gate opens.
+  struct position passage;
+  int passage_w;
+  int passage_h;
+  // May be NULL.
+  gate_opened_fn on_opened;
+} gate;
+
+// Registers a gate and returns its ID, or COT_GATE_INVALID if the gate table is full or the
+// gate is invalid. IDs are assigned in registration order, so register gates in the same order
+// every time to keep saved progress.
+int CotRegisterGate(const gate* g);
+const gate* CotGetGate(int gate_id);
+
+// Collects a piece. Returns false if the ID is invalid or the piece was already collected.
+bool CotCollectGatePiece(int gate_id, int piece);
+bool CotHasGatePiece(int gate_id, int piece);
+// Returns the number of collected pieces.
+int CotGetGateProgress(int gate_id);
+bool CotGateIsOpen(int gate_id);
+// Forgets all collected pieces of a gate and closes it again.
+void CotResetGate(int gate_id);
+
+// Registers the progress of all gates as a save block, see save_data.h.
+bool CotGatesRegisterSaveBlock(void);
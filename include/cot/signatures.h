@@ -0,0 +1,50 @@
+#pragma once
+
+// Address signature self-checks.
+//
+// Custom symbols in symbols/custom_[region].ld are plain addresses, so patching a ROM of the
+// wrong region or revision makes calls end up in unrelated code. A signature lists the bytes
+// expected at an address; CotCheckSignatures compares them and reports every mismatch.
+//
+// COT_SIGNATURE_TABLE declares the signatures to check at boot, usually wrapped in
+// #ifdef COT_REGION_NA and friends since the bytes differ between regions:
+//
+//   COT_SIGNATURE_TABLE(
+//     COT_SIGNATURE(MyCustomSymbol, 0x12, 0x34, 0x56, 0x78),
+//   );
+//
+// The table is checked the first time the game calls into c-of-time: a special process, an item
+// or move effect, or a floor generation. Only put arm9 addresses in the table; the overlay
+// needed by a symbol might not be loaded at that point, so check overlay signatures with
+// CotCheckSignatures from a hook inside the overlay instead.
+//
+// The addresses c-of-time patches itself are checked when the ROM is patched, see cot_expect in
+// patches/internal.asm. The same macro can check the addresses of your own patches.
+
+#define COT_SIGNATURE_MAX_BYTES 16
+
+typedef struct address_signature {
+  const char* name;
+  const void* addr;
+  uint8_t bytes[COT_SIGNATURE_MAX_BYTES];
+  uint8_t n_bytes;
+} address_signature;
+
+#define COT_SIGNATURE(symbol, ...) \
+  { \
+    .name = #symbol, \
+    .addr = (const void*) &symbol, \
+    .bytes = { __VA_ARGS__ }, \
+    .n_bytes = sizeof((uint8_t[]) { __VA_ARGS__ }), \
+  }
+
+#define COT_SIGNATURE_TABLE(...) \
+  const address_signature cotSignatureTable[] = { __VA_ARGS__ }; \
+  const int cotSignatureTableSize = sizeof(cotSignatureTable) / sizeof(address_signature)
+
+// Returns the number of signatures that don't match. Each mismatch is logged as an error.
+int CotCheckSignatures(const address_signature* signatures, int n);
+
+// Checks the COT_SIGNATURE_TABLE once and stops the game with an error if any signature doesn't
+// match, even in release builds. Later calls do nothing.
+void cotInternalRunBootSelfCheck(void);
@@ -0,0 +1,32 @@
+#pragma once
+
+// Builder for spawning monsters in dungeon mode.
+//
+//   monster_spawn spawn;
+//   CotSpawnInit(&spawn, MONSTER_PIKACHU);
+//   CotSpawnSetLevel(&spawn, 20);
+//   CotSpawnSetPosition(&spawn, 10, 12);
+//   struct entity* entity = CotSpawn(&spawn);
+//
+// Without an explicit position, the monster is placed on a random free floor tile, never on the
+// stairs or the team spawn.
+
+typedef struct monster_spawn {
+  enum monster_id species;
+  int level;
+  enum monster_behavior behavior;
+  bool has_position;
+  struct position pos;
+  enum item_id held_item;
+  bool can_be_asleep;
+} monster_spawn;
+
+void CotSpawnInit(monster_spawn* spawn, enum monster_id species);
+void CotSpawnSetLevel(monster_spawn* spawn, int level);
+void CotSpawnSetBehavior(monster_spawn* spawn, enum monster_behavior behavior);
+void CotSpawnSetPosition(monster_spawn* spawn, int x, int y);
+void CotSpawnSetHeldItem(monster_spawn* spawn, enum item_id item);
+void CotSpawnSetCanBeAsleep(monster_spawn* spawn, bool can_be_asleep);
+
+// Spawns the monster. Returns NULL if no monster slot or position was available.
+struct entity* CotSpawn(const monster_spawn* spawn);
@@ -0,0 +1,52 @@
+#pragma once
+
+// Declarative constraints for choosing spawn positions.
+//
+// A constraint is built up from conditions a tile must meet and then evaluated against the
+// floor, so custom spawners describe where something should go instead of scanning tiles by
+// hand:
+//
+//   spawn_constraint c;
+//   CotConstraintInit(&c);
+//   CotConstraintInRoom(&c);
+//   CotConstraintNotNearStairs(&c);
+//   CotConstraintMinLeaderDistance(&c, 10);
+//   struct position pos;
+//   if (CotPickConstrainedTile(&c, &pos)) { ... }
+//
+// Every constraint requires an open floor tile without a monster on it.
+
+typedef struct spawn_constraint {
+  bool in_room;
+  bool in_corridor;
+  bool no_object;
+  bool not_near_stairs;
+  bool not_in_shop;
+  bool not_in_monster_house;
+  // Distance in moves. -1 if unconstrained.
+  int min_leader_distance;
+  int max_leader_distance;
+  bool has_area;
+  room_rect area;
+  // May be NULL.
+  spawn_tile_filter_fn custom;
+} spawn_constraint;
+
+void CotConstraintInit(spawn_constraint* c);
+void CotConstraintInRoom(spawn_constraint* c);
+void CotConstraintInCorridor(spawn_constraint* c);
+void CotConstraintNoObject(spawn_constraint* c);
+// Excludes the stairs and the 8 tiles around them.
+void CotConstraintNotNearStairs(spawn_constraint* c);
+void CotConstraintNotInShop(spawn_constraint* c);
+void CotConstraintNotInMonsterHouse(spawn_constraint* c);
+void CotConstraintMinLeaderDistance(spawn_constraint* c, int distance);
+void CotConstraintMaxLeaderDistance(spawn_constraint* c, int distance);
+// Limits the tiles to an inclusive rectangle.
+void CotConstraintInArea(spawn_constraint* c, room_rect area);
+void CotConstraintCustom(spawn_constraint* c, spawn_tile_filter_fn filter);
+
+bool CotConstraintMatches(const spawn_constraint* c, int x, int y);
+int CotCountConstrainedTiles(const spawn_constraint* c);
+// Picks a random tile that meets the constraint. Returns false if there's none.
+bool CotPickConstrainedTile(const spawn_constraint* c, struct position* out);
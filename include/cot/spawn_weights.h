@@ -0,0 +1,47 @@
+#pragma once
+
+// Per-tile weight maps for choosing spawn positions.
+//
+// A weight map assigns every tile of the floor a relative chance of being picked as the
+// position of a random spawn, e.g. to make items cluster in the middle of the floor. Tiles
+// with weight 0 are never picked. Maps are set per spawn category and stay active until
+// they're removed.
+//
+// Random spawns of c-of-time (CotSpawn without a position, CotSpawnItemRandom) use the maps
+// automatically, and custom spawners can pick tiles with CotPickSpawnTile. The game's own
+// spawner uses them too: while SpawnNonEnemies and SpawnInitialMonsters run, the positions
+// passed to SpawnItem, SpawnTrap and SpawnMonster are replaced by a tile picked from the map of
+// their category (see hooks.c). Spawns in Kecleon shops and Monster Houses keep their position,
+// as do the stairs.
+
+typedef enum spawn_category {
+  SPAWN_CATEGORY_MONSTER = 0,
+  SPAWN_CATEGORY_ITEM,
+  SPAWN_CATEGORY_TRAP,
+  COT_N_SPAWN_CATEGORIES,
+} spawn_category;
+
+typedef struct spawn_weight_map {
+  uint8_t weights[COT_FLOOR_HEIGHT][COT_FLOOR_WIDTH];
+} spawn_weight_map;
+
+// Returns whether a tile can be used for the spawn at all.
+typedef bool (*spawn_tile_filter_fn)(int x, int y);
+
+// `map` must stay valid while it's set. Pass NULL to go back to uniform spawns.
+void CotSetSpawnWeightMap(spawn_category category, const spawn_weight_map* map);
+const spawn_weight_map* CotGetSpawnWeightMap(spawn_category category);
+
+// Picks a tile accepted by `filter` by the weights of `category`, or uniformly if the
+// category has no map. Returns false if no tile has a nonzero weight.
+bool CotPickSpawnTile(spawn_category category, spawn_tile_filter_fn filter, struct position* out);
+
+// Set around the game's spawner, see above.
+void cotInternalSetGameSpawning(bool spawning);
+// Replaces `pos` by a tile picked from the map of `category` while the game's spawner runs.
+void cotInternalPickGameSpawnPos(spawn_category category, struct position* pos);
+
+void CotFillSpawnWeights(spawn_weight_map* map, uint8_t weight);
+// Weights falling off linearly from `max_weight` at (cx, cy) by `falloff` per tile of
+// distance, down to a minimum of 0.
+void CotRadialSpawnWeights(spawn_weight_map* map, int cx, int cy, int max_weight, int falloff);
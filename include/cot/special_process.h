@@ -0,0 +1,78 @@
+#pragma once
+
+// Runtime registration of special process handlers.
+//
+// Registered handlers are tried before CustomScriptSpecialProcessCall, so they only apply to
+// special process IDs of 100 and greater. Handlers receive the arguments through a
+// special_process_args struct and the accessors below instead of raw shorts.
+//
+// Handlers can also be declared statically. COT_SPECIAL_PROCESS defines a handler whose
+// arguments are converted to the given types, and COT_SPECIAL_PROCESS_TABLE maps IDs to
+// handlers without any registration code:
+//
+//   COT_SPECIAL_PROCESS(SpHeal, enum monster_id, species, int, amount) {
+//     ...
+//     return 0;
+//   }
+//
+//   COT_SPECIAL_PROCESS_TABLE(
+//     { 101, SpHeal },
+//   );
+//
+// The table may be defined once. Runtime registrations take precedence over it. The IDs reserved
+// for script calls are handled before the table.
+
+#define COT_MAX_SPECIAL_PROCESSES 32
+
+typedef struct special_process_args {
+  undefined4* unknown;
+  short arg1;
+  short arg2;
+} special_process_args;
+
+// Returns the value passed back to the script engine.
+typedef int (*special_process_fn)(special_process_args* args);
+
+typedef struct special_process_entry {
+  uint32_t id;
+  special_process_fn handler;
+} special_process_entry;
+
+// Defines `name` as a special_process_fn. The body sees the arguments as `arg1_name` and
+// `arg2_name`, converted to `arg1_type` and `arg2_type`, and the raw arguments as `args`.
+#define COT_SPECIAL_PROCESS(name, arg1_type, arg1_name, arg2_type, arg2_name) \
+  static int name##Typed(special_process_args* args, arg1_type arg1_name, arg2_type arg2_name); \
+  static int name(special_process_args* args) { \
+    return name##Typed(args, (arg1_type) CotSpArgInt(args, 0), (arg2_type) CotSpArgInt(args, 1)); \
+  } \
+  static int name##Typed(special_process_args* args, arg1_type arg1_name, arg2_type arg2_name)
+
+#define COT_SPECIAL_PROCESS_TABLE(...) \
+  const special_process_entry cotSpecialProcessTable[] = { __VA_ARGS__ }; \
+  const int cotSpecialProcessTableSize = sizeof(cotSpecialProcessTable) / sizeof(special_process_entry)
+
+// Registers `handler` for `id`. Returns false if `id` is already registered, below 100, reserved
+// for script calls (see script_calls.h) or the handler table is full.
+bool CotRegisterSpecialProcess(uint32_t id, special_process_fn handler);
+void CotUnregisterSpecialProcess(uint32_t id);
+
+static inline int CotSpArgInt(special_process_args* args, int n) {
+  return n == 0 ? args->arg1 : args->arg2;
+}
+static inline bool CotSpArgBool(special_process_args* args, int n) {
+  return CotSpArgInt(args, n) != 0;
+}
+static inline enum monster_id CotSpArgMonster(special_process_args* args, int n) {
+  return (enum monster_id) CotSpArgInt(args, n);
+}
+static inline enum item_id CotSpArgItem(special_process_args* args, int n) {
+  return (enum item_id) CotSpArgInt(args, n);
+}
+static inline enum dungeon_id CotSpArgDungeon(special_process_args* args, int n) {
+  return (enum dungeon_id) CotSpArgInt(args, n);
+}
+
+// Runs the registered or table handler for `special_process_id`. Returns true if one exists.
+bool cotInternalDispatchRegisteredSpecialProcess(
+  undefined4* unknown, uint32_t special_process_id, short arg1, short arg2, int* return_val
+);
@@ -0,0 +1,63 @@
+#pragma once
+
+#define COT_FEMALE_FORM_OFFSET 600
+
+// Accessors for the monster species data table (monster.md).
+//
+// CotGetSpeciesInfo collects the commonly used fields of a species into one struct. The
+// individual fields are read through the game's own accessor functions. CotPatchSpeciesData
+// changes the table loaded from monster.md in RAM, e.g. to apply balance tweaks at boot.
+//
+// The game doesn't store gender ratios. Species with both genders have a separate entry for
+// their female form, COT_FEMALE_FORM_OFFSET IDs after the male one, and spawn lists name the
+// form to spawn. CotRollGenderForm picks one of both forms with a given ratio.
+
+typedef struct species_info {
+  enum monster_id id;
+  int dex_number;
+  enum monster_gender gender;
+  enum type_id types[2];
+  enum ability_id abilities[2];
+  int base_hp;
+  // Attack, Special Attack
+  int base_offensive_stats[2];
+  // Defense, Special Defense
+  int base_defensive_stats[2];
+  int exp_yield;
+  int recruit_rate[2];
+  enum mobility_type mobility;
+  int body_size;
+  enum monster_id pre_evolution;
+  // Secondary female form of the species, MONSTER_NONE if there's none.
+  enum monster_id female_form;
+} species_info;
+
+void CotGetSpeciesInfo(enum monster_id id, species_info* out);
+
+bool CotSpeciesHasType(enum monster_id id, enum type_id type);
+bool CotSpeciesHasAbility(enum monster_id id, enum ability_id ability);
+
+// Returns the secondary female form of a species, or MONSTER_NONE if there's none.
+enum monster_id CotGetFemaleForm(enum monster_id id);
+// Returns the female form of `id` with a `female_percent`% chance and `id` otherwise. Species
+// without a female form are returned unchanged.
+enum monster_id CotRollGenderForm(enum monster_id id, int female_percent);
+
+// Makes a monster look like species `id` and loads its sprite, without changing its data.
+// Returns false if `entity` isn't a valid monster.
+bool CotSetApparentSpecies(struct entity* entity, enum monster_id id);
+
+// Fields set to COT_DATA_KEEP keep their current value, see data_tables.h.
+typedef struct species_patch {
+  int types[2];
+  int abilities[2];
+  int base_hp;
+  // Attack, Special Attack
+  int base_offensive_stats[2];
+  // Defense, Special Defense
+  int base_defensive_stats[2];
+  int exp_yield;
+} species_patch;
+
+// Returns false without changing anything if the species ID or a value is out of range.
+bool CotPatchSpeciesData(enum monster_id id, const species_patch* patch);
@@ -0,0 +1,51 @@
+#pragma once
+
+// Per-run and lifetime statistics.
+//
+// Events are recorded through the CotStatsRecord* functions. c-of-time records the damage
+// calculated by the game (see damage.h), items used through the item effect dispatcher and every
+// generated floor; other events can be recorded by the mod. A run starts with CotStatsBeginRun and is folded into the lifetime totals by
+// CotStatsEndRun. The lifetime totals can be serialized with CotStatsSerialize and
+// CotStatsDeserialize, or registered as a save block with CotStatsRegisterSaveBlock.
+
+#define COT_STATS_MAX_SPECIES 32
+
+typedef struct species_stats {
+  uint16_t species;
+  uint32_t damage_dealt;
+  uint32_t damage_taken;
+} species_stats;
+
+typedef struct run_stats {
+  uint32_t runs;
+  uint32_t floors_visited;
+  uint32_t items_used;
+  uint32_t damage_dealt;
+  uint32_t damage_taken;
+  // Per-species totals. Species beyond COT_STATS_MAX_SPECIES are only counted in the totals.
+  uint16_t n_species;
+  species_stats species[COT_STATS_MAX_SPECIES];
+} run_stats;
+
+void CotStatsBeginRun(void);
+void CotStatsEndRun(void);
+
+// Records `amount` damage dealt by `attacker` to `defender`. Either may be NULL. Damage counts
+// as dealt if the attacker is a team member and as taken if the defender is one.
+void CotStatsRecordDamage(struct entity* attacker, struct entity* defender, int amount);
+// Only items used by team members are counted.
+void CotStatsRecordItemUsed(struct entity* user, struct item* item);
+void CotStatsRecordFloor(void);
+
+const run_stats* CotGetRunStats(void);
+const run_stats* CotGetLifetimeStats(void);
+// Returns the entry for `species` in `stats` or NULL if it isn't tracked.
+const species_stats* CotGetSpeciesStats(const run_stats* stats, enum monster_id species);
+
+// Size of the serialized lifetime statistics.
+int CotStatsSerializedSize(void);
+// Writes the lifetime statistics into `buf`. Returns false if `size` is too small.
+bool CotStatsSerialize(void* buf, int size);
+bool CotStatsDeserialize(const void* buf, int size);
+// Registers the lifetime statistics as a save block, see save_data.h.
+bool CotStatsRegisterSaveBlock(void);
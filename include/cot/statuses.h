@@ -0,0 +1,34 @@
+#pragma once
+
+// Inflicting, curing and querying status conditions of dungeon monsters.
+//
+// The game groups statuses into classes (sleep, burn, frozen, cringe); a monster can only have
+// one status of each class at a time. The functions here go through the game's own
+// TryInflict*/End* functions, so immunities, abilities and log messages work as usual.
+
+typedef enum status_effect {
+  STATUS_EFFECT_SLEEP = 0,
+  STATUS_EFFECT_NIGHTMARE,
+  STATUS_EFFECT_YAWNING,
+  STATUS_EFFECT_NAPPING,
+  STATUS_EFFECT_BURN,
+  STATUS_EFFECT_POISONED,
+  STATUS_EFFECT_BADLY_POISONED,
+  STATUS_EFFECT_PARALYSIS,
+  STATUS_EFFECT_FROZEN,
+  STATUS_EFFECT_CRINGE,
+  STATUS_EFFECT_CONFUSED,
+  STATUS_EFFECT_PAUSED,
+  STATUS_EFFECT_INFATUATED,
+  STATUS_EFFECT_COUNT,
+} status_effect;
+
+// Use as `turns` to let the game pick the duration.
+#define COT_STATUS_DEFAULT_TURNS 0
+
+// Tries to inflict `status` on `target`. `turns` is only used by sleep-class statuses and
+// Pause. Returns true if the target has the status afterwards.
+bool CotInflictStatus(struct entity* user, struct entity* target, status_effect status, int turns);
+// Cures `status` if the target has it. Returns false if it didn't.
+bool CotCureStatus(struct entity* user, struct entity* target, status_effect status);
+bool CotHasStatus(struct entity* entity, status_effect status);
@@ -0,0 +1,36 @@
+#pragma once
+
+// String building in fixed buffers, e.g. on the stack.
+//
+// A str_buf appends into a buffer it doesn't own and keeps it null-terminated at all times.
+// Appending past the end truncates the string and sets `truncated` instead of overflowing, so
+// per-turn HUD text and messages can be built without heap allocations:
+//
+//   COT_STR_BUF(text, 64);
+//   CotStrBufAppend(&text, "Floor ");
+//   CotStrBufAppendInt(&text, floor);
+//   LogMessage(leader, text.buf, true);
+//
+// CotStrBufAppendMessage (see messages.h) appends text with the game's tags and typed
+// arguments. Text written in UTF-8, e.g. in C string literals, can be converted to the game's
+// encoding with CotStrBufAppendUtf8.
+
+typedef struct str_buf {
+  char* buf;
+  int size;
+  int len;
+  bool truncated;
+} str_buf;
+
+// Declares a str_buf `name` with a local buffer of `size` bytes. `size` must be a constant.
+#define COT_STR_BUF(name, size) str_buf name = CotStrBufInit((char[(size)]) { 0 }, (size))
+
+str_buf CotStrBufInit(char* buf, int size);
+void CotStrBufClear(str_buf* sb);
+void CotStrBufAppend(str_buf* sb, const char* str);
+void CotStrBufAppendChar(str_buf* sb, char c);
+void CotStrBufAppendInt(str_buf* sb, int value);
+// Appends UTF-8 text converted to the game's text encoding, Windows-1252 in the North American
+// and European versions. Characters the encoding doesn't have are replaced with '?', as are
+// invalid UTF-8 sequences.
+void CotStrBufAppendUtf8(str_buf* sb, const char* str);
@@ -0,0 +1,32 @@
+#pragma once
+
+// Exchange and prize overrides for a Swap Shop.
+//
+// Exchange entries define which items have to be traded in (and the fee) for an item. Prize
+// pools replace the items that can be handed out for a tier of traded-in treasure. The game's
+// own Swap Shop menu keeps using its built-in tables: pmdsky-debug doesn't document the
+// functions it looks recipes and fees up with, so they can't be wrapped like the other lookups in
+// hooks.c. The overrides are meant for a custom shop instead, e.g. a menu built with menus.h,
+// which looks them up with CotGetSwapExchange and CotRollSwapPrize. Both return false when
+// there's no override.
+
+#define COT_MAX_SWAP_EXCHANGES 32
+#define COT_SWAP_MAX_INPUTS 3
+#define COT_SWAP_N_TIERS 4
+
+typedef struct swap_exchange {
+  enum item_id receive;
+  enum item_id give[COT_SWAP_MAX_INPUTS];
+  int n_give;
+  int fee;
+} swap_exchange;
+
+bool CotSetSwapExchange(swap_exchange exchange);
+void CotRemoveSwapExchange(enum item_id receive);
+// Replaces the prize pool of a tier. `items` must stay alive while the pool is in use.
+// Pass n = 0 to restore the vanilla pool.
+void CotSetSwapPrizePool(int tier, const enum item_id* items, int n);
+
+bool CotGetSwapExchange(enum item_id receive, swap_exchange* out);
+// Picks a random item from the prize pool of `tier`.
+bool CotRollSwapPrize(int tier, enum item_id* out);
@@ -0,0 +1,36 @@
+#pragma once
+
+// Optional sliding (ice) and forced movement (conveyor) terrain.
+//
+// Terrain mechanics are stored in a per-floor grid next to the game's tile data, so they don't
+// use any of the game's own tile flags. Custom generators place them with CotSetTerrainMechanic
+// and CotFillTerrainMechanic; the grid must be reset with CotClearTerrainMechanics on every floor.
+// Steps are detected at the end of every turn (see ai_hooks.h) by comparing the positions of the
+// monsters to the last turn, and the mechanic of the tile a monster stepped onto is applied then.
+// Custom movement code can apply them right away with CotApplyTerrainMechanics.
+
+#define COT_MAX_TERRAIN_MONSTERS 24
+
+typedef enum terrain_mechanic {
+  TERRAIN_MECHANIC_NONE = 0,
+  // Monsters stepping onto ice keep sliding in their movement direction until they reach a
+  // tile without ice or are blocked.
+  TERRAIN_MECHANIC_ICE,
+  // Monsters stepping onto a conveyor are moved one tile in the conveyor's direction. Monsters
+  // that stand still on a conveyor aren't moved.
+  TERRAIN_MECHANIC_CONVEYOR_DOWN,
+  TERRAIN_MECHANIC_CONVEYOR_RIGHT,
+  TERRAIN_MECHANIC_CONVEYOR_UP,
+  TERRAIN_MECHANIC_CONVEYOR_LEFT,
+} terrain_mechanic;
+
+void CotClearTerrainMechanics(void);
+void CotSetTerrainMechanic(int x, int y, terrain_mechanic mechanic);
+void CotFillTerrainMechanic(int x, int y, int w, int h, terrain_mechanic mechanic);
+terrain_mechanic CotGetTerrainMechanic(int x, int y);
+
+// Applies the terrain mechanic of the tile the monster stands on. `direction` is the direction
+// of the step the monster just took. Returns true if the monster was moved.
+bool CotApplyTerrainMechanics(struct entity* entity, enum direction_id direction);
+
+void cotInternalUpdateTerrainMechanics(void);
@@ -0,0 +1,87 @@
+#pragma once
+
+// Safe view over the dungeon's tile grid.
+//
+// Unlike GetTileSafe, which hands out a shared dummy tile for out-of-bounds positions, the
+// accessors here report out-of-bounds positions to the caller. Terrain changes made through
+// CotSetTileTerrain also keep the walkable neighbor flags of the surrounding tiles in sync.
+//
+// The remaining properties of a tile have typed getters and setters, so callers don't have to
+// know how the game packs them. Getters return the value of an empty hallway tile for
+// out-of-bounds positions and setters ignore them.
+
+// Floor size in tiles.
+#define COT_FLOOR_WIDTH 56
+#define COT_FLOOR_HEIGHT 32
+// Room index of tiles that aren't part of a room, e.g. hallways.
+#define COT_NO_ROOM 0xFF
+// Floor number that matches every floor of a dungeon, in rules set up per floor.
+#define COT_ANY_FLOOR 0
+
+static inline bool CotTileInBounds(int x, int y) {
+  return x >= 0 && x < COT_FLOOR_WIDTH && y >= 0 && y < COT_FLOOR_HEIGHT;
+}
+
+// Returns the tile at (x, y) or NULL if it's out of bounds.
+struct tile* CotTileAt(int x, int y);
+
+// Returns true if (x, y) is in bounds and is open floor (not a wall, water, lava or chasm).
+bool CotTileIsFloor(int x, int y);
+// Returns true if (x, y) is in bounds and is a wall.
+bool CotTileIsWall(int x, int y);
+// Returns the monster standing on (x, y), or NULL.
+struct entity* CotTileMonster(int x, int y);
+
+// Changes the terrain of (x, y). Walls can be made unbreakable, e.g. for doors.
+void CotSetTileTerrain(int x, int y, enum terrain_type terrain, bool unbreakable);
+
+// Returns the index of the room containing (x, y), or COT_NO_ROOM.
+int CotTileRoom(int x, int y);
+void CotSetTileRoom(int x, int y, int room);
+
+// Natural junctions are the hallway tiles next to a room's entrance, where the AI decides where
+// to go next.
+bool CotTileIsJunction(int x, int y);
+void CotSetTileJunction(int x, int y, bool junction);
+
+bool CotTileIsStairs(int x, int y);
+// Moves the floor's stairs to (x, y), clearing the stairs flag of their previous tile.
+void CotSetStairs(int x, int y);
+
+// Marks where the game's spawner may place things while a floor is generated. During the floor,
+// the same bits are reused for the tile's visibility.
+typedef enum tile_spawn_flag {
+  TILE_SPAWN_STAIRS = 1 << 0,
+  TILE_SPAWN_ITEM = 1 << 1,
+  TILE_SPAWN_TRAP = 1 << 2,
+  TILE_SPAWN_MONSTER = 1 << 3,
+} tile_spawn_flag;
+
+// Return and take a combination of tile_spawn_flag values.
+unsigned CotTileSpawnFlags(int x, int y);
+void CotSetTileSpawnFlags(int x, int y, unsigned flags);
+
+// Whether the tile is shown on the minimap.
+bool CotTileIsRevealed(int x, int y);
+void CotSetTileRevealed(int x, int y, bool revealed);
+
+typedef struct room_iter {
+  int room;
+  struct position pos;
+} room_iter;
+
+room_iter CotIterRoom(int room);
+bool CotRoomNext(room_iter* it, struct position* out);
+
+// Iterates over the tiles of a room, row by row:
+//
+//   struct position pos;
+//   COT_FOR_EACH_TILE_IN_ROOM(room, pos) {
+//     ...
+//   }
+#define COT_FOR_EACH_TILE_IN_ROOM(room, var) \
+  for (room_iter _cot_it_##var = CotIterRoom(room); CotRoomNext(&_cot_it_##var, &var);)
+
+// Calls `fn` for every tile in the rectangle, clipped to the floor.
+void CotForEachTile(int x, int y, int w, int h, void (*fn)(struct tile* tile, int x, int y, void* ctx),
+                    void* ctx);
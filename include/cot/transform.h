@@ -0,0 +1,43 @@
+#pragma once
+
+// Copying another monster's appearance, types, abilities, stats and moves, like Transform.
+//
+// CotTransform doesn't set the game's Transform status, so the copy lasts until it's reverted
+// with the snapshot taken before transforming. A transform hook can veto a transformation or
+// change what's copied, e.g. to block transformations into certain species.
+
+typedef enum transform_flags {
+  TRANSFORM_APPEARANCE = 1 << 0,
+  TRANSFORM_TYPES = 1 << 1,
+  TRANSFORM_ABILITIES = 1 << 2,
+  TRANSFORM_STATS = 1 << 3,
+  TRANSFORM_MOVES = 1 << 4,
+  TRANSFORM_ALL = TRANSFORM_APPEARANCE | TRANSFORM_TYPES | TRANSFORM_ABILITIES | TRANSFORM_STATS
+      | TRANSFORM_MOVES,
+} transform_flags;
+
+// PP given to copied moves, like the game does for Transform.
+#define COT_TRANSFORM_MOVE_PP 5
+
+// Everything CotTransform can change, to be restored with CotRevertTransform.
+typedef struct transform_snapshot {
+  unsigned flags;
+  enum monster_id apparent_id;
+  enum type_id types[2];
+  enum ability_id abilities[2];
+  uint8_t offensive_stats[2];
+  uint8_t defensive_stats[2];
+  struct move moves[4];
+} transform_snapshot;
+
+// Returns false to cancel the transformation. May change `flags`.
+typedef bool (*transform_hook_fn)(struct entity* user, struct entity* target, unsigned* flags);
+
+void CotSetTransformHook(transform_hook_fn hook);
+
+// Copies the parts of `target` selected by `flags` onto `user`. If `snapshot` is not NULL,
+// the previous state of `user` is saved there. Returns false if the hook cancelled it or
+// either entity isn't a valid monster.
+bool CotTransform(struct entity* user, struct entity* target, unsigned flags,
+                  transform_snapshot* snapshot);
+void CotRevertTransform(struct entity* entity, const transform_snapshot* snapshot);
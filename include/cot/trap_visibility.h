@@ -0,0 +1,57 @@
+#pragma once
+
+// Trap reveal and Trapbust mechanics.
+//
+// Hidden traps can be revealed by the game, e.g. when a monster steps on them, searches for
+// them, holds Goggle Specs or uses an orb, and by c-of-time with CotRevealTraps. Each reveal
+// source has a mask of the trap IDs it can reveal, which mods can narrow down to specific trap
+// categories, and a policy callback can overrule the decision for every single trap.
+//
+// Reveals by the game are checked at the end of every turn with a turn hook (see ai_hooks.h),
+// once a mask or a policy has been set: traps the game revealed against the masks are hidden
+// again. Trapbust Orbs are handled by a wrapper around the game's DoMoveTrapbust in hooks.c;
+// once a Trapbust mask has been set, they destroy the traps in the mask on the whole floor.
+
+// Bit for a trap ID in a trap mask. Categories of traps are masks with several bits set.
+#define COT_TRAP_BIT(id) (1u << (id))
+#define COT_TRAP_MASK_ALL 0xFFFFFFFFu
+
+typedef enum trap_reveal_source {
+  // Any reveal by the game: stepping on the trap, searching, held items, orbs and moves.
+  TRAP_REVEAL_GAME = 0,
+  // Reveals from CotRevealTraps.
+  TRAP_REVEAL_CUSTOM,
+  TRAP_REVEAL_N_SOURCES,
+} trap_reveal_source;
+
+// Returns whether `trap` should be revealed. `vanilla` is the decision of the reveal masks.
+// `revealer` is the monster causing the reveal, or NULL if it isn't known.
+typedef bool (*trap_reveal_policy_fn)(struct entity* trap, trap_reveal_source source,
+                                      struct entity* revealer, bool vanilla);
+
+// Returns the trap ID of a trap entity, or TRAP_NONE for other entities.
+enum trap_id CotGetTrapId(struct entity* trap);
+bool CotIsTrapVisible(struct entity* trap);
+void CotSetTrapVisible(struct entity* trap, bool visible);
+
+// Sets which trap IDs `source` can reveal. All sources can reveal all traps by default.
+void CotSetTrapRevealMask(trap_reveal_source source, uint32_t mask);
+uint32_t CotGetTrapRevealMask(trap_reveal_source source);
+void CotSetTrapRevealPolicy(trap_reveal_policy_fn policy);
+
+// Reveals the traps in `mask` within `area`. Returns the number of traps that were revealed.
+int CotRevealTraps(tile_area area, uint32_t mask);
+// Hides the traps in `mask` on the whole floor. Returns the number of hidden traps.
+int CotHideTraps(uint32_t mask);
+
+// Sets which trap IDs a Trapbust Orb can destroy.
+void CotSetTrapbustMask(uint32_t mask);
+// Destroys the traps in `mask` within `area`, like a Trapbust Orb. Traps outside of the
+// Trapbust mask are kept. Returns the number of destroyed traps.
+int CotBustTraps(tile_area area, uint32_t mask);
+
+bool cotInternalShouldRevealTrap(struct entity* trap, trap_reveal_source source,
+                                 struct entity* revealer, bool vanilla);
+// Returns false to run the game's Trapbust.
+bool cotInternalDoTrapbust(void);
+void cotInternalOnTrapsSpawned(void);
@@ -0,0 +1,23 @@
+#pragma once
+
+// Custom trap behavior.
+//
+// A registered trap handler replaces the effect of a vanilla trap or defines the effect of a
+// new trap ID. Handlers run from the wrapper of the game's ApplyTrapEffect in hooks.c; traps
+// without a handler are left to the game. A Random Trap runs the handler of the trap it picks.
+
+#define COT_MAX_CUSTOM_TRAPS 16
+
+// `user` is the monster that set off the trap and `target` the monster affected by it, `pos`
+// the trap's position. Return true to remove the trap afterwards.
+typedef bool (*trap_effect_fn)(struct entity* user, struct entity* target, struct trap* trap,
+                               struct position* pos);
+
+// `id` can be any trap ID that fits into a trap's 8-bit ID, except TRAP_NONE.
+bool CotRegisterTrapHandler(enum trap_id id, trap_effect_fn effect);
+void CotRemoveTrapHandler(enum trap_id id);
+
+// Returns true if a handler ran, with its result stored in `remove_trap`.
+bool cotInternalDispatchApplyTrapEffect(struct entity* user, struct entity* target,
+                                        struct trap* trap, struct position* pos,
+                                        enum trap_id id, bool* remove_trap);
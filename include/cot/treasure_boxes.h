@@ -0,0 +1,34 @@
+#pragma once
+
+// Treasure Box opening hook.
+//
+// Custom box types are registered per box item. CotOpenTreasureBox looks up the box's type, lets
+// it decide the contents and plays its opening animation. Call it from a special process at
+// Xatu's appraisal shop (see special_process.h), or from a custom item effect for boxes opened in
+// a dungeon (see effects.c). Boxes without a registered type are left to the game.
+
+#define COT_MAX_TREASURE_BOX_TYPES 8
+
+typedef enum treasure_box_location {
+  TREASURE_BOX_AT_APPRAISAL = 0,
+  TREASURE_BOX_IN_DUNGEON,
+} treasure_box_location;
+
+// Writes the item the box contains into `contents`. Return false to leave the contents chosen
+// by the game untouched.
+typedef bool (*treasure_box_contents_fn)(struct item* box, struct item* contents,
+                                         treasure_box_location location);
+// Plays a custom opening animation. Runs before the contents are revealed.
+typedef void (*treasure_box_animation_fn)(struct item* box, treasure_box_location location);
+
+typedef struct treasure_box_type {
+  enum item_id box_item;
+  treasure_box_contents_fn contents;
+  // May be NULL to keep the game's animation.
+  treasure_box_animation_fn animation;
+} treasure_box_type;
+
+bool CotRegisterTreasureBoxType(treasure_box_type type);
+
+// Returns true if the box was handled by a registered type.
+bool CotOpenTreasureBox(struct item* box, struct item* contents, treasure_box_location location);
@@ -0,0 +1,28 @@
+#pragma once
+
+// Typed access to the game's unlockable content flags, built on the script variable API.
+
+typedef enum cot_special_episode {
+  COT_EPISODE_BIDOOFS_WISH = 0,
+  COT_EPISODE_IGGLYBUFF_THE_PRODIGY,
+  COT_EPISODE_TODAYS_OH_MY_GOSH,
+  COT_EPISODE_HERE_COMES_TEAM_CHARM,
+  COT_EPISODE_IN_THE_FUTURE_OF_DARKNESS,
+  COT_N_SPECIAL_EPISODES,
+} cot_special_episode;
+
+// Number of dungeons in the game's per-dungeon flags, like VAR_DUNGEON_OPEN_LIST. The dungeon
+// functions ignore IDs past it.
+#define COT_N_DUNGEON_FLAGS 180
+
+bool CotIsSpecialEpisodeUnlocked(cot_special_episode episode);
+void CotSetSpecialEpisodeUnlocked(cot_special_episode episode, bool unlocked);
+bool CotIsSpecialEpisodeCleared(cot_special_episode episode);
+void CotSetSpecialEpisodeCleared(cot_special_episode episode, bool cleared);
+
+// Whether a dungeon is selectable (shown on the world map or in the dungeon list).
+bool CotIsDungeonUnlocked(enum dungeon_id dungeon);
+void CotSetDungeonUnlocked(enum dungeon_id dungeon, bool unlocked);
+// Whether a dungeon has been cleared at least once.
+bool CotIsDungeonCleared(enum dungeon_id dungeon);
+void CotSetDungeonCleared(enum dungeon_id dungeon, bool cleared);
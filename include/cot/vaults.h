@@ -0,0 +1,31 @@
+#pragma once
+
+// Sealed vault rooms.
+//
+// A vault is a room without any hallway, surrounded by breakable walls and filled with loot.
+// It can only be reached by breaking walls or warping. CotCarveVault looks for a solid block
+// of walls large enough for the vault and carves the room into it.
+//
+// Vaults are carved into finished floors, usually from a post-generate hook (see
+// floor_gen.h). Reachability checks like the game's StairsAlwaysReachable and floor
+// validators have already run at that point, so the unreachable vault doesn't make them
+// reject the floor. Custom validators that run later can skip vault tiles with CotIsVaultTile.
+
+typedef struct vault_config {
+  // Size of the room's interior, at least 1x1.
+  int width;
+  int height;
+  // Items placed inside. Each is picked from `loot` at random.
+  int n_items;
+  const enum item_id* loot;
+  int n_loot;
+  // Number of random positions tried before giving up.
+  int max_attempts;
+} vault_config;
+
+// Carves a vault into the current floor. Returns false if no space was found.
+bool CotCarveVault(const vault_config* config);
+// Returns true if the tile is inside the vault carved on the current floor.
+bool CotIsVaultTile(int x, int y);
+// Forgets the vault of the previous floor. Called automatically by CotCarveVault.
+void CotClearVault(void);
@@ -0,0 +1,33 @@
+#pragma once
+
+// Moving through walls, like ghost-type monsters with the intangible mobility type.
+//
+// Wall passing can be granted or revoked per monster, or granted to every monster holding a
+// specific item. Revoking also takes away a species' natural ability to pass walls. No matter
+// what's granted, monsters can never enter impassable walls, like the border of the floor, or
+// walls next to a Kecleon shop, so shops can't be entered or left through their walls.
+//
+// Moves are checked by wrapping the game's CanMonsterMoveInDirection, see hooks.c. Per-monster
+// modes only last until the end of the floor.
+
+#define COT_MAX_WALL_PASS_ITEMS 8
+
+typedef enum wall_pass_mode {
+  WALL_PASS_DEFAULT = 0,
+  WALL_PASS_GRANTED,
+  WALL_PASS_REVOKED,
+} wall_pass_mode;
+
+// Grants, revokes or resets wall passing for one monster until the end of the floor.
+void CotSetWallPass(struct entity* entity, wall_pass_mode mode);
+wall_pass_mode CotGetWallPass(struct entity* entity);
+// Monsters holding `item` can pass walls.
+bool CotAddWallPassItem(enum item_id item);
+// Resets all per-monster modes. Done automatically at the start of every floor.
+void CotClearWallPass(void);
+
+// Returns true if no monster may ever enter the wall on (x, y).
+bool CotIsWallPassBlocked(int x, int y);
+
+bool cotInternalCanMoveInDirection(struct entity* entity, enum direction_id direction,
+                                   bool vanilla);
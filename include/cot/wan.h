@@ -0,0 +1,71 @@
+#pragma once
+
+// Decoding of WAN sprites, the game's sprite format.
+//
+// A WAN file (wrapped in an SIR0 container) holds the sprite's images as strips of tile
+// data, its palettes, meta-frames that assemble images into OAM pieces, and animations that
+// sequence meta-frames. The decoder works on a file loaded into memory, e.g. with
+// CotLoadFile, and produces data that can be copied to VRAM and OAM as is:
+//
+// - Images are decoded into 4bpp (or 8bpp) tile data
+// - Palettes are converted to BGR555
+// - Meta-frame pieces and animation frames are returned as plain structs
+//
+// To display a sprite with the game's own renderer instead, load it into the game's WAN table
+// with CotWanLoadSprite. The returned sprite ID works wherever the game takes one, e.g. with
+// SetSpriteIdForAnimationControl for an animation_control.
+
+typedef struct wan_file {
+  const uint8_t* data;
+  int size;
+  const uint8_t* anim_info;
+  const uint8_t* image_info;
+} wan_file;
+
+// An OAM piece of a meta-frame.
+typedef struct wan_piece {
+  // -1 if the piece reuses the tiles of the previous piece.
+  int image_index;
+  int x;
+  int y;
+  // OAM shape and size
+  int shape;
+  int obj_size;
+  bool hflip;
+  bool vflip;
+  int palette;
+} wan_piece;
+
+typedef struct wan_anim_frame {
+  // Duration in frames
+  int duration;
+  int meta_frame;
+  int offset_x;
+  int offset_y;
+  int shadow_x;
+  int shadow_y;
+} wan_anim_frame;
+
+bool CotWanOpen(wan_file* wan, const void* data, int size);
+
+int CotWanImageCount(const wan_file* wan);
+bool CotWanIs256Colors(const wan_file* wan);
+// Decodes image `idx` into `dst`. Returns the number of bytes written, or -1 on error.
+int CotWanDecodeImage(const wan_file* wan, int idx, void* dst, int dst_capacity);
+
+// Returns the number of colors in the palette data.
+int CotWanColorCount(const wan_file* wan);
+// Converts up to `n` colors starting at `first` to BGR555. Returns the number converted.
+int CotWanGetColors(const wan_file* wan, int first, uint16_t* out, int n);
+
+// Writes up to `max` pieces of meta-frame `idx`. Returns the number of pieces, or -1.
+int CotWanGetMetaFrame(const wan_file* wan, int idx, wan_piece* out, int max);
+// Writes up to `max` frames of animation `anim` in group `group` (e.g. a direction of a
+// monster's animation). Returns the number of frames, or -1.
+int CotWanGetAnimation(const wan_file* wan, int group, int anim, wan_anim_frame* out, int max);
+
+// Validates the sprite file at `path` and loads it into the game's WAN table with
+// LoadWanTableEntry, passing `flags` as is. Returns the sprite ID, or -1 on error.
+int CotWanLoadSprite(const char* path, uint32_t flags);
+// Removes a sprite loaded with CotWanLoadSprite from the WAN table.
+void CotWanUnloadSprite(int sprite_id);
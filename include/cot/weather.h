@@ -0,0 +1,27 @@
+#pragma once
+
+// Dungeon weather.
+//
+// Weather set with CotSetWeather lasts for the given number of turns, like weather from moves
+// such as Rain Dance, and then reverts to the floor's natural weather. Callbacks registered
+// with CotAddWeatherTurnHook run once per turn with the updated weather, from a wrapper around
+// the game's TryActivateWeather in hooks.c, the turn step that counts down the weather's turns
+// and activates the weather that's due. Weather changes made with CotSetWeather don't run
+// them.
+
+#define COT_MAX_WEATHER_HOOKS 4
+
+typedef void (*weather_turn_fn)(enum weather_id weather);
+
+// Returns the current weather of the floor.
+enum weather_id CotGetWeather(void);
+// Returns the weather as seen by `entity`, e.g. clear for monsters with Cloud Nine nearby.
+enum weather_id CotGetApparentWeather(struct entity* entity);
+// Returns the weather the floor reverts to.
+enum weather_id CotGetNaturalWeather(void);
+void CotSetWeather(enum weather_id weather, int turns);
+
+bool CotAddWeatherTurnHook(weather_turn_fn hook);
+void CotRemoveWeatherTurnHook(weather_turn_fn hook);
+
+void cotInternalOnWeatherTurn(void);
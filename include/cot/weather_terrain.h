@@ -0,0 +1,45 @@
+#pragma once
+
+// Terrain that changes with the weather, e.g. rain filling chasms with water or sun drying
+// water into ground.
+//
+// Each rule turns tiles of one terrain into another while a given weather is active, either
+// all at once or a few tiles per turn. Rules only apply in dungeons enabled with
+// CotSetWeatherTerrainEnabled. Tiles are changed through CotSetTileTerrain, which keeps the
+// walkable neighbor flags used by pathing in sync. Tiles with stairs, objects or in Kecleon
+// shops are never changed, and tiles with monsters only become normal floor.
+//
+// CotInstallWeatherTerrain registers a weather turn hook (see weather.h), so the rules run
+// once per turn. Changed tiles are redrawn on the minimap right away. The graphics of the
+// dungeon map itself are picked from the tileset when the floor is loaded, and pmdsky-debug
+// doesn't document how to recompute them; to update them, e.g. by reloading the map, set a
+// callback with CotSetWeatherTerrainRedraw. It's called with the changed area after every turn
+// that changed tiles.
+
+#define COT_MAX_WEATHER_TERRAIN_RULES 8
+#define COT_MAX_WEATHER_TERRAIN_DUNGEONS 16
+
+typedef struct weather_terrain_rule {
+  // COT_ANY_DUNGEON for rules that apply in all enabled dungeons.
+  enum dungeon_id dungeon;
+  enum weather_id weather;
+  enum terrain_type from;
+  enum terrain_type to;
+  // Maximum number of tiles changed per turn, 0 to change all of them at once.
+  int tiles_per_turn;
+} weather_terrain_rule;
+
+// Called with the bounding rectangle of the tiles changed during a turn.
+typedef void (*weather_terrain_redraw_fn)(room_rect area);
+
+bool CotAddWeatherTerrainRule(weather_terrain_rule rule);
+void CotClearWeatherTerrainRules(void);
+bool CotSetWeatherTerrainEnabled(enum dungeon_id dungeon, bool enabled);
+bool CotWeatherTerrainEnabled(enum dungeon_id dungeon);
+// `redraw` may be NULL.
+void CotSetWeatherTerrainRedraw(weather_terrain_redraw_fn redraw);
+
+// Applies all rules for `weather` in the current dungeon. Returns the number of changed tiles.
+int CotApplyWeatherTerrain(enum weather_id weather);
+
+bool CotInstallWeatherTerrain(void);
@@ -0,0 +1,32 @@
+#pragma once
+
+// Species size and weight data, and hooks for weight-based moves.
+//
+// The game doesn't store actual weights. Instead, each species has a Low Kick multiplier (a
+// fixed-point value with 8 fractional bits) that Low Kick and Grass Knot use, and a body size
+// that determines how many team slots the species takes. Weights can be overridden per
+// species; the overrides are applied by a wrapper around the game's GetLowKickMultiplier in
+// hooks.c, so Low Kick and Grass Knot use them too.
+//
+// Custom moves, e.g. extended moves (see extended_moves.h), can register their own weight-based
+// formula and get their multiplier with CotGetWeightMultiplier.
+
+#define COT_MAX_WEIGHT_OVERRIDES 32
+#define COT_MAX_WEIGHT_MOVES 8
+
+// Returns the multiplier (8 fractional bits) of a weight-based move. `weight` is the target's
+// multiplier after overrides.
+typedef int (*weight_move_fn)(struct entity* user, struct entity* target, int weight);
+
+// Returns the Low Kick multiplier of `species`, including overrides.
+int CotGetSpeciesWeight(enum monster_id species);
+bool CotSetSpeciesWeight(enum monster_id species, int weight);
+int CotGetSpeciesBodySize(enum monster_id species);
+
+// Registers a custom weight-based move.
+bool CotRegisterWeightMove(enum move_id move, weight_move_fn fn);
+// Returns the multiplier of `move` against `target`: the result of its registered formula, or
+// the target's weight for moves without one.
+int CotGetWeightMultiplier(enum move_id move, struct entity* user, struct entity* target);
+
+int cotInternalGetSpeciesWeight(enum monster_id species, int vanilla);
@@ -0,0 +1,87 @@
+#pragma once
+
+// Floor-wide effect zones.
+//
+// A zone is a named set of tiles with an effect that is applied to every monster standing in
+// it at the end of every turn (see ai_hooks.h). Zones are managed in a central table that is
+// reset before every floor by the pre-generate hook CotInstallZones adds (see floor_gen.h), so
+// zones are added from a post-generate hook.
+//
+// While the leader stands in a named zone, its name is shown in a text box on the screen, and
+// entering it is announced in the message log.
+
+#define COT_MAX_ZONES 8
+#define COT_ZONE_INVALID -1
+// Position and width of the zone indicator's text box, in tiles of 8 pixels.
+#ifndef COT_ZONE_INDICATOR_X
+#define COT_ZONE_INDICATOR_X 2
+#endif
+#ifndef COT_ZONE_INDICATOR_Y
+#define COT_ZONE_INDICATOR_Y 2
+#endif
+#ifndef COT_ZONE_INDICATOR_WIDTH
+#define COT_ZONE_INDICATOR_WIDTH 12
+#endif
+
+typedef enum zone_effect_kind {
+  ZONE_EFFECT_NONE = 0,
+  // Deals `amount` HP of damage. Zone damage never knocks out a monster, its HP stops at 1.
+  ZONE_EFFECT_DAMAGE,
+  // Restores `amount` HP.
+  ZONE_EFFECT_HEAL,
+  // Raises (positive `amount`) or lowers (negative `amount`) the offensive stat `stat_idx` by
+  // `amount` stages.
+  ZONE_EFFECT_OFFENSIVE_STAT,
+  // Same as ZONE_EFFECT_OFFENSIVE_STAT, but for defensive stats.
+  ZONE_EFFECT_DEFENSIVE_STAT,
+  // Calls `custom` for each occupant.
+  ZONE_EFFECT_CUSTOM,
+} zone_effect_kind;
+
+// Which monsters a zone affects.
+typedef enum zone_target {
+  ZONE_TARGET_ALL = 0,
+  ZONE_TARGET_TEAM,
+  ZONE_TARGET_ENEMIES,
+} zone_target;
+
+struct zone;
+typedef void (*zone_effect_fn)(struct zone* zone, struct entity* occupant);
+
+typedef struct zone_effect {
+  zone_effect_kind kind;
+  zone_target target;
+  int amount;
+  int stat_idx;
+  zone_effect_fn custom;
+} zone_effect;
+
+typedef struct zone {
+  bool active;
+  // Shown in the indicator and the message log when the leader enters the zone. May be NULL.
+  const char* name;
+  zone_effect effect;
+  // Rectangle shape. Unused if `tiles` is set.
+  struct position top_left;
+  struct position bottom_right;
+  // Tile set shape. The array must stay alive while the zone is active.
+  const struct position* tiles;
+  int n_tiles;
+} zone;
+
+// Adds a rectangular zone covering w*h tiles starting at (x, y).
+// Returns the zone ID or COT_ZONE_INVALID if the zone table is full.
+int CotAddRectZone(const char* name, int x, int y, int w, int h, zone_effect effect);
+// Adds a zone covering an arbitrary set of tiles.
+// Returns the zone ID or COT_ZONE_INVALID if the zone table is full.
+int CotAddTileZone(const char* name, const struct position* tiles, int n_tiles, zone_effect effect);
+void CotRemoveZone(int zone_id);
+void CotClearZones(void);
+bool CotInstallZones(void);
+
+zone* CotGetZone(int zone_id);
+// Returns the ID of the first active zone containing (x, y) or COT_ZONE_INVALID.
+int CotGetZoneAt(int x, int y);
+
+// Applies zone effects to all occupants and updates the leader's zone indicator.
+void cotInternalUpdateZones(void);
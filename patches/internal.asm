@@ -1,6 +1,57 @@
 .nds
 .include "symbols.asm"
 
+// Wraps the game function `func`: its first instruction is moved to the stub `original` in
+// overlay 36 and replaced with a branch to `trampoline`. Both labels are defined with
+// cot_wrapper in src/cot/trampolines.s; see src/cot/hooks.c for the wrappers themselves.
+// Only instructions that don't depend on their own address can be moved as is: pushes, stack
+// allocations, and loads, stores and immediate arithmetic that don't involve pc. Small leaf
+// functions often start by loading a literal instead (ldr rX, [pc, #imm]); the literal is
+// copied into the stub and the load rewritten to read it from there. Anything else stops the
+// build.
+.macro cot_wrap, func, trampoline, original, file, file_start
+  .open "overlay36.bin", overlay36_start
+    .org original
+    .if (readu32(file, func - file_start) & 0xFFFF0000) == 0xE59F0000
+      // ldr rX, [pc, #0] reads the literal slot right after the branch back in the stub
+      .word readu32(file, func - file_start) & 0xFFFFF000
+      .org original + 8
+      .word readu32(file, func + 8 + (readu32(file, func - file_start) & 0xFFF) - file_start)
+    .elseif (readu32(file, func - file_start) & 0xFFFF0000) == 0xE92D0000
+      .word readu32(file, func - file_start)
+    .elseif (readu32(file, func - file_start) & 0xFFFFF000) == 0xE24DD000
+      .word readu32(file, func - file_start)
+    .elseif (readu32(file, func - file_start) & 0xFC000000) == 0xE4000000 || (readu32(file, func - file_start) & 0xFE000000) == 0xE2000000
+      .if (readu32(file, func - file_start) & 0x000F0000) == 0x000F0000 || (readu32(file, func - file_start) & 0x0000F000) == 0x0000F000
+        .error "cot_wrap: the first instruction of the function depends on its address"
+      .endif
+      .word readu32(file, func - file_start)
+    .else
+      .error "cot_wrap: the first instruction of the function depends on its address"
+    .endif
+  .close
+  .open file, file_start
+    .org func
+      b trampoline
+  .close
+.endmacro
+
+// Stops the build if the game's instruction at `addr` isn't `expected`. Addresses in
+// symbols/custom_[region].ld are only valid for one ROM revision, so every address patched below
+// is checked against the instruction the trampolines in src/cot/trampolines.s restore.
+.macro cot_expect, addr, expected, file, file_start
+  .if readu32(file, addr - file_start) != expected
+    .error "cot_expect: unexpected instruction, check the addresses in symbols/custom_[region].ld"
+  .endif
+.endmacro
+
+// push {r3-r9, sl, fp, lr}
+cot_expect ScriptSpecialProcessCall, 0xE92D4FF8, "overlay11.bin", overlay11_start
+// cmp r0, #0
+cot_expect ApplyItemEffectHookAddr, 0xE3500000, "overlay29.bin", overlay29_start
+// mov r1, #0x1
+cot_expect ApplyMoveEffectHookAddr, 0xE3A01001, "overlay29.bin", overlay29_start
+
 .open "overlay11.bin", overlay11_start
   .org ScriptSpecialProcessCall
     b cotInternalTrampolineScriptSpecialProcessCall
@@ -12,3 +63,83 @@
   .org ApplyMoveEffectHookAddr
     b cotInternalTrampolineApplyMoveEffect
 .close
+
+cot_wrap GenerateFloor, cotInternalTrampolineGenerateFloor, cotInternalOriginalGenerateFloor, "overlay29.bin", overlay29_start
+cot_wrap ApplyTrapEffect, cotInternalTrampolineApplyTrapEffect, cotInternalOriginalApplyTrapEffect, "overlay29.bin", overlay29_start
+cot_wrap RunFractionalTurn, cotInternalTrampolineRunFractionalTurn, cotInternalOriginalRunFractionalTurn, "overlay29.bin", overlay29_start
+cot_wrap RunMonsterAi, cotInternalTrampolineRunMonsterAi, cotInternalOriginalRunMonsterAi, "overlay29.bin", overlay29_start
+cot_wrap IqSkillIsEnabled, cotInternalTrampolineIqSkillIsEnabled, cotInternalOriginalIqSkillIsEnabled, "overlay29.bin", overlay29_start
+cot_wrap GenerateFixedRoom, cotInternalTrampolineGenerateFixedRoom, cotInternalOriginalGenerateFixedRoom, "overlay29.bin", overlay29_start
+cot_wrap HandleFaint, cotInternalTrampolineHandleFaint, cotInternalOriginalHandleFaint, "overlay29.bin", overlay29_start
+cot_wrap SpawnNonEnemies, cotInternalTrampolineSpawnNonEnemies, cotInternalOriginalSpawnNonEnemies, "overlay29.bin", overlay29_start
+cot_wrap GetActualBuyPrice, cotInternalTrampolineGetActualBuyPrice, cotInternalOriginalGetActualBuyPrice, "arm9.bin", arm9_start
+cot_wrap GetActualSellPrice, cotInternalTrampolineGetActualSellPrice, cotInternalOriginalGetActualSellPrice, "arm9.bin", arm9_start
+cot_wrap HiddenStairsTrigger, cotInternalTrampolineHiddenStairsTrigger, cotInternalOriginalHiddenStairsTrigger, "overlay29.bin", overlay29_start
+cot_wrap DrawMinimapTile, cotInternalTrampolineDrawMinimapTile, cotInternalOriginalDrawMinimapTile, "overlay29.bin", overlay29_start
+cot_wrap TryActivateWeather, cotInternalTrampolineTryActivateWeather, cotInternalOriginalTryActivateWeather, "overlay29.bin", overlay29_start
+cot_wrap DoMoveTrapbust, cotInternalTrampolineDoMoveTrapbust, cotInternalOriginalDoMoveTrapbust, "overlay29.bin", overlay29_start
+cot_wrap CanMonsterMoveInDirection, cotInternalTrampolineCanMonsterMoveInDirection, cotInternalOriginalCanMonsterMoveInDirection, "overlay29.bin", overlay29_start
+cot_wrap GetType, cotInternalTrampolineGetType, cotInternalOriginalGetType, "arm9.bin", arm9_start
+cot_wrap GetAbility, cotInternalTrampolineGetAbility, cotInternalOriginalGetAbility, "arm9.bin", arm9_start
+cot_wrap GetNameString, cotInternalTrampolineGetNameString, cotInternalOriginalGetNameString, "arm9.bin", arm9_start
+cot_wrap GetCategoryString, cotInternalTrampolineGetCategoryString, cotInternalOriginalGetCategoryString, "arm9.bin", arm9_start
+cot_wrap GetSpriteIndex, cotInternalTrampolineGetSpriteIndex, cotInternalOriginalGetSpriteIndex, "arm9.bin", arm9_start
+cot_wrap GetMonsterIdToSpawn, cotInternalTrampolineGetMonsterIdToSpawn, cotInternalOriginalGetMonsterIdToSpawn, "overlay29.bin", overlay29_start
+cot_wrap GetAiUseItemProbability, cotInternalTrampolineGetAiUseItemProbability, cotInternalOriginalGetAiUseItemProbability, "overlay29.bin", overlay29_start
+cot_wrap CalcDamage, cotInternalTrampolineCalcDamage, cotInternalOriginalCalcDamage, "overlay29.bin", overlay29_start
+cot_wrap GetLowKickMultiplier, cotInternalTrampolineGetLowKickMultiplier, cotInternalOriginalGetLowKickMultiplier, "arm9.bin", arm9_start
+cot_wrap SpawnMonster, cotInternalTrampolineSpawnMonster, cotInternalOriginalSpawnMonster, "overlay29.bin", overlay29_start
+cot_wrap GetMoveType, cotInternalTrampolineGetMoveType, cotInternalOriginalGetMoveType, "arm9.bin", arm9_start
+cot_wrap GetMoveBasePower, cotInternalTrampolineGetMoveBasePower, cotInternalOriginalGetMoveBasePower, "arm9.bin", arm9_start
+cot_wrap GetMoveBasePp, cotInternalTrampolineGetMoveBasePp, cotInternalOriginalGetMoveBasePp, "arm9.bin", arm9_start
+cot_wrap GetMoveAccuracyOrAiChance, cotInternalTrampolineGetMoveAccuracyOrAiChance, cotInternalOriginalGetMoveAccuracyOrAiChance, "arm9.bin", arm9_start
+cot_wrap GetMoveCategory, cotInternalTrampolineGetMoveCategory, cotInternalOriginalGetMoveCategory, "arm9.bin", arm9_start
+cot_wrap GetItemCategory, cotInternalTrampolineGetItemCategory, cotInternalOriginalGetItemCategory, "arm9.bin", arm9_start
+cot_wrap GetItemSpriteId, cotInternalTrampolineGetItemSpriteId, cotInternalOriginalGetItemSpriteId, "arm9.bin", arm9_start
+cot_wrap GetItemPaletteId, cotInternalTrampolineGetItemPaletteId, cotInternalOriginalGetItemPaletteId, "arm9.bin", arm9_start
+cot_wrap GetItemBuyPrice, cotInternalTrampolineGetItemBuyPrice, cotInternalOriginalGetItemBuyPrice, "arm9.bin", arm9_start
+cot_wrap GetItemSellPrice, cotInternalTrampolineGetItemSellPrice, cotInternalOriginalGetItemSellPrice, "arm9.bin", arm9_start
+cot_wrap GetKecleonIdToSpawnByFloor, cotInternalTrampolineGetKecleonIdToSpawnByFloor, cotInternalOriginalGetKecleonIdToSpawnByFloor, "overlay29.bin", overlay29_start
+cot_wrap RunNextOpcode, cotInternalTrampolineRunNextOpcode, cotInternalOriginalRunNextOpcode, "overlay11.bin", overlay11_start
+cot_wrap GetDexNumber, cotInternalTrampolineGetDexNumber, cotInternalOriginalGetDexNumber, "arm9.bin", arm9_start
+cot_wrap GetMonsterGender, cotInternalTrampolineGetMonsterGender, cotInternalOriginalGetMonsterGender, "arm9.bin", arm9_start
+cot_wrap GetBodySize, cotInternalTrampolineGetBodySize, cotInternalOriginalGetBodySize, "arm9.bin", arm9_start
+cot_wrap GetSpriteSize, cotInternalTrampolineGetSpriteSize, cotInternalOriginalGetSpriteSize, "arm9.bin", arm9_start
+cot_wrap GetShadowSize, cotInternalTrampolineGetShadowSize, cotInternalOriginalGetShadowSize, "arm9.bin", arm9_start
+cot_wrap GetSpeedStatus, cotInternalTrampolineGetSpeedStatus, cotInternalOriginalGetSpeedStatus, "arm9.bin", arm9_start
+cot_wrap GetMobilityType, cotInternalTrampolineGetMobilityType, cotInternalOriginalGetMobilityType, "arm9.bin", arm9_start
+cot_wrap GetRegenSpeed, cotInternalTrampolineGetRegenSpeed, cotInternalOriginalGetRegenSpeed, "arm9.bin", arm9_start
+cot_wrap GetCanMoveFlag, cotInternalTrampolineGetCanMoveFlag, cotInternalOriginalGetCanMoveFlag, "arm9.bin", arm9_start
+cot_wrap GetChanceAsleep, cotInternalTrampolineGetChanceAsleep, cotInternalOriginalGetChanceAsleep, "arm9.bin", arm9_start
+cot_wrap GetBaseHp, cotInternalTrampolineGetBaseHp, cotInternalOriginalGetBaseHp, "arm9.bin", arm9_start
+cot_wrap GetRecruitRate1, cotInternalTrampolineGetRecruitRate1, cotInternalOriginalGetRecruitRate1, "arm9.bin", arm9_start
+cot_wrap GetRecruitRate2, cotInternalTrampolineGetRecruitRate2, cotInternalOriginalGetRecruitRate2, "arm9.bin", arm9_start
+cot_wrap GetIqGroup, cotInternalTrampolineGetIqGroup, cotInternalOriginalGetIqGroup, "arm9.bin", arm9_start
+cot_wrap GetSpawnThreshold, cotInternalTrampolineGetSpawnThreshold, cotInternalOriginalGetSpawnThreshold, "arm9.bin", arm9_start
+cot_wrap NeedsItemToSpawn, cotInternalTrampolineNeedsItemToSpawn, cotInternalOriginalNeedsItemToSpawn, "arm9.bin", arm9_start
+cot_wrap GetBaseOffensiveStat, cotInternalTrampolineGetBaseOffensiveStat, cotInternalOriginalGetBaseOffensiveStat, "arm9.bin", arm9_start
+cot_wrap GetBaseDefensiveStat, cotInternalTrampolineGetBaseDefensiveStat, cotInternalOriginalGetBaseDefensiveStat, "arm9.bin", arm9_start
+cot_wrap GetExp, cotInternalTrampolineGetExp, cotInternalOriginalGetExp, "arm9.bin", arm9_start
+cot_wrap GetLvlUpEntry, cotInternalTrampolineGetLvlUpEntry, cotInternalOriginalGetLvlUpEntry, "arm9.bin", arm9_start
+cot_wrap GetNameRaw, cotInternalTrampolineGetNameRaw, cotInternalOriginalGetNameRaw, "arm9.bin", arm9_start
+cot_wrap GetMaxPp, cotInternalTrampolineGetMaxPp, cotInternalOriginalGetMaxPp, "arm9.bin", arm9_start
+cot_wrap GetMoveCritChance, cotInternalTrampolineGetMoveCritChance, cotInternalOriginalGetMoveCritChance, "arm9.bin", arm9_start
+cot_wrap GetMoveAnimation, cotInternalTrampolineGetMoveAnimation, cotInternalOriginalGetMoveAnimation, "overlay10.bin", overlay10_start
+cot_wrap GetMoveAnimationSpeed, cotInternalTrampolineGetMoveAnimationSpeed, cotInternalOriginalGetMoveAnimationSpeed, "overlay10.bin", overlay10_start
+cot_wrap StringFromId, cotInternalTrampolineStringFromId, cotInternalOriginalStringFromId, "arm9.bin", arm9_start
+cot_wrap GetItemName, cotInternalTrampolineGetItemName, cotInternalOriginalGetItemName, "arm9.bin", arm9_start
+cot_wrap IsThrownItem, cotInternalTrampolineIsThrownItem, cotInternalOriginalIsThrownItem, "arm9.bin", arm9_start
+cot_wrap IsEdible, cotInternalTrampolineIsEdible, cotInternalOriginalIsEdible, "arm9.bin", arm9_start
+cot_wrap AddMissionToJobList, cotInternalTrampolineAddMissionToJobList, cotInternalOriginalAddMissionToJobList, "arm9.bin", arm9_start
+cot_wrap GroundToDungeonMoveset, cotInternalTrampolineGroundToDungeonMoveset, cotInternalOriginalGroundToDungeonMoveset, "arm9.bin", arm9_start
+cot_wrap ApplyGummiBoostsDungeonMode, cotInternalTrampolineApplyGummiBoostsDungeonMode, cotInternalOriginalApplyGummiBoostsDungeonMode, "overlay29.bin", overlay29_start
+cot_wrap SubstitutePlaceholderStringTags, cotInternalTrampolineSubstitutePlaceholderStringTags, cotInternalOriginalSubstitutePlaceholderStringTags, "overlay29.bin", overlay29_start
+cot_wrap PickUpItem, cotInternalTrampolinePickUpItem, cotInternalOriginalPickUpItem, "overlay29.bin", overlay29_start
+cot_wrap SpawnInitialMonsters, cotInternalTrampolineSpawnInitialMonsters, cotInternalOriginalSpawnInitialMonsters, "overlay29.bin", overlay29_start
+cot_wrap SpawnItem, cotInternalTrampolineSpawnItem, cotInternalOriginalSpawnItem, "overlay29.bin", overlay29_start
+cot_wrap SpawnTrap, cotInternalTrampolineSpawnTrap, cotInternalOriginalSpawnTrap, "overlay29.bin", overlay29_start
+cot_wrap GetMovesetLevelUpPtr, cotInternalTrampolineGetMovesetLevelUpPtr, cotInternalOriginalGetMovesetLevelUpPtr, "arm9.bin", arm9_start
+cot_wrap GetMovesetHmTmPtr, cotInternalTrampolineGetMovesetHmTmPtr, cotInternalOriginalGetMovesetHmTmPtr, "arm9.bin", arm9_start
+cot_wrap GetMovesetEggPtr, cotInternalTrampolineGetMovesetEggPtr, cotInternalOriginalGetMovesetEggPtr, "arm9.bin", arm9_start
+cot_wrap CalcDamageFixed, cotInternalTrampolineCalcDamageFixed, cotInternalOriginalCalcDamageFixed, "overlay29.bin", overlay29_start
+cot_wrap IsMarowakTrainingMaze, cotInternalTrampolineIsMarowakTrainingMaze, cotInternalOriginalIsMarowakTrainingMaze, "arm9.bin", arm9_start
@@ -0,0 +1,86 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+static COT_VEC(turn_hook_fn, COT_MAX_TURN_HOOKS) turn_start_hooks;
+static COT_VEC(turn_hook_fn, COT_MAX_TURN_HOOKS) turn_end_hooks;
+static COT_VEC(action_hook_fn, COT_MAX_ACTION_HOOKS) action_hooks;
+
+bool CotAddTurnStartHook(turn_hook_fn hook) {
+  if (!COT_VEC_PUSH(&turn_start_hooks, hook)) {
+    COT_WARN(COT_LOG_CAT_AI_HOOKS, "Turn start hook table is full");
+    return false;
+  }
+  return true;
+}
+
+bool CotAddTurnEndHook(turn_hook_fn hook) {
+  if (!COT_VEC_PUSH(&turn_end_hooks, hook)) {
+    COT_WARN(COT_LOG_CAT_AI_HOOKS, "Turn end hook table is full");
+    return false;
+  }
+  return true;
+}
+
+// Keeps the order of the remaining hooks
+#define REMOVE_HOOK(vec, hook) \
+  for (int i = 0; i < (vec)->len; i++) { \
+    if ((vec)->items[i] == (hook)) { \
+      COT_VEC_REMOVE((vec), i); \
+      break; \
+    } \
+  }
+
+void CotRemoveTurnHook(turn_hook_fn hook) {
+  REMOVE_HOOK(&turn_start_hooks, hook);
+  REMOVE_HOOK(&turn_end_hooks, hook);
+}
+
+bool CotAddActionHook(action_hook_fn hook) {
+  if (!COT_VEC_PUSH(&action_hooks, hook)) {
+    COT_WARN(COT_LOG_CAT_AI_HOOKS, "Action hook table is full");
+    return false;
+  }
+  return true;
+}
+
+void CotRemoveActionHook(action_hook_fn hook) {
+  REMOVE_HOOK(&action_hooks, hook);
+}
+
+// Hooks may add or remove hooks, which would shift the list while it's iterated. Iterating a
+// copy runs every hook exactly once.
+void cotInternalOnTurnStart(void) {
+  cotInternalAnnounceOutlaws();
+
+  __typeof__(turn_start_hooks) hooks = turn_start_hooks;
+  for (int i = 0; i < hooks.len; i++) {
+    hooks.items[i]();
+  }
+}
+
+void cotInternalOnTurnEnd(void) {
+  cotInternalCheckIllusionsDamaged();
+  cotInternalCheckEscortsDamaged();
+  cotInternalCheckShopTheft();
+  cotInternalUpdateTerrainMechanics();
+  cotInternalUpdateFlavorEvents();
+  cotInternalUpdateZones();
+
+  __typeof__(turn_end_hooks) hooks = turn_end_hooks;
+  for (int i = 0; i < hooks.len; i++) {
+    hooks.items[i]();
+  }
+}
+
+void cotInternalOnMonsterActionChosen(struct entity* monster) {
+  if (monster == NULL || !EntityIsValid(monster) || monster->type != ENTITY_MONSTER) {
+    return;
+  }
+  cotInternalRunEscortAi(monster);
+
+  struct action_data* action = &((struct monster*) monster->info)->action;
+  __typeof__(action_hooks) hooks = action_hooks;
+  for (int i = 0; i < hooks.len; i++) {
+    hooks.items[i](monster, action);
+  }
+}
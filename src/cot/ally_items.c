@@ -0,0 +1,64 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+// Indexed by [is guest]
+static ally_item_rule use_rules[2] = { ALLY_ITEMS_VANILLA, ALLY_ITEMS_VANILLA };
+static ally_item_rule pick_up_rules[2] = { ALLY_ITEMS_VANILLA, ALLY_ITEMS_VANILLA };
+static ally_item_hook_fn item_hook = NULL;
+
+void CotSetAllyItemUseRule(ally_item_rule team, ally_item_rule guests) {
+  use_rules[0] = team;
+  use_rules[1] = guests;
+}
+
+void CotSetAllyItemPickUpRule(ally_item_rule team, ally_item_rule guests) {
+  pick_up_rules[0] = team;
+  pick_up_rules[1] = guests;
+}
+
+void CotSetAllyItemHook(ally_item_hook_fn hook) {
+  item_hook = hook;
+}
+
+static bool IsAlly(struct entity* entity) {
+  struct entity* leader = GetLeader();
+  return CotGetMonster(entity) != NULL && leader != NULL
+         && GetTreatmentBetweenMonsters(leader, entity, true, false) == TREATMENT_TREAT_AS_ALLY;
+}
+
+static bool Decide(const ally_item_rule* rules, struct entity* ally, struct item* item,
+                   ally_item_action action, bool vanilla) {
+  struct monster* monster = (struct monster*) ally->info;
+  bool allowed = vanilla;
+  switch (rules[monster->is_not_team_member ? 1 : 0]) {
+    case ALLY_ITEMS_NEVER:
+      allowed = false;
+      break;
+    case ALLY_ITEMS_ALWAYS:
+      allowed = true;
+      break;
+    default:
+      break;
+  }
+  if (item_hook != NULL) {
+    allowed = item_hook(ally, item, action, allowed);
+  }
+  return allowed;
+}
+
+int cotInternalGetAllyItemUseChance(struct entity* ally, struct item* item, int vanilla) {
+  if (!IsAlly(ally)) {
+    return vanilla;
+  }
+  if (!Decide(use_rules, ally, item, ALLY_ITEM_USE, vanilla > 0)) {
+    return 0;
+  }
+  return vanilla > 0 ? vanilla : 100;
+}
+
+bool cotInternalCanAllyPickUpItem(struct entity* ally, struct item* item) {
+  if (!IsAlly(ally)) {
+    return true;
+  }
+  return Decide(pick_up_rules, ally, item, ALLY_ITEM_PICK_UP, true);
+}
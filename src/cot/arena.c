@@ -0,0 +1,142 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+// Every allocation is preceded by the size of its block, padded to keep the data 8-byte
+// aligned.
+#define HEADER_SIZE COT_ARENA_HEADER_SIZE
+
+typedef struct tracked_alloc {
+  const cot_arena* arena;
+  void* ptr;
+  uint32_t size;
+  void* caller;
+} tracked_alloc;
+
+static tracked_alloc tracked[COT_ARENA_MAX_TRACKED];
+
+static void Track(const cot_arena* arena, void* ptr, uint32_t size, void* caller) {
+  for (int i = 0; i < COT_ARENA_MAX_TRACKED; i++) {
+    if (tracked[i].ptr == NULL) {
+      tracked[i] = (tracked_alloc) { .arena = arena, .ptr = ptr, .size = size, .caller = caller };
+      return;
+    }
+  }
+  COT_WARN(COT_LOG_CAT_ARENA, "Leak tracking table is full");
+}
+
+static void Untrack(void* ptr) {
+  for (int i = 0; i < COT_ARENA_MAX_TRACKED; i++) {
+    if (tracked[i].ptr == ptr) {
+      tracked[i].ptr = NULL;
+      return;
+    }
+  }
+}
+
+void CotArenaInit(cot_arena* arena, struct mem_arena* mem_arena, uint32_t budget) {
+  *arena = (cot_arena) { .arena = mem_arena, .budget = budget };
+}
+
+uint32_t CotArenaRemaining(const cot_arena* arena) {
+  return arena->budget - arena->used;
+}
+
+void* CotArenaAlloc(cot_arena* arena, uint32_t size) {
+  uint32_t block_size = size + HEADER_SIZE;
+  if (block_size < size || block_size > CotArenaRemaining(arena)) {
+    COT_WARNFMT(COT_LOG_CAT_ARENA,
+                "Allocation of %d bytes exceeds the remaining budget of %d bytes", size,
+                CotArenaRemaining(arena));
+    return NULL;
+  }
+  uint8_t* block = arena->arena != NULL
+    ? MemArenaAlloc(arena->arena, block_size, 0)
+    : MemAlloc(block_size, 0);
+  if (block == NULL) {
+    COT_WARNFMT(COT_LOG_CAT_ARENA, "Out of memory allocating %d bytes", size);
+    return NULL;
+  }
+  *(uint32_t*) block = block_size;
+  arena->used += block_size;
+  arena->peak = arena->used > arena->peak ? arena->used : arena->peak;
+  arena->n_allocs++;
+  if (arena->track_leaks) {
+    Track(arena, block + HEADER_SIZE, size, __builtin_return_address(0));
+  }
+  return block + HEADER_SIZE;
+}
+
+void CotArenaFree(cot_arena* arena, void* ptr) {
+  if (ptr == NULL) {
+    return;
+  }
+  uint8_t* block = (uint8_t*) ptr - HEADER_SIZE;
+  arena->used -= *(uint32_t*) block;
+  arena->n_allocs--;
+  // Even if tracking has been disabled since, so no stale entries are left behind
+  Untrack(ptr);
+  if (arena->arena != NULL) {
+    MemArenaFree(arena->arena, block);
+  } else {
+    MemFree(block);
+  }
+}
+
+arena_stats CotGetArenaStats(const cot_arena* arena) {
+  arena_stats stats = {
+    .total = arena->budget,
+    .used = arena->used,
+    .free = CotArenaRemaining(arena),
+    .peak = arena->peak,
+    .n_allocs = arena->n_allocs,
+  };
+  // Blocks of the game's allocator that aren't in use have a `used` size of 0
+  struct mem_arena* mem_arena = arena->arena != NULL ? arena->arena : &DEFAULT_MEMORY_ARENA;
+  stats.arena_total = mem_arena->data_size;
+  for (uint32_t i = 0; i < mem_arena->n_blocks; i++) {
+    struct mem_block* block = &mem_arena->blocks[i];
+    if (block->used != 0) {
+      stats.arena_used += block->available;
+    } else if (block->available > stats.largest_free_block) {
+      stats.largest_free_block = block->available;
+    }
+  }
+  uint32_t fits = stats.largest_free_block < stats.free ? stats.largest_free_block : stats.free;
+  stats.headroom = fits > HEADER_SIZE ? fits - HEADER_SIZE : 0;
+  return stats;
+}
+
+void CotArenaSetLeakTracking(cot_arena* arena, bool enabled) {
+  arena->track_leaks = enabled;
+}
+
+int CotArenaReportLeaks(const cot_arena* arena) {
+  int n_leaks = 0;
+  for (int i = 0; i < COT_ARENA_MAX_TRACKED; i++) {
+    if (tracked[i].ptr != NULL && tracked[i].arena == arena) {
+      COT_WARNFMT(COT_LOG_CAT_ARENA, "Leaked %d bytes at %p, allocated from %p",
+                  tracked[i].size, tracked[i].ptr, tracked[i].caller);
+      n_leaks++;
+    }
+  }
+  return n_leaks;
+}
+
+void* CotScopeAlloc(arena_scope* scope, uint32_t size) {
+  if (scope->n_allocs >= COT_ARENA_SCOPE_MAX_ALLOCS) {
+    COT_WARN(COT_LOG_CAT_ARENA, "Arena scope is full");
+    return NULL;
+  }
+  void* ptr = CotArenaAlloc(scope->arena, size);
+  if (ptr != NULL) {
+    scope->allocs[scope->n_allocs++] = ptr;
+  }
+  return ptr;
+}
+
+void CotArenaScopeEnd(arena_scope* scope) {
+  // Free in reverse order, which keeps the game's allocator from fragmenting
+  while (scope->n_allocs > 0) {
+    CotArenaFree(scope->arena, scope->allocs[--scope->n_allocs]);
+  }
+}
@@ -0,0 +1,139 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+#ifndef NDEBUG
+
+#define REG_TM2CNT_L (*(volatile uint16_t*) 0x04000108)
+#define REG_TM2CNT_H (*(volatile uint16_t*) 0x0400010A)
+#define REG_TM3CNT_L (*(volatile uint16_t*) 0x0400010C)
+#define REG_TM3CNT_H (*(volatile uint16_t*) 0x0400010E)
+
+#define TIMER_ENABLE 0x80
+#define TIMER_CASCADE 0x04
+#define TIMER_PRESCALER_64 0x01
+
+void CotBenchStart(void) {
+  REG_TM2CNT_H = 0;
+  REG_TM3CNT_H = 0;
+  REG_TM2CNT_L = 0;
+  REG_TM3CNT_L = 0;
+  REG_TM3CNT_H = TIMER_ENABLE | TIMER_CASCADE;
+  REG_TM2CNT_H = TIMER_ENABLE | TIMER_PRESCALER_64;
+}
+
+uint32_t CotBenchStop(void) {
+  REG_TM2CNT_H = 0;
+  return ((uint32_t) REG_TM3CNT_L << 16) | REG_TM2CNT_L;
+}
+
+static void LogResult(const char* name, uint32_t ticks, int iterations) {
+  uint32_t per_call = ticks / iterations;
+  // Approximates 1000000 / COT_BENCH_TICKS_PER_SECOND without 64-bit arithmetic
+  COT_LOGFMT(COT_LOG_CAT_BENCH, "%s: %d ticks (~%d us) per call", name, per_call,
+             per_call * 1000 / 524);
+}
+
+static void CountFloorTile(struct tile* tile, int x, int y, void* ctx) {
+  *(int*) ctx += tile->terrain_flags.terrain_type == TERRAIN_NORMAL;
+}
+
+// The game's generator, without the generation hooks. Defined in trampolines.s.
+extern __typeof__(GenerateFloor) cotInternalOriginalGenerateFloor;
+
+static void GenerateBuiltin(void) {
+  cotInternalOriginalGenerateFloor();
+}
+
+static void GenerateRooms(void) {
+  COT_ROOMS_GENERATOR.generate(&DUNGEON_PTR->floor_properties, NULL);
+}
+
+// Generation time depends heavily on the layout, so every generator is timed on the same
+// seeds 1..iterations and the spread is logged along with the average.
+static void BenchAcrossSeeds(const char* name, void (*generate)(void), int iterations) {
+  uint32_t total = 0;
+  uint32_t min = UINT32_MAX;
+  uint32_t max = 0;
+  for (int i = 0; i < iterations; i++) {
+    CotSeedDungeonRng(i + 1);
+    CotBenchStart();
+    generate();
+    uint32_t ticks = CotBenchStop();
+    total += ticks;
+    min = ticks < min ? ticks : min;
+    max = ticks > max ? ticks : max;
+  }
+  LogResult(name, total, iterations);
+  COT_LOGFMT(COT_LOG_CAT_BENCH, "%s: %d to %d ticks over %d seeds", name, min, max, iterations);
+}
+
+void CotRunFloorGenBenchmarks(int iterations) {
+  COT_ASSERT(iterations > 0);
+
+  CotBenchStart();
+  for (int i = 0; i < iterations; i++) {
+    ResetFloor();
+  }
+  LogResult("ResetFloor", CotBenchStop(), iterations);
+
+  rng_snapshot rng;
+  CotSaveDungeonRng(&rng);
+  cotInternalSetGeneratingFloor(true);
+  BenchAcrossSeeds("Builtin generator", GenerateBuiltin, iterations);
+  BenchAcrossSeeds("Rooms generator", GenerateRooms, iterations);
+  cotInternalSetGeneratingFloor(false);
+  CotRestoreDungeonRng(&rng);
+
+  // Time each phase of the rooms generator separately
+  static const char* phase_names[] = {
+    "Rooms phase", "Imperfections phase", "Corridors phase", "Extra hallways phase",
+    "Finalize phase",
+  };
+  uint32_t phase_ticks[ROOMS_GEN_DONE] = { 0 };
+  uint32_t commit_ticks = 0;
+  rooms_gen_state* state = MemAlloc(sizeof(rooms_gen_state), 0);
+  if (state != NULL) {
+    for (int i = 0; i < iterations; i++) {
+      CotRoomsGenBegin(state, NULL);
+      while (state->phase != ROOMS_GEN_DONE) {
+        rooms_gen_phase phase = state->phase;
+        CotBenchStart();
+        CotRoomsGenStep(state);
+        phase_ticks[phase] += CotBenchStop();
+      }
+      CotBenchStart();
+      CotRoomsGenCommit(state);
+      commit_ticks += CotBenchStop();
+    }
+    MemFree(state);
+    for (int i = 0; i < ROOMS_GEN_DONE; i++) {
+      LogResult(phase_names[i], phase_ticks[i], iterations);
+    }
+    LogResult("Commit", commit_ticks, iterations);
+  }
+
+  int n_floor_tiles = 0;
+  CotBenchStart();
+  for (int i = 0; i < iterations; i++) {
+    CotForEachTile(0, 0, COT_FLOOR_WIDTH, COT_FLOOR_HEIGHT, CountFloorTile, &n_floor_tiles);
+  }
+  LogResult("CotForEachTile (full floor)", CotBenchStop(), iterations);
+
+  floor_snapshot snapshot;
+  if (CotFloorSnapshotAlloc(&snapshot)) {
+    CotBenchStart();
+    for (int i = 0; i < iterations; i++) {
+      CotFloorSnapshotCapture(&snapshot);
+    }
+    LogResult("CotFloorSnapshotCapture", CotBenchStop(), iterations);
+
+    CotBenchStart();
+    for (int i = 0; i < iterations; i++) {
+      CotFloorSnapshotRestore(&snapshot);
+    }
+    LogResult("CotFloorSnapshotRestore", CotBenchStop(), iterations);
+    CotFloorSnapshotFree(&snapshot);
+  }
+}
+
+#endif
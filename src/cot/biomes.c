@@ -0,0 +1,103 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+typedef struct biome_rule {
+  enum dungeon_id dungeon;
+  int first_floor;
+  int last_floor;
+  const biome* biome;
+} biome_rule;
+
+static biome_rule rules[COT_MAX_BIOME_RULES];
+static int n_rules = 0;
+static const biome* current_biome = NULL;
+
+bool CotAddBiomeRule(enum dungeon_id dungeon, int first_floor, int last_floor, const biome* biome) {
+  if (n_rules >= COT_MAX_BIOME_RULES) {
+    COT_WARN(COT_LOG_CAT_FLOOR_GEN, "Biome rule table is full");
+    return false;
+  }
+  rules[n_rules++] = (biome_rule) {
+    .dungeon = dungeon, .first_floor = first_floor, .last_floor = last_floor, .biome = biome,
+  };
+  return true;
+}
+
+void CotClearBiomeRules(void) {
+  n_rules = 0;
+}
+
+const biome* CotGetCurrentBiome(void) {
+  return current_biome;
+}
+
+static bool RuleMatches(const biome_rule* rule, enum dungeon_id dungeon, int floor) {
+  if (rule->dungeon != COT_ANY_DUNGEON && rule->dungeon != dungeon) {
+    return false;
+  }
+  return (rule->first_floor == COT_ANY_FLOOR || floor >= rule->first_floor)
+      && (rule->last_floor == COT_ANY_FLOOR || floor <= rule->last_floor);
+}
+
+static const biome* SelectBiome(void) {
+  for (int i = 0; i < n_rules; i++) {
+    if (RuleMatches(&rules[i], DUNGEON_PTR->id.val, DUNGEON_PTR->floor)) {
+      return rules[i].biome;
+    }
+  }
+  return NULL;
+}
+
+static void ApplyBiomeProperties(struct floor_properties* props) {
+  current_biome = SelectBiome();
+  if (current_biome == NULL) {
+    return;
+  }
+  COT_LOGFMT(COT_LOG_CAT_FLOOR_GEN, "Using biome %s", current_biome->name);
+  if (current_biome->tileset != COT_BIOME_KEEP) {
+    props->tileset = current_biome->tileset;
+  }
+  if (current_biome->music_table_idx != COT_BIOME_KEEP) {
+    props->music_table_idx = current_biome->music_table_idx;
+  }
+  if (current_biome->weather_chance > 0 && CotRandChance(RNG_DUNGEON, current_biome->weather_chance)) {
+    props->weather.val = current_biome->weather;
+  }
+}
+
+static void SpawnBiomeMonsters(const biome* biome) {
+  int weights[COT_MAX_BIOME_SPAWNS];
+  int n = biome->n_spawns < COT_MAX_BIOME_SPAWNS ? biome->n_spawns : COT_MAX_BIOME_SPAWNS;
+  for (int i = 0; i < n; i++) {
+    weights[i] = biome->spawns[i].weight;
+  }
+  for (int i = 0; i < biome->n_extra_spawns; i++) {
+    int picked = CotRandWeighted(RNG_DUNGEON, weights, n);
+    if (picked < 0) {
+      return;
+    }
+    monster_spawn spawn;
+    CotSpawnInit(&spawn, biome->spawns[picked].species);
+    CotSpawnSetLevel(&spawn, biome->spawns[picked].level);
+    if (CotSpawn(&spawn) == NULL) {
+      return;
+    }
+  }
+}
+
+static void ApplyBiomeFeatures(struct floor_properties* props) {
+  if (current_biome == NULL) {
+    return;
+  }
+  if (current_biome->n_mutations > 0) {
+    CotApplyFloorMutations(current_biome->mutations, current_biome->n_mutations);
+  }
+  CotApplyRoomDecorators(current_biome->decorators, current_biome->n_decorators);
+  if (current_biome->n_spawns > 0) {
+    SpawnBiomeMonsters(current_biome);
+  }
+}
+
+bool CotInstallBiomes(void) {
+  return CotAddPreGenerateHook(ApplyBiomeProperties) && CotAddPostGenerateHook(ApplyBiomeFeatures);
+}
@@ -0,0 +1,147 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+#define N_TILES (COT_FLOOR_WIDTH * COT_FLOOR_HEIGHT)
+#define NO_PARENT 0xFFFF
+
+// Bridges only connect tiles orthogonally
+#define N_STRAIGHT_DIRECTIONS 4
+
+// Too large for the stack
+static floor_bitset reachable;
+static uint16_t parents[N_TILES];
+static uint16_t queue[N_TILES];
+
+static bool IsReachable(int idx) {
+  return CotBitsetTest(&reachable, idx % COT_FLOOR_WIDTH, idx / COT_FLOOR_WIDTH);
+}
+
+static void MarkReachable(int idx) {
+  CotBitsetSet(&reachable, idx % COT_FLOOR_WIDTH, idx / COT_FLOOR_WIDTH);
+}
+
+static bool IsSecondary(int x, int y) {
+  struct tile* tile = CotTileAt(x, y);
+  return tile != NULL && tile->terrain_flags.terrain_type == TERRAIN_SECONDARY;
+}
+
+static bool MakeFloor(int x, int y) {
+  if (!IsSecondary(x, y)) {
+    return false;
+  }
+  CotSetTileTerrain(x, y, TERRAIN_NORMAL, false);
+  return true;
+}
+
+int CotBuildBridge(int x0, int y0, int x1, int y1) {
+  int changed = 0;
+  int x = x0;
+  int y = y0;
+  changed += MakeFloor(x, y);
+  while (x != x1) {
+    x += x < x1 ? 1 : -1;
+    changed += MakeFloor(x, y);
+  }
+  while (y != y1) {
+    y += y < y1 ? 1 : -1;
+    changed += MakeFloor(x, y);
+  }
+  return changed;
+}
+
+int CotPlaceSteppingStones(int x, int y, int w, int h, int spacing) {
+  int changed = 0;
+  for (int j = y; j < y + h; j++) {
+    for (int i = x; i < x + w; i++) {
+      if (spacing > 0 && (i + j) % spacing == 0) {
+        changed += MakeFloor(i, j);
+      }
+    }
+  }
+  return changed;
+}
+
+// Marks all floor tiles reachable on foot from the tiles already marked.
+static void FloodFill(void) {
+  int head = 0;
+  int tail = 0;
+  for (int i = 0; i < N_TILES; i++) {
+    if (IsReachable(i)) {
+      queue[tail++] = i;
+    }
+  }
+  while (head < tail) {
+    int idx = queue[head++];
+    for (int d = 0; d < N_STRAIGHT_DIRECTIONS; d++) {
+      struct position offset = CotDirectionOffset((enum direction_id) (d * 2));
+      int x = idx % COT_FLOOR_WIDTH + offset.x;
+      int y = idx / COT_FLOOR_WIDTH + offset.y;
+      int next = y * COT_FLOOR_WIDTH + x;
+      if (CotTileIsFloor(x, y) && !IsReachable(next)) {
+        MarkReachable(next);
+        queue[tail++] = next;
+      }
+    }
+  }
+}
+
+// Searches outwards from the reachable area through secondary terrain and turns the shortest
+// path to an unreachable floor tile into a bridge.
+static bool BuildShortestBridge(void) {
+  int head = 0;
+  int tail = 0;
+  for (int i = 0; i < N_TILES; i++) {
+    parents[i] = NO_PARENT;
+    if (IsReachable(i)) {
+      parents[i] = i;
+      queue[tail++] = i;
+    }
+  }
+  while (head < tail) {
+    int idx = queue[head++];
+    for (int d = 0; d < N_STRAIGHT_DIRECTIONS; d++) {
+      struct position offset = CotDirectionOffset((enum direction_id) (d * 2));
+      int x = idx % COT_FLOOR_WIDTH + offset.x;
+      int y = idx / COT_FLOOR_WIDTH + offset.y;
+      int next = y * COT_FLOOR_WIDTH + x;
+      if (!CotTileInBounds(x, y) || parents[next] != NO_PARENT) {
+        continue;
+      }
+      if (CotTileIsFloor(x, y)) {
+        // Reached a cut-off area; walk back and bridge the secondary terrain on the way
+        for (int i = idx; !IsReachable(i); i = parents[i]) {
+          MakeFloor(i % COT_FLOOR_WIDTH, i / COT_FLOOR_WIDTH);
+          MarkReachable(i);
+        }
+        MarkReachable(next);
+        return true;
+      }
+      if (IsSecondary(x, y)) {
+        parents[next] = idx;
+        queue[tail++] = next;
+      }
+    }
+  }
+  return false;
+}
+
+int CotConnectAcrossSecondaryTerrain(int max_bridges) {
+  CotBitsetClear(&reachable);
+  struct position spawn = DUNGEON_PTR->gen_info.team_spawn_pos;
+  if (!CotTileIsFloor(spawn.x, spawn.y)) {
+    COT_WARN(COT_LOG_CAT_FLOOR_GEN, "Team spawn isn't on a floor tile");
+    return 0;
+  }
+  CotBitsetSet(&reachable, spawn.x, spawn.y);
+  FloodFill();
+
+  int n_bridges = 0;
+  while (n_bridges < max_bridges && BuildShortestBridge()) {
+    n_bridges++;
+    FloodFill();
+  }
+  if (n_bridges > 0) {
+    COT_LOGFMT(COT_LOG_CAT_FLOOR_GEN, "Built %d bridges", n_bridges);
+  }
+  return n_bridges;
+}
@@ -0,0 +1,14 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+uint32_t CotCrc32(const void* data, int size) {
+  const uint8_t* bytes = data;
+  uint32_t crc = 0xFFFFFFFF;
+  for (int i = 0; i < size; i++) {
+    crc ^= bytes[i];
+    for (int bit = 0; bit < 8; bit++) {
+      crc = (crc >> 1) ^ (0xEDB88320 & -(crc & 1));
+    }
+  }
+  return ~crc;
+}
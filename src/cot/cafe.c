@@ -0,0 +1,121 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+#ifdef COT_FEATURE_SPINDA_CAFE
+
+static struct {
+  enum item_id ingredient;
+  cafe_drink_outcome outcome;
+} drinks[COT_MAX_CAFE_DRINKS];
+static int n_drinks = 0;
+static cafe_event_fn event_hook = NULL;
+
+static cafe_drink_outcome* FindDrink(enum item_id ingredient) {
+  for (int i = 0; i < n_drinks; i++) {
+    if (drinks[i].ingredient == ingredient) {
+      return &drinks[i].outcome;
+    }
+  }
+  return NULL;
+}
+
+bool CotSetCafeDrinkOutcome(enum item_id ingredient, cafe_drink_outcome outcome) {
+  cafe_drink_outcome* existing = FindDrink(ingredient);
+  if (existing != NULL) {
+    *existing = outcome;
+    return true;
+  }
+  if (n_drinks >= COT_MAX_CAFE_DRINKS) {
+    COT_WARN(COT_LOG_CAT_CAFE, "Café drink table is full");
+    return false;
+  }
+  drinks[n_drinks].ingredient = ingredient;
+  drinks[n_drinks].outcome = outcome;
+  n_drinks++;
+  return true;
+}
+
+const cafe_drink_outcome* CotGetCafeDrinkOutcome(enum item_id ingredient) {
+  return FindDrink(ingredient);
+}
+
+int CotGetCafeDrinkCount(void) {
+  return n_drinks;
+}
+
+enum item_id CotGetCafeDrinkIngredient(int index) {
+  if (index < 0 || index >= n_drinks) {
+    return ITEM_NOTHING;
+  }
+  return drinks[index].ingredient;
+}
+
+void CotSetCafeEventHook(cafe_event_fn hook) {
+  event_hook = hook;
+}
+
+static int ClampStat(int value, int max) {
+  if (value < 1) {
+    return 1;
+  }
+  return value > max ? max : value;
+}
+
+bool CotServeCafeDrink(enum item_id ingredient, struct ground_monster* member) {
+  cafe_drink_outcome* outcome = FindDrink(ingredient);
+  if (outcome == NULL) {
+    return false;
+  }
+  COT_LOGFMT(COT_LOG_CAT_CAFE, "Applying custom drink for ingredient %d", ingredient);
+  member->iq = ClampStat(member->iq + outcome->iq, 999);
+  member->max_hp = ClampStat(member->max_hp + outcome->max_hp, 999);
+  for (int j = 0; j < 2; j++) {
+    member->offensive_stats[j] =
+        ClampStat(member->offensive_stats[j] + outcome->offensive_stats[j], 255);
+    member->defensive_stats[j] =
+        ClampStat(member->defensive_stats[j] + outcome->defensive_stats[j], 255);
+  }
+  if (outcome->custom != NULL) {
+    outcome->custom(ingredient, member);
+  }
+  return true;
+}
+
+bool CotDecideCafeEvent(int event_id, bool* trigger) {
+  if (event_hook == NULL) {
+    return false;
+  }
+  *trigger = event_hook(event_id);
+  return true;
+}
+
+static void ServeDrink(script_opcode_context* ctx) {
+  struct ground_monster* member = GetTeamMember(ctx->args[1]);
+  if (member == NULL) {
+    COT_WARNFMT(COT_LOG_CAT_CAFE, "No team member at index %d", ctx->args[1]);
+    return;
+  }
+  ctx->return_val = CotServeCafeDrink(ctx->args[0], member);
+}
+
+static void DecideEvent(script_opcode_context* ctx) {
+  bool trigger;
+  if (CotDecideCafeEvent(ctx->args[0], &trigger)) {
+    ctx->return_val = trigger ? COT_CAFE_EVENT_TRIGGER : COT_CAFE_EVENT_SKIP;
+  } else {
+    ctx->return_val = COT_CAFE_EVENT_KEEP;
+  }
+}
+
+bool CotInstallCafe(void) {
+  if (!CotRegisterScriptOpcode(COT_SCRIPT_OPCODE_CAFE_DRINK, 2, ServeDrink)) {
+    return false;
+  }
+  if (!CotRegisterScriptOpcode(COT_SCRIPT_OPCODE_CAFE_EVENT, 1, DecideEvent)) {
+    CotUnregisterScriptOpcode(COT_SCRIPT_OPCODE_CAFE_DRINK);
+    return false;
+  }
+  return true;
+}
+
+#endif
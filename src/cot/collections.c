@@ -0,0 +1,18 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+int CotBitsetCount(const floor_bitset* set) {
+  int n = 0;
+  for (int i = 0; i < COT_FLOOR_BITSET_WORDS; i++) {
+    n += __builtin_popcount(set->words[i]);
+  }
+  return n;
+}
+
+int CotMonsterSlot(struct entity* entity) {
+  struct entity* monsters = DUNGEON_PTR->entity_table.entities;
+  if (entity < monsters || entity >= monsters + COT_N_MONSTER_SLOTS) {
+    return -1;
+  }
+  return entity - monsters;
+}
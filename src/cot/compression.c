@@ -0,0 +1,271 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+#define PX_MAGIC_SIZE 5
+#define PX_N_FLAGS 9
+#define PX_FLAGS_OFFSET 7
+#define PX_AT4PX_HEADER_SIZE 0x12
+#define PX_PKDPX_HEADER_SIZE 0x14
+#define PX_WINDOW_SIZE 0x1000
+#define PX_MIN_MATCH 3
+// The encoder sets every control flag to 0xF, leaving lengths 3 to 17 for back-references.
+#define PX_ENCODER_FLAG 0xF
+#define PX_MAX_MATCH (PX_MIN_MATCH + PX_ENCODER_FLAG - 1)
+
+#define NRL_MAX_ZEROS 0x80
+#define NRL_MAX_REPEAT 0x40
+#define NRL_MAX_LITERALS 0x40
+
+static bool HasMagic(const uint8_t* src, const char* magic) {
+  for (int i = 0; i < PX_MAGIC_SIZE; i++) {
+    if (src[i] != magic[i]) {
+      return false;
+    }
+  }
+  return true;
+}
+
+// Returns the header size of a PX container, or 0 if `src` isn't one.
+static int GetPxHeaderSize(const uint8_t* src) {
+  if (HasMagic(src, "AT4PX")) {
+    return PX_AT4PX_HEADER_SIZE;
+  }
+  if (HasMagic(src, "PKDPX")) {
+    return PX_PKDPX_HEADER_SIZE;
+  }
+  return 0;
+}
+
+int CotPxGetDecompressedSize(const void* src) {
+  const uint8_t* bytes = src;
+  switch (GetPxHeaderSize(bytes)) {
+    case PX_AT4PX_HEADER_SIZE:
+      return CotReadU16(bytes + 0x10);
+    case PX_PKDPX_HEADER_SIZE:
+      return CotReadU32(bytes + 0x10);
+    default:
+      return -1;
+  }
+}
+
+// Expands a control flag match into two bytes. `idx` is the index of the matched flag.
+static void ExpandPxPattern(int idx, int nibble, uint8_t out[2]) {
+  if (idx == 0) {
+    out[0] = out[1] = (nibble << 4) | nibble;
+    return;
+  }
+  int nibbles[4];
+  int base = idx <= 4 ? nibble + 1 : nibble - 1;
+  for (int i = 0; i < 4; i++) {
+    nibbles[i] = base & 0xF;
+  }
+  if (idx <= 4) {
+    nibbles[idx - 1] = (nibbles[idx - 1] - 1) & 0xF;
+  } else {
+    nibbles[idx - 5] = (nibbles[idx - 5] + 1) & 0xF;
+  }
+  out[0] = (nibbles[0] << 4) | nibbles[1];
+  out[1] = (nibbles[2] << 4) | nibbles[3];
+}
+
+int CotPxDecompress(const void* src, void* dst, int dst_capacity) {
+  const uint8_t* bytes = src;
+  uint8_t* out = dst;
+  int header_size = GetPxHeaderSize(bytes);
+  int size = CotPxGetDecompressedSize(src);
+  if (header_size == 0 || size > dst_capacity) {
+    return -1;
+  }
+  const uint8_t* flags = bytes + PX_FLAGS_OFFSET;
+  const uint8_t* in = bytes + header_size;
+  const uint8_t* in_end = bytes + CotReadU16(bytes + PX_MAGIC_SIZE);
+
+  int pos = 0;
+  while (pos < size && in < in_end) {
+    uint8_t command = *in++;
+    for (int bit = 7; bit >= 0 && pos < size && in < in_end; bit--) {
+      if (command & (1 << bit)) {
+        out[pos++] = *in++;
+        continue;
+      }
+      int high = *in >> 4;
+      int low = *in++ & 0xF;
+      int idx = -1;
+      for (int i = 0; i < PX_N_FLAGS && idx < 0; i++) {
+        if (flags[i] == high) {
+          idx = i;
+        }
+      }
+      if (idx >= 0) {
+        uint8_t pattern[2];
+        ExpandPxPattern(idx, low, pattern);
+        for (int i = 0; i < 2 && pos < size; i++) {
+          out[pos++] = pattern[i];
+        }
+      } else {
+        // Back-references take a second byte
+        if (in >= in_end) {
+          return -1;
+        }
+        int from = pos + ((low << 8) | *in++) - PX_WINDOW_SIZE;
+        if (from < 0) {
+          return -1;
+        }
+        for (int i = 0; i < high + PX_MIN_MATCH && pos < size; i++) {
+          out[pos++] = out[from + i];
+        }
+      }
+    }
+  }
+  return pos == size ? size : -1;
+}
+
+// Finds the longest earlier match for the data at `pos`. Returns its length.
+static int FindPxMatch(const uint8_t* src, int size, int pos, int* distance) {
+  int best = 0;
+  int start = pos > PX_WINDOW_SIZE ? pos - PX_WINDOW_SIZE : 0;
+  int max_len = size - pos < PX_MAX_MATCH ? size - pos : PX_MAX_MATCH;
+  for (int from = start; from < pos; from++) {
+    int len = 0;
+    while (len < max_len && src[from + len] == src[pos + len]) {
+      len++;
+    }
+    if (len > best) {
+      best = len;
+      *distance = pos - from;
+    }
+  }
+  return best;
+}
+
+int CotPxCompress(const void* src, int size, void* dst, int dst_capacity, px_format format) {
+  const uint8_t* in = src;
+  uint8_t* out = dst;
+  int header_size = format == PX_FORMAT_AT4PX ? PX_AT4PX_HEADER_SIZE : PX_PKDPX_HEADER_SIZE;
+  if (dst_capacity < header_size || (format == PX_FORMAT_AT4PX && size > 0xFFFF)) {
+    return -1;
+  }
+
+  int out_pos = header_size;
+  int pos = 0;
+  while (pos < size) {
+    // Every command byte is followed by up to 8 literals or back-references of 2 bytes
+    if (out_pos + 1 + 8 * 2 > dst_capacity) {
+      return -1;
+    }
+    int command_pos = out_pos++;
+    uint8_t command = 0;
+    for (int bit = 7; bit >= 0 && pos < size; bit--) {
+      int distance = 0;
+      int len = FindPxMatch(in, size, pos, &distance);
+      if (len >= PX_MIN_MATCH) {
+        int offset = PX_WINDOW_SIZE - distance;
+        out[out_pos++] = ((len - PX_MIN_MATCH) << 4) | (offset >> 8);
+        out[out_pos++] = offset & 0xFF;
+        pos += len;
+      } else {
+        command |= 1 << bit;
+        out[out_pos++] = in[pos++];
+      }
+    }
+    out[command_pos] = command;
+  }
+  if (out_pos > 0xFFFF) {
+    return -1;
+  }
+
+  memcpy(out, format == PX_FORMAT_AT4PX ? "AT4PX" : "PKDPX", PX_MAGIC_SIZE);
+  CotWriteU16(out + PX_MAGIC_SIZE, out_pos);
+  memset(out + PX_FLAGS_OFFSET, PX_ENCODER_FLAG, PX_N_FLAGS);
+  if (format == PX_FORMAT_AT4PX) {
+    CotWriteU16(out + 0x10, size);
+  } else {
+    CotWriteU32(out + 0x10, size);
+  }
+  return out_pos;
+}
+
+int CotNrlDecompress(const void* src, int src_size, void* dst, int dst_capacity) {
+  const uint8_t* in = src;
+  const uint8_t* in_end = in + src_size;
+  uint8_t* out = dst;
+  int pos = 0;
+  while (in < in_end) {
+    uint8_t command = *in++;
+    if (command < 0x80) {
+      int count = command + 1;
+      if (pos + count > dst_capacity) {
+        return -1;
+      }
+      memset(out + pos, 0, count);
+      pos += count;
+    } else if (command < 0xC0) {
+      int count = command - 0x7F;
+      if (in >= in_end || pos + count > dst_capacity) {
+        return -1;
+      }
+      memset(out + pos, *in++, count);
+      pos += count;
+    } else {
+      int count = command - 0xBF;
+      if (in + count > in_end || pos + count > dst_capacity) {
+        return -1;
+      }
+      memcpy(out + pos, in, count);
+      in += count;
+      pos += count;
+    }
+  }
+  return pos;
+}
+
+static int RunLength(const uint8_t* src, int size, int pos, int max) {
+  int len = 1;
+  while (pos + len < size && len < max && src[pos + len] == src[pos]) {
+    len++;
+  }
+  return len;
+}
+
+int CotNrlCompress(const void* src, int size, void* dst, int dst_capacity) {
+  const uint8_t* in = src;
+  uint8_t* out = dst;
+  int out_pos = 0;
+  int pos = 0;
+  while (pos < size) {
+    if (in[pos] == 0) {
+      int len = RunLength(in, size, pos, NRL_MAX_ZEROS);
+      if (out_pos + 1 > dst_capacity) {
+        return -1;
+      }
+      out[out_pos++] = len - 1;
+      pos += len;
+      continue;
+    }
+    int len = RunLength(in, size, pos, NRL_MAX_REPEAT);
+    if (len >= 3) {
+      if (out_pos + 2 > dst_capacity) {
+        return -1;
+      }
+      out[out_pos++] = 0x7F + len;
+      out[out_pos++] = in[pos];
+      pos += len;
+      continue;
+    }
+    // Collect literals until the next zero or run worth encoding
+    int n_literals = 0;
+    while (pos + n_literals < size && n_literals < NRL_MAX_LITERALS
+           && in[pos + n_literals] != 0
+           && RunLength(in, size, pos + n_literals, 3) < 3) {
+      n_literals++;
+    }
+    if (out_pos + 1 + n_literals > dst_capacity) {
+      return -1;
+    }
+    out[out_pos++] = 0xBF + n_literals;
+    memcpy(out + out_pos, in + pos, n_literals);
+    out_pos += n_literals;
+    pos += n_literals;
+  }
+  return out_pos;
+}
@@ -0,0 +1,99 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+// Spreads floor numbers over the seed space
+#define FLOOR_SEED_STEP 0x9E3779B9
+
+// NitroSDK's RTCDate
+typedef struct rtc_date {
+  // Years since 2000
+  uint32_t year;
+  uint32_t month;
+  uint32_t day;
+  uint32_t week;
+} rtc_date;
+
+// This NitroSDK function isn't in pmdsky-debug yet. Its address can be added to
+// "symbols/custom_[region].ld"; until then it's NULL. Returns 0 on success.
+extern int RTC_GetDate(rtc_date* date) __attribute__((weak));
+
+static bool active = false;
+static daily_result current;
+static daily_result last;
+static bool has_last = false;
+
+bool CotGetTodaysDate(daily_date* out) {
+  if (RTC_GetDate == NULL) {
+    COT_WARN(COT_LOG_CAT_DAILY, "RTC_GetDate is missing from the custom symbols");
+    return false;
+  }
+  rtc_date date;
+  if (RTC_GetDate(&date) != 0) {
+    COT_WARN(COT_LOG_CAT_DAILY, "Failed to read the real-time clock");
+    return false;
+  }
+  *out = (daily_date) { .year = date.year, .month = date.month, .day = date.day };
+  return true;
+}
+
+uint32_t CotDailySeed(const daily_date* date, uint32_t salt) {
+  uint32_t data[4] = { date->year, date->month, date->day, salt };
+  return CotCrc32(data, sizeof(data));
+}
+
+static void SeedFloor(struct floor_properties* props) {
+  (void) props;
+  if (DUNGEON_PTR->id.val != current.dungeon) {
+    return;
+  }
+  current.floors_reached = DUNGEON_PTR->floor;
+  CotSeedDungeonRng(current.seed + DUNGEON_PTR->floor * FLOOR_SEED_STEP);
+}
+
+bool CotDailyBegin(enum dungeon_id dungeon, uint32_t salt) {
+  daily_date date;
+  if (!CotGetTodaysDate(&date)) {
+    return false;
+  }
+  return CotDailyBeginOnDate(dungeon, &date, salt);
+}
+
+bool CotDailyBeginOnDate(enum dungeon_id dungeon, const daily_date* date, uint32_t salt) {
+  if (!active && !CotAddPreGenerateHook(SeedFloor)) {
+    COT_WARN(COT_LOG_CAT_DAILY, "Can't add the daily run hook");
+    return false;
+  }
+  memset(&current, 0, sizeof(current));
+  current.date = *date;
+  current.seed = CotDailySeed(date, salt);
+  current.dungeon = dungeon;
+  active = true;
+  CotLockGeneralRngToDungeon(dungeon);
+  CotStatsBeginRun();
+  COT_LOGFMT(COT_LOG_CAT_DAILY, "Daily run of dungeon %d with seed %08x", dungeon, current.seed);
+  return true;
+}
+
+void CotDailyEnd(bool cleared) {
+  if (!active) {
+    return;
+  }
+  const run_stats* stats = CotGetRunStats();
+  current.cleared = cleared;
+  current.damage_dealt = stats->damage_dealt;
+  current.damage_taken = stats->damage_taken;
+  CotStatsEndRun();
+  last = current;
+  has_last = true;
+  active = false;
+  CotRemoveFloorGenHook(SeedFloor);
+  CotSetGeneralRngLocked(false);
+}
+
+bool CotIsDailyRunActive(void) {
+  return active;
+}
+
+const daily_result* CotGetLastDailyResult(void) {
+  return has_last ? &last : NULL;
+}
@@ -0,0 +1,47 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+static damage_hook_fn damage_hook = NULL;
+
+static void ToResult(const struct damage_data* data, damage_result* out) {
+  out->damage = data->damage;
+  out->critical = data->critical;
+  out->type = data->type.val;
+  out->category = data->category.val;
+  out->type_matchup = data->type_matchup.val;
+  out->immune = data->full_type_immunity;
+}
+
+void CotCalcDamage(struct entity* attacker, struct entity* defender, enum move_id move,
+                   enum type_id type, int power, int crit_chance, damage_result* out) {
+  struct damage_data data;
+  memset(&data, 0, sizeof(data));
+  CalcDamage(attacker, defender, type, power, crit_chance, &data, COT_DAMAGE_MULT_ONE, move, false);
+  ToResult(&data, out);
+}
+
+void CotCalcFixedDamage(struct entity* attacker, struct entity* defender, int amount,
+                        enum type_id type, enum move_category category, damage_result* out) {
+  struct damage_data data;
+  memset(&data, 0, sizeof(data));
+  CalcDamageFixed(attacker, defender, amount, false, &data, type, category, 0,
+                  DAMAGE_MESSAGE_MOVE, 0, 0);
+  ToResult(&data, out);
+}
+
+void CotSetDamageHook(damage_hook_fn hook) {
+  damage_hook = hook;
+}
+
+void cotInternalOnDamageCalculated(struct entity* attacker, struct entity* defender,
+                                   enum move_id move, struct damage_data* data) {
+  if (damage_hook != NULL) {
+    int vanilla = data->damage;
+    damage_hook(attacker, defender, move, data);
+    if (data->damage != vanilla) {
+      COT_LOGFMT(COT_LOG_CAT_DAMAGE, "Damage hook changed damage from %d to %d", vanilla,
+                 data->damage);
+    }
+  }
+  CotStatsRecordDamage(attacker, defender, data->damage);
+}
@@ -0,0 +1,101 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+#define N_MOVE_DATA_ENTRIES \
+  ((int) (sizeof(MOVE_DATA_TABLE_PTR->entries) / sizeof(MOVE_DATA_TABLE_PTR->entries[0])))
+
+static bool IsValidMove(enum move_id id) {
+  return (id >= 0 && id < N_MOVE_DATA_ENTRIES) || CotGetExtendedMove(id) != NULL;
+}
+
+static bool IsValidItem(enum item_id id) {
+  return (id >= 0 && id < COT_N_VANILLA_ITEMS) || CotGetExtendedItem(id) != NULL;
+}
+
+bool CotGetMoveInfo(enum move_id id, move_info* out) {
+  if (!IsValidMove(id)) {
+    COT_WARNFMT(COT_LOG_CAT_DATA_TABLES, "Invalid move %d", id);
+    return false;
+  }
+  struct move move;
+  memset(&move, 0, sizeof(move));
+  move.f_exists = true;
+  move.id.val = id;
+
+  out->id = id;
+  out->type = GetMoveType(&move);
+  out->category = GetMoveCategory(id);
+  out->base_power = GetMoveBasePower(&move);
+  out->accuracy = GetMoveAccuracyOrAiChance(&move, 0);
+  out->max_pp = GetMaxPp(&move);
+  out->crit_chance = GetMoveCritChance(&move);
+  return true;
+}
+
+bool CotGetItemInfo(enum item_id id, item_info* out) {
+  if (!IsValidItem(id)) {
+    COT_WARNFMT(COT_LOG_CAT_DATA_TABLES, "Invalid item %d", id);
+    return false;
+  }
+  out->id = id;
+  out->category = GetItemCategory(id);
+  out->buy_price = GetItemBuyPrice(id);
+  out->sell_price = GetItemSellPrice(id);
+  out->thrown = IsThrownItem(id);
+  out->edible = IsEdible(id);
+  out->tm_move = out->category == CATEGORY_TMS_HMS ? GetItemMoveId(id) : MOVE_NOTHING;
+  return true;
+}
+
+static bool IsKeptOrInRange(int value, int max) {
+  return value == COT_DATA_KEEP || (value >= 0 && value <= max);
+}
+
+bool CotPatchMoveData(enum move_id id, const move_patch* patch) {
+  if (MOVE_DATA_TABLE_PTR == NULL || id < 0 || id >= N_MOVE_DATA_ENTRIES) {
+    COT_WARNFMT(COT_LOG_CAT_DATA_TABLES, "Can't patch move %d", id);
+    return false;
+  }
+  if (!IsKeptOrInRange(patch->base_power, INT16_MAX) || !IsKeptOrInRange(patch->accuracy, UINT8_MAX)
+      || !IsKeptOrInRange(patch->max_pp, UINT8_MAX)
+      || !IsKeptOrInRange(patch->crit_chance, UINT8_MAX)) {
+    COT_WARNFMT(COT_LOG_CAT_DATA_TABLES, "Out-of-range patch for move %d", id);
+    return false;
+  }
+
+  struct move_data* data = &MOVE_DATA_TABLE_PTR->entries[id];
+  if (patch->base_power != COT_DATA_KEEP) {
+    data->base_power = patch->base_power;
+  }
+  if (patch->accuracy != COT_DATA_KEEP) {
+    data->accuracy1 = patch->accuracy;
+  }
+  if (patch->max_pp != COT_DATA_KEEP) {
+    data->pp = patch->max_pp;
+  }
+  if (patch->crit_chance != COT_DATA_KEEP) {
+    data->crit_chance = patch->crit_chance;
+  }
+  return true;
+}
+
+bool CotPatchItemData(enum item_id id, const item_patch* patch) {
+  if (ITEM_DATA_TABLE_PTRS.item_data == NULL || id < 0 || id >= COT_N_VANILLA_ITEMS) {
+    COT_WARNFMT(COT_LOG_CAT_DATA_TABLES, "Can't patch item %d", id);
+    return false;
+  }
+  if (!IsKeptOrInRange(patch->buy_price, INT16_MAX)
+      || !IsKeptOrInRange(patch->sell_price, INT16_MAX)) {
+    COT_WARNFMT(COT_LOG_CAT_DATA_TABLES, "Out-of-range patch for item %d", id);
+    return false;
+  }
+
+  struct item_data* data = &ITEM_DATA_TABLE_PTRS.item_data[id];
+  if (patch->buy_price != COT_DATA_KEEP) {
+    data->buy_price = patch->buy_price;
+  }
+  if (patch->sell_price != COT_DATA_KEEP) {
+    data->sell_price = patch->sell_price;
+  }
+  return true;
+}
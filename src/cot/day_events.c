@@ -0,0 +1,78 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+static guild_event events[COT_MAX_GUILD_EVENTS];
+static int n_events = 0;
+static int event_chance = 100;
+static struct {
+  int days;
+  story_beat_fn beat;
+} story_beats[COT_MAX_STORY_BEATS];
+static shop_restock_fn restock_hook = NULL;
+
+bool CotRegisterGuildEvent(guild_event event) {
+  if (n_events >= COT_MAX_GUILD_EVENTS) {
+    COT_WARN(COT_LOG_CAT_DAY_EVENTS, "Guild event table is full");
+    return false;
+  }
+  events[n_events++] = event;
+  return true;
+}
+
+void CotSetGuildEventChance(int percent) {
+  event_chance = percent;
+}
+
+bool CotScheduleStoryBeat(int days, story_beat_fn beat) {
+  for (int i = 0; i < COT_MAX_STORY_BEATS; i++) {
+    if (story_beats[i].beat == NULL) {
+      story_beats[i].days = days;
+      story_beats[i].beat = beat;
+      return true;
+    }
+  }
+  COT_WARN(COT_LOG_CAT_DAY_EVENTS, "Story beat table is full");
+  return false;
+}
+
+void CotSetShopRestockHook(shop_restock_fn hook) {
+  restock_hook = hook;
+}
+
+static void RunStoryBeats(void) {
+  for (int i = 0; i < COT_MAX_STORY_BEATS; i++) {
+    if (story_beats[i].beat == NULL) {
+      continue;
+    }
+    if (story_beats[i].days-- <= 0) {
+      story_beat_fn beat = story_beats[i].beat;
+      // Free the slot first so the beat can schedule a follow-up
+      story_beats[i].beat = NULL;
+      beat();
+    }
+  }
+}
+
+static void RunGuildEvent(void) {
+  if (n_events == 0 || !CotRandChance(RNG_GENERAL, event_chance)) {
+    return;
+  }
+  int weights[COT_MAX_GUILD_EVENTS];
+  for (int i = 0; i < n_events; i++) {
+    bool available = events[i].condition == NULL || events[i].condition();
+    weights[i] = available ? events[i].weight : 0;
+  }
+  int picked = CotRandWeighted(RNG_GENERAL, weights, n_events);
+  if (picked >= 0) {
+    COT_LOGFMT(COT_LOG_CAT_DAY_EVENTS, "Running guild event %d", picked);
+    events[picked].run();
+  }
+}
+
+void CotRunDayEnd(void) {
+  RunStoryBeats();
+  if (restock_hook != NULL) {
+    restock_hook();
+  }
+  RunGuildEvent();
+}
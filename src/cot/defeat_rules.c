@@ -0,0 +1,83 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+static struct {
+  uint8_t dungeon;
+  uint8_t rule;
+} rules[COT_MAX_DEFEAT_RULES];
+static int n_rules = 0;
+static defeat_rule_fn rule_hook = NULL;
+
+static struct {
+  bool valid;
+  int money;
+  int n_items;
+  struct item items[COT_BAG_MAX_ITEMS];
+} snapshot;
+
+bool CotSetDefeatItemRule(enum dungeon_id dungeon, defeat_item_rule rule) {
+  for (int i = 0; i < n_rules; i++) {
+    if (rules[i].dungeon == dungeon) {
+      rules[i].rule = rule;
+      return true;
+    }
+  }
+  if (n_rules >= COT_MAX_DEFEAT_RULES) {
+    COT_WARN(COT_LOG_CAT_DEFEAT_RULES, "Defeat rule table is full");
+    return false;
+  }
+  rules[n_rules].dungeon = dungeon;
+  rules[n_rules].rule = rule;
+  n_rules++;
+  return true;
+}
+
+void CotSetDefeatItemRuleHook(defeat_rule_fn hook) {
+  rule_hook = hook;
+}
+
+defeat_item_rule CotGetDefeatItemRule(enum dungeon_id dungeon) {
+  defeat_item_rule rule = DEFEAT_LOSE_ALL;
+  for (int i = 0; i < n_rules; i++) {
+    if (rules[i].dungeon == dungeon) {
+      rule = rules[i].rule;
+      break;
+    }
+  }
+  if (rule_hook != NULL) {
+    rule = rule_hook(dungeon, rule);
+  }
+  return rule;
+}
+
+void cotInternalOnLeaderFainted(void) {
+  snapshot.money = GetMoneyCarried();
+  snapshot.n_items = GetCurrentBagCapacity();
+  if (snapshot.n_items > COT_BAG_MAX_ITEMS) {
+    snapshot.n_items = COT_BAG_MAX_ITEMS;
+  }
+  for (int i = 0; i < snapshot.n_items; i++) {
+    snapshot.items[i] = *GetItemAtIdx(i);
+  }
+  snapshot.valid = true;
+}
+
+void CotApplyDefeatRule(enum dungeon_id dungeon) {
+  if (!snapshot.valid) {
+    COT_WARN(COT_LOG_CAT_DEFEAT_RULES, "No inventory snapshot, keeping the vanilla penalty");
+    return;
+  }
+  snapshot.valid = false;
+
+  defeat_item_rule rule = CotGetDefeatItemRule(dungeon);
+  COT_LOGFMT(COT_LOG_CAT_DEFEAT_RULES, "Applying defeat rule %d for dungeon %d", rule, dungeon);
+  if (rule == DEFEAT_LOSE_ALL) {
+    return;
+  }
+  for (int i = 0; i < snapshot.n_items; i++) {
+    *GetItemAtIdx(i) = snapshot.items[i];
+  }
+  if (rule == DEFEAT_KEEP_ALL) {
+    SetMoneyCarried(snapshot.money);
+  }
+}
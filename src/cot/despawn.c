@@ -0,0 +1,73 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+// Items and traps are removed through the game, which also clears their tile state, updates
+// the floor's item count and redraws trap tiles.
+static bool RemoveObject(struct entity* entity) {
+  struct position pos = entity->pos;
+  if (entity->type == ENTITY_ITEM) {
+    RemoveGroundItem(&pos, true);
+    return true;
+  }
+  if (entity->type == ENTITY_TRAP && TryRemoveTrap(&pos, true)) {
+    UpdateTrapsVisibility();
+    return true;
+  }
+  return false;
+}
+
+void CotDespawnEntity(struct entity* entity) {
+  if (!EntityIsValid(entity) || RemoveObject(entity)) {
+    return;
+  }
+  struct tile* tile = CotTileAt(entity->pos.x, entity->pos.y);
+  if (tile != NULL) {
+    if (tile->monster == entity) {
+      tile->monster = NULL;
+    }
+    if (tile->object == entity) {
+      tile->object = NULL;
+    }
+  }
+  entity->is_visible = false;
+  entity->type = ENTITY_NOTHING;
+}
+
+static int DespawnAll(entity_iter_kind kind, despawn_filter_fn filter, void* ctx) {
+  int n_despawned = 0;
+  struct entity* entity;
+  COT_FOR_EACH_ENTITY(kind, entity) {
+    if (filter == NULL || filter(entity, ctx)) {
+      CotDespawnEntity(entity);
+      n_despawned++;
+    }
+  }
+  return n_despawned;
+}
+
+int CotDespawnMonsters(despawn_filter_fn filter, void* ctx) {
+  int n_despawned = DespawnAll(ITER_NON_TEAM, filter, ctx);
+  CotCompactActiveMonsters();
+  return n_despawned;
+}
+
+int CotDespawnItems(despawn_filter_fn filter, void* ctx) {
+  return DespawnAll(ITER_ITEMS, filter, ctx);
+}
+
+int CotDespawnTraps(despawn_filter_fn filter, void* ctx) {
+  return DespawnAll(ITER_TRAPS, filter, ctx);
+}
+
+bool CotFilterInRoom(struct entity* entity, void* ctx) {
+  struct tile* tile = CotTileAt(entity->pos.x, entity->pos.y);
+  return tile != NULL && tile->room != COT_NO_ROOM && tile->room == *(int*) ctx;
+}
+
+void CotClearFloor(void) {
+  int n_monsters = CotDespawnMonsters(NULL, NULL);
+  int n_items = CotDespawnItems(NULL, NULL);
+  int n_traps = CotDespawnTraps(NULL, NULL);
+  COT_LOGFMT(COT_LOG_CAT_DESPAWN, "Cleared floor: %d monsters, %d items, %d traps",
+             n_monsters, n_items, n_traps);
+}
@@ -0,0 +1,85 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+static training_course courses[COT_MAX_TRAINING_COURSES];
+static int n_courses = 0;
+static dojo_reward_fn reward_hook = NULL;
+// Last training maze entered, or -1 if none
+static int last_maze = -1;
+
+bool CotRegisterTrainingCourse(training_course course) {
+  if (n_courses >= COT_MAX_TRAINING_COURSES) {
+    COT_WARN(COT_LOG_CAT_DOJO, "Training course table is full");
+    return false;
+  }
+  courses[n_courses++] = course;
+  return true;
+}
+
+int CotGetTrainingCourseCount(void) {
+  return n_courses;
+}
+
+const training_course* CotGetTrainingCourse(int index) {
+  if (index < 0 || index >= n_courses) {
+    return NULL;
+  }
+  return &courses[index];
+}
+
+static const training_course* FindCourse(enum dungeon_id dungeon) {
+  for (int i = 0; i < n_courses; i++) {
+    if (courses[i].dungeon == dungeon) {
+      return &courses[i];
+    }
+  }
+  return NULL;
+}
+
+bool cotInternalIsTrainingCourse(enum dungeon_id dungeon) {
+  return FindCourse(dungeon) != NULL;
+}
+
+bool CotIsTrainingMaze(enum dungeon_id dungeon) {
+  // Registered courses are included by the wrapper in hooks.c
+  return IsMarowakTrainingMaze(dungeon);
+}
+
+void CotSetDojoRewardHook(dojo_reward_fn hook) {
+  reward_hook = hook;
+}
+
+bool CotRewardDojoClear(enum dungeon_id dungeon) {
+  COT_LOGFMT(COT_LOG_CAT_DOJO, "Cleared training maze %d", dungeon);
+  const training_course* course = FindCourse(dungeon);
+  if (course != NULL && course->reward != NULL) {
+    return course->reward(dungeon);
+  }
+  if (reward_hook != NULL) {
+    return reward_hook(dungeon);
+  }
+  return false;
+}
+
+static void TrackMaze(struct floor_properties* props) {
+  enum dungeon_id dungeon = DUNGEON_PTR->id.val;
+  if (CotIsTrainingMaze(dungeon)) {
+    last_maze = dungeon;
+  }
+}
+
+static void Reward(script_opcode_context* ctx) {
+  if (last_maze < 0) {
+    COT_WARN(COT_LOG_CAT_DOJO, "Reward opcode reached without entering a training maze");
+    return;
+  }
+  ctx->return_val = CotRewardDojoClear(last_maze);
+  last_maze = -1;
+}
+
+bool CotInstallDojo(void) {
+  if (!CotRegisterScriptOpcode(COT_SCRIPT_OPCODE_DOJO_REWARD, 0, Reward)) {
+    return false;
+  }
+  return CotAddPreGenerateHook(TrackMaze);
+}
@@ -0,0 +1,80 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+// Number of frames a DIALOGUE_STEP_MOVE waits for the walking animation to settle.
+#define DIALOGUE_MOVE_SETTLE_FRAMES 8
+
+static bool dialogue_running = false;
+
+static void WaitFrames(int frames) {
+  for (int i = 0; i < frames; i++) {
+    AdvanceFrame(0);
+  }
+}
+
+static void RunMessageStep(const dialogue_step* step) {
+  if (step->entity == NULL) {
+    DisplayMessage(NULL, step->message_id, true);
+    return;
+  }
+  if (!EntityIsValid(step->entity)) {
+    COT_WARNFMT(COT_LOG_CAT_DIALOGUE, "Skipping message %d: speaker is invalid", step->message_id);
+    return;
+  }
+
+  enum monster_id portrait_monster = step->portrait_monster;
+  if (portrait_monster == MONSTER_NONE && step->entity->type == ENTITY_MONSTER) {
+    portrait_monster = ((struct monster*) step->entity->info)->apparent_id.val;
+  }
+
+  struct portrait_params portrait;
+  InitPortraitParamsWithMonsterId(&portrait, portrait_monster);
+  SetPortraitExpressionId(&portrait, step->emotion);
+  DisplayMessage(&portrait, step->message_id, true);
+}
+
+static void RunMoveStep(const dialogue_step* step) {
+  if (step->entity == NULL || !EntityIsValid(step->entity) || step->entity->type != ENTITY_MONSTER) {
+    COT_WARN(COT_LOG_CAT_DIALOGUE, "Skipping move: entity is not a valid monster");
+    return;
+  }
+  struct entity* occupant = CotTileMonster(step->target.x, step->target.y);
+  if (!CotTileInBounds(step->target.x, step->target.y)
+      || (occupant != NULL && occupant != step->entity)) {
+    COT_WARNFMT(COT_LOG_CAT_DIALOGUE, "Skipping move to (%d, %d): tile is out of bounds or occupied",
+                step->target.x, step->target.y);
+    return;
+  }
+  MoveMonsterToPos(step->entity, step->target.x, step->target.y, 0);
+  WaitFrames(DIALOGUE_MOVE_SETTLE_FRAMES);
+}
+
+void CotRunDungeonDialogue(const dialogue_step* steps) {
+  COT_ASSERT(!dialogue_running);
+  dialogue_running = true;
+
+  for (const dialogue_step* step = steps; step->kind != DIALOGUE_STEP_END; step++) {
+    switch (step->kind) {
+      case DIALOGUE_STEP_MESSAGE:
+        RunMessageStep(step);
+        break;
+      case DIALOGUE_STEP_MOVE:
+        RunMoveStep(step);
+        break;
+      case DIALOGUE_STEP_WAIT:
+        WaitFrames(step->frames);
+        break;
+      default:
+        COT_ERRORFMT(COT_LOG_CAT_DIALOGUE, "Unknown dialogue step kind %d", step->kind);
+        break;
+    }
+  }
+
+  // Give the game one frame to redraw the dungeon before the turn loop resumes.
+  AdvanceFrame(0);
+  dialogue_running = false;
+}
+
+bool CotIsDungeonDialogueRunning(void) {
+  return dialogue_running;
+}
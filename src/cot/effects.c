@@ -7,9 +7,14 @@
 bool cotInternalDispatchApplyItemEffect(
         struct entity* user, struct entity* target, struct item* item, bool is_thrown
 ) {
+    cotInternalRunBootSelfCheck();
     COT_LOGFMT(COT_LOG_CAT_EFFECTS, "Running item effect %d", item->id.val);
+    CotStatsRecordItemUsed(user, item);
 
-    bool handled = CustomApplyItemEffect(user, target, item, is_thrown);
+    bool handled = cotInternalApplyExtendedItemEffect(user, target, item, is_thrown);
+    if (!handled) {
+      handled = CustomApplyItemEffect(user, target, item, is_thrown);
+    }
 #ifdef COT_RUST
     // If the Rust runtime of c-of-time is used, ask the Rust implementation to process the effect.
     if (!handled) {
@@ -22,9 +27,13 @@ bool cotInternalDispatchApplyItemEffect(
 bool cotInternalDispatchApplyMoveEffect(
         move_effect_input* data, struct entity* user, struct entity* target, struct move* move
 ) {
+    cotInternalRunBootSelfCheck();
     COT_LOGFMT(COT_LOG_CAT_EFFECTS, "Running move effect %d", data->move_id);
 
-    bool handled = CustomApplyMoveEffect(data, user, target, move);
+    bool handled = cotInternalApplyExtendedMoveEffect(data, user, target, move);
+    if (!handled) {
+      handled = CustomApplyMoveEffect(data, user, target, move);
+    }
 #ifdef COT_RUST
     // If the Rust runtime of c-of-time is used, ask the Rust implementation to process the effect.
     if (!handled) {
@@ -37,12 +46,21 @@ bool cotInternalDispatchApplyMoveEffect(
 int cotInternalDispatchScriptSpecialProcessCall(
         undefined4* unknown, uint32_t special_process_id, short arg1, short arg2
 ) {
+    cotInternalRunBootSelfCheck();
     // TODO: arg2 doesn't seem to match the argument in the script engine?
     COT_LOGFMT(COT_LOG_CAT_SPECIAL_PROCESS, "Running special process %d (arg1=%d, arg2=%d)",
                special_process_id, arg1, arg2);
 
     int return_val = 0;
-    bool handled = CustomScriptSpecialProcessCall(unknown, special_process_id, arg1, arg2, &return_val);
+    bool handled = cotInternalHandleScriptCallSpecialProcess(special_process_id, arg1, &return_val);
+    if (!handled) {
+      handled = cotInternalDispatchRegisteredSpecialProcess(
+        unknown, special_process_id, arg1, arg2, &return_val
+      );
+    }
+    if (!handled) {
+      handled = CustomScriptSpecialProcessCall(unknown, special_process_id, arg1, arg2, &return_val);
+    }
     if (!handled) {
 #ifdef COT_RUST
       // If the Rust runtime of c-of-time is used, ask it to take over from here.
@@ -0,0 +1,81 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+entity_iter CotIterEntities(entity_iter_kind kind) {
+  struct entity_table_hdr* header = &DUNGEON_PTR->entity_table.header;
+  switch (kind) {
+    case ITER_TEAM:
+      return (entity_iter) { .slots = header->monster_slot_ptrs, .index = 0, .end = COT_N_TEAM_SLOTS };
+    case ITER_NON_TEAM:
+      return (entity_iter) {
+        .slots = header->monster_slot_ptrs, .index = COT_N_TEAM_SLOTS, .end = COT_N_MONSTER_SLOTS
+      };
+    case ITER_ITEMS:
+      return (entity_iter) { .slots = header->item_ptrs, .index = 0, .end = COT_N_ITEM_SLOTS };
+    case ITER_TRAPS:
+      return (entity_iter) { .slots = header->trap_ptrs, .index = 0, .end = COT_N_TRAP_SLOTS };
+    default:
+      return (entity_iter) { .slots = header->active_monster_ptrs, .index = 0, .end = COT_N_MONSTER_SLOTS };
+  }
+}
+
+bool CotIterNext(entity_iter* it, struct entity** out) {
+  while (it->index < it->end) {
+    struct entity* entity = it->slots[it->index++];
+    if (EntityIsValid(entity)) {
+      *out = entity;
+      return true;
+    }
+  }
+  return false;
+}
+
+static bool HasType(struct entity* entity, enum entity_type type) {
+  return entity != NULL && EntityIsValid(entity) && entity->type == type;
+}
+
+struct monster* CotGetMonster(struct entity* entity) {
+  return HasType(entity, ENTITY_MONSTER) ? (struct monster*) entity->info : NULL;
+}
+
+struct item* CotGetItem(struct entity* entity) {
+  return HasType(entity, ENTITY_ITEM) ? (struct item*) entity->info : NULL;
+}
+
+struct trap* CotGetTrap(struct entity* entity) {
+  return HasType(entity, ENTITY_TRAP) ? (struct trap*) entity->info : NULL;
+}
+
+struct position CotGetEntityPos(struct entity* entity) {
+  return entity->pos;
+}
+
+enum monster_id CotGetMonsterSpecies(struct entity* entity) {
+  struct monster* monster = CotGetMonster(entity);
+  return monster != NULL ? monster->id.val : MONSTER_NONE;
+}
+
+int CotGetMonsterHp(struct entity* entity) {
+  struct monster* monster = CotGetMonster(entity);
+  return monster != NULL ? monster->hp : 0;
+}
+
+int CotGetMonsterMaxHp(struct entity* entity) {
+  struct monster* monster = CotGetMonster(entity);
+  return monster != NULL ? monster->max_hp_stat + monster->max_hp_boost : 0;
+}
+
+struct statuses* CotGetMonsterStatuses(struct entity* entity) {
+  struct monster* monster = CotGetMonster(entity);
+  return monster != NULL ? &monster->statuses : NULL;
+}
+
+bool CotIsTeamMember(struct entity* entity) {
+  struct monster* monster = CotGetMonster(entity);
+  return monster != NULL && !monster->is_not_team_member;
+}
+
+enum item_id CotGetItemId(struct entity* entity) {
+  struct item* item = CotGetItem(entity);
+  return item != NULL ? item->id.val : ITEM_NOTHING;
+}
@@ -0,0 +1,63 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+static struct entity** GetSlots(entity_slot_kind kind, int* first, int* count) {
+  struct entity_table_hdr* header = &DUNGEON_PTR->entity_table.header;
+  switch (kind) {
+    case SLOT_TEAM:
+      *first = 0;
+      *count = COT_N_TEAM_SLOTS;
+      return header->monster_slot_ptrs;
+    case SLOT_NON_TEAM:
+      *first = COT_N_TEAM_SLOTS;
+      *count = COT_N_MONSTER_SLOTS - COT_N_TEAM_SLOTS;
+      return header->monster_slot_ptrs;
+    case SLOT_ITEM:
+      *first = 0;
+      *count = COT_N_ITEM_SLOTS;
+      return header->item_ptrs;
+    default:
+      *first = 0;
+      *count = COT_N_TRAP_SLOTS;
+      return header->trap_ptrs;
+  }
+}
+
+static bool IsFree(struct entity* entity) {
+  return entity != NULL && entity->type == ENTITY_NOTHING;
+}
+
+int CotFindFreeEntitySlot(entity_slot_kind kind) {
+  int first, count;
+  struct entity** slots = GetSlots(kind, &first, &count);
+  for (int i = first; i < first + count; i++) {
+    if (IsFree(slots[i])) {
+      return i;
+    }
+  }
+  return -1;
+}
+
+int CotCountFreeEntitySlots(entity_slot_kind kind) {
+  int first, count;
+  struct entity** slots = GetSlots(kind, &first, &count);
+  int n_free = 0;
+  for (int i = first; i < first + count; i++) {
+    n_free += IsFree(slots[i]);
+  }
+  return n_free;
+}
+
+int CotCompactActiveMonsters(void) {
+  struct entity** active = DUNGEON_PTR->entity_table.header.active_monster_ptrs;
+  int n_active = 0;
+  for (int i = 0; i < COT_N_MONSTER_SLOTS; i++) {
+    if (EntityIsValid(active[i])) {
+      active[n_active++] = active[i];
+    }
+  }
+  for (int i = n_active; i < COT_N_MONSTER_SLOTS; i++) {
+    active[i] = NULL;
+  }
+  return n_active;
+}
@@ -0,0 +1,112 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+typedef struct escort {
+  struct entity* entity;
+  escort_config config;
+  // HP at the end of the last turn
+  int hp;
+} escort;
+
+static escort escorts[COT_MAX_ESCORTS];
+static int n_escorts = 0;
+static escort_failed_fn failed_handler = NULL;
+static bool failed = false;
+
+static escort* FindEscort(struct entity* entity) {
+  for (int i = 0; i < n_escorts; i++) {
+    if (escorts[i].entity == entity) {
+      return &escorts[i];
+    }
+  }
+  return NULL;
+}
+
+bool CotProtectEntity(struct entity* entity, escort_config config) {
+  escort* existing = FindEscort(entity);
+  if (existing != NULL) {
+    existing->config = config;
+    return true;
+  }
+  if (n_escorts >= COT_MAX_ESCORTS) {
+    COT_WARN(COT_LOG_CAT_ESCORTS, "Escort table is full");
+    return false;
+  }
+  escorts[n_escorts++] =
+    (escort) { .entity = entity, .config = config, .hp = CotGetMonsterHp(entity) };
+  return true;
+}
+
+void CotUnprotectEntity(struct entity* entity) {
+  escort* existing = FindEscort(entity);
+  if (existing != NULL) {
+    *existing = escorts[--n_escorts];
+  }
+}
+
+bool CotIsProtected(struct entity* entity) {
+  return FindEscort(entity) != NULL;
+}
+
+void CotClearEscorts(void) {
+  n_escorts = 0;
+  failed = false;
+}
+
+static void ClearEscortsOnNewFloor(struct floor_properties* props) {
+  CotClearEscorts();
+}
+
+bool CotInstallEscorts(void) {
+  return CotAddPreGenerateHook(ClearEscortsOnNewFloor);
+}
+
+void CotSetEscortFailedHandler(escort_failed_fn handler) {
+  failed_handler = handler;
+}
+
+bool CotEscortFailed(void) {
+  return failed;
+}
+
+void cotInternalCheckEscortsDamaged(void) {
+  for (int i = 0; i < n_escorts; i++) {
+    escort* protected = &escorts[i];
+    int hp = CotGetMonsterHp(protected->entity);
+    int damage = protected->hp - hp;
+    protected->hp = hp;
+    if (damage > 0 && protected->config.on_damaged != NULL) {
+      protected->config.on_damaged(protected->entity, damage);
+    }
+  }
+}
+
+void cotInternalOnEscortFainted(struct entity* entity) {
+  escort* protected = FindEscort(entity);
+  if (protected == NULL) {
+    return;
+  }
+  COT_LOG(COT_LOG_CAT_ESCORTS, "Escort fainted");
+  if (protected->config.on_fainted != NULL) {
+    protected->config.on_fainted(entity);
+  }
+  CotUnprotectEntity(entity);
+  failed = true;
+  if (failed_handler != NULL) {
+    failed_handler(entity);
+  }
+}
+
+void cotInternalRunEscortAi(struct entity* entity) {
+  escort* protected = FindEscort(entity);
+  struct entity* leader = GetLeader();
+  if (protected == NULL || protected->config.follow_distance <= 0 || leader == NULL) {
+    return;
+  }
+  if (CotChebyshevDistance(entity->pos, leader->pos) <= protected->config.follow_distance) {
+    return;
+  }
+  struct monster* monster = (struct monster*) entity->info;
+  monster->action.action_id.val = ACTION_WALK;
+  monster->action.direction.val = GetDirectionTowardsPosition(&entity->pos, &leader->pos);
+}
@@ -0,0 +1,64 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+static const extended_item* extended_items[COT_MAX_EXTENDED_ITEMS];
+static int n_extended_items = 0;
+
+bool CotRegisterExtendedItem(const extended_item* item) {
+  if (item->id < COT_N_VANILLA_ITEMS || item->template_item >= COT_N_VANILLA_ITEMS) {
+    COT_WARNFMT(COT_LOG_CAT_EFFECTS, "Invalid extended item %d (template %d)",
+                item->id, item->template_item);
+    return false;
+  }
+  if (CotGetExtendedItem(item->id) != NULL) {
+    COT_WARNFMT(COT_LOG_CAT_EFFECTS, "Extended item %d is already registered", item->id);
+    return false;
+  }
+  if (n_extended_items >= COT_MAX_EXTENDED_ITEMS) {
+    COT_WARN(COT_LOG_CAT_EFFECTS, "Extended item table is full");
+    return false;
+  }
+  extended_items[n_extended_items++] = item;
+  return true;
+}
+
+const extended_item* CotGetExtendedItem(enum item_id id) {
+  if (id < COT_N_VANILLA_ITEMS) {
+    return NULL;
+  }
+  for (int i = 0; i < n_extended_items; i++) {
+    if (extended_items[i]->id == id) {
+      return extended_items[i];
+    }
+  }
+  return NULL;
+}
+
+enum item_id cotInternalResolveItemDataId(enum item_id id) {
+  const extended_item* item = CotGetExtendedItem(id);
+  return item != NULL ? item->template_item : id;
+}
+
+enum item_id cotInternalResolveItemIconId(enum item_id id) {
+  const extended_item* item = CotGetExtendedItem(id);
+  if (item == NULL) {
+    return id;
+  }
+  return item->icon_item != 0 ? item->icon_item : item->template_item;
+}
+
+int cotInternalGetItemNameStringId(enum item_id id) {
+  const extended_item* item = CotGetExtendedItem(id);
+  return item != NULL ? item->name_string_id : 0;
+}
+
+bool cotInternalApplyExtendedItemEffect(
+        struct entity* user, struct entity* target, struct item* item, bool is_thrown
+) {
+  const extended_item* extended = CotGetExtendedItem(item->id.val);
+  if (extended == NULL || extended->effect == NULL) {
+    return false;
+  }
+  extended->effect(user, target, item, is_thrown);
+  return true;
+}
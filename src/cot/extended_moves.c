@@ -0,0 +1,84 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+static const extended_move* extended_moves[COT_MAX_EXTENDED_MOVES];
+static int n_extended_moves = 0;
+static int name_string_base = -1;
+static int description_string_base = -1;
+
+bool CotRegisterExtendedMove(const extended_move* move) {
+  if (move->id < COT_N_VANILLA_MOVES || move->template_move >= COT_N_VANILLA_MOVES) {
+    COT_WARNFMT(COT_LOG_CAT_EFFECTS, "Invalid extended move %d (template %d)",
+                move->id, move->template_move);
+    return false;
+  }
+  if (CotGetExtendedMove(move->id) != NULL) {
+    COT_WARNFMT(COT_LOG_CAT_EFFECTS, "Extended move %d is already registered", move->id);
+    return false;
+  }
+  if (n_extended_moves >= COT_MAX_EXTENDED_MOVES) {
+    COT_WARN(COT_LOG_CAT_EFFECTS, "Extended move table is full");
+    return false;
+  }
+  extended_moves[n_extended_moves++] = move;
+  return true;
+}
+
+const extended_move* CotGetExtendedMove(enum move_id id) {
+  if (id < COT_N_VANILLA_MOVES) {
+    return NULL;
+  }
+  for (int i = 0; i < n_extended_moves; i++) {
+    if (extended_moves[i]->id == id) {
+      return extended_moves[i];
+    }
+  }
+  return NULL;
+}
+
+enum move_id cotInternalResolveMoveDataId(enum move_id id) {
+  const extended_move* move = CotGetExtendedMove(id);
+  return move != NULL ? move->template_move : id;
+}
+
+enum move_id cotInternalResolveMoveAnimationId(enum move_id id) {
+  const extended_move* move = CotGetExtendedMove(id);
+  if (move == NULL) {
+    return id;
+  }
+  return move->animation_move != 0 ? move->animation_move : move->template_move;
+}
+
+void CotSetMoveStringBases(int name_base, int description_base) {
+  name_string_base = name_base;
+  description_string_base = description_base;
+}
+
+int cotInternalResolveMoveStringId(int string_id) {
+  if (name_string_base < 0) {
+    return string_id;
+  }
+  for (int i = 0; i < n_extended_moves; i++) {
+    const extended_move* move = extended_moves[i];
+    if (string_id == name_string_base + move->id) {
+      return move->name_string_id != 0 ? move->name_string_id
+                                       : name_string_base + move->template_move;
+    }
+    if (string_id == description_string_base + move->id) {
+      return move->description_string_id != 0 ? move->description_string_id
+                                               : description_string_base + move->template_move;
+    }
+  }
+  return string_id;
+}
+
+bool cotInternalApplyExtendedMoveEffect(
+        move_effect_input* data, struct entity* user, struct entity* target, struct move* move
+) {
+  const extended_move* extended = CotGetExtendedMove(data->move_id);
+  if (extended == NULL || extended->effect == NULL) {
+    return false;
+  }
+  data->out_dealt_damage = extended->effect(user, target, move);
+  return true;
+}
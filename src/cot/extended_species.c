@@ -0,0 +1,92 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+static const extended_species* extended_species_table[COT_MAX_EXTENDED_SPECIES];
+static int n_extended_species = 0;
+
+bool CotRegisterExtendedSpecies(const extended_species* species) {
+  if (species->id < COT_N_VANILLA_SPECIES || species->template_species >= COT_N_VANILLA_SPECIES) {
+    COT_WARNFMT(COT_LOG_CAT_SPECIES, "Invalid extended species %d (template %d)",
+                species->id, species->template_species);
+    return false;
+  }
+  if (CotGetExtendedSpecies(species->id) != NULL) {
+    COT_WARNFMT(COT_LOG_CAT_SPECIES, "Extended species %d is already registered", species->id);
+    return false;
+  }
+  if (n_extended_species >= COT_MAX_EXTENDED_SPECIES) {
+    COT_WARN(COT_LOG_CAT_SPECIES, "Extended species table is full");
+    return false;
+  }
+  extended_species_table[n_extended_species++] = species;
+  return true;
+}
+
+const extended_species* CotGetExtendedSpecies(enum monster_id id) {
+  if (id < COT_N_VANILLA_SPECIES) {
+    return NULL;
+  }
+  for (int i = 0; i < n_extended_species; i++) {
+    if (extended_species_table[i]->id == id) {
+      return extended_species_table[i];
+    }
+  }
+  return NULL;
+}
+
+enum monster_id cotInternalResolveSpeciesDataId(enum monster_id id) {
+  const extended_species* species = CotGetExtendedSpecies(id);
+  return species != NULL ? species->template_species : id;
+}
+
+enum monster_id cotInternalResolveSpriteId(enum monster_id id) {
+  const extended_species* species = CotGetExtendedSpecies(id);
+  if (species == NULL) {
+    return id;
+  }
+  return species->sprite_id != MONSTER_NONE ? species->sprite_id : species->template_species;
+}
+
+enum type_id cotInternalGetSpeciesType(enum monster_id id, int idx, enum type_id vanilla) {
+  const extended_species* species = CotGetExtendedSpecies(id);
+  if (species == NULL || idx < 0 || idx >= 2 || species->types[idx] == TYPE_NONE) {
+    return vanilla;
+  }
+  return species->types[idx];
+}
+
+enum ability_id cotInternalGetSpeciesAbility(enum monster_id id, int idx, enum ability_id vanilla) {
+  const extended_species* species = CotGetExtendedSpecies(id);
+  if (species == NULL || idx < 0 || idx >= 2 || species->abilities[idx] == ABILITY_UNKNOWN) {
+    return vanilla;
+  }
+  return species->abilities[idx];
+}
+
+int cotInternalGetSpeciesNameStringId(enum monster_id id) {
+  const extended_species* species = CotGetExtendedSpecies(id);
+  return species != NULL ? species->name_string_id : 0;
+}
+
+int cotInternalGetSpeciesCategoryStringId(enum monster_id id) {
+  const extended_species* species = CotGetExtendedSpecies(id);
+  return species != NULL ? species->category_string_id : 0;
+}
+
+enum monster_id cotInternalRollSpeciesSpawn(enum dungeon_id dungeon, enum monster_id species) {
+  for (int i = 0; i < n_extended_species; i++) {
+    const extended_species* extended = extended_species_table[i];
+    if (extended->replaces != species || extended->replaces == MONSTER_NONE) {
+      continue;
+    }
+    if (extended->spawn_dungeon != dungeon && extended->spawn_dungeon != COT_ANY_DUNGEON) {
+      continue;
+    }
+    if (CotRandChance(RNG_DUNGEON, extended->replace_chance)) {
+      COT_LOGFMT(COT_LOG_CAT_SPECIES, "Spawning extended species %d in place of %d",
+                 extended->id, species);
+      return extended->id;
+    }
+  }
+  return species;
+}
@@ -0,0 +1,43 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+static COT_VEC(faint_hook_fn, COT_MAX_FAINT_HOOKS) faint_hooks;
+
+bool CotAddFaintHook(faint_hook_fn hook) {
+  if (!COT_VEC_PUSH(&faint_hooks, hook)) {
+    COT_WARN(COT_LOG_CAT_FAINT, "Faint hook table is full");
+    return false;
+  }
+  return true;
+}
+
+void CotRemoveFaintHook(faint_hook_fn hook) {
+  for (int i = 0; i < faint_hooks.len; i++) {
+    if (faint_hooks.items[i] == hook) {
+      COT_VEC_REMOVE(&faint_hooks, i);
+      break;
+    }
+  }
+}
+
+bool CotFaintedByTeam(struct entity* killer) {
+  return CotIsTeamMember(killer);
+}
+
+void cotInternalOnMonsterFainted(struct entity* fainted, struct entity* killer) {
+  if (CotGetMonster(fainted) == NULL) {
+    return;
+  }
+  cotInternalOnEscortFainted(fainted);
+  cotInternalOnOutlawFainted(fainted, killer);
+  cotInternalOnMissionMonsterFainted(fainted);
+  if (fainted == GetLeader()) {
+    cotInternalOnLeaderFainted();
+  }
+
+  // Iterate a copy, hooks may add or remove hooks
+  __typeof__(faint_hooks) hooks = faint_hooks;
+  for (int i = 0; i < hooks.len; i++) {
+    hooks.items[i](fainted, killer);
+  }
+}
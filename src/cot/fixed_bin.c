@@ -0,0 +1,73 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+#define SIR0_HEADER_SIZE 0x10
+#define ACTION_MASK 0xFFF
+#define REPEAT_SHIFT 12
+
+static bool IsRoomOffset(const fixed_bin* bin, uint32_t offset) {
+  return offset >= SIR0_HEADER_SIZE
+         && offset + COT_FIXED_BIN_ROOM_HEADER_SIZE <= (uint32_t) bin->size;
+}
+
+bool CotFixedBinOpen(fixed_bin* bin, const void* data, int size) {
+  bin->data = data;
+  bin->size = size;
+  const uint8_t* header = bin->data;
+  if (size < SIR0_HEADER_SIZE || header[0] != 'S' || header[1] != 'I' || header[2] != 'R'
+      || header[3] != '0') {
+    COT_WARN(COT_LOG_CAT_FIXED_BIN, "fixed.bin is not an SIR0 container");
+    return false;
+  }
+  uint32_t list = CotReadU32(header + 4);
+  if (list < SIR0_HEADER_SIZE || list >= (uint32_t) size) {
+    COT_WARN(COT_LOG_CAT_FIXED_BIN, "Invalid fixed.bin header");
+    return false;
+  }
+  bin->rooms = bin->data + list;
+  bin->n_rooms = 0;
+  for (uint32_t entry = list; entry + 4 <= (uint32_t) size; entry += 4) {
+    if (!IsRoomOffset(bin, CotReadU32(bin->data + entry))) {
+      break;
+    }
+    bin->n_rooms++;
+  }
+  return true;
+}
+
+bool CotFixedBinGetRoom(const fixed_bin* bin, int fixed_room_id, fixed_bin_room* out) {
+  if (fixed_room_id < 0 || fixed_room_id >= bin->n_rooms) {
+    COT_WARNFMT(COT_LOG_CAT_FIXED_BIN, "No fixed room %d in fixed.bin", fixed_room_id);
+    return false;
+  }
+  uint32_t offset = CotReadU32(bin->rooms + 4 * fixed_room_id);
+  const uint8_t* room = bin->data + offset;
+  out->width = CotReadU16(room);
+  out->height = CotReadU16(room + 2);
+  out->properties = CotReadU16(room + 4);
+  out->actions = room + COT_FIXED_BIN_ROOM_HEADER_SIZE;
+  out->actions_size = bin->size - offset - COT_FIXED_BIN_ROOM_HEADER_SIZE;
+  return true;
+}
+
+int CotFixedBinDecodeRoom(const fixed_bin_room* room, uint16_t* out, int max) {
+  int n_tiles = room->width * room->height;
+  if (n_tiles > max) {
+    COT_WARNFMT(COT_LOG_CAT_FIXED_BIN, "Fixed room of %dx%d doesn't fit", room->width,
+                room->height);
+    return -1;
+  }
+  int tile = 0;
+  for (int pos = 0; tile < n_tiles; pos += 2) {
+    if (pos + 2 > room->actions_size) {
+      COT_WARN(COT_LOG_CAT_FIXED_BIN, "Truncated fixed room actions");
+      return -1;
+    }
+    uint16_t value = CotReadU16(room->actions + pos);
+    int repeat = 1 + (value >> REPEAT_SHIFT);
+    for (int i = 0; i < repeat && tile < n_tiles; i++) {
+      out[tile++] = value & ACTION_MASK;
+    }
+  }
+  return tile;
+}
@@ -0,0 +1,85 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+static bool InLayout(const fixed_room_builder* builder, int x, int y) {
+  return x >= 0 && x < builder->room.width && y >= 0 && y < builder->room.height;
+}
+
+bool CotFixedRoomBuilderInit(fixed_room_builder* builder, int fixed_room_id, int width, int height) {
+  if (width < 1 || width > COT_FIXED_ROOM_MAX_WIDTH
+      || height < 1 || height > COT_FIXED_ROOM_MAX_HEIGHT) {
+    COT_WARNFMT(COT_LOG_CAT_FIXED_ROOMS, "Fixed room %d has an invalid size", fixed_room_id);
+    return false;
+  }
+  builder->room = (custom_fixed_room) {
+    .fixed_room_id = fixed_room_id,
+    .width = width,
+    .height = height,
+    .layout = builder->row_ptrs,
+    .spawns = builder->spawns,
+    .n_spawns = 0,
+    .populate = NULL,
+  };
+  for (int y = 0; y < height; y++) {
+    memset(builder->rows[y], '.', width);
+    builder->rows[y][width] = '\0';
+    builder->row_ptrs[y] = builder->rows[y];
+  }
+  return true;
+}
+
+void CotFixedRoomBuilderSetTile(fixed_room_builder* builder, int x, int y, char c) {
+  if (InLayout(builder, x, y)) {
+    builder->rows[y][x] = c;
+  }
+}
+
+void CotFixedRoomBuilderFillRect(fixed_room_builder* builder, int x, int y, int w, int h, char c) {
+  for (int i = x; i < x + w; i++) {
+    for (int j = y; j < y + h; j++) {
+      CotFixedRoomBuilderSetTile(builder, i, j, c);
+    }
+  }
+}
+
+static bool AddSpawn(fixed_room_builder* builder, fixed_room_spawn spawn) {
+  if (!InLayout(builder, spawn.x, spawn.y)) {
+    COT_WARNFMT(COT_LOG_CAT_FIXED_ROOMS, "Spawn at (%d, %d) is outside of fixed room %d",
+                spawn.x, spawn.y, builder->room.fixed_room_id);
+    return false;
+  }
+  if (builder->room.n_spawns >= COT_FIXED_ROOM_MAX_SPAWNS) {
+    COT_WARNFMT(COT_LOG_CAT_FIXED_ROOMS, "Spawn list of fixed room %d is full",
+                builder->room.fixed_room_id);
+    return false;
+  }
+  builder->spawns[builder->room.n_spawns++] = spawn;
+  return true;
+}
+
+bool CotFixedRoomBuilderAddMonster(fixed_room_builder* builder, int x, int y,
+                                   enum monster_id monster, int level) {
+  return AddSpawn(builder, (fixed_room_spawn) {
+    .kind = FIXED_ROOM_SPAWN_MONSTER, .x = x, .y = y, .id = monster, .level = level,
+  });
+}
+
+bool CotFixedRoomBuilderAddItem(fixed_room_builder* builder, int x, int y, enum item_id item) {
+  return AddSpawn(builder, (fixed_room_spawn) {
+    .kind = FIXED_ROOM_SPAWN_ITEM, .x = x, .y = y, .id = item,
+  });
+}
+
+bool CotFixedRoomBuilderAddTrap(fixed_room_builder* builder, int x, int y, enum trap_id trap) {
+  return AddSpawn(builder, (fixed_room_spawn) {
+    .kind = FIXED_ROOM_SPAWN_TRAP, .x = x, .y = y, .id = trap,
+  });
+}
+
+void CotFixedRoomBuilderSetPopulate(fixed_room_builder* builder, fixed_room_populate_fn populate) {
+  builder->room.populate = populate;
+}
+
+bool CotFixedRoomBuilderRegister(fixed_room_builder* builder) {
+  return CotRegisterFixedRoom(&builder->room);
+}
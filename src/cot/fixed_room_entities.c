@@ -0,0 +1,112 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+typedef struct entity_override {
+  int code;
+  struct fixed_room_entity_spawn_entry original;
+  struct fixed_room_monster_spawn_entry monster;
+  struct fixed_room_item_spawn_entry item;
+  struct fixed_room_tile_spawn_entry tile;
+} entity_override;
+
+static entity_override overrides[COT_MAX_FIXED_ROOM_ENTITY_OVERRIDES];
+static int n_overrides = 0;
+
+static struct fixed_room_entity_spawn_entry* GetEntry(int code) {
+  if (code < 0 || code >= COT_N_FIXED_ROOM_ENTITY_CODES) {
+    COT_WARNFMT(COT_LOG_CAT_FIXED_ROOMS, "Invalid fixed room entity code %d", code);
+    return NULL;
+  }
+  return &FIXED_ROOM_ENTITY_SPAWN_TABLE[code];
+}
+
+// Returns the override of `code`, creating it from the current entry if needed.
+static entity_override* GetOverride(int code) {
+  struct fixed_room_entity_spawn_entry* entry = GetEntry(code);
+  if (entry == NULL) {
+    return NULL;
+  }
+  for (int i = 0; i < n_overrides; i++) {
+    if (overrides[i].code == code) {
+      return &overrides[i];
+    }
+  }
+  if (n_overrides >= COT_MAX_FIXED_ROOM_ENTITY_OVERRIDES) {
+    COT_WARN(COT_LOG_CAT_FIXED_ROOMS, "Fixed room entity override table is full");
+    return NULL;
+  }
+
+  entity_override* override = &overrides[n_overrides++];
+  override->code = code;
+  override->original = *entry;
+  override->monster = *entry->monster;
+  override->item = *entry->item;
+  override->tile = *entry->tile;
+  entry->monster = &override->monster;
+  entry->item = &override->item;
+  entry->tile = &override->tile;
+  return override;
+}
+
+bool CotGetFixedRoomEntity(int code, fixed_room_entity* out) {
+  struct fixed_room_entity_spawn_entry* entry = GetEntry(code);
+  if (entry == NULL) {
+    return false;
+  }
+  out->monster = entry->monster->id.val;
+  out->behavior = entry->monster->behavior.val;
+  out->item = entry->item->id.val;
+  out->trap = entry->tile->trap_id.val;
+  return true;
+}
+
+bool CotSetFixedRoomMonster(int code, enum monster_id monster, enum monster_behavior behavior) {
+  entity_override* override = GetOverride(code);
+  if (override == NULL) {
+    return false;
+  }
+  override->monster.id.val = monster;
+  override->monster.behavior.val = behavior;
+  return true;
+}
+
+bool CotSetFixedRoomItem(int code, enum item_id item) {
+  entity_override* override = GetOverride(code);
+  if (override == NULL) {
+    return false;
+  }
+  override->item.id.val = item;
+  return true;
+}
+
+bool CotSetFixedRoomTrap(int code, enum trap_id trap) {
+  entity_override* override = GetOverride(code);
+  if (override == NULL) {
+    return false;
+  }
+  override->tile.trap_id.val = trap;
+  return true;
+}
+
+void CotResetFixedRoomEntity(int code) {
+  for (int i = 0; i < n_overrides; i++) {
+    if (overrides[i].code == code) {
+      FIXED_ROOM_ENTITY_SPAWN_TABLE[code] = overrides[i].original;
+      overrides[i] = overrides[--n_overrides];
+      if (i < n_overrides) {
+        // The moved override's entry still points to its old slot
+        struct fixed_room_entity_spawn_entry* entry = &FIXED_ROOM_ENTITY_SPAWN_TABLE[overrides[i].code];
+        entry->monster = &overrides[i].monster;
+        entry->item = &overrides[i].item;
+        entry->tile = &overrides[i].tile;
+      }
+      return;
+    }
+  }
+}
+
+void CotResetFixedRoomEntities(void) {
+  while (n_overrides > 0) {
+    CotResetFixedRoomEntity(overrides[0].code);
+  }
+}
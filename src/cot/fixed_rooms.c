@@ -0,0 +1,203 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+static const custom_fixed_room* fixed_rooms[COT_MAX_CUSTOM_FIXED_ROOMS];
+static int n_fixed_rooms = 0;
+
+bool CotRegisterFixedRoom(const custom_fixed_room* room) {
+  if (!CotValidateFixedRoom(room)) {
+    return false;
+  }
+  if (CotGetCustomFixedRoom(room->fixed_room_id) != NULL) {
+    COT_WARNFMT(COT_LOG_CAT_FIXED_ROOMS, "Fixed room %d is already registered", room->fixed_room_id);
+    return false;
+  }
+  if (n_fixed_rooms >= COT_MAX_CUSTOM_FIXED_ROOMS) {
+    COT_WARN(COT_LOG_CAT_FIXED_ROOMS, "Fixed room table is full");
+    return false;
+  }
+  fixed_rooms[n_fixed_rooms++] = room;
+  return true;
+}
+
+const custom_fixed_room* CotGetCustomFixedRoom(int fixed_room_id) {
+  for (int i = 0; i < n_fixed_rooms; i++) {
+    if (fixed_rooms[i]->fixed_room_id == fixed_room_id) {
+      return fixed_rooms[i];
+    }
+  }
+  return NULL;
+}
+
+static bool IsLayoutTile(char c) {
+  switch (c) {
+    case '#':
+    case 'X':
+    case '~':
+    case '_':
+    case 'S':
+    case 'P':
+    case '.':
+      return true;
+    default:
+      return false;
+  }
+}
+
+static void WriteLayoutTile(int x, int y, char c) {
+  struct tile* tile = CotTileAt(x, y);
+  tile->room = COT_NO_ROOM;
+  switch (c) {
+    case '#':
+      tile->terrain_flags.terrain_type = TERRAIN_WALL;
+      return;
+    case 'X':
+      tile->terrain_flags.terrain_type = TERRAIN_WALL;
+      tile->terrain_flags.f_impassable_wall = true;
+      tile->terrain_flags.f_unbreakable = true;
+      return;
+    case '~':
+      tile->terrain_flags.terrain_type = TERRAIN_SECONDARY;
+      return;
+    case '_':
+      tile->terrain_flags.terrain_type = TERRAIN_CHASM;
+      return;
+    case 'S':
+      tile->terrain_flags.f_stairs = true;
+      DUNGEON_PTR->gen_info.stairs_pos = (struct position) { .x = x, .y = y };
+      break;
+    case 'P':
+      DUNGEON_PTR->gen_info.team_spawn_pos = (struct position) { .x = x, .y = y };
+      break;
+    default:
+      break;
+  }
+  tile->terrain_flags.terrain_type = TERRAIN_NORMAL;
+  tile->room = 0;
+}
+
+bool CotValidateFixedRoom(const custom_fixed_room* room) {
+  if (room->layout == NULL) {
+    if (CotGetCustomFixedRoom(room->base_fixed_room_id) != NULL
+        || room->base_fixed_room_id == room->fixed_room_id) {
+      COT_WARNFMT(COT_LOG_CAT_FIXED_ROOMS, "Fixed room %d needs a vanilla base room",
+                  room->fixed_room_id);
+      return false;
+    }
+  } else {
+    if (room->width < 1 || room->width > COT_FLOOR_WIDTH - 2
+        || room->height < 1 || room->height > COT_FLOOR_HEIGHT - 2) {
+      COT_WARNFMT(COT_LOG_CAT_FIXED_ROOMS, "Fixed room %d has an invalid size",
+                  room->fixed_room_id);
+      return false;
+    }
+    for (int y = 0; y < room->height; y++) {
+      const char* row = room->layout[y];
+      for (int x = 0; x < room->width; x++) {
+        if (row[x] == '\0') {
+          COT_WARNFMT(COT_LOG_CAT_FIXED_ROOMS, "Row %d of fixed room %d is too short",
+                      y, room->fixed_room_id);
+          return false;
+        }
+        if (!IsLayoutTile(row[x])) {
+          COT_WARNFMT(COT_LOG_CAT_FIXED_ROOMS, "Invalid tile '%c' in fixed room %d",
+                      row[x], room->fixed_room_id);
+          return false;
+        }
+      }
+    }
+  }
+
+  int max_x = room->layout != NULL ? room->width : COT_FLOOR_WIDTH;
+  int max_y = room->layout != NULL ? room->height : COT_FLOOR_HEIGHT;
+  for (int i = 0; i < room->n_spawns; i++) {
+    const fixed_room_spawn* spawn = &room->spawns[i];
+    if (spawn->x < 0 || spawn->x >= max_x || spawn->y < 0 || spawn->y >= max_y) {
+      COT_WARNFMT(COT_LOG_CAT_FIXED_ROOMS, "Spawn %d of fixed room %d is out of bounds",
+                  i, room->fixed_room_id);
+      return false;
+    }
+  }
+  return true;
+}
+
+static void PlaceSpawn(const fixed_room_spawn* spawn, int x, int y) {
+  switch (spawn->kind) {
+    case FIXED_ROOM_SPAWN_MONSTER: {
+      monster_spawn monster;
+      CotSpawnInit(&monster, spawn->id);
+      CotSpawnSetLevel(&monster, spawn->level);
+      CotSpawnSetPosition(&monster, x, y);
+      CotSpawn(&monster);
+      break;
+    }
+    case FIXED_ROOM_SPAWN_ITEM: {
+      item_spawn item;
+      CotItemSpawnInit(&item, spawn->id);
+      CotSpawnItemAt(&item, x, y);
+      break;
+    }
+    case FIXED_ROOM_SPAWN_TRAP: {
+      struct position pos = { .x = x, .y = y };
+      SpawnTrap(spawn->id, &pos, 0, 0);
+      break;
+    }
+  }
+}
+
+static void WriteLayout(const custom_fixed_room* room, int origin_x, int origin_y) {
+  ResetFloor();
+  for (int y = 0; y < COT_FLOOR_HEIGHT; y++) {
+    for (int x = 0; x < COT_FLOOR_WIDTH; x++) {
+      struct tile* tile = CotTileAt(x, y);
+      tile->terrain_flags.f_impassable_wall = true;
+      tile->terrain_flags.f_unbreakable = true;
+    }
+  }
+
+  for (int y = 0; y < room->height; y++) {
+    for (int x = 0; x < room->width; x++) {
+      // Tiles inside the layout are breakable unless marked otherwise
+      struct tile* tile = CotTileAt(origin_x + x, origin_y + y);
+      tile->terrain_flags.f_impassable_wall = false;
+      tile->terrain_flags.f_unbreakable = false;
+      WriteLayoutTile(origin_x + x, origin_y + y, room->layout[y][x]);
+    }
+  }
+  FinalizeJunctions();
+}
+
+bool CotGenerateCustomFixedRoom(const custom_fixed_room* room, struct floor_properties* props) {
+  if (!CotValidateFixedRoom(room)) {
+    return false;
+  }
+
+  int origin_x = 0;
+  int origin_y = 0;
+  if (room->layout != NULL) {
+    origin_x = (COT_FLOOR_WIDTH - room->width) / 2;
+    origin_y = (COT_FLOOR_HEIGHT - room->height) / 2;
+    WriteLayout(room, origin_x, origin_y);
+  } else if (!GenerateFixedRoom(room->base_fixed_room_id, props)) {
+    return false;
+  }
+
+  for (int i = 0; i < room->n_spawns; i++) {
+    PlaceSpawn(&room->spawns[i], origin_x + room->spawns[i].x, origin_y + room->spawns[i].y);
+  }
+  if (room->populate != NULL) {
+    room->populate(origin_x, origin_y);
+  }
+  return true;
+}
+
+bool cotInternalDispatchGenerateFixedRoom(enum fixed_room_id fixed_room_id,
+                                          struct floor_properties* props, bool* result) {
+  const custom_fixed_room* room = CotGetCustomFixedRoom(fixed_room_id);
+  if (room == NULL) {
+    return false;
+  }
+  COT_LOGFMT(COT_LOG_CAT_FIXED_ROOMS, "Generating custom fixed room %d", fixed_room_id);
+  *result = CotGenerateCustomFixedRoom(room, props);
+  return true;
+}
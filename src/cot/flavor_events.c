@@ -0,0 +1,104 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+typedef struct flavor_interval {
+  enum dungeon_id dungeon;
+  int min_turns;
+  int max_turns;
+} flavor_interval;
+
+static flavor_event events[COT_MAX_FLAVOR_EVENTS];
+static int n_events = 0;
+static flavor_interval intervals[COT_MAX_FLAVOR_INTERVALS];
+static int n_intervals = 0;
+static flavor_interval default_interval = { .min_turns = 100, .max_turns = 300 };
+
+static enum dungeon_id current_dungeon;
+static int turns_left = -1;
+
+bool CotRegisterFlavorEvent(flavor_event event) {
+  if (n_events >= COT_MAX_FLAVOR_EVENTS) {
+    COT_WARN(COT_LOG_CAT_FLAVOR_EVENTS, "Flavor event table is full");
+    return false;
+  }
+  events[n_events++] = event;
+  return true;
+}
+
+static void SetInterval(flavor_interval* interval, int min_turns, int max_turns) {
+  interval->min_turns = min_turns < 1 ? 1 : min_turns;
+  interval->max_turns = max_turns < interval->min_turns ? interval->min_turns : max_turns;
+}
+
+void CotSetFlavorEventInterval(int min_turns, int max_turns) {
+  SetInterval(&default_interval, min_turns, max_turns);
+}
+
+bool CotSetDungeonFlavorEventInterval(enum dungeon_id dungeon, int min_turns, int max_turns) {
+  flavor_interval* interval = NULL;
+  for (int i = 0; i < n_intervals; i++) {
+    if (intervals[i].dungeon == dungeon) {
+      interval = &intervals[i];
+    }
+  }
+  if (interval == NULL) {
+    if (n_intervals >= COT_MAX_FLAVOR_INTERVALS) {
+      COT_WARN(COT_LOG_CAT_FLAVOR_EVENTS, "Flavor event interval table is full");
+      return false;
+    }
+    interval = &intervals[n_intervals++];
+    interval->dungeon = dungeon;
+  }
+  SetInterval(interval, min_turns, max_turns);
+  return true;
+}
+
+static void ScheduleNext(void) {
+  const flavor_interval* interval = &default_interval;
+  for (int i = 0; i < n_intervals; i++) {
+    if (intervals[i].dungeon == current_dungeon) {
+      interval = &intervals[i];
+    }
+  }
+  turns_left = interval->min_turns
+      + CotRandInt(RNG_DUNGEON, interval->max_turns - interval->min_turns + 1);
+}
+
+static void StartFlavorEvents(struct floor_properties* props) {
+  current_dungeon = DUNGEON_PTR->id.val;
+  ScheduleNext();
+}
+
+bool CotInstallFlavorEvents(void) {
+  return CotAddPostGenerateHook(StartFlavorEvents);
+}
+
+static void RunEvent(const flavor_event* event) {
+  if (event->message != NULL) {
+    LogMessage(GetLeader(), event->message, true);
+  }
+  if (event->se_id != 0) {
+    PlaySeVolumeWrapper(event->se_id);
+  }
+  if (event->run != NULL) {
+    event->run();
+  }
+}
+
+void cotInternalUpdateFlavorEvents(void) {
+  if (turns_left < 0 || --turns_left > 0) {
+    return;
+  }
+  ScheduleNext();
+
+  int weights[COT_MAX_FLAVOR_EVENTS];
+  for (int i = 0; i < n_events; i++) {
+    bool available = events[i].dungeon == current_dungeon
+        || events[i].dungeon == COT_ANY_DUNGEON;
+    weights[i] = available ? events[i].weight : 0;
+  }
+  int picked = CotRandWeighted(RNG_DUNGEON, weights, n_events);
+  if (picked >= 0) {
+    RunEvent(&events[picked]);
+  }
+}
@@ -0,0 +1,378 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+// Rooms never touch the outer two rows and columns of the floor.
+#define FLOOR_MARGIN 2
+
+static const floor_generator* active_generator = NULL;
+static floor_validator_fn floor_validator = NULL;
+static int max_attempts = 1;
+static floor_gen_hook_fn pre_generate_hooks[COT_MAX_FLOOR_GEN_HOOKS];
+static floor_gen_hook_fn post_generate_hooks[COT_MAX_FLOOR_GEN_HOOKS];
+static floor_override_hook_fn override_hooks[COT_MAX_FLOOR_GEN_HOOKS];
+static int n_override_hooks = 0;
+
+// Returns a random number in [0, n), or 0 if the range is empty.
+static int RandBelow(int n) {
+  return n > 0 ? CotRandInt(RNG_DUNGEON, n) : 0;
+}
+
+static int Clamp(int value, int min, int max) {
+  return value < min ? min : (value > max ? max : value);
+}
+
+static bool IsOpen(const rooms_gen_state* state, int x, int y) {
+  return state->tiles[y][x] != COT_ROOMS_GEN_WALL;
+}
+
+static void CarveRoom(rooms_gen_state* state, const room_rect* rect, uint8_t room) {
+  for (int y = rect->y0; y <= rect->y1; y++) {
+    for (int x = rect->x0; x <= rect->x1; x++) {
+      state->tiles[y][x] = room;
+    }
+  }
+}
+
+// Corridors only carve through walls, so they never overwrite room tiles.
+static void CarveHallwayTile(rooms_gen_state* state, int x, int y) {
+  if (!IsOpen(state, x, y)) {
+    state->tiles[y][x] = COT_ROOMS_GEN_HALLWAY;
+  }
+}
+
+static void CarveCorridor(rooms_gen_state* state, struct position from, struct position to) {
+  int x = from.x;
+  int y = from.y;
+  while (x != to.x) {
+    CarveHallwayTile(state, x, y);
+    x += x < to.x ? 1 : -1;
+  }
+  while (y != to.y) {
+    CarveHallwayTile(state, x, y);
+    y += y < to.y ? 1 : -1;
+  }
+}
+
+static struct position RandomRoomTile(const room_rect* rect) {
+  return (struct position) {
+    .x = rect->x0 + RandBelow(rect->x1 - rect->x0 + 1),
+    .y = rect->y0 + RandBelow(rect->y1 - rect->y0 + 1),
+  };
+}
+
+// Imperfections may have turned a random tile of the room back into a wall. The center of a
+// room is never affected.
+static struct position RandomOpenRoomTile(const rooms_gen_state* state, int room) {
+  const room_rect* rect = &state->rooms[room];
+  struct position pos = RandomRoomTile(rect);
+  if (state->tiles[pos.y][pos.x] != room) {
+    pos = (struct position) { .x = (rect->x0 + rect->x1) / 2, .y = (rect->y0 + rect->y1) / 2 };
+  }
+  return pos;
+}
+
+void CotRoomsGenBegin(rooms_gen_state* state, const rooms_generator_config* config) {
+  rooms_generator_config defaults = {
+    .grid_x = 3,
+    .grid_y = 2,
+    .min_room_size = 3,
+    .extra_hallways = 2,
+    .imperfection_chance = 30,
+  };
+  if (config == NULL) {
+    config = &defaults;
+  }
+  state->grid_x = Clamp(config->grid_x, 2, 4);
+  state->grid_y = Clamp(config->grid_y, 2, 3);
+  state->cell_w = (COT_FLOOR_WIDTH - 2 * FLOOR_MARGIN) / state->grid_x;
+  state->cell_h = (COT_FLOOR_HEIGHT - 2 * FLOOR_MARGIN) / state->grid_y;
+  state->min_size = Clamp(config->min_room_size, 2, state->cell_h - 2);
+  state->extra_hallways = Clamp(config->extra_hallways, 0, COT_ROOMS_GEN_MAX_ROOMS);
+  state->imperfection_chance = Clamp(config->imperfection_chance, 0, 100);
+  state->n_rooms = state->grid_x * state->grid_y;
+  state->phase = ROOMS_GEN_ROOMS;
+  state->cursor = 0;
+  memset(state->tiles, COT_ROOMS_GEN_WALL, sizeof(state->tiles));
+}
+
+// Rooms are stored column-major: room i is in grid cell (i / grid_y, i % grid_y).
+static void StepRoom(rooms_gen_state* state) {
+  int i = state->cursor;
+  int cell_x = FLOOR_MARGIN + (i / state->grid_y) * state->cell_w;
+  int cell_y = FLOOR_MARGIN + (i % state->grid_y) * state->cell_h;
+  // Keep one tile of space to the next cell so rooms never merge
+  int w = state->min_size + RandBelow(state->cell_w - state->min_size - 1);
+  int h = state->min_size + RandBelow(state->cell_h - state->min_size - 1);
+  int x = cell_x + RandBelow(state->cell_w - w);
+  int y = cell_y + RandBelow(state->cell_h - h);
+  state->rooms[i] = (room_rect) { .x0 = x, .y0 = y, .x1 = x + w - 1, .y1 = y + h - 1 };
+  CarveRoom(state, &state->rooms[i], i);
+}
+
+// Chips off a triangle of tiles at every corner of a room, up to a third of its size.
+static void StepImperfections(rooms_gen_state* state) {
+  const room_rect* rect = &state->rooms[state->cursor];
+  int w = rect->x1 - rect->x0 + 1;
+  int h = rect->y1 - rect->y0 + 1;
+  int max_depth = (w < h ? w : h) / 3;
+  if (max_depth < 1 || RandBelow(100) >= state->imperfection_chance) {
+    return;
+  }
+  const int corner_x[4] = { rect->x0, rect->x1, rect->x0, rect->x1 };
+  const int corner_y[4] = { rect->y0, rect->y0, rect->y1, rect->y1 };
+  for (int corner = 0; corner < 4; corner++) {
+    int depth = RandBelow(max_depth + 1);
+    int dx = corner_x[corner] == rect->x0 ? 1 : -1;
+    int dy = corner_y[corner] == rect->y0 ? 1 : -1;
+    for (int i = 0; i < depth; i++) {
+      for (int j = 0; i + j < depth; j++) {
+        state->tiles[corner_y[corner] + dy * j][corner_x[corner] + dx * i] = COT_ROOMS_GEN_WALL;
+      }
+    }
+  }
+}
+
+// Connects a room to its neighbors below and to the right.
+static void StepCorridors(rooms_gen_state* state) {
+  int i = state->cursor;
+  struct position from = RandomOpenRoomTile(state, i);
+  if (i % state->grid_y + 1 < state->grid_y) {
+    CarveCorridor(state, from, RandomOpenRoomTile(state, i + 1));
+  }
+  if (i + state->grid_y < state->n_rooms) {
+    CarveCorridor(state, from, RandomOpenRoomTile(state, i + state->grid_y));
+  }
+}
+
+// Digs a winding hallway out of a random room until it runs into another open tile or the
+// floor margin.
+static void StepExtraHallway(rooms_gen_state* state) {
+  int room = RandBelow(state->n_rooms);
+  struct position pos = RandomOpenRoomTile(state, room);
+  // Hallways only go straight
+  enum direction_id direction = (enum direction_id) (RandBelow(4) * 2);
+  // Leave the room first
+  while (state->tiles[pos.y][pos.x] == room) {
+    pos = CotPosStep(pos, direction);
+    if (pos.x < FLOOR_MARGIN || pos.x >= COT_FLOOR_WIDTH - FLOOR_MARGIN || pos.y < FLOOR_MARGIN
+        || pos.y >= COT_FLOOR_HEIGHT - FLOOR_MARGIN) {
+      return;
+    }
+  }
+  for (int length = 0; length < COT_FLOOR_WIDTH; length++) {
+    if (IsOpen(state, pos.x, pos.y)) {
+      return;
+    }
+    CarveHallwayTile(state, pos.x, pos.y);
+    if (RandBelow(4) == 0) {
+      direction = CotDirectionRotate(direction, RandBelow(2) == 0 ? 2 : -2);
+    }
+    struct position next = CotPosStep(pos, direction);
+    if (next.x < FLOOR_MARGIN || next.x >= COT_FLOOR_WIDTH - FLOOR_MARGIN
+        || next.y < FLOOR_MARGIN || next.y >= COT_FLOOR_HEIGHT - FLOOR_MARGIN) {
+      return;
+    }
+    pos = next;
+  }
+}
+
+static void StepFinalize(rooms_gen_state* state) {
+  int stairs_room = RandBelow(state->n_rooms);
+  int spawn_room = (stairs_room + 1 + RandBelow(state->n_rooms - 1)) % state->n_rooms;
+  state->stairs_pos = RandomOpenRoomTile(state, stairs_room);
+  state->team_spawn_pos = RandomOpenRoomTile(state, spawn_room);
+}
+
+rooms_gen_phase CotRoomsGenStep(rooms_gen_state* state) {
+  switch (state->phase) {
+    case ROOMS_GEN_ROOMS:
+      StepRoom(state);
+      if (++state->cursor == state->n_rooms) {
+        state->phase = ROOMS_GEN_IMPERFECTIONS;
+        state->cursor = 0;
+      }
+      break;
+    case ROOMS_GEN_IMPERFECTIONS:
+      StepImperfections(state);
+      if (++state->cursor == state->n_rooms) {
+        state->phase = ROOMS_GEN_CORRIDORS;
+        state->cursor = 0;
+      }
+      break;
+    case ROOMS_GEN_CORRIDORS:
+      StepCorridors(state);
+      if (++state->cursor == state->n_rooms) {
+        state->phase = ROOMS_GEN_EXTRA_HALLWAYS;
+        state->cursor = 0;
+      }
+      break;
+    case ROOMS_GEN_EXTRA_HALLWAYS:
+      if (state->cursor < state->extra_hallways) {
+        StepExtraHallway(state);
+      }
+      if (++state->cursor >= state->extra_hallways) {
+        state->phase = ROOMS_GEN_FINALIZE;
+      }
+      break;
+    case ROOMS_GEN_FINALIZE:
+      StepFinalize(state);
+      state->phase = ROOMS_GEN_DONE;
+      break;
+    default:
+      break;
+  }
+  return state->phase;
+}
+
+void CotRoomsGenCommit(const rooms_gen_state* state) {
+  COT_ASSERT(state->phase == ROOMS_GEN_DONE);
+  ResetFloor();
+  for (int y = 0; y < COT_FLOOR_HEIGHT; y++) {
+    for (int x = 0; x < COT_FLOOR_WIDTH; x++) {
+      if (IsOpen(state, x, y)) {
+        struct tile* tile = GetTileSafe(x, y);
+        tile->terrain_flags.terrain_type = TERRAIN_NORMAL;
+        tile->room = state->tiles[y][x];
+      }
+    }
+  }
+  GetTileSafe(state->stairs_pos.x, state->stairs_pos.y)->terrain_flags.f_stairs = true;
+  DUNGEON_PTR->gen_info.stairs_pos = state->stairs_pos;
+  DUNGEON_PTR->gen_info.team_spawn_pos = state->team_spawn_pos;
+
+  EnsureImpassableTilesAreWalls();
+  FinalizeJunctions();
+}
+
+static bool GenerateRooms(struct floor_properties* props, void* ctx) {
+  rooms_gen_state* state = MemAlloc(sizeof(rooms_gen_state), 0);
+  if (state == NULL) {
+    COT_ERROR(COT_LOG_CAT_FLOOR_GEN, "Failed to allocate the rooms generator state");
+    return false;
+  }
+  CotRoomsGenBegin(state, ctx);
+  while (CotRoomsGenStep(state) != ROOMS_GEN_DONE) {
+  }
+  CotRoomsGenCommit(state);
+  MemFree(state);
+  return true;
+}
+
+const floor_generator COT_ROOMS_GENERATOR = {
+  .name = "rooms",
+  .generate = GenerateRooms,
+  .ctx = NULL,
+};
+
+void CotSetFloorGenerator(const floor_generator* generator) {
+  active_generator = generator;
+}
+
+void CotSetFloorValidator(floor_validator_fn validator, int attempts) {
+  floor_validator = validator;
+  max_attempts = attempts < 1 ? 1 : attempts;
+}
+
+const floor_generator* CotGetFloorGenerator(void) {
+  return active_generator;
+}
+
+static bool AddHook(floor_gen_hook_fn* hooks, floor_gen_hook_fn hook) {
+  for (int i = 0; i < COT_MAX_FLOOR_GEN_HOOKS; i++) {
+    if (hooks[i] == NULL) {
+      hooks[i] = hook;
+      return true;
+    }
+  }
+  COT_WARN(COT_LOG_CAT_FLOOR_GEN, "Floor generation hook table is full");
+  return false;
+}
+
+static void RunHooks(floor_gen_hook_fn* hooks, struct floor_properties* props) {
+  for (int i = 0; i < COT_MAX_FLOOR_GEN_HOOKS; i++) {
+    if (hooks[i] != NULL) {
+      hooks[i](props);
+    }
+  }
+}
+
+bool CotAddPreGenerateHook(floor_gen_hook_fn hook) {
+  return AddHook(pre_generate_hooks, hook);
+}
+
+bool CotAddPostGenerateHook(floor_gen_hook_fn hook) {
+  return AddHook(post_generate_hooks, hook);
+}
+
+void CotRemoveFloorGenHook(floor_gen_hook_fn hook) {
+  for (int i = 0; i < COT_MAX_FLOOR_GEN_HOOKS; i++) {
+    if (pre_generate_hooks[i] == hook) {
+      pre_generate_hooks[i] = NULL;
+    }
+    if (post_generate_hooks[i] == hook) {
+      post_generate_hooks[i] = NULL;
+    }
+  }
+}
+
+bool CotAddGenerationOverrideHook(floor_override_hook_fn hook) {
+  if (n_override_hooks >= COT_MAX_FLOOR_GEN_HOOKS) {
+    COT_WARN(COT_LOG_CAT_FLOOR_GEN, "Floor generation hook table is full");
+    return false;
+  }
+  override_hooks[n_override_hooks++] = hook;
+  return true;
+}
+
+void CotRemoveGenerationOverrideHook(floor_override_hook_fn hook) {
+  for (int i = 0; i < n_override_hooks; i++) {
+    if (override_hooks[i] == hook) {
+      // Keep the order, the first hook to replace the generator wins
+      for (int j = i + 1; j < n_override_hooks; j++) {
+        override_hooks[j - 1] = override_hooks[j];
+      }
+      n_override_hooks--;
+      return;
+    }
+  }
+}
+
+static bool RunOverrideHooks(struct floor_properties* props) {
+  for (int i = 0; i < n_override_hooks; i++) {
+    if (override_hooks[i](props) == GEN_OVERRIDE_REPLACE) {
+      COT_LOG(COT_LOG_CAT_FLOOR_GEN, "Floor generated by an override hook");
+      return true;
+    }
+  }
+  return false;
+}
+
+static bool RunBackend(struct floor_properties* props) {
+  if (active_generator == NULL) {
+    return false;
+  }
+  COT_LOGFMT(COT_LOG_CAT_FLOOR_GEN, "Generating floor with backend '%s'", active_generator->name);
+  if (!CotGenerateFloorWithRollback(active_generator, props, floor_validator, max_attempts)) {
+    COT_WARNFMT(COT_LOG_CAT_FLOOR_GEN, "Backend '%s' failed, falling back to the builtin generator",
+                active_generator->name);
+    return false;
+  }
+  return true;
+}
+
+bool cotInternalDispatchGenerateFloor(struct floor_properties* props) {
+  RunHooks(pre_generate_hooks, props);
+  if (!RunOverrideHooks(props) && !RunBackend(props)) {
+    return false;
+  }
+  MarkNonEnemySpawns(props, false);
+  MarkEnemySpawns(props, false);
+  ResolveInvalidSpawns();
+  return true;
+}
+
+void cotInternalOnFloorGenerated(struct floor_properties* props) {
+  cotInternalOnMissionFloorStart(DUNGEON_PTR->id.val, DUNGEON_PTR->floor);
+  CotStatsRecordFloor();
+  RunHooks(post_generate_hooks, props);
+}
@@ -0,0 +1,245 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+typedef struct mutation_pipeline {
+  enum dungeon_id dungeon;
+  const floor_mutation* mutations;
+  int n;
+} mutation_pipeline;
+
+static mutation_pipeline pipelines[COT_MAX_MUTATION_PIPELINES];
+static int n_pipelines = 0;
+
+// Tiles on the edge of the floor are never changed.
+static bool IsInner(int x, int y) {
+  return x > 0 && x < COT_FLOOR_WIDTH - 1 && y > 0 && y < COT_FLOOR_HEIGHT - 1;
+}
+
+// Tiles holding something, or marked for the game's spawner, must stay reachable
+static bool IsProtected(int x, int y) {
+  struct position spawn = DUNGEON_PTR->gen_info.team_spawn_pos;
+  return CotTileIsStairs(x, y) || (spawn.x == x && spawn.y == y) || CotTileAt(x, y)->object != NULL
+         || CotTileSpawnFlags(x, y) != 0;
+}
+
+static bool IsBreakableWall(int x, int y) {
+  return IsInner(x, y) && CotTileIsWall(x, y) && !CotTileAt(x, y)->terrain_flags.f_unbreakable;
+}
+
+static void SetTerrain(int x, int y, enum terrain_type terrain, uint8_t room) {
+  struct tile* tile = CotTileAt(x, y);
+  tile->terrain_flags.terrain_type = terrain;
+  tile->room = room;
+}
+
+static bool HasNeighbor(int x, int y, bool (*pred)(int x, int y)) {
+  for (int j = y - 1; j <= y + 1; j++) {
+    for (int i = x - 1; i <= x + 1; i++) {
+      if ((i != x || j != y) && pred(i, j)) {
+        return true;
+      }
+    }
+  }
+  return false;
+}
+
+static bool IsRoomTile(int x, int y) {
+  return CotTileIsFloor(x, y) && CotTileAt(x, y)->room != COT_NO_ROOM;
+}
+
+static void ErodeWalls(int percent) {
+  // Decide on the original layout first, so erosion doesn't spread through the new floor
+  uint8_t erode[COT_FLOOR_WIDTH * COT_FLOOR_HEIGHT / 8];
+  memset(erode, 0, sizeof(erode));
+  for (int y = 0; y < COT_FLOOR_HEIGHT; y++) {
+    for (int x = 0; x < COT_FLOOR_WIDTH; x++) {
+      if (IsBreakableWall(x, y) && HasNeighbor(x, y, CotTileIsFloor)
+          && CotRandChance(RNG_DUNGEON, percent)) {
+        int i = y * COT_FLOOR_WIDTH + x;
+        erode[i / 8] |= 1 << (i % 8);
+      }
+    }
+  }
+  for (int i = 0; i < COT_FLOOR_WIDTH * COT_FLOOR_HEIGHT; i++) {
+    if (erode[i / 8] & (1 << (i % 8))) {
+      SetTerrain(i % COT_FLOOR_WIDTH, i / COT_FLOOR_WIDTH, TERRAIN_NORMAL, COT_NO_ROOM);
+    }
+  }
+}
+
+static void WidenCorridors(void) {
+  // Going backwards, tiles carved to the right and below have already been visited
+  for (int y = COT_FLOOR_HEIGHT - 1; y >= 0; y--) {
+    for (int x = COT_FLOOR_WIDTH - 1; x >= 0; x--) {
+      if (!CotTileIsFloor(x, y) || CotTileAt(x, y)->room != COT_NO_ROOM) {
+        continue;
+      }
+      if (IsBreakableWall(x + 1, y)) {
+        SetTerrain(x + 1, y, TERRAIN_NORMAL, COT_NO_ROOM);
+      }
+      if (IsBreakableWall(x, y + 1)) {
+        SetTerrain(x, y + 1, TERRAIN_NORMAL, COT_NO_ROOM);
+      }
+    }
+  }
+}
+
+// Rubble that cuts off the stairs is rolled back as a whole. Rubble tiles keep their room
+// index until the check passed, so rolling back only needs to restore the terrain.
+static void ScatterRubble(int percent) {
+  uint8_t rubble[COT_FLOOR_WIDTH * COT_FLOOR_HEIGHT / 8];
+  memset(rubble, 0, sizeof(rubble));
+  for (int y = 0; y < COT_FLOOR_HEIGHT; y++) {
+    for (int x = 0; x < COT_FLOOR_WIDTH; x++) {
+      if (IsRoomTile(x, y) && !IsProtected(x, y) && CotRandChance(RNG_DUNGEON, percent)) {
+        int i = y * COT_FLOOR_WIDTH + x;
+        rubble[i / 8] |= 1 << (i % 8);
+        CotTileAt(x, y)->terrain_flags.terrain_type = TERRAIN_WALL;
+      }
+    }
+  }
+
+  bool reachable = CotStairsReachable(false);
+  if (!reachable) {
+    COT_LOG(COT_LOG_CAT_FLOOR_GEN, "Rubble cut off the stairs, rolling back");
+  }
+  for (int i = 0; i < COT_FLOOR_WIDTH * COT_FLOOR_HEIGHT; i++) {
+    if (rubble[i / 8] & (1 << (i % 8))) {
+      struct tile* tile = CotTileAt(i % COT_FLOOR_WIDTH, i / COT_FLOOR_WIDTH);
+      if (reachable) {
+        tile->room = COT_NO_ROOM;
+      } else {
+        tile->terrain_flags.terrain_type = TERRAIN_NORMAL;
+      }
+    }
+  }
+}
+
+static int MirrorX(int x) {
+  return COT_FLOOR_WIDTH - 1 - x;
+}
+
+// Unset positions, e.g. of hidden stairs that weren't placed, are left alone.
+static void MirrorPosition(struct position* pos) {
+  if (CotPosInBounds(*pos)) {
+    pos->x = MirrorX(pos->x);
+  }
+}
+
+// Mirrors a horizontal range given by its inclusive bounds. The bounds are swapped, so the
+// minimum stays the left edge.
+#define MIRROR_RANGE(min_x, max_x) \
+  do { \
+    int _cot_min_x = (min_x); \
+    (min_x) = MirrorX(max_x); \
+    (max_x) = MirrorX(_cot_min_x); \
+  } while (0)
+
+static void Mirror(void) {
+  for (int y = 0; y < COT_FLOOR_HEIGHT; y++) {
+    for (int x = 0; x < COT_FLOOR_WIDTH / 2; x++) {
+      struct tile* left = CotTileAt(x, y);
+      struct tile* right = CotTileAt(MirrorX(x), y);
+      struct tile tmp = *left;
+      *left = *right;
+      *right = tmp;
+    }
+  }
+
+  struct dungeon_generation_info* gen_info = &DUNGEON_PTR->gen_info;
+  MirrorPosition(&gen_info->stairs_pos);
+  MirrorPosition(&gen_info->team_spawn_pos);
+  MirrorPosition(&gen_info->hidden_stairs_pos);
+  if (gen_info->kecleon_shop_min_x <= gen_info->kecleon_shop_max_x) {
+    MIRROR_RANGE(gen_info->kecleon_shop_min_x, gen_info->kecleon_shop_max_x);
+  }
+
+  int n_rooms = sizeof(DUNGEON_PTR->room_data) / sizeof(DUNGEON_PTR->room_data[0]);
+  for (int i = 0; i < n_rooms; i++) {
+    struct room_data* room = &DUNGEON_PTR->room_data[i];
+    if (room->top_left_corner.x <= room->bottom_right_corner.x) {
+      MIRROR_RANGE(room->top_left_corner.x, room->bottom_right_corner.x);
+    }
+  }
+}
+
+static void RoomMoat(void) {
+  // Moat tiles aren't room tiles, so a single pass doesn't spread
+  for (int y = 0; y < COT_FLOOR_HEIGHT; y++) {
+    for (int x = 0; x < COT_FLOOR_WIDTH; x++) {
+      if (IsBreakableWall(x, y) && HasNeighbor(x, y, IsRoomTile)) {
+        SetTerrain(x, y, TERRAIN_SECONDARY, COT_NO_ROOM);
+      }
+    }
+  }
+}
+
+void CotApplyFloorMutations(const floor_mutation* mutations, int n) {
+  for (int i = 0; i < n; i++) {
+    const floor_mutation* mutation = &mutations[i];
+    switch (mutation->kind) {
+      case MUTATE_ERODE_WALLS:
+        ErodeWalls(mutation->amount);
+        break;
+      case MUTATE_WIDEN_CORRIDORS:
+        WidenCorridors();
+        break;
+      case MUTATE_SCATTER_RUBBLE:
+        ScatterRubble(mutation->amount);
+        break;
+      case MUTATE_MIRROR:
+        Mirror();
+        break;
+      case MUTATE_ROOM_MOAT:
+        RoomMoat();
+        break;
+      case MUTATE_CUSTOM:
+        if (mutation->custom != NULL) {
+          mutation->custom(mutation->amount);
+        }
+        break;
+    }
+  }
+  FinalizeJunctions();
+}
+
+static mutation_pipeline* FindPipeline(enum dungeon_id dungeon) {
+  for (int i = 0; i < n_pipelines; i++) {
+    if (pipelines[i].dungeon == dungeon) {
+      return &pipelines[i];
+    }
+  }
+  return NULL;
+}
+
+bool CotSetDungeonMutations(enum dungeon_id dungeon, const floor_mutation* mutations, int n) {
+  mutation_pipeline* pipeline = FindPipeline(dungeon);
+  if (pipeline == NULL) {
+    if (n_pipelines >= COT_MAX_MUTATION_PIPELINES) {
+      COT_WARN(COT_LOG_CAT_FLOOR_GEN, "Mutation pipeline table is full");
+      return false;
+    }
+    pipeline = &pipelines[n_pipelines++];
+  }
+  *pipeline = (mutation_pipeline) { .dungeon = dungeon, .mutations = mutations, .n = n };
+  return true;
+}
+
+void CotClearDungeonMutations(enum dungeon_id dungeon) {
+  mutation_pipeline* pipeline = FindPipeline(dungeon);
+  if (pipeline != NULL) {
+    *pipeline = pipelines[--n_pipelines];
+  }
+}
+
+static void RunDungeonMutations(struct floor_properties* props) {
+  mutation_pipeline* pipeline = FindPipeline(DUNGEON_PTR->id.val);
+  if (pipeline != NULL) {
+    COT_LOGFMT(COT_LOG_CAT_FLOOR_GEN, "Applying %d floor mutations", pipeline->n);
+    CotApplyFloorMutations(pipeline->mutations, pipeline->n);
+  }
+}
+
+bool CotInstallFloorMutations(void) {
+  return CotAddPostGenerateHook(RunDungeonMutations);
+}
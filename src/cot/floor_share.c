@@ -0,0 +1,186 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+#define N_TILES (COT_FLOOR_WIDTH * COT_FLOOR_HEIGHT)
+// Terrain is packed as 2 bits per tile, flags as 4 bits per tile
+#define PACKED_TERRAIN_SIZE (N_TILES / 4)
+#define PACKED_FLAGS_SIZE (N_TILES / 2)
+#define ROOM_DATA_SIZE ((int) sizeof(DUNGEON_PTR->room_data))
+#define N_ROOM_DATA (ROOM_DATA_SIZE / (int) sizeof(DUNGEON_PTR->room_data[0]))
+// Offset of the room indexes of open tiles
+#define ROOMS_OFFSET (PACKED_TERRAIN_SIZE + PACKED_FLAGS_SIZE + ROOM_DATA_SIZE)
+
+#define FLAG_UNBREAKABLE 0x1
+#define FLAG_IMPASSABLE_WALL 0x2
+#define FLAG_IN_KECLEON_SHOP 0x4
+#define FLAG_IN_MONSTER_HOUSE 0x8
+
+// Header layout
+#define OFFSET_VERSION 4
+#define OFFSET_PAYLOAD_SIZE 6
+#define OFFSET_CRC 8
+#define OFFSET_STAIRS 12
+#define OFFSET_SPAWN 14
+
+static const char MAGIC[4] = { 'C', 'o', 'T', 'F' };
+
+static struct tile* TileAtIndex(int i) {
+  return CotTileAt(i % COT_FLOOR_WIDTH, i / COT_FLOOR_WIDTH);
+}
+
+static int PackedTerrain(const uint8_t* raw, int i) {
+  return (raw[i / 4] >> ((i % 4) * 2)) & 3;
+}
+
+static int PackedFlags(const uint8_t* raw, int i) {
+  return (raw[PACKED_TERRAIN_SIZE + i / 2] >> ((i % 2) * 4)) & 0xF;
+}
+
+// Packs the terrain and flags of all tiles and the room data, followed by the room index of
+// every non-wall tile.
+static int PackFloor(uint8_t* raw) {
+  int n_rooms = 0;
+  memset(raw, 0, PACKED_TERRAIN_SIZE + PACKED_FLAGS_SIZE);
+  for (int i = 0; i < N_TILES; i++) {
+    struct tile* tile = TileAtIndex(i);
+    int terrain = tile->terrain_flags.terrain_type;
+    int flags = (tile->terrain_flags.f_unbreakable ? FLAG_UNBREAKABLE : 0)
+              | (tile->terrain_flags.f_impassable_wall ? FLAG_IMPASSABLE_WALL : 0)
+              | (tile->terrain_flags.f_in_kecleon_shop ? FLAG_IN_KECLEON_SHOP : 0)
+              | (tile->terrain_flags.f_in_monster_house ? FLAG_IN_MONSTER_HOUSE : 0);
+    raw[i / 4] |= terrain << ((i % 4) * 2);
+    raw[PACKED_TERRAIN_SIZE + i / 2] |= flags << ((i % 2) * 4);
+    if (terrain != TERRAIN_WALL) {
+      raw[ROOMS_OFFSET + n_rooms++] = tile->room;
+    }
+  }
+  memcpy(raw + PACKED_TERRAIN_SIZE + PACKED_FLAGS_SIZE, DUNGEON_PTR->room_data, ROOM_DATA_SIZE);
+  return ROOMS_OFFSET + n_rooms;
+}
+
+static bool IsOpenRaw(const uint8_t* raw, struct position pos) {
+  return PackedTerrain(raw, pos.y * COT_FLOOR_WIDTH + pos.x) != TERRAIN_WALL;
+}
+
+// Room indexes must be hallways or refer to an entry of the room data
+static bool IsValidRaw(const uint8_t* raw, int raw_size, struct position stairs,
+                       struct position spawn) {
+  if (raw_size < ROOMS_OFFSET) {
+    return false;
+  }
+  int n_open = 0;
+  for (int i = 0; i < N_TILES; i++) {
+    n_open += PackedTerrain(raw, i) != TERRAIN_WALL;
+  }
+  if (ROOMS_OFFSET + n_open != raw_size || !IsOpenRaw(raw, stairs) || !IsOpenRaw(raw, spawn)) {
+    return false;
+  }
+  for (int i = 0; i < n_open; i++) {
+    uint8_t room = raw[ROOMS_OFFSET + i];
+    if (room != COT_NO_ROOM && room >= N_ROOM_DATA) {
+      return false;
+    }
+  }
+  return true;
+}
+
+// Only called with raw data that passed IsValidRaw.
+static void UnpackFloor(const uint8_t* raw) {
+  int n_rooms = 0;
+  ResetFloor();
+  for (int i = 0; i < N_TILES; i++) {
+    struct tile* tile = TileAtIndex(i);
+    int terrain = PackedTerrain(raw, i);
+    int flags = PackedFlags(raw, i);
+    tile->terrain_flags.terrain_type = terrain;
+    tile->terrain_flags.f_unbreakable = (flags & FLAG_UNBREAKABLE) != 0;
+    tile->terrain_flags.f_impassable_wall = (flags & FLAG_IMPASSABLE_WALL) != 0;
+    tile->terrain_flags.f_in_kecleon_shop = (flags & FLAG_IN_KECLEON_SHOP) != 0;
+    tile->terrain_flags.f_in_monster_house = (flags & FLAG_IN_MONSTER_HOUSE) != 0;
+    if (terrain != TERRAIN_WALL) {
+      tile->room = raw[ROOMS_OFFSET + n_rooms++];
+    }
+  }
+  memcpy(DUNGEON_PTR->room_data, raw + PACKED_TERRAIN_SIZE + PACKED_FLAGS_SIZE, ROOM_DATA_SIZE);
+}
+
+int CotEncodeFloorCode(void* dst, int dst_capacity) {
+  uint8_t* out = dst;
+  if (dst_capacity < COT_FLOOR_CODE_HEADER_SIZE) {
+    return -1;
+  }
+  uint8_t* raw = MemAlloc(COT_FLOOR_CODE_RAW_MAX_SIZE, 0);
+  if (raw == NULL) {
+    return -1;
+  }
+  int raw_size = PackFloor(raw);
+  int payload_size = CotNrlCompress(raw, raw_size, out + COT_FLOOR_CODE_HEADER_SIZE,
+                                    dst_capacity - COT_FLOOR_CODE_HEADER_SIZE);
+  MemFree(raw);
+  if (payload_size < 0) {
+    return -1;
+  }
+
+  struct position stairs = DUNGEON_PTR->gen_info.stairs_pos;
+  struct position spawn = DUNGEON_PTR->gen_info.team_spawn_pos;
+  memcpy(out, MAGIC, sizeof(MAGIC));
+  out[OFFSET_VERSION] = COT_FLOOR_CODE_VERSION;
+  out[OFFSET_VERSION + 1] = 0;
+  CotWriteU16(out + OFFSET_PAYLOAD_SIZE, payload_size);
+  out[OFFSET_STAIRS] = stairs.x;
+  out[OFFSET_STAIRS + 1] = stairs.y;
+  out[OFFSET_SPAWN] = spawn.x;
+  out[OFFSET_SPAWN + 1] = spawn.y;
+  // The CRC covers the positions and the payload
+  CotWriteU32(out + OFFSET_CRC, CotCrc32(out + OFFSET_STAIRS,
+                                         COT_FLOOR_CODE_HEADER_SIZE - OFFSET_STAIRS + payload_size));
+  return COT_FLOOR_CODE_HEADER_SIZE + payload_size;
+}
+
+bool CotLoadFloorCode(const void* code, int size) {
+  const uint8_t* in = code;
+  if (size < COT_FLOOR_CODE_HEADER_SIZE || in[0] != MAGIC[0] || in[1] != MAGIC[1]
+      || in[2] != MAGIC[2] || in[3] != MAGIC[3]) {
+    COT_WARN(COT_LOG_CAT_FLOOR_GEN, "Not a floor code");
+    return false;
+  }
+  if (in[OFFSET_VERSION] != COT_FLOOR_CODE_VERSION) {
+    COT_WARNFMT(COT_LOG_CAT_FLOOR_GEN, "Unsupported floor code version %d", in[OFFSET_VERSION]);
+    return false;
+  }
+  int payload_size = CotReadU16(in + OFFSET_PAYLOAD_SIZE);
+  if (COT_FLOOR_CODE_HEADER_SIZE + payload_size > size
+      || CotCrc32(in + OFFSET_STAIRS, COT_FLOOR_CODE_HEADER_SIZE - OFFSET_STAIRS + payload_size)
+             != CotReadU32(in + OFFSET_CRC)) {
+    COT_WARN(COT_LOG_CAT_FLOOR_GEN, "Floor code is corrupted");
+    return false;
+  }
+  if (!CotTileInBounds(in[OFFSET_STAIRS], in[OFFSET_STAIRS + 1])
+      || !CotTileInBounds(in[OFFSET_SPAWN], in[OFFSET_SPAWN + 1])) {
+    COT_WARN(COT_LOG_CAT_FLOOR_GEN, "Floor code has invalid positions");
+    return false;
+  }
+
+  uint8_t* raw = MemAlloc(COT_FLOOR_CODE_RAW_MAX_SIZE, 0);
+  if (raw == NULL) {
+    return false;
+  }
+  struct position stairs = { .x = in[OFFSET_STAIRS], .y = in[OFFSET_STAIRS + 1] };
+  struct position spawn = { .x = in[OFFSET_SPAWN], .y = in[OFFSET_SPAWN + 1] };
+  int raw_size = CotNrlDecompress(in + COT_FLOOR_CODE_HEADER_SIZE, payload_size, raw,
+                                  COT_FLOOR_CODE_RAW_MAX_SIZE);
+  if (raw_size < 0 || !IsValidRaw(raw, raw_size, stairs, spawn)) {
+    MemFree(raw);
+    COT_WARN(COT_LOG_CAT_FLOOR_GEN, "Floor code has an invalid payload");
+    return false;
+  }
+  UnpackFloor(raw);
+  MemFree(raw);
+
+  CotTileAt(stairs.x, stairs.y)->terrain_flags.f_stairs = true;
+  DUNGEON_PTR->gen_info.stairs_pos = stairs;
+  DUNGEON_PTR->gen_info.team_spawn_pos = spawn;
+  EnsureImpassableTilesAreWalls();
+  FinalizeJunctions();
+  return true;
+}
@@ -0,0 +1,55 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+#define TILE_GRID_SIZE (sizeof(struct tile) * COT_FLOOR_WIDTH * COT_FLOOR_HEIGHT)
+
+bool CotFloorSnapshotAlloc(floor_snapshot* snapshot) {
+  snapshot->tiles = MemAlloc(TILE_GRID_SIZE, 0);
+  if (snapshot->tiles == NULL) {
+    COT_ERROR(COT_LOG_CAT_FLOOR_GEN, "Failed to allocate floor snapshot");
+    return false;
+  }
+  return true;
+}
+
+void CotFloorSnapshotFree(floor_snapshot* snapshot) {
+  if (snapshot->tiles != NULL) {
+    MemFree(snapshot->tiles);
+    snapshot->tiles = NULL;
+  }
+}
+
+void CotFloorSnapshotCapture(floor_snapshot* snapshot) {
+  COT_ASSERT(snapshot->tiles != NULL);
+  memcpy(snapshot->tiles, DUNGEON_PTR->tiles, TILE_GRID_SIZE);
+  snapshot->gen_info = DUNGEON_PTR->gen_info;
+}
+
+void CotFloorSnapshotRestore(const floor_snapshot* snapshot) {
+  COT_ASSERT(snapshot->tiles != NULL);
+  memcpy(DUNGEON_PTR->tiles, snapshot->tiles, TILE_GRID_SIZE);
+  DUNGEON_PTR->gen_info = snapshot->gen_info;
+}
+
+bool CotGenerateFloorWithRollback(const floor_generator* generator,
+                                  struct floor_properties* props, floor_validator_fn validator,
+                                  int max_attempts) {
+  floor_snapshot previous;
+  if (!CotFloorSnapshotAlloc(&previous)) {
+    return false;
+  }
+  CotFloorSnapshotCapture(&previous);
+
+  bool success = false;
+  for (int attempt = 0; attempt < max_attempts && !success; attempt++) {
+    success = generator->generate(props, generator->ctx) && (validator == NULL || validator());
+    if (!success) {
+      COT_LOGFMT(COT_LOG_CAT_FLOOR_GEN, "Attempt %d of '%s' rejected, rolling back",
+                 attempt + 1, generator->name);
+      CotFloorSnapshotRestore(&previous);
+    }
+  }
+
+  CotFloorSnapshotFree(&previous);
+  return success;
+}
@@ -0,0 +1,98 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+// These NitroSDK functions aren't in pmdsky-debug yet. Their addresses can be added to
+// "symbols/custom_[region].ld"; until then they're NULL.
+extern bool FS_ConvertPathToFileID(cot_file_id* id_out, const char* path) __attribute__((weak));
+extern bool FS_OpenFileFast(struct file_stream* file, cot_file_id id) __attribute__((weak));
+
+bool CotFileGetId(const char* path, cot_file_id* id_out) {
+  if (FS_ConvertPathToFileID == NULL) {
+    COT_WARN(COT_LOG_CAT_FS, "FS_ConvertPathToFileID is missing from the custom symbols");
+    return false;
+  }
+  DataTransferInit();
+  bool found = FS_ConvertPathToFileID(id_out, path);
+  DataTransferStop();
+  return found;
+}
+
+bool CotFileOpenById(cot_file* file, cot_file_id id) {
+  file->open = false;
+  if (FS_OpenFileFast == NULL) {
+    COT_WARN(COT_LOG_CAT_FS, "FS_OpenFileFast is missing from the custom symbols");
+    return false;
+  }
+  FileInit(&file->stream);
+  DataTransferInit();
+  file->open = FS_OpenFileFast(&file->stream, id);
+  DataTransferStop();
+  return file->open;
+}
+
+bool CotFileOpen(cot_file* file, const char* path) {
+  if (FS_ConvertPathToFileID != NULL && FS_OpenFileFast != NULL) {
+    cot_file_id id;
+    if (!CotFileGetId(path, &id)) {
+      COT_WARNFMT(COT_LOG_CAT_FS, "File %s doesn't exist", path);
+      file->open = false;
+      return false;
+    }
+    return CotFileOpenById(file, id);
+  }
+  // The game's own function doesn't return if the file doesn't exist
+  FileInit(&file->stream);
+  DataTransferInit();
+  FileOpen(&file->stream, path);
+  DataTransferStop();
+  file->open = true;
+  return true;
+}
+
+void CotFileClose(cot_file* file) {
+  if (file->open) {
+    DataTransferInit();
+    FileClose(&file->stream);
+    DataTransferStop();
+    file->open = false;
+  }
+}
+
+int CotFileRead(cot_file* file, void* buf, int size) {
+  COT_ASSERT(file->open);
+  DataTransferInit();
+  int n_read = FileRead(&file->stream, buf, size);
+  DataTransferStop();
+  return n_read;
+}
+
+void CotFileSeek(cot_file* file, int offset, file_seek_origin origin) {
+  COT_ASSERT(file->open);
+  DataTransferInit();
+  FileSeek(&file->stream, offset, origin);
+  DataTransferStop();
+}
+
+int CotFileSize(cot_file* file) {
+  COT_ASSERT(file->open);
+  return FileGetSize(&file->stream);
+}
+
+void* CotLoadFile(const char* path, int* size_out) {
+  cot_file file;
+  if (!CotFileOpen(&file, path)) {
+    return NULL;
+  }
+  int size = CotFileSize(&file);
+  void* buf = MemAlloc(size, 0);
+  if (buf != NULL && CotFileRead(&file, buf, size) != size) {
+    COT_WARNFMT(COT_LOG_CAT_FS, "Failed to read file %s", path);
+    MemFree(buf);
+    buf = NULL;
+  }
+  CotFileClose(&file);
+  if (buf != NULL && size_out != NULL) {
+    *size_out = size;
+  }
+  return buf;
+}
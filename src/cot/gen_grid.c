@@ -0,0 +1,238 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+static bool generating_floor = false;
+
+bool CotIsGeneratingFloor(void) {
+  return generating_floor;
+}
+
+void cotInternalSetGeneratingFloor(bool generating) {
+  generating_floor = generating;
+}
+
+bool CotInitGenGrid(gen_grid* grid, struct dungeon_grid_cell* cells, int size_x, int size_y,
+                    int* list_x, int* list_y) {
+  if (!generating_floor) {
+    COT_ERROR(COT_LOG_CAT_GEN_GRID, "The generator buffers are only valid during floor generation");
+    return false;
+  }
+  if (size_x < 1 || size_x > COT_GRID_MAX_SIZE || size_y < 1 || size_y > COT_GRID_MAX_SIZE) {
+    COT_ERRORFMT(COT_LOG_CAT_GEN_GRID, "Invalid grid size %dx%d", size_x, size_y);
+    return false;
+  }
+  COT_ASSERT(cells != NULL);
+  *grid = (gen_grid) {
+    .cells = cells,
+    .size_x = size_x,
+    .size_y = size_y,
+    .list_x = list_x,
+    .list_y = list_y,
+  };
+  return true;
+}
+
+bool CotGenGridInBounds(const gen_grid* grid, int x, int y) {
+  return generating_floor && x >= 0 && x < grid->size_x && y >= 0 && y < grid->size_y;
+}
+
+struct dungeon_grid_cell* CotGenGridCell(const gen_grid* grid, int x, int y) {
+  if (!CotGenGridInBounds(grid, x, y)) {
+    COT_WARNFMT(COT_LOG_CAT_GEN_GRID, "Grid cell (%d, %d) is out of bounds", x, y);
+    return NULL;
+  }
+  return &grid->cells[x * COT_GRID_MAX_SIZE + y];
+}
+
+int CotGenGridLineX(const gen_grid* grid, int i) {
+  if (!generating_floor || grid->list_x == NULL || i < 0 || i > grid->size_x) {
+    return -1;
+  }
+  return grid->list_x[i];
+}
+
+int CotGenGridLineY(const gen_grid* grid, int i) {
+  if (!generating_floor || grid->list_y == NULL || i < 0 || i > grid->size_y) {
+    return -1;
+  }
+  return grid->list_y[i];
+}
+
+grid_cell_kind CotGenGridCellKind(const gen_grid* grid, int x, int y) {
+  if (!CotGenGridInBounds(grid, x, y)) {
+    return GRID_CELL_INVALID;
+  }
+  struct dungeon_grid_cell* cell = CotGenGridCell(grid, x, y);
+  if (cell->is_invalid) {
+    return GRID_CELL_INVALID;
+  }
+  return cell->is_room ? GRID_CELL_ROOM : GRID_CELL_ANCHOR;
+}
+
+bool CotGenGridCellBounds(const gen_grid* grid, int x, int y, struct position* top_left,
+                          struct position* bottom_right) {
+  if (CotGenGridCellKind(grid, x, y) == GRID_CELL_INVALID) {
+    return false;
+  }
+  struct dungeon_grid_cell* cell = CotGenGridCell(grid, x, y);
+  *top_left = (struct position) { .x = cell->start_x, .y = cell->start_y };
+  // The end coordinates are exclusive
+  *bottom_right = (struct position) { .x = cell->end_x - 1, .y = cell->end_y - 1 };
+  return true;
+}
+
+static bool* ConnectionFlag(struct dungeon_grid_cell* cell, grid_side side) {
+  switch (side) {
+    case GRID_SIDE_TOP:
+      return &cell->is_connected_to_top;
+    case GRID_SIDE_BOTTOM:
+      return &cell->is_connected_to_bottom;
+    case GRID_SIDE_LEFT:
+      return &cell->is_connected_to_left;
+    default:
+      return &cell->is_connected_to_right;
+  }
+}
+
+bool CotGenGridIsConnected(const gen_grid* grid, int x, int y, grid_side side) {
+  struct dungeon_grid_cell* cell = CotGenGridCell(grid, x, y);
+  return cell != NULL && *ConnectionFlag(cell, side);
+}
+
+bool CotGenGridConnect(const gen_grid* grid, int x, int y, grid_side side) {
+  static const int8_t offsets[4][2] = { { 0, -1 }, { 0, 1 }, { -1, 0 }, { 1, 0 } };
+  static const grid_side opposite[4] = {
+    GRID_SIDE_BOTTOM, GRID_SIDE_TOP, GRID_SIDE_RIGHT, GRID_SIDE_LEFT
+  };
+  int nx = x + offsets[side][0];
+  int ny = y + offsets[side][1];
+  if (CotGenGridCellKind(grid, x, y) == GRID_CELL_INVALID
+      || CotGenGridCellKind(grid, nx, ny) == GRID_CELL_INVALID) {
+    return false;
+  }
+  struct dungeon_grid_cell* cell = CotGenGridCell(grid, x, y);
+  struct dungeon_grid_cell* neighbor = CotGenGridCell(grid, nx, ny);
+  *ConnectionFlag(cell, side) = true;
+  *ConnectionFlag(neighbor, opposite[side]) = true;
+  cell->is_connected = true;
+  neighbor->is_connected = true;
+  return true;
+}
+
+bool CotAllocGenGrid(gen_grid* grid, int size_x, int size_y) {
+  struct dungeon_grid_cell* cells =
+      MemAlloc(sizeof(struct dungeon_grid_cell) * COT_GRID_MAX_SIZE * COT_GRID_MAX_SIZE, 0);
+  int* lists = MemAlloc(sizeof(int) * 2 * (COT_GRID_MAX_SIZE + 1), 0);
+  if (cells == NULL || lists == NULL
+      || !CotInitGenGrid(grid, cells, size_x, size_y, lists, lists + COT_GRID_MAX_SIZE + 1)) {
+    COT_ERROR(COT_LOG_CAT_GEN_GRID, "Failed to allocate the grid");
+    if (cells != NULL) {
+      MemFree(cells);
+    }
+    if (lists != NULL) {
+      MemFree(lists);
+    }
+    return false;
+  }
+  return true;
+}
+
+void CotFreeGenGrid(gen_grid* grid) {
+  if (grid->cells != NULL) {
+    MemFree(grid->cells);
+    grid->cells = NULL;
+  }
+  // Both lists share one allocation
+  if (grid->list_x != NULL) {
+    MemFree(grid->list_x);
+    grid->list_x = NULL;
+    grid->list_y = NULL;
+  }
+}
+
+// The game's stages index the cells and lists without any checks.
+static bool CanRunStage(const gen_grid* grid, const char* stage) {
+  if (!generating_floor || grid->cells == NULL || grid->list_x == NULL || grid->list_y == NULL
+      || grid->size_x < 1 || grid->size_x > COT_GRID_MAX_SIZE || grid->size_y < 1
+      || grid->size_y > COT_GRID_MAX_SIZE) {
+    COT_ERRORFMT(COT_LOG_CAT_GEN_GRID, "Can't run %s on this grid", stage);
+    return false;
+  }
+  return true;
+}
+
+bool CotGenGridComputePositions(const gen_grid* grid) {
+  if (!CanRunStage(grid, "GetGridPositions")) {
+    return false;
+  }
+  GetGridPositions(grid->list_x, grid->list_y, grid->size_x, grid->size_y);
+  return true;
+}
+
+bool CotGenGridInitCells(const gen_grid* grid) {
+  if (!CanRunStage(grid, "InitDungeonGrid")) {
+    return false;
+  }
+  InitDungeonGrid(grid->cells, grid->size_x, grid->size_y);
+  return true;
+}
+
+bool CotGenGridAssignRooms(const gen_grid* grid, int n_rooms) {
+  if (!CanRunStage(grid, "AssignRooms") || n_rooms < 1) {
+    return false;
+  }
+  AssignRooms(grid->cells, grid->size_x, grid->size_y, n_rooms);
+  return true;
+}
+
+bool CotGenGridCreateRooms(const gen_grid* grid, uint32_t room_flags) {
+  if (!CanRunStage(grid, "CreateRoomsAndAnchors")) {
+    return false;
+  }
+  CreateRoomsAndAnchors(grid->cells, grid->size_x, grid->size_y, grid->list_x, grid->list_y,
+                        room_flags);
+  return true;
+}
+
+bool CotGenGridAssignConnections(const gen_grid* grid, struct floor_properties* props) {
+  if (!CanRunStage(grid, "AssignGridCellConnections")) {
+    return false;
+  }
+  int cursor_x = CotRandInt(RNG_DUNGEON, grid->size_x);
+  int cursor_y = CotRandInt(RNG_DUNGEON, grid->size_y);
+  AssignGridCellConnections(grid->cells, grid->size_x, grid->size_y, cursor_x, cursor_y, props);
+  return true;
+}
+
+bool CotGenGridCreateConnections(const gen_grid* grid, bool disable_room_merging) {
+  if (!CanRunStage(grid, "CreateGridCellConnections")) {
+    return false;
+  }
+  CreateGridCellConnections(grid->cells, grid->size_x, grid->size_y, grid->list_x, grid->list_y,
+                            disable_room_merging);
+  return true;
+}
+
+bool CotGenGridEnsureConnected(const gen_grid* grid) {
+  if (!CanRunStage(grid, "EnsureConnectedGrid")) {
+    return false;
+  }
+  EnsureConnectedGrid(grid->cells, grid->size_x, grid->size_y, grid->list_x, grid->list_y);
+  return true;
+}
+
+bool CotGenGridAddImperfections(const gen_grid* grid) {
+  if (!CanRunStage(grid, "GenerateRoomImperfections")) {
+    return false;
+  }
+  GenerateRoomImperfections(grid->cells, grid->size_x, grid->size_y);
+  return true;
+}
+
+bool CotGenGridAddExtraHallways(const gen_grid* grid, int n_hallways) {
+  if (!CanRunStage(grid, "GenerateExtraHallways") || n_hallways < 0) {
+    return false;
+  }
+  GenerateExtraHallways(grid->cells, grid->size_x, grid->size_y, n_hallways);
+  return true;
+}
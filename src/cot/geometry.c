@@ -0,0 +1,128 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+const struct position cotDirectionOffsets[COT_N_DIRECTIONS] = {
+  { .x =  0, .y =  1 },
+  { .x =  1, .y =  1 },
+  { .x =  1, .y =  0 },
+  { .x =  1, .y = -1 },
+  { .x =  0, .y = -1 },
+  { .x = -1, .y = -1 },
+  { .x = -1, .y =  0 },
+  { .x = -1, .y =  1 },
+};
+
+static int Abs(int n) {
+  return n < 0 ? -n : n;
+}
+
+static int Sign(int n) {
+  return (n > 0) - (n < 0);
+}
+
+int CotChebyshevDistance(struct position a, struct position b) {
+  int dx = Abs(a.x - b.x);
+  int dy = Abs(a.y - b.y);
+  return dx > dy ? dx : dy;
+}
+
+int CotManhattanDistance(struct position a, struct position b) {
+  return Abs(a.x - b.x) + Abs(a.y - b.y);
+}
+
+bool CotDirectionTowards(struct position from, struct position to, enum direction_id* out) {
+  int dx = to.x - from.x;
+  int dy = to.y - from.y;
+  if (dx == 0 && dy == 0) {
+    return false;
+  }
+  // Snap to a straight direction if the other axis is less than half as long
+  int step_x = 2 * Abs(dx) < Abs(dy) ? 0 : Sign(dx);
+  int step_y = 2 * Abs(dy) < Abs(dx) ? 0 : Sign(dy);
+  for (int i = 0; i < COT_N_DIRECTIONS; i++) {
+    if (cotDirectionOffsets[i].x == step_x && cotDirectionOffsets[i].y == step_y) {
+      *out = (enum direction_id) i;
+      return true;
+    }
+  }
+  return false;
+}
+
+pixel_pos CotTileToPixel(struct position pos) {
+  return (pixel_pos) { .x = pos.x * COT_TILE_PIXELS, .y = pos.y * COT_TILE_PIXELS };
+}
+
+pixel_pos CotTileCenterToPixel(struct position pos) {
+  pixel_pos corner = CotTileToPixel(pos);
+  return (pixel_pos) { .x = corner.x + COT_TILE_PIXELS / 2, .y = corner.y + COT_TILE_PIXELS / 2 };
+}
+
+struct position CotPixelToTile(pixel_pos pos) {
+  // Round towards negative infinity so pixels left of or above the floor stay out of bounds
+  int x = pos.x >= 0 ? pos.x / COT_TILE_PIXELS : -((-pos.x + COT_TILE_PIXELS - 1) / COT_TILE_PIXELS);
+  int y = pos.y >= 0 ? pos.y / COT_TILE_PIXELS : -((-pos.y + COT_TILE_PIXELS - 1) / COT_TILE_PIXELS);
+  return (struct position) { .x = x, .y = y };
+}
+
+neighbor_iter CotIterNeighbors(struct position center) {
+  return (neighbor_iter) { .center = center, .direction = 0 };
+}
+
+bool CotNeighborNext(neighbor_iter* it, struct position* out) {
+  while (it->direction < COT_N_DIRECTIONS) {
+    struct position pos = CotPosStep(it->center, (enum direction_id) it->direction++);
+    if (CotPosInBounds(pos)) {
+      *out = pos;
+      return true;
+    }
+  }
+  return false;
+}
+
+line_iter CotIterLine(struct position from, struct position to) {
+  int dx = Abs(to.x - from.x);
+  int dy = -Abs(to.y - from.y);
+  return (line_iter) { .pos = from, .to = to, .dx = dx, .dy = dy, .err = dx + dy, .done = false };
+}
+
+bool CotLineNext(line_iter* it, struct position* out) {
+  if (it->done) {
+    return false;
+  }
+  *out = it->pos;
+  if (CotPosEqual(it->pos, it->to)) {
+    it->done = true;
+    return true;
+  }
+  // Bresenham's line algorithm
+  int e2 = 2 * it->err;
+  if (e2 >= it->dy) {
+    it->err += it->dy;
+    it->pos.x += Sign(it->to.x - it->pos.x);
+  }
+  if (e2 <= it->dx) {
+    it->err += it->dx;
+    it->pos.y += Sign(it->to.y - it->pos.y);
+  }
+  return true;
+}
+
+area_iter CotIterArea(int x, int y, int w, int h) {
+  int x0 = x < 0 ? 0 : x;
+  int y0 = y < 0 ? 0 : y;
+  int x1 = x + w > COT_FLOOR_WIDTH ? COT_FLOOR_WIDTH : x + w;
+  int y1 = y + h > COT_FLOOR_HEIGHT ? COT_FLOOR_HEIGHT : y + h;
+  return (area_iter) { .x0 = x0, .x1 = x1, .y1 = y1, .pos = { .x = x0, .y = y0 } };
+}
+
+bool CotAreaNext(area_iter* it, struct position* out) {
+  if (it->x0 >= it->x1 || it->pos.y >= it->y1) {
+    return false;
+  }
+  *out = it->pos;
+  if (++it->pos.x >= it->x1) {
+    it->pos.x = it->x0;
+    it->pos.y++;
+  }
+  return true;
+}
@@ -0,0 +1,91 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+void CotItemSpawnInit(item_spawn* spawn, enum item_id id) {
+  *spawn = (item_spawn) {
+    .id = id,
+    .quantity = 0,
+    .sticky = false,
+    .in_shop = false,
+  };
+}
+
+void CotItemSpawnSetQuantity(item_spawn* spawn, int quantity) {
+  spawn->quantity = quantity;
+}
+
+void CotItemSpawnSetSticky(item_spawn* spawn, bool sticky) {
+  spawn->sticky = sticky;
+}
+
+void CotItemSpawnSetInShop(item_spawn* spawn, bool in_shop) {
+  spawn->in_shop = in_shop;
+}
+
+bool CotSpawnItemAt(const item_spawn* spawn, int x, int y) {
+  struct tile* tile = CotTileAt(x, y);
+  if (tile == NULL || !CotTileIsFloor(x, y) || tile->object != NULL) {
+    COT_WARNFMT(COT_LOG_CAT_GROUND_ITEMS, "Can't spawn item %d on (%d, %d)", spawn->id, x, y);
+    return false;
+  }
+
+  struct item item;
+  InitItem(&item, spawn->id, spawn->quantity, spawn->sticky);
+  item.f_in_shop = spawn->in_shop;
+  struct position pos = { .x = x, .y = y };
+  if (!SpawnItem(&pos, &item, true)) {
+    COT_WARNFMT(COT_LOG_CAT_GROUND_ITEMS, "Failed to spawn item %d", spawn->id);
+    return false;
+  }
+  return true;
+}
+
+static bool IsFreeItemTile(int x, int y) {
+  struct tile* tile = CotTileAt(x, y);
+  return tile != NULL && CotTileIsFloor(x, y) && tile->object == NULL && !tile->terrain_flags.f_stairs;
+}
+
+bool CotSpawnItemRandom(const item_spawn* spawn) {
+  struct position pos;
+  if (!CotPickSpawnTile(SPAWN_CATEGORY_ITEM, IsFreeItemTile, &pos)) {
+    COT_WARNFMT(COT_LOG_CAT_GROUND_ITEMS, "No free tile to spawn item %d", spawn->id);
+    return false;
+  }
+  return CotSpawnItemAt(spawn, pos.x, pos.y);
+}
+
+struct item* CotFloorItemAt(int x, int y) {
+  struct tile* tile = CotTileAt(x, y);
+  if (tile == NULL || tile->object == NULL || tile->object->type != ENTITY_ITEM) {
+    return NULL;
+  }
+  return (struct item*) tile->object->info;
+}
+
+int CotCountFloorItems(void) {
+  int n = 0;
+  struct entity* entity;
+  COT_FOR_EACH_ENTITY(ITER_ITEMS, entity) {
+    n++;
+  }
+  return n;
+}
+
+void CotSetItemSticky(struct item* item, bool sticky) {
+  item->f_sticky = sticky;
+}
+
+void CotSetItemInShop(struct item* item, bool in_shop) {
+  item->f_in_shop = in_shop;
+}
+
+int CotFindBagItem(enum item_id item) {
+  int capacity = GetCurrentBagCapacity();
+  for (int i = 0; i < capacity; i++) {
+    struct item* bag_item = GetItemAtIdx(i);
+    if (bag_item->f_exists && bag_item->id.val == item) {
+      return i;
+    }
+  }
+  return -1;
+}
@@ -0,0 +1,120 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+#define N_GUMMI_TYPES ((int) (sizeof(GUMMI_IQ_GAINS) / sizeof(GUMMI_IQ_GAINS[0])))
+#define N_MONSTER_TYPES ((int) (sizeof(GUMMI_IQ_GAINS[0]) / sizeof(GUMMI_IQ_GAINS[0][0])))
+// Marks a field of an override entry as unset.
+#define UNSET -1
+
+static struct {
+  int8_t gummi_type;
+  int8_t monster_type;
+  int16_t iq_gain;
+  int16_t stat_boost;
+} overrides[COT_MAX_GUMMI_OVERRIDES];
+static int n_overrides = 0;
+
+// Copy of the game's table from before the first override
+static __typeof__(GUMMI_IQ_GAINS) vanilla_iq_gains;
+static bool vanilla_saved = false;
+
+// Rebuilds the game's table from the vanilla values. Exact monster type matches take precedence
+// over COT_GUMMI_ANY_TYPE, so wildcards are written first.
+static void ApplyOverrides(void) {
+  if (!vanilla_saved) {
+    memcpy(vanilla_iq_gains, GUMMI_IQ_GAINS, sizeof(vanilla_iq_gains));
+    vanilla_saved = true;
+  }
+  memcpy(GUMMI_IQ_GAINS, vanilla_iq_gains, sizeof(vanilla_iq_gains));
+  for (int i = 0; i < n_overrides; i++) {
+    if (overrides[i].monster_type == COT_GUMMI_ANY_TYPE && overrides[i].iq_gain != UNSET) {
+      for (int j = 0; j < N_MONSTER_TYPES; j++) {
+        GUMMI_IQ_GAINS[overrides[i].gummi_type][j] = overrides[i].iq_gain;
+      }
+    }
+  }
+  for (int i = 0; i < n_overrides; i++) {
+    if (overrides[i].monster_type != COT_GUMMI_ANY_TYPE && overrides[i].iq_gain != UNSET) {
+      GUMMI_IQ_GAINS[overrides[i].gummi_type][overrides[i].monster_type] = overrides[i].iq_gain;
+    }
+  }
+}
+
+// Returns the index of the override entry for the combination, adding an empty one if needed,
+// or -1 if the combination is invalid or the table is full.
+static int GetOrAddOverride(enum type_id gummi_type, int monster_type) {
+  if (gummi_type < 0 || gummi_type >= N_GUMMI_TYPES || monster_type < COT_GUMMI_ANY_TYPE
+      || monster_type >= N_MONSTER_TYPES) {
+    COT_WARNFMT(COT_LOG_CAT_GUMMIS, "Invalid gummi type combination %d/%d", gummi_type,
+                monster_type);
+    return -1;
+  }
+  for (int i = 0; i < n_overrides; i++) {
+    if (overrides[i].gummi_type == gummi_type && overrides[i].monster_type == monster_type) {
+      return i;
+    }
+  }
+  if (n_overrides >= COT_MAX_GUMMI_OVERRIDES) {
+    COT_WARN(COT_LOG_CAT_GUMMIS, "Gummi override table is full");
+    return -1;
+  }
+  overrides[n_overrides].gummi_type = gummi_type;
+  overrides[n_overrides].monster_type = monster_type;
+  overrides[n_overrides].iq_gain = UNSET;
+  overrides[n_overrides].stat_boost = UNSET;
+  return n_overrides++;
+}
+
+bool CotSetGummiIqGain(enum type_id gummi_type, int monster_type, int iq_gain) {
+  int i = GetOrAddOverride(gummi_type, monster_type);
+  if (i < 0) {
+    return false;
+  }
+  overrides[i].iq_gain = iq_gain;
+  ApplyOverrides();
+  return true;
+}
+
+bool CotSetGummiStatBoost(enum type_id gummi_type, int monster_type, int stat_boost) {
+  int i = GetOrAddOverride(gummi_type, monster_type);
+  if (i < 0) {
+    return false;
+  }
+  overrides[i].stat_boost = stat_boost;
+  return true;
+}
+
+void CotClearGummiOverrides(void) {
+  n_overrides = 0;
+  if (vanilla_saved) {
+    ApplyOverrides();
+  }
+}
+
+// Returns the stat boost override for a monster of `monster_type`, or UNSET.
+static int FindStatBoost(enum type_id gummi_type, enum type_id monster_type) {
+  int wildcard = UNSET;
+  for (int i = 0; i < n_overrides; i++) {
+    if (overrides[i].gummi_type != gummi_type || overrides[i].stat_boost == UNSET) {
+      continue;
+    }
+    if (overrides[i].monster_type == monster_type) {
+      return overrides[i].stat_boost;
+    }
+    if (overrides[i].monster_type == COT_GUMMI_ANY_TYPE) {
+      wildcard = overrides[i].stat_boost;
+    }
+  }
+  return wildcard;
+}
+
+int cotInternalGetGummiStatBoost(struct entity* target, enum type_id gummi_type, int vanilla) {
+  struct monster* monster = target->info;
+  for (int i = 0; i < 2; i++) {
+    int stat_boost = FindStatBoost(gummi_type, monster->types[i]);
+    if (stat_boost != UNSET) {
+      return stat_boost;
+    }
+  }
+  return vanilla;
+}
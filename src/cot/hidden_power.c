@@ -0,0 +1,43 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+static hidden_power_roll_fn hidden_power_roller = NULL;
+static hidden_power_use_fn hidden_power_use_hook = NULL;
+
+void CotGetHiddenPower(struct entity* entity, enum type_id* type, int* power) {
+  struct monster* monster = (struct monster*) entity->info;
+  *type = monster->hidden_power_type.val;
+  *power = monster->hidden_power_base_power;
+}
+
+void CotSetHiddenPower(struct entity* entity, enum type_id type, int power) {
+  struct monster* monster = (struct monster*) entity->info;
+  monster->hidden_power_type.val = type;
+  monster->hidden_power_base_power = power;
+}
+
+void CotSetHiddenPowerRoller(hidden_power_roll_fn roller) {
+  hidden_power_roller = roller;
+}
+
+void CotSetHiddenPowerUseHook(hidden_power_use_fn hook) {
+  hidden_power_use_hook = hook;
+}
+
+void cotInternalRollHiddenPower(struct entity* entity) {
+  if (hidden_power_roller == NULL || !EntityIsValid(entity) || entity->type != ENTITY_MONSTER) {
+    return;
+  }
+  enum type_id type;
+  int power;
+  CotGetHiddenPower(entity, &type, &power);
+  hidden_power_roller(((struct monster*) entity->info)->id.val, &type, &power);
+  CotSetHiddenPower(entity, type, power);
+}
+
+void cotInternalAdjustHiddenPower(struct entity* user, struct entity* target,
+                                  enum type_id* type, int* power) {
+  if (hidden_power_use_hook != NULL) {
+    hidden_power_use_hook(user, target, type, power);
+  }
+}
@@ -0,0 +1,75 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+static hidden_stairs_entry_fn entry_handler = NULL;
+
+bool CotHiddenStairsPresent(void) {
+  return HiddenStairsPresent();
+}
+
+enum hidden_stairs_type CotGetHiddenStairsType(void) {
+  return DUNGEON_PTR->gen_info.hidden_stairs_type.val;
+}
+
+bool CotGetHiddenStairsPosition(struct position* out) {
+  if (!CotHiddenStairsPresent()) {
+    return false;
+  }
+  *out = DUNGEON_PTR->gen_info.hidden_stairs_pos;
+  return true;
+}
+
+void CotSetHiddenStairsType(enum hidden_stairs_type type) {
+  DUNGEON_PTR->gen_info.hidden_stairs_type.val = type;
+}
+
+// Removes the current hidden stairs, so only the new ones are left after relocating them
+static void ClearHiddenStairs(void) {
+  struct position old_pos;
+  if (!CotGetHiddenStairsPosition(&old_pos)) {
+    return;
+  }
+  struct position stairs = DUNGEON_PTR->gen_info.stairs_pos;
+  struct tile* old_tile = CotTileAt(old_pos.x, old_pos.y);
+  if (old_tile == NULL || (old_pos.x == stairs.x && old_pos.y == stairs.y)) {
+    return;
+  }
+  old_tile->terrain_flags.f_stairs = false;
+  if (old_tile->object != NULL && old_tile->object->type == ENTITY_HIDDEN_STAIRS) {
+    CotDespawnEntity(old_tile->object);
+  }
+}
+
+bool CotSpawnHiddenStairs(int x, int y, enum hidden_stairs_type type) {
+  if (type == HIDDEN_STAIRS_NONE) {
+    COT_WARN(COT_LOG_CAT_HIDDEN_STAIRS, "Hidden stairs need a type");
+    return false;
+  }
+  struct tile* tile = CotTileAt(x, y);
+  if (!CotTileIsFloor(x, y) || tile->terrain_flags.f_stairs || tile->object != NULL) {
+    COT_WARNFMT(COT_LOG_CAT_HIDDEN_STAIRS, "Can't place hidden stairs on (%d, %d)", x, y);
+    return false;
+  }
+  ClearHiddenStairs();
+  uint8_t pos[2] = { x, y };
+  SpawnStairs(pos, &DUNGEON_PTR->gen_info, type);
+  return true;
+}
+
+void CotSetHiddenStairsEntryHandler(hidden_stairs_entry_fn handler) {
+  entry_handler = handler;
+}
+
+void cotInternalOnEnterHiddenStairs(void) {
+  enum hidden_stairs_type type = CotGetHiddenStairsType();
+  if (entry_handler == NULL || type == HIDDEN_STAIRS_NONE) {
+    return;
+  }
+  struct position pos = DUNGEON_PTR->gen_info.hidden_stairs_pos;
+  enum hidden_stairs_type redirected = entry_handler(type, pos.x, pos.y);
+  if (redirected != type && redirected != HIDDEN_STAIRS_NONE) {
+    COT_LOGFMT(COT_LOG_CAT_HIDDEN_STAIRS, "Redirecting hidden stairs from %d to %d", type,
+               redirected);
+    CotSetHiddenStairsType(redirected);
+  }
+}
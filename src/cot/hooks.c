@@ -0,0 +1,540 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+// Wrappers around game functions, called in trampolines.s.
+//
+// patches/internal.asm replaces the first instruction of each wrapped function with a jump to
+// its wrapper, cotInternalHook<function>. The game's own implementation is still available as
+// cotInternalOriginal<function>.
+
+// Declares the wrapper and the original of `func`. A wrapper with a different signature than
+// the game's function fails to compile.
+#define COT_WRAP(func) \
+  __typeof__(func) cotInternalHook##func; \
+  extern __typeof__(func) cotInternalOriginal##func
+
+COT_WRAP(GenerateFloor);
+
+void cotInternalHookGenerateFloor(void) {
+  cotInternalRunBootSelfCheck();
+  struct floor_properties* props = &DUNGEON_PTR->floor_properties;
+  cotInternalSetGeneratingFloor(true);
+  if (!cotInternalDispatchGenerateFloor(props)) {
+    cotInternalOriginalGenerateFloor();
+  }
+  cotInternalOnFloorGenerated(props);
+  cotInternalSetGeneratingFloor(false);
+}
+
+COT_WRAP(ApplyTrapEffect);
+
+bool cotInternalHookApplyTrapEffect(struct trap* trap, struct entity* user, struct entity* target,
+                                    struct tile* tile, struct position* pos, enum trap_id id,
+                                    bool random_trap) {
+  bool remove_trap;
+  if (cotInternalDispatchApplyTrapEffect(user, target, trap, pos, id, &remove_trap)) {
+    return remove_trap;
+  }
+  return cotInternalOriginalApplyTrapEffect(trap, user, target, tile, pos, id, random_trap);
+}
+
+COT_WRAP(RunFractionalTurn);
+
+void cotInternalHookRunFractionalTurn(bool is_first_loop) {
+  cotInternalOnTurnStart();
+  cotInternalOriginalRunFractionalTurn(is_first_loop);
+  cotInternalOnTurnEnd();
+}
+
+COT_WRAP(RunMonsterAi);
+
+void cotInternalHookRunMonsterAi(struct entity* monster, undefined param_2) {
+  cotInternalOriginalRunMonsterAi(monster, param_2);
+  cotInternalOnMonsterActionChosen(monster);
+}
+
+COT_WRAP(IqSkillIsEnabled);
+
+bool cotInternalHookIqSkillIsEnabled(struct entity* entity, enum iq_skill_id iq_id) {
+  bool vanilla = cotInternalOriginalIqSkillIsEnabled(entity, iq_id);
+  return cotInternalIqSkillIsEnabled(entity, iq_id, vanilla);
+}
+
+COT_WRAP(GenerateFixedRoom);
+
+bool cotInternalHookGenerateFixedRoom(enum fixed_room_id fixed_room_id,
+                                      struct floor_properties* props) {
+  bool result;
+  if (cotInternalDispatchGenerateFixedRoom(fixed_room_id, props, &result)) {
+    return result;
+  }
+  return cotInternalOriginalGenerateFixedRoom(fixed_room_id, props);
+}
+
+COT_WRAP(HandleFaint);
+
+void cotInternalHookHandleFaint(struct entity* fainted_entity, union damage_source damage_source,
+                                struct entity* killer) {
+  cotInternalOnMonsterFainted(fainted_entity, killer);
+  cotInternalOriginalHandleFaint(fainted_entity, damage_source, killer);
+}
+
+COT_WRAP(SpawnNonEnemies);
+
+void cotInternalHookSpawnNonEnemies(struct floor_properties* floor_props,
+                                    bool empty_monster_house) {
+  cotInternalSetGameSpawning(true);
+  cotInternalOriginalSpawnNonEnemies(floor_props, empty_monster_house);
+  cotInternalSetGameSpawning(false);
+  cotInternalOnTrapsSpawned();
+  cotInternalOnShopStocked();
+}
+
+COT_WRAP(GetActualBuyPrice);
+
+int cotInternalHookGetActualBuyPrice(struct item* item) {
+  return cotInternalGetShopPrice(item, false, cotInternalOriginalGetActualBuyPrice(item));
+}
+
+COT_WRAP(GetActualSellPrice);
+
+int cotInternalHookGetActualSellPrice(struct item* item) {
+  return cotInternalGetShopPrice(item, true, cotInternalOriginalGetActualSellPrice(item));
+}
+
+COT_WRAP(HiddenStairsTrigger);
+
+void cotInternalHookHiddenStairsTrigger(bool show_message) {
+  cotInternalOnEnterHiddenStairs();
+  cotInternalOriginalHiddenStairsTrigger(show_message);
+}
+
+COT_WRAP(DrawMinimapTile);
+
+void cotInternalHookDrawMinimapTile(int x, int y) {
+  cotInternalDrawMinimapTile(x, y, cotInternalOriginalDrawMinimapTile);
+}
+
+COT_WRAP(TryActivateWeather);
+
+void cotInternalHookTryActivateWeather(bool param_1, bool param_2) {
+  cotInternalOriginalTryActivateWeather(param_1, param_2);
+  cotInternalOnWeatherTurn();
+}
+
+COT_WRAP(DoMoveTrapbust);
+
+bool cotInternalHookDoMoveTrapbust(struct entity* attacker, struct entity* defender,
+                                   struct move* move, enum item_id item_id) {
+  if (cotInternalDoTrapbust()) {
+    return true;
+  }
+  return cotInternalOriginalDoMoveTrapbust(attacker, defender, move, item_id);
+}
+
+COT_WRAP(CanMonsterMoveInDirection);
+
+bool cotInternalHookCanMonsterMoveInDirection(struct entity* monster,
+                                              enum direction_id direction) {
+  bool vanilla = cotInternalOriginalCanMonsterMoveInDirection(monster, direction);
+  return cotInternalCanMoveInDirection(monster, direction, vanilla);
+}
+
+COT_WRAP(GetType);
+
+enum type_id cotInternalHookGetType(enum monster_id monster_id, int type_idx) {
+  enum type_id vanilla =
+      cotInternalOriginalGetType(cotInternalResolveSpeciesDataId(monster_id), type_idx);
+  return cotInternalGetSpeciesType(monster_id, type_idx, vanilla);
+}
+
+COT_WRAP(GetAbility);
+
+enum ability_id cotInternalHookGetAbility(enum monster_id monster_id, int ability_idx) {
+  enum ability_id vanilla =
+      cotInternalOriginalGetAbility(cotInternalResolveSpeciesDataId(monster_id), ability_idx);
+  return cotInternalGetSpeciesAbility(monster_id, ability_idx, vanilla);
+}
+
+COT_WRAP(GetNameString);
+
+char* cotInternalHookGetNameString(enum monster_id monster_id) {
+  int string_id = cotInternalGetSpeciesNameStringId(monster_id);
+  if (string_id != 0) {
+    return StringFromId(string_id);
+  }
+  return cotInternalOriginalGetNameString(cotInternalResolveSpeciesDataId(monster_id));
+}
+
+COT_WRAP(GetCategoryString);
+
+char* cotInternalHookGetCategoryString(enum monster_id monster_id) {
+  int string_id = cotInternalGetSpeciesCategoryStringId(monster_id);
+  if (string_id != 0) {
+    return StringFromId(string_id);
+  }
+  return cotInternalOriginalGetCategoryString(cotInternalResolveSpeciesDataId(monster_id));
+}
+
+COT_WRAP(GetSpriteIndex);
+
+uint16_t cotInternalHookGetSpriteIndex(enum monster_id monster_id) {
+  return cotInternalOriginalGetSpriteIndex(cotInternalResolveSpriteId(monster_id));
+}
+
+// The monster data accessors below read the data of the template for extended species. Most of
+// them only take the species, so their wrappers are generated.
+#define COT_WRAP_SPECIES_ACCESSOR(func) \
+  COT_WRAP(func); \
+  __typeof__(func(0)) cotInternalHook##func(enum monster_id monster_id) { \
+    return cotInternalOriginal##func(cotInternalResolveSpeciesDataId(monster_id)); \
+  }
+
+COT_WRAP_SPECIES_ACCESSOR(GetDexNumber)
+COT_WRAP_SPECIES_ACCESSOR(GetMonsterGender)
+COT_WRAP_SPECIES_ACCESSOR(GetBodySize)
+COT_WRAP_SPECIES_ACCESSOR(GetSpriteSize)
+COT_WRAP_SPECIES_ACCESSOR(GetShadowSize)
+COT_WRAP_SPECIES_ACCESSOR(GetSpeedStatus)
+COT_WRAP_SPECIES_ACCESSOR(GetMobilityType)
+COT_WRAP_SPECIES_ACCESSOR(GetRegenSpeed)
+COT_WRAP_SPECIES_ACCESSOR(GetCanMoveFlag)
+COT_WRAP_SPECIES_ACCESSOR(GetChanceAsleep)
+COT_WRAP_SPECIES_ACCESSOR(GetBaseHp)
+COT_WRAP_SPECIES_ACCESSOR(GetRecruitRate1)
+COT_WRAP_SPECIES_ACCESSOR(GetRecruitRate2)
+COT_WRAP_SPECIES_ACCESSOR(GetIqGroup)
+COT_WRAP_SPECIES_ACCESSOR(GetSpawnThreshold)
+COT_WRAP_SPECIES_ACCESSOR(NeedsItemToSpawn)
+COT_WRAP_SPECIES_ACCESSOR(GetMovesetLevelUpPtr)
+COT_WRAP_SPECIES_ACCESSOR(GetMovesetHmTmPtr)
+COT_WRAP_SPECIES_ACCESSOR(GetMovesetEggPtr)
+
+COT_WRAP(GetBaseOffensiveStat);
+
+int cotInternalHookGetBaseOffensiveStat(enum monster_id monster_id, enum stat_index stat_idx) {
+  return cotInternalOriginalGetBaseOffensiveStat(cotInternalResolveSpeciesDataId(monster_id),
+                                                 stat_idx);
+}
+
+COT_WRAP(GetBaseDefensiveStat);
+
+int cotInternalHookGetBaseDefensiveStat(enum monster_id monster_id, enum stat_index stat_idx) {
+  return cotInternalOriginalGetBaseDefensiveStat(cotInternalResolveSpeciesDataId(monster_id),
+                                                 stat_idx);
+}
+
+COT_WRAP(GetExp);
+
+int cotInternalHookGetExp(enum monster_id monster_id, int level) {
+  return cotInternalOriginalGetExp(cotInternalResolveSpeciesDataId(monster_id), level);
+}
+
+COT_WRAP(GetLvlUpEntry);
+
+void cotInternalHookGetLvlUpEntry(struct level_up_entry* entry, enum monster_id monster_id,
+                                  int level) {
+  cotInternalOriginalGetLvlUpEntry(entry, cotInternalResolveSpeciesDataId(monster_id), level);
+}
+
+COT_WRAP(GetNameRaw);
+
+void cotInternalHookGetNameRaw(char* dst, enum monster_id monster_id) {
+  int string_id = cotInternalGetSpeciesNameStringId(monster_id);
+  if (string_id != 0) {
+    strcpy(dst, StringFromId(string_id));
+    return;
+  }
+  cotInternalOriginalGetNameRaw(dst, cotInternalResolveSpeciesDataId(monster_id));
+}
+
+COT_WRAP(GetMonsterIdToSpawn);
+
+enum monster_id cotInternalHookGetMonsterIdToSpawn(int spawn_weight) {
+  enum monster_id vanilla = cotInternalOriginalGetMonsterIdToSpawn(spawn_weight);
+  return cotInternalRollSpeciesSpawn(DUNGEON_PTR->id.val, vanilla);
+}
+
+COT_WRAP(GetAiUseItemProbability);
+
+int cotInternalHookGetAiUseItemProbability(struct entity* item_consumer, struct item* item,
+                                           uint32_t flags) {
+  int vanilla = cotInternalOriginalGetAiUseItemProbability(item_consumer, item, flags);
+  return cotInternalGetAllyItemUseChance(item_consumer, item, vanilla);
+}
+
+COT_WRAP(CalcDamage);
+
+void cotInternalHookCalcDamage(struct entity* attacker, struct entity* defender,
+                               enum type_id attack_type, int attack_power, int crit_chance,
+                               struct damage_data* damage_out, int damage_mult_fp,
+                               enum move_id move_id, bool param_9) {
+  if (move_id == MOVE_HIDDEN_POWER) {
+    cotInternalAdjustHiddenPower(attacker, defender, &attack_type, &attack_power);
+  }
+  cotInternalOriginalCalcDamage(attacker, defender, attack_type, attack_power, crit_chance,
+                                damage_out, damage_mult_fp, move_id, param_9);
+  cotInternalOnDamageCalculated(attacker, defender, move_id, damage_out);
+}
+
+COT_WRAP(CalcDamageFixed);
+
+void cotInternalHookCalcDamageFixed(struct entity* attacker, struct entity* defender,
+                                    int fixed_damage, bool param_4,
+                                    struct damage_data* damage_out, enum type_id attack_type,
+                                    enum move_category move_category, int param_8,
+                                    enum damage_message damage_message, int param_10,
+                                    int param_11) {
+  cotInternalOriginalCalcDamageFixed(attacker, defender, fixed_damage, param_4, damage_out,
+                                     attack_type, move_category, param_8, damage_message,
+                                     param_10, param_11);
+  // Fixed damage isn't tied to a move
+  cotInternalOnDamageCalculated(attacker, defender, MOVE_NOTHING, damage_out);
+}
+
+COT_WRAP(GetLowKickMultiplier);
+
+int cotInternalHookGetLowKickMultiplier(enum monster_id monster_id) {
+  int vanilla =
+      cotInternalOriginalGetLowKickMultiplier(cotInternalResolveSpeciesDataId(monster_id));
+  return cotInternalGetSpeciesWeight(monster_id, vanilla);
+}
+
+COT_WRAP(SpawnMonster);
+
+struct entity* cotInternalHookSpawnMonster(struct spawned_monster_data* monster_data,
+                                           bool cannot_be_asleep) {
+  struct spawned_monster_data data = *monster_data;
+  cotInternalPickGameSpawnPos(SPAWN_CATEGORY_MONSTER, &data.pos);
+  struct entity* entity = cotInternalOriginalSpawnMonster(&data, cannot_be_asleep);
+  if (entity == NULL || !cotInternalOnPunishmentSpawn(entity)) {
+    return NULL;
+  }
+  cotInternalRollHiddenPower(entity);
+  return entity;
+}
+
+COT_WRAP(GetKecleonIdToSpawnByFloor);
+
+enum monster_id cotInternalHookGetKecleonIdToSpawnByFloor(void) {
+  return cotInternalGetKecleonSpecies(cotInternalOriginalGetKecleonIdToSpawnByFloor());
+}
+
+// The move data accessors below read the data of the template for extended moves.
+static struct move ResolveMove(struct move* move) {
+  struct move resolved = *move;
+  resolved.id.val = cotInternalResolveMoveDataId(move->id.val);
+  return resolved;
+}
+
+COT_WRAP(GetMoveType);
+
+enum type_id cotInternalHookGetMoveType(struct move* move) {
+  struct move resolved = ResolveMove(move);
+  return cotInternalOriginalGetMoveType(&resolved);
+}
+
+COT_WRAP(GetMoveBasePower);
+
+int cotInternalHookGetMoveBasePower(struct move* move) {
+  struct move resolved = ResolveMove(move);
+  return cotInternalOriginalGetMoveBasePower(&resolved);
+}
+
+COT_WRAP(GetMoveBasePp);
+
+int cotInternalHookGetMoveBasePp(struct move* move) {
+  struct move resolved = ResolveMove(move);
+  return cotInternalOriginalGetMoveBasePp(&resolved);
+}
+
+COT_WRAP(GetMoveAccuracyOrAiChance);
+
+int cotInternalHookGetMoveAccuracyOrAiChance(struct move* move, int which) {
+  struct move resolved = ResolveMove(move);
+  return cotInternalOriginalGetMoveAccuracyOrAiChance(&resolved, which);
+}
+
+COT_WRAP(GetMoveCategory);
+
+enum move_category cotInternalHookGetMoveCategory(enum move_id move_id) {
+  return cotInternalOriginalGetMoveCategory(cotInternalResolveMoveDataId(move_id));
+}
+
+COT_WRAP(GetMaxPp);
+
+int cotInternalHookGetMaxPp(struct move* move) {
+  struct move resolved = ResolveMove(move);
+  return cotInternalOriginalGetMaxPp(&resolved);
+}
+
+COT_WRAP(GetMoveCritChance);
+
+int cotInternalHookGetMoveCritChance(struct move* move) {
+  struct move resolved = ResolveMove(move);
+  return cotInternalOriginalGetMoveCritChance(&resolved);
+}
+
+COT_WRAP(GetMoveAnimation);
+
+struct move_animation* cotInternalHookGetMoveAnimation(enum move_id move_id) {
+  return cotInternalOriginalGetMoveAnimation(cotInternalResolveMoveAnimationId(move_id));
+}
+
+COT_WRAP(GetMoveAnimationSpeed);
+
+int cotInternalHookGetMoveAnimationSpeed(enum move_id move_id) {
+  return cotInternalOriginalGetMoveAnimationSpeed(cotInternalResolveMoveAnimationId(move_id));
+}
+
+COT_WRAP(StringFromId);
+
+char* cotInternalHookStringFromId(int string_id) {
+  return cotInternalOriginalStringFromId(cotInternalResolveMoveStringId(string_id));
+}
+
+// The item data accessors below read the data of the template for extended items.
+COT_WRAP(GetItemCategory);
+
+enum item_category cotInternalHookGetItemCategory(enum item_id item_id) {
+  return cotInternalOriginalGetItemCategory(cotInternalResolveItemDataId(item_id));
+}
+
+COT_WRAP(GetItemSpriteId);
+
+int cotInternalHookGetItemSpriteId(enum item_id item_id) {
+  return cotInternalOriginalGetItemSpriteId(cotInternalResolveItemIconId(item_id));
+}
+
+COT_WRAP(GetItemPaletteId);
+
+int cotInternalHookGetItemPaletteId(enum item_id item_id) {
+  return cotInternalOriginalGetItemPaletteId(cotInternalResolveItemIconId(item_id));
+}
+
+COT_WRAP(GetItemBuyPrice);
+
+int cotInternalHookGetItemBuyPrice(enum item_id item_id) {
+  return cotInternalOriginalGetItemBuyPrice(cotInternalResolveItemDataId(item_id));
+}
+
+COT_WRAP(GetItemSellPrice);
+
+int cotInternalHookGetItemSellPrice(enum item_id item_id) {
+  return cotInternalOriginalGetItemSellPrice(cotInternalResolveItemDataId(item_id));
+}
+
+COT_WRAP(GetItemName);
+
+char* cotInternalHookGetItemName(enum item_id item_id) {
+  int string_id = cotInternalGetItemNameStringId(item_id);
+  if (string_id != 0) {
+    return StringFromId(string_id);
+  }
+  return cotInternalOriginalGetItemName(cotInternalResolveItemDataId(item_id));
+}
+
+COT_WRAP(IsThrownItem);
+
+bool cotInternalHookIsThrownItem(enum item_id item_id) {
+  return cotInternalOriginalIsThrownItem(cotInternalResolveItemDataId(item_id));
+}
+
+COT_WRAP(IsEdible);
+
+bool cotInternalHookIsEdible(enum item_id item_id) {
+  return cotInternalOriginalIsEdible(cotInternalResolveItemDataId(item_id));
+}
+
+COT_WRAP(RunNextOpcode);
+
+int cotInternalHookRunNextOpcode(struct script_routine* routine) {
+  // Extension opcodes are skipped over, so the game runs the one after them
+  while (cotInternalDispatchScriptOpcode(routine)) {
+  }
+  return cotInternalOriginalRunNextOpcode(routine);
+}
+
+COT_WRAP(AddMissionToJobList);
+
+void cotInternalHookAddMissionToJobList(struct mission* mission) {
+  struct mission accepted = *mission;
+  if (cotInternalOnMissionAccepted(&accepted)) {
+    cotInternalOriginalAddMissionToJobList(&accepted);
+  }
+}
+
+COT_WRAP(GroundToDungeonMoveset);
+
+void cotInternalHookGroundToDungeonMoveset(struct move* moveset_dungeon,
+                                           struct ground_move* moveset_ground) {
+  // The game converts the moves of team members, stored inline in their ground_monster
+  struct ground_monster* member = (struct ground_monster*) (
+    (char*) moveset_ground - __builtin_offsetof(struct ground_monster, moves));
+  cotInternalOnMovesetToDungeon(member);
+  cotInternalOriginalGroundToDungeonMoveset(moveset_dungeon, moveset_ground);
+}
+
+COT_WRAP(ApplyGummiBoostsDungeonMode);
+
+void cotInternalHookApplyGummiBoostsDungeonMode(struct entity* user, struct entity* target,
+                                                enum type_id gummi_type, int random_stat_boost) {
+  random_stat_boost = cotInternalGetGummiStatBoost(target, gummi_type, random_stat_boost);
+  cotInternalOriginalApplyGummiBoostsDungeonMode(user, target, gummi_type, random_stat_boost);
+}
+
+COT_WRAP(SubstitutePlaceholderStringTags);
+
+void cotInternalHookSubstitutePlaceholderStringTags(int string_id, struct entity* entity,
+                                                    undefined4 param_3) {
+  if (entity == NULL || entity->type != ENTITY_MONSTER) {
+    cotInternalOriginalSubstitutePlaceholderStringTags(string_id, entity, param_3);
+    return;
+  }
+  // The game names monsters by their species, so it's swapped for the duration of the call
+  struct monster* monster = entity->info;
+  enum monster_id real = monster->id.val;
+  monster->id.val = cotInternalGetAnnouncedSpecies(entity, real);
+  cotInternalOriginalSubstitutePlaceholderStringTags(string_id, entity, param_3);
+  monster->id.val = real;
+}
+
+COT_WRAP(PickUpItem);
+
+void cotInternalHookPickUpItem(struct entity* pokemon, struct entity* item_entity) {
+  if (cotInternalCanAllyPickUpItem(pokemon, (struct item*) item_entity->info)) {
+    cotInternalOriginalPickUpItem(pokemon, item_entity);
+  }
+}
+
+COT_WRAP(SpawnInitialMonsters);
+
+void cotInternalHookSpawnInitialMonsters(void) {
+  cotInternalSetGameSpawning(true);
+  cotInternalOriginalSpawnInitialMonsters();
+  cotInternalSetGameSpawning(false);
+}
+
+COT_WRAP(SpawnItem);
+
+bool cotInternalHookSpawnItem(struct position* position, struct item* item, bool flag) {
+  struct position pos = *position;
+  cotInternalPickGameSpawnPos(SPAWN_CATEGORY_ITEM, &pos);
+  return cotInternalOriginalSpawnItem(&pos, item, flag);
+}
+
+COT_WRAP(SpawnTrap);
+
+struct entity* cotInternalHookSpawnTrap(enum trap_id trap_id, struct position* position,
+                                        uint8_t team, uint8_t flags) {
+  struct position pos = *position;
+  cotInternalPickGameSpawnPos(SPAWN_CATEGORY_TRAP, &pos);
+  return cotInternalOriginalSpawnTrap(trap_id, &pos, team, flags);
+}
+
+COT_WRAP(IsMarowakTrainingMaze);
+
+bool cotInternalHookIsMarowakTrainingMaze(enum dungeon_id dungeon_id) {
+  return cotInternalOriginalIsMarowakTrainingMaze(dungeon_id)
+         || cotInternalIsTrainingCourse(dungeon_id);
+}
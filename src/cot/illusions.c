@@ -0,0 +1,100 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+typedef struct illusion {
+  struct entity* entity;
+  enum monster_id species;
+  enum monster_id real_species;
+  unsigned reveal_on;
+  // HP at the end of the last turn
+  int hp;
+} illusion;
+
+static illusion illusions[COT_MAX_ILLUSIONS];
+static int n_illusions = 0;
+static illusion_reveal_fn reveal_handler = NULL;
+
+static illusion* FindIllusion(struct entity* entity) {
+  for (int i = 0; i < n_illusions; i++) {
+    if (illusions[i].entity == entity) {
+      return &illusions[i];
+    }
+  }
+  return NULL;
+}
+
+bool CotAddIllusion(struct entity* entity, enum monster_id species, unsigned reveal_on) {
+  if (!EntityIsValid(entity) || entity->type != ENTITY_MONSTER) {
+    return false;
+  }
+  illusion* existing = FindIllusion(entity);
+  if (existing == NULL) {
+    if (n_illusions >= COT_MAX_ILLUSIONS) {
+      COT_WARN(COT_LOG_CAT_ILLUSIONS, "Illusion table is full");
+      return false;
+    }
+    existing = &illusions[n_illusions++];
+    existing->entity = entity;
+    existing->real_species = ((struct monster*) entity->info)->apparent_id.val;
+  }
+  existing->species = species;
+  existing->reveal_on = reveal_on;
+  existing->hp = CotGetMonsterHp(entity);
+  CotSetApparentSpecies(entity, species);
+  return true;
+}
+
+bool CotHasIllusion(struct entity* entity) {
+  return FindIllusion(entity) != NULL;
+}
+
+enum monster_id CotGetIllusionSpecies(struct entity* entity) {
+  illusion* il = FindIllusion(entity);
+  return il != NULL ? il->species : ((struct monster*) entity->info)->apparent_id.val;
+}
+
+static bool Reveal(struct entity* entity, illusion_reveal_reason reason) {
+  illusion* il = FindIllusion(entity);
+  if (il == NULL) {
+    return false;
+  }
+  if (EntityIsValid(entity)) {
+    CotSetApparentSpecies(entity, il->real_species);
+  }
+  *il = illusions[--n_illusions];
+  COT_LOGFMT(COT_LOG_CAT_ILLUSIONS, "Illusion revealed (reason %d)", reason);
+  if (reveal_handler != NULL) {
+    reveal_handler(entity, reason);
+  }
+  return true;
+}
+
+bool CotRevealIllusion(struct entity* entity) {
+  return Reveal(entity, ILLUSION_REVEALED_MANUALLY);
+}
+
+void CotSetIllusionRevealHandler(illusion_reveal_fn handler) {
+  reveal_handler = handler;
+}
+
+void CotClearIllusions(void) {
+  n_illusions = 0;
+}
+
+enum monster_id cotInternalGetAnnouncedSpecies(struct entity* entity, enum monster_id vanilla) {
+  illusion* il = FindIllusion(entity);
+  return il != NULL ? il->species : vanilla;
+}
+
+void cotInternalCheckIllusionsDamaged(void) {
+  // Iterate backwards, revealing an illusion moves the last one into its slot
+  for (int i = n_illusions - 1; i >= 0; i--) {
+    illusion* il = &illusions[i];
+    int hp = CotGetMonsterHp(il->entity);
+    bool damaged = hp < il->hp;
+    il->hp = hp;
+    if (damaged && (il->reveal_on & ILLUSION_REVEAL_ON_DAMAGE)) {
+      Reveal(il->entity, ILLUSION_REVEALED_BY_DAMAGE);
+    }
+  }
+}
@@ -0,0 +1,88 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+typedef struct iq_override {
+  enum iq_skill_id skill;
+  iq_skill_override_fn fn;
+} iq_override;
+
+static iq_override overrides[COT_MAX_IQ_OVERRIDES];
+static int n_overrides = 0;
+
+static struct monster* GetMonster(struct entity* entity) {
+  if (entity == NULL || !EntityIsValid(entity) || entity->type != ENTITY_MONSTER) {
+    return NULL;
+  }
+  return entity->info;
+}
+
+int CotGetIq(struct entity* entity) {
+  struct monster* monster = GetMonster(entity);
+  return monster != NULL ? monster->iq : 0;
+}
+
+void CotSetIq(struct entity* entity, int iq) {
+  struct monster* monster = GetMonster(entity);
+  if (monster == NULL) {
+    return;
+  }
+  monster->iq = iq < 0 ? 0 : (iq > COT_MAX_IQ ? COT_MAX_IQ : iq);
+}
+
+bool CotHasIqSkill(struct entity* entity, enum iq_skill_id skill) {
+  struct monster* monster = GetMonster(entity);
+  return monster != NULL && IqSkillFlagTest(monster->iq_skill_flags, skill);
+}
+
+static int FindOverride(enum iq_skill_id skill) {
+  for (int i = 0; i < n_overrides; i++) {
+    if (overrides[i].skill == skill) {
+      return i;
+    }
+  }
+  return -1;
+}
+
+bool CotIqSkillIsEnabled(struct entity* entity, enum iq_skill_id skill) {
+  bool enabled = CotHasIqSkill(entity, skill);
+  int i = FindOverride(skill);
+  return i >= 0 ? overrides[i].fn(entity, skill, enabled) : enabled;
+}
+
+void CotSetIqSkill(struct entity* entity, enum iq_skill_id skill, bool enabled) {
+  struct monster* monster = GetMonster(entity);
+  if (monster == NULL) {
+    return;
+  }
+  uint32_t bit = 1u << (skill % 32);
+  if (enabled) {
+    monster->iq_skill_flags[skill / 32] |= bit;
+  } else {
+    monster->iq_skill_flags[skill / 32] &= ~bit;
+  }
+}
+
+bool CotRegisterIqSkillOverride(enum iq_skill_id skill, iq_skill_override_fn fn) {
+  if (FindOverride(skill) >= 0) {
+    COT_WARNFMT(COT_LOG_CAT_IQ, "IQ skill %d already has an override", skill);
+    return false;
+  }
+  if (n_overrides >= COT_MAX_IQ_OVERRIDES) {
+    COT_WARN(COT_LOG_CAT_IQ, "IQ skill override table is full");
+    return false;
+  }
+  overrides[n_overrides++] = (iq_override) { .skill = skill, .fn = fn };
+  return true;
+}
+
+void CotUnregisterIqSkillOverride(enum iq_skill_id skill) {
+  int i = FindOverride(skill);
+  if (i >= 0) {
+    overrides[i] = overrides[--n_overrides];
+  }
+}
+
+bool cotInternalIqSkillIsEnabled(struct entity* entity, enum iq_skill_id skill, bool vanilla) {
+  int i = FindOverride(skill);
+  return i >= 0 ? overrides[i].fn(entity, skill, vanilla) : vanilla;
+}
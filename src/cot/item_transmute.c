@@ -0,0 +1,101 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+// Random picks that don't land on an item of the right category are retried this often.
+#define POLYMORPH_ATTEMPTS 64
+
+// Only the ID and what comes with it change, the item keeps its holder and flags
+static void Transmute(struct item* item, enum item_id new_id) {
+  struct item old = *item;
+  InitItem(item, new_id, 0, old.f_sticky);
+  item->f_in_shop = old.f_in_shop;
+  item->f_set = old.f_set;
+  item->held_by = old.held_by;
+}
+
+static struct entity* FindFloorEntity(const struct item* item) {
+  struct entity* entity;
+  COT_FOR_EACH_ENTITY(ITER_ITEMS, entity) {
+    if (entity->info == item) {
+      return entity;
+    }
+  }
+  return NULL;
+}
+
+// Floor items are spawned again in place, so the game sets up their sprite and minimap icon
+// for the new item
+static void RespawnFloorItem(struct position pos, struct item item) {
+  RemoveGroundItem(&pos, true);
+  if (!SpawnItem(&pos, &item, true)) {
+    COT_WARNFMT(COT_LOG_CAT_TRANSMUTE, "Failed to respawn item %d", item.id.val);
+  }
+}
+
+void CotTransmuteItem(struct item* item, enum item_id new_id) {
+  Transmute(item, new_id);
+  struct entity* entity = FindFloorEntity(item);
+  if (entity != NULL) {
+    RespawnFloorItem(entity->pos, *item);
+  }
+}
+
+bool CotIsTransmuteTarget(enum item_id id) {
+  return id > ITEM_NOTHING && id < COT_N_VANILLA_ITEMS && IsItemValid(id);
+}
+
+bool CotPolymorphItem(struct item* item) {
+  enum item_category category = GetItemCategory(item->id.val);
+  for (int i = 0; i < POLYMORPH_ATTEMPTS; i++) {
+    enum item_id candidate = 1 + CotRandInt(RNG_DUNGEON, COT_N_VANILLA_ITEMS - 1);
+    if (candidate != item->id.val && CotIsTransmuteTarget(candidate)
+        && GetItemCategory(candidate) == category) {
+      CotTransmuteItem(item, candidate);
+      return true;
+    }
+  }
+  COT_WARNFMT(COT_LOG_CAT_TRANSMUTE, "No polymorph target found for item %d", item->id.val);
+  return false;
+}
+
+bool CotPolymorphItemFromPool(struct item* item, const enum item_id* pool, int n) {
+  int n_valid = 0;
+  for (int i = 0; i < n; i++) {
+    n_valid += CotIsTransmuteTarget(pool[i]);
+  }
+  if (n_valid == 0) {
+    return false;
+  }
+  // Pick among the valid entries only, so invalid ones don't skew the odds
+  int pick = CotRandInt(RNG_DUNGEON, n_valid);
+  for (int i = 0; i < n; i++) {
+    if (CotIsTransmuteTarget(pool[i]) && pick-- == 0) {
+      CotTransmuteItem(item, pool[i]);
+      break;
+    }
+  }
+  return true;
+}
+
+int CotTransmuteFloorItems(transmute_fn fn, void* ctx) {
+  // Respawned items may take a slot that's still to be visited, so they're respawned once all
+  // items were visited
+  struct position changed[COT_N_ITEM_SLOTS];
+  int n_changed = 0;
+  struct entity* entity;
+  COT_FOR_EACH_ENTITY(ITER_ITEMS, entity) {
+    struct item* item = (struct item*) entity->info;
+    enum item_id new_id = fn(item, ctx);
+    if (new_id != item->id.val && CotIsTransmuteTarget(new_id)) {
+      Transmute(item, new_id);
+      changed[n_changed++] = entity->pos;
+    }
+  }
+  for (int i = 0; i < n_changed; i++) {
+    struct item* item = CotFloorItemAt(changed[i].x, changed[i].y);
+    if (item != NULL) {
+      RespawnFloorItem(changed[i], *item);
+    }
+  }
+  return n_changed;
+}
@@ -0,0 +1,230 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+typedef struct shop_rule {
+  enum dungeon_id dungeon;
+  int floor;
+  shop_rule_kind kind;
+} shop_rule;
+
+typedef struct shopkeeper_override {
+  enum dungeon_id dungeon;
+  enum monster_id species;
+} shopkeeper_override;
+
+static shop_rule rules[COT_MAX_SHOP_RULES];
+static int n_rules = 0;
+static int buy_scale = 100;
+static int sell_scale = 100;
+static shop_price_fn price_override = NULL;
+static shop_stock_fn stock_hook = NULL;
+static bool aggression_enabled = true;
+static shop_theft_fn theft_handler = NULL;
+static bool theft_detected = false;
+static int punishment_limit = -1;
+static shop_punishment_fn punishment_hook = NULL;
+static int n_punishment_spawns = 0;
+static COT_VEC(shopkeeper_override, COT_MAX_SHOPKEEPER_OVERRIDES) shopkeepers;
+
+static bool HasShopkeeperMode(struct entity* entity, enum shopkeeper_mode mode) {
+  return ((struct monster*) entity->info)->shopkeeper.val == mode;
+}
+
+// Returns the Kecleon species for the current dungeon, or MONSTER_NONE to keep the game's.
+static enum monster_id GetShopkeeperSpecies(void) {
+  enum monster_id species = MONSTER_NONE;
+  for (int i = 0; i < shopkeepers.len; i++) {
+    if (shopkeepers.items[i].dungeon == DUNGEON_PTR->id.val) {
+      return shopkeepers.items[i].species;
+    }
+    if (shopkeepers.items[i].dungeon == COT_ANY_DUNGEON) {
+      species = shopkeepers.items[i].species;
+    }
+  }
+  return species;
+}
+
+bool CotGetShopBounds(room_rect* bounds) {
+  bool found = false;
+  for (int y = 0; y < COT_FLOOR_HEIGHT; y++) {
+    for (int x = 0; x < COT_FLOOR_WIDTH; x++) {
+      if (!CotTileAt(x, y)->terrain_flags.f_in_kecleon_shop) {
+        continue;
+      }
+      if (!found) {
+        *bounds = (room_rect) { .x0 = x, .y0 = y, .x1 = x, .y1 = y };
+        found = true;
+      }
+      bounds->x0 = x < bounds->x0 ? x : bounds->x0;
+      bounds->x1 = x > bounds->x1 ? x : bounds->x1;
+      bounds->y1 = y;
+    }
+  }
+  return found;
+}
+
+bool CotFloorHasShop(void) {
+  room_rect bounds;
+  return CotGetShopBounds(&bounds);
+}
+
+void CotForEachShopItem(void (*fn)(struct item* item, int x, int y, void* ctx), void* ctx) {
+  struct entity* entity;
+  COT_FOR_EACH_ENTITY(ITER_ITEMS, entity) {
+    struct item* item = entity->info;
+    struct tile* tile = CotTileAt(entity->pos.x, entity->pos.y);
+    if (item->f_in_shop && tile != NULL && tile->terrain_flags.f_in_kecleon_shop) {
+      fn(item, entity->pos.x, entity->pos.y, ctx);
+    }
+  }
+}
+
+void CotReplaceShopItem(struct item* item, enum item_id id) {
+  item->id.val = id;
+  item->f_in_shop = true;
+}
+
+void CotSetShopStockHook(shop_stock_fn fn) {
+  stock_hook = fn;
+}
+
+static void RunStockHook(struct item* item, int x, int y, void* ctx) {
+  (void) ctx;
+  stock_hook(item, x, y);
+}
+
+void cotInternalOnShopStocked(void) {
+  CotResetShopTheft();
+  n_punishment_spawns = 0;
+  if (stock_hook != NULL) {
+    CotForEachShopItem(RunStockHook, NULL);
+  }
+}
+
+void CotSetShopPriceScale(int buy_percent, int sell_percent) {
+  buy_scale = buy_percent;
+  sell_scale = sell_percent;
+}
+
+void CotSetShopPriceOverride(shop_price_fn fn) {
+  price_override = fn;
+}
+
+int cotInternalGetShopPrice(struct item* item, bool selling, int price) {
+  price = price * (selling ? sell_scale : buy_scale) / 100;
+  if (price_override != NULL) {
+    price = price_override(item, selling, price);
+  }
+  return price;
+}
+
+bool CotAddShopRule(enum dungeon_id dungeon, int floor, shop_rule_kind kind) {
+  if (n_rules >= COT_MAX_SHOP_RULES) {
+    COT_WARN(COT_LOG_CAT_FLOOR_GEN, "Shop rule table is full");
+    return false;
+  }
+  rules[n_rules++] = (shop_rule) { .dungeon = dungeon, .floor = floor, .kind = kind };
+  return true;
+}
+
+static void ApplyShopRules(struct floor_properties* props) {
+  for (int i = 0; i < n_rules; i++) {
+    if (rules[i].dungeon == DUNGEON_PTR->id.val
+        && (rules[i].floor == COT_ANY_FLOOR || rules[i].floor == DUNGEON_PTR->floor)) {
+      props->kecleon_shop_spawn_chance = rules[i].kind == SHOP_RULE_FORCE ? 100 : 0;
+      return;
+    }
+  }
+}
+
+bool CotInstallShopRules(void) {
+  return CotAddPreGenerateHook(ApplyShopRules);
+}
+
+void CotSetShopAggression(bool enabled) {
+  aggression_enabled = enabled;
+}
+
+void CotSetShopTheftHandler(shop_theft_fn handler) {
+  theft_handler = handler;
+}
+
+bool CotShopTheftDetected(void) {
+  return theft_detected;
+}
+
+void CotResetShopTheft(void) {
+  theft_detected = false;
+}
+
+bool CotSetShopkeeperSpecies(enum dungeon_id dungeon, enum monster_id species) {
+  for (int i = 0; i < shopkeepers.len; i++) {
+    if (shopkeepers.items[i].dungeon == dungeon) {
+      shopkeepers.items[i].species = species;
+      return true;
+    }
+  }
+  shopkeeper_override entry = { .dungeon = dungeon, .species = species };
+  if (!COT_VEC_PUSH(&shopkeepers, entry)) {
+    COT_WARN(COT_LOG_CAT_KECLEON_SHOPS, "Shopkeeper override table is full");
+    return false;
+  }
+  return true;
+}
+
+void CotSetShopPunishmentLimit(int max_spawns) {
+  punishment_limit = max_spawns;
+}
+
+void CotSetShopPunishmentHook(shop_punishment_fn fn) {
+  punishment_hook = fn;
+}
+
+enum monster_id cotInternalGetKecleonSpecies(enum monster_id vanilla) {
+  enum monster_id species = GetShopkeeperSpecies();
+  return species != MONSTER_NONE ? species : vanilla;
+}
+
+bool cotInternalOnPunishmentSpawn(struct entity* entity) {
+  if (!DUNGEON_PTR->thief_alert
+      || ((struct monster*) entity->info)->id.val != GetKecleonIdToSpawnByFloor()) {
+    return true;
+  }
+  int index = n_punishment_spawns++;
+  if ((punishment_limit < 0 || index < punishment_limit)
+      && (punishment_hook == NULL || punishment_hook(entity, index))) {
+    return true;
+  }
+  COT_LOGFMT(COT_LOG_CAT_KECLEON_SHOPS, "Removed punishment spawn %d", index);
+  CotDespawnEntity(entity);
+  CotCompactActiveMonsters();
+  return false;
+}
+
+void cotInternalCheckShopTheft(void) {
+  if (theft_detected) {
+    return;
+  }
+  struct entity* entity;
+  bool hostile = false;
+  COT_FOR_EACH_ENTITY(ITER_NON_TEAM, entity) {
+    if (HasShopkeeperMode(entity, SHOPKEEPER_MODE_ATTACK_TEAM)) {
+      hostile = true;
+      break;
+    }
+  }
+  if (!hostile) {
+    return;
+  }
+  if (aggression_enabled && (theft_handler == NULL || theft_handler())) {
+    COT_LOG(COT_LOG_CAT_KECLEON_SHOPS, "Shop theft detected");
+    theft_detected = true;
+    return;
+  }
+  COT_LOG(COT_LOG_CAT_KECLEON_SHOPS, "Shop theft forgiven");
+  COT_FOR_EACH_ENTITY(ITER_NON_TEAM, entity) {
+    if (HasShopkeeperMode(entity, SHOPKEEPER_MODE_ATTACK_TEAM)) {
+      ((struct monster*) entity->info)->shopkeeper.val = SHOPKEEPER_MODE_SHOPKEEPER;
+    }
+  }
+}
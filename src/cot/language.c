@@ -0,0 +1,57 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+typedef struct language_table {
+  const char* const* strings;
+  int n_strings;
+} language_table;
+
+static const char* const LANGUAGE_CODES[COT_N_LANGUAGES] = {
+  [LANG_JAPANESE] = "ja",
+  [LANG_ENGLISH] = "en",
+  [LANG_FRENCH] = "fr",
+  [LANG_GERMAN] = "de",
+  [LANG_ITALIAN] = "it",
+  [LANG_SPANISH] = "es",
+};
+
+static language_table tables[COT_N_LANGUAGES];
+
+cot_language CotGetLanguage(void) {
+  int language = GetLanguage();
+  if (language < 0 || language >= COT_N_LANGUAGES) {
+    COT_WARNFMT(COT_LOG_CAT_LANGUAGE, "Unknown language %d", language);
+    return LANG_ENGLISH;
+  }
+  return language;
+}
+
+const char* CotLanguageCode(cot_language language) {
+  return language < COT_N_LANGUAGES ? LANGUAGE_CODES[language] : LANGUAGE_CODES[LANG_ENGLISH];
+}
+
+void CotUseGameLanguageForStrings(void) {
+  CotSetStringsLanguage(CotLanguageCode(CotGetLanguage()), LANGUAGE_CODES[LANG_ENGLISH]);
+}
+
+bool CotRegisterLanguageTable(cot_language language, const char* const* strings, int n_strings) {
+  if (language >= COT_N_LANGUAGES) {
+    COT_WARNFMT(COT_LOG_CAT_LANGUAGE, "Invalid language %d", language);
+    return false;
+  }
+  tables[language] = (language_table) { .strings = strings, .n_strings = n_strings };
+  return true;
+}
+
+static const char* Lookup(cot_language language, int idx) {
+  const language_table* table = &tables[language];
+  if (table->strings == NULL || idx < 0 || idx >= table->n_strings) {
+    return NULL;
+  }
+  return table->strings[idx];
+}
+
+const char* CotLangString(int idx) {
+  const char* string = Lookup(CotGetLanguage(), idx);
+  return string != NULL ? string : Lookup(LANG_ENGLISH, idx);
+}
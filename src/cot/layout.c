@@ -0,0 +1,22 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+// Layouts of the game structs c-of-time accesses directly. The values are the ones of the
+// game; if one of these fails, the definitions in pmdsky-debug have changed.
+
+COT_ASSERT_SIZE(struct tile, 0x14);
+COT_ASSERT_OFFSET(struct tile, room, 0x7);
+COT_ASSERT_OFFSET(struct tile, monster, 0xC);
+COT_ASSERT_OFFSET(struct tile, object, 0x10);
+
+COT_ASSERT_SIZE(struct entity, 0xB8);
+COT_ASSERT_SIZE(struct monster, 0x240);
+COT_ASSERT_SIZE(struct item, 0x6);
+
+COT_ASSERT_SIZE(struct floor_properties, 0x20);
+COT_ASSERT_OFFSET(struct floor_properties, tileset, 0x2);
+COT_ASSERT_OFFSET(struct floor_properties, music_table_idx, 0x3);
+COT_ASSERT_OFFSET(struct floor_properties, weather, 0x4);
+
+COT_ASSERT_OFFSET(struct dungeon, id, 0x748);
+COT_ASSERT_OFFSET(struct dungeon, floor, 0x749);
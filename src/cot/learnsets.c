@@ -0,0 +1,71 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+// Movesets are zero-terminated lists of values encoded big-endian in 7-bit groups, with the
+// high bit set on all but the last byte. Level-up movesets store (move ID, level) pairs,
+// TM/HM and egg movesets only move IDs.
+static int ReadEncoded(const uint8_t** ptr) {
+  int value = 0;
+  uint8_t byte;
+  do {
+    byte = *(*ptr)++;
+    value = (value << 7) | (byte & 0x7F);
+  } while (byte & 0x80);
+  return value;
+}
+
+static bool MovesetContains(const uint8_t* moveset, enum move_id move) {
+  while (*moveset != 0) {
+    if (ReadEncoded(&moveset) == move) {
+      return true;
+    }
+  }
+  return false;
+}
+
+int CotGetLevelUpMoveLevel(enum monster_id species, enum move_id move) {
+  const uint8_t* moveset = GetMovesetLevelUpPtr(species);
+  while (*moveset != 0) {
+    int entry_move = ReadEncoded(&moveset);
+    int level = ReadEncoded(&moveset);
+    if (entry_move == move) {
+      return level;
+    }
+  }
+  return -1;
+}
+
+int CotGetLevelUpMoves(enum monster_id species, int max_level, enum move_id* out, int max) {
+  const uint8_t* moveset = GetMovesetLevelUpPtr(species);
+  int count = 0;
+  while (*moveset != 0 && count < max) {
+    enum move_id move = ReadEncoded(&moveset);
+    int level = ReadEncoded(&moveset);
+    if (level > max_level) {
+      continue;
+    }
+    bool duplicate = false;
+    for (int i = 0; i < count; i++) {
+      duplicate |= out[i] == move;
+    }
+    if (!duplicate) {
+      out[count++] = move;
+    }
+  }
+  return count;
+}
+
+bool CotCanLearnTmMove(enum monster_id species, enum move_id move) {
+  return MovesetContains(GetMovesetHmTmPtr(species), move);
+}
+
+bool CotCanUseTm(enum monster_id species, enum item_id tm) {
+  if (GetItemCategory(tm) != CATEGORY_TMS_HMS) {
+    return false;
+  }
+  return CotCanLearnTmMove(species, GetItemMoveId(tm));
+}
+
+bool CotIsEggMove(enum monster_id species, enum move_id move) {
+  return MovesetContains(GetMovesetEggPtr(species), move);
+}
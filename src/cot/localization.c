@@ -0,0 +1,117 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+typedef struct localized_string {
+  const char* language;
+  const char* key;
+  const char* value;
+} localized_string;
+
+static char* strings_file = NULL;
+static localized_string strings[COT_MAX_LOCALIZED_STRINGS];
+static int n_strings = 0;
+static const char* current_language = "en";
+static const char* fallback_language = "en";
+
+static bool StringsEqual(const char* a, const char* b) {
+  while (*a != '\0' && *a == *b) {
+    a++;
+    b++;
+  }
+  return *a == *b;
+}
+
+// Splits the file into lines in place and indexes its entries.
+static void ParseStrings(char* data, int size) {
+  const char* language = NULL;
+  char* line = data;
+  for (int i = 0; i <= size; i++) {
+    if (i < size && data[i] != '\n') {
+      continue;
+    }
+    data[i] = '\0';
+    if (i > 0 && data[i - 1] == '\r') {
+      data[i - 1] = '\0';
+    }
+
+    if (line[0] == '[') {
+      char* end = line + 1;
+      while (*end != '\0' && *end != ']') {
+        end++;
+      }
+      *end = '\0';
+      language = line + 1;
+    } else if (line[0] != '#' && line[0] != '\0') {
+      char* separator = line;
+      while (*separator != '\0' && *separator != '=') {
+        separator++;
+      }
+      if (*separator == '\0' || language == NULL) {
+        COT_WARNFMT(COT_LOG_CAT_LOCALIZATION, "Invalid line in strings file: %s", line);
+      } else if (n_strings >= COT_MAX_LOCALIZED_STRINGS) {
+        COT_WARN(COT_LOG_CAT_LOCALIZATION, "Too many localized strings");
+        return;
+      } else {
+        *separator = '\0';
+        strings[n_strings++] = (localized_string) {
+          .language = language, .key = line, .value = separator + 1,
+        };
+      }
+    }
+    line = data + i + 1;
+  }
+}
+
+bool CotLoadStrings(const char* path) {
+  CotUnloadStrings();
+  int size;
+  void* data = CotLoadFile(path, &size);
+  if (data == NULL) {
+    return false;
+  }
+  // Room for the terminator of the last line
+  strings_file = MemAlloc(size + 1, 0);
+  if (strings_file == NULL) {
+    MemFree(data);
+    return false;
+  }
+  memcpy(strings_file, data, size);
+  MemFree(data);
+  ParseStrings(strings_file, size);
+  COT_LOGFMT(COT_LOG_CAT_LOCALIZATION, "Loaded %d strings from %s", n_strings, path);
+  return true;
+}
+
+void CotUnloadStrings(void) {
+  if (strings_file != NULL) {
+    MemFree(strings_file);
+    strings_file = NULL;
+  }
+  n_strings = 0;
+}
+
+void CotSetStringsLanguage(const char* language, const char* fallback) {
+  current_language = language;
+  fallback_language = fallback;
+}
+
+static const char* Find(const char* language, const char* key) {
+  for (int i = 0; i < n_strings; i++) {
+    if (StringsEqual(strings[i].language, language) && StringsEqual(strings[i].key, key)) {
+      return strings[i].value;
+    }
+  }
+  return NULL;
+}
+
+const char* CotGetString(const char* key) {
+  const char* value = Find(current_language, key);
+  if (value == NULL) {
+    value = Find(fallback_language, key);
+  }
+  if (value == NULL) {
+    COT_WARNFMT(COT_LOG_CAT_LOCALIZATION, "Missing string '%s'", key);
+    return key;
+  }
+  return value;
+}
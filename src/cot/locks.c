@@ -0,0 +1,133 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+typedef struct lock {
+  struct position pos;
+  int8_t key_type_id;
+  lock_kind kind;
+  bool locked;
+  lock_open_fn on_open;
+} lock;
+
+static key_type key_types[COT_MAX_KEY_TYPES];
+static int n_key_types = 0;
+static lock locks[COT_MAX_LOCKS];
+static int n_locks = 0;
+
+int CotRegisterKeyType(key_type type) {
+  if (n_key_types >= COT_MAX_KEY_TYPES) {
+    COT_WARN(COT_LOG_CAT_LOCKS, "Key type table is full");
+    return COT_KEY_TYPE_INVALID;
+  }
+  key_types[n_key_types] = type;
+  return n_key_types++;
+}
+
+int CotGetKeyTypeForItem(enum item_id item) {
+  for (int i = 0; i < n_key_types; i++) {
+    if (key_types[i].key_item == item) {
+      return i;
+    }
+  }
+  return COT_KEY_TYPE_INVALID;
+}
+
+static void SetTileOpen(int x, int y, bool open) {
+  CotSetTileTerrain(x, y, open ? TERRAIN_NORMAL : TERRAIN_WALL, true);
+}
+
+bool CotAddLock(int x, int y, int key_type_id, lock_kind kind, lock_open_fn on_open) {
+  if (n_locks >= COT_MAX_LOCKS || key_type_id < 0 || key_type_id >= n_key_types) {
+    COT_WARNFMT(COT_LOG_CAT_LOCKS, "Can't add lock at (%d, %d)", x, y);
+    return false;
+  }
+  locks[n_locks++] = (lock) {
+    .pos = { .x = x, .y = y },
+    .key_type_id = key_type_id,
+    .kind = kind,
+    .locked = true,
+    .on_open = on_open,
+  };
+  if (kind == LOCK_DOOR) {
+    SetTileOpen(x, y, false);
+  }
+  return true;
+}
+
+void CotClearLocks(void) {
+  n_locks = 0;
+}
+
+static lock* FindLock(int x, int y) {
+  for (int i = 0; i < n_locks; i++) {
+    if (locks[i].locked && locks[i].pos.x == x && locks[i].pos.y == y) {
+      return &locks[i];
+    }
+  }
+  return NULL;
+}
+
+bool CotIsLocked(int x, int y) {
+  return FindLock(x, y) != NULL;
+}
+
+static void OpenLock(lock* l) {
+  COT_LOGFMT(COT_LOG_CAT_LOCKS, "Unlocked (%d, %d)", l->pos.x, l->pos.y);
+  l->locked = false;
+  if (l->kind == LOCK_DOOR) {
+    SetTileOpen(l->pos.x, l->pos.y, true);
+  }
+  if (l->on_open != NULL) {
+    l->on_open(l->pos.x, l->pos.y, l->kind);
+  }
+}
+
+bool CotForceUnlock(int x, int y) {
+  lock* l = FindLock(x, y);
+  if (l == NULL) {
+    return false;
+  }
+  OpenLock(l);
+  return true;
+}
+
+bool CotTryUnlock(struct entity* entity) {
+  if (!EntityIsValid(entity) || entity->type != ENTITY_MONSTER) {
+    return false;
+  }
+  enum direction_id direction = ((struct monster*) entity->info)->action.direction.val;
+  struct position facing = CotPosStep(entity->pos, direction);
+  lock* l = FindLock(facing.x, facing.y);
+  if (l == NULL) {
+    return false;
+  }
+
+  key_type* type = &key_types[l->key_type_id];
+  int bag_idx = CotFindBagItem(type->key_item);
+  if (bag_idx < 0) {
+    if (type->no_key_message_id != 0) {
+      DisplayMessage(NULL, type->no_key_message_id, true);
+    }
+    return false;
+  }
+  if (type->prompt_message_id != 0 && YesNoMenu(0, type->prompt_message_id, 0, 0) != 1) {
+    return false;
+  }
+
+  if (type->consumed) {
+    RemoveItemNoHole(bag_idx);
+  }
+  OpenLock(l);
+  return true;
+}
+
+void CotOpenVanillaKeyDoor(int x, int y) {
+  struct tile* tile = CotTileAt(x, y);
+  if (tile == NULL || !tile->terrain_flags.f_key_door) {
+    return;
+  }
+  tile->terrain_flags.f_key_door = false;
+  tile->terrain_flags.f_key_door_key_locked = false;
+  tile->terrain_flags.f_key_door_escort_locked = false;
+  SetTileOpen(x, y, true);
+}
@@ -0,0 +1,51 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+int CotShowMenu(const menu_config* config, const menu_option* options, int n_options, void* ctx) {
+  if (n_options < 1 || n_options > COT_MAX_MENU_OPTIONS) {
+    COT_WARNFMT(COT_LOG_CAT_MENUS, "Invalid number of menu options: %d", n_options);
+    return COT_MENU_CANCELLED;
+  }
+
+  // Results are 1-based, so that 0 can't be mistaken for the first option
+  struct simple_menu_id_item items[COT_MAX_MENU_OPTIONS + 1];
+  for (int i = 0; i < n_options; i++) {
+    items[i] = (struct simple_menu_id_item) {
+      .string_id = options[i].string_id, .result_value = i + 1,
+    };
+  }
+  items[n_options] = (struct simple_menu_id_item) { .string_id = 0, .result_value = 0 };
+
+  struct window_params params = {
+    .x_offset = config->x,
+    .y_offset = config->y,
+    .width = config->width,
+  };
+  struct window_flags flags = {
+    .a_accept = true,
+    .b_cancel = config->cancelable,
+    .up_down_buttons = true,
+    .se_on = true,
+    .set_choice = true,
+    .menu_title = config->title_string_id != 0,
+  };
+  struct window_extra_info extra = {
+    .set_choice_index = config->initial_cursor,
+    .title_string_id = config->title_string_id,
+  };
+  int window_id = CreateSimpleMenuFromStringIds(&params, flags, &extra, items, n_options);
+  while (IsSimpleMenuActive(window_id)) {
+    AdvanceFrame(0);
+  }
+  int result = GetSimpleMenuResult(window_id);
+  CloseSimpleMenu(window_id);
+
+  if (result < 1 || result > n_options) {
+    return COT_MENU_CANCELLED;
+  }
+  int idx = result - 1;
+  if (options[idx].on_select != NULL) {
+    options[idx].on_select(idx, ctx);
+  }
+  return idx;
+}
@@ -0,0 +1,79 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+// Returns false if the argument was dropped.
+static bool AppendArg(str_buf* sb, const message_arg* arg, int* n_monsters) {
+  char tmp[32];
+  switch (arg->kind) {
+    case MSG_ARG_STRING:
+      CotStrBufAppend(sb, arg->string != NULL ? arg->string : "");
+      break;
+    case MSG_ARG_INT:
+      CotStrBufAppendInt(sb, arg->integer);
+      break;
+    case MSG_ARG_MONSTER:
+      if (*n_monsters >= COT_MSG_MAX_MONSTERS) {
+        COT_WARN(COT_LOG_CAT_MESSAGES, "Too many monsters in message");
+        return false;
+      }
+      SubstitutePlaceholderStringTags(*n_monsters, arg->monster, 0);
+      CotStrBufAppend(sb, "[string:");
+      CotStrBufAppendInt(sb, *n_monsters);
+      CotStrBufAppend(sb, "]");
+      (*n_monsters)++;
+      break;
+    case MSG_ARG_SPECIES:
+      GetNameRaw(tmp, arg->species);
+      CotStrBufAppend(sb, COT_TAG_COLOR("N"));
+      CotStrBufAppend(sb, tmp);
+      CotStrBufAppend(sb, COT_TAG_COLOR_RESET);
+      break;
+    case MSG_ARG_ITEM:
+      CotStrBufAppend(sb, COT_TAG_COLOR("M"));
+      CotStrBufAppend(sb, GetItemName(arg->item));
+      CotStrBufAppend(sb, COT_TAG_COLOR_RESET);
+      break;
+  }
+  return true;
+}
+
+bool CotStrBufAppendMessage(str_buf* sb, const char* fmt, const message_arg* args, int n_args) {
+  int next_arg = 0;
+  int n_monsters = 0;
+  bool missing_args = false;
+  bool dropped_args = false;
+  for (const char* c = fmt; *c != '\0' && !sb->truncated; c++) {
+    if (c[0] == '{' && c[1] == '}') {
+      if (next_arg < n_args) {
+        dropped_args |= !AppendArg(sb, &args[next_arg++], &n_monsters);
+      } else {
+        missing_args = true;
+      }
+      c++;
+      continue;
+    }
+    CotStrBufAppendChar(sb, *c);
+  }
+  if (sb->truncated || missing_args || dropped_args) {
+    COT_WARNFMT(COT_LOG_CAT_MESSAGES, "Could not fully format message '%s'", fmt);
+    return false;
+  }
+  return true;
+}
+
+bool CotFormatMessage(char* buf, int size, const char* fmt, const message_arg* args, int n_args) {
+  str_buf sb = CotStrBufInit(buf, size);
+  return CotStrBufAppendMessage(&sb, fmt, args, n_args);
+}
+
+void CotLogMessage(struct entity* entity, const char* fmt, const message_arg* args, int n_args) {
+  char buf[COT_MSG_BUFFER_SIZE];
+  CotFormatMessage(buf, sizeof(buf), fmt, args, n_args);
+  LogMessage(entity, buf, true);
+}
+
+void CotShowDialogueMessage(int dbox_id, const char* fmt, const message_arg* args, int n_args) {
+  char buf[COT_MSG_BUFFER_SIZE];
+  CotFormatMessage(buf, sizeof(buf), fmt, args, n_args);
+  ShowStringInDialogueBox(dbox_id, 0, buf, NULL);
+}
@@ -0,0 +1,129 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+static minigame_hooks hooks[COT_N_MINIGAMES];
+static minigame_state state;
+// Reward decided by the last MINIGAME_END opcode
+static enum item_id reward_item = ITEM_NOTHING;
+static int reward_money = 0;
+
+void CotSetMinigameHooks(minigame_kind kind, minigame_hooks new_hooks) {
+  if (kind >= 0 && kind < COT_N_MINIGAMES) {
+    hooks[kind] = new_hooks;
+  }
+}
+
+const minigame_state* CotGetMinigameState(void) {
+  return &state;
+}
+
+void CotMinigameStart(minigame_kind kind, int* n_rounds, int* time_limit) {
+  COT_ASSERT(kind >= 0 && kind < COT_N_MINIGAMES);
+  if (hooks[kind].difficulty != NULL) {
+    hooks[kind].difficulty(kind, n_rounds, time_limit);
+  }
+  state = (minigame_state) {
+    .active = true,
+    .kind = kind,
+    .round = 0,
+    .n_rounds = *n_rounds,
+    .time_limit = *time_limit,
+    .score = 0,
+  };
+  COT_LOGFMT(COT_LOG_CAT_MINIGAMES, "Minigame %d started with %d rounds", kind, *n_rounds);
+}
+
+void CotMinigameRound(int round, int* subject) {
+  if (!state.active) {
+    return;
+  }
+  state.round = round;
+  if (hooks[state.kind].inject_round != NULL) {
+    hooks[state.kind].inject_round(state.kind, round, subject);
+  }
+}
+
+void CotMinigameScore(int points) {
+  if (state.active) {
+    state.score += points;
+  }
+}
+
+bool CotMinigameEnd(enum item_id* reward_item, int* reward_money) {
+  if (!state.active) {
+    return false;
+  }
+  state.active = false;
+  COT_LOGFMT(COT_LOG_CAT_MINIGAMES, "Minigame %d ended with score %d", state.kind, state.score);
+  if (hooks[state.kind].reward != NULL) {
+    return hooks[state.kind].reward(state.kind, state.score, reward_item, reward_money);
+  }
+  return false;
+}
+
+static void Start(script_opcode_context* ctx) {
+  if (ctx->args[0] >= COT_N_MINIGAMES) {
+    COT_WARNFMT(COT_LOG_CAT_MINIGAMES, "Unknown minigame %d", ctx->args[0]);
+    return;
+  }
+  int n_rounds = ctx->args[1];
+  int time_limit = ctx->args[2];
+  CotMinigameStart(ctx->args[0], &n_rounds, &time_limit);
+  ctx->return_val = n_rounds;
+}
+
+static void TimeLimit(script_opcode_context* ctx) {
+  ctx->return_val = state.time_limit;
+}
+
+static void Round(script_opcode_context* ctx) {
+  int subject = 0;
+  CotMinigameRound(state.round + 1, &subject);
+  ctx->return_val = subject;
+}
+
+static void Score(script_opcode_context* ctx) {
+  CotMinigameScore(ctx->args[0]);
+  ctx->return_val = state.score;
+}
+
+static void End(script_opcode_context* ctx) {
+  reward_item = ITEM_NOTHING;
+  reward_money = 0;
+  ctx->return_val = CotMinigameEnd(&reward_item, &reward_money);
+}
+
+static void RewardItem(script_opcode_context* ctx) {
+  ctx->return_val = reward_item;
+}
+
+static void RewardMoney(script_opcode_context* ctx) {
+  ctx->return_val = reward_money;
+}
+
+static const struct {
+  uint16_t opcode;
+  int n_args;
+  script_opcode_fn handler;
+} opcodes[] = {
+  { COT_SCRIPT_OPCODE_MINIGAME_START, 3, Start },
+  { COT_SCRIPT_OPCODE_MINIGAME_TIME_LIMIT, 0, TimeLimit },
+  { COT_SCRIPT_OPCODE_MINIGAME_ROUND, 0, Round },
+  { COT_SCRIPT_OPCODE_MINIGAME_SCORE, 1, Score },
+  { COT_SCRIPT_OPCODE_MINIGAME_END, 0, End },
+  { COT_SCRIPT_OPCODE_MINIGAME_REWARD_ITEM, 0, RewardItem },
+  { COT_SCRIPT_OPCODE_MINIGAME_REWARD_MONEY, 0, RewardMoney },
+};
+
+bool CotInstallMinigames(void) {
+  int n = sizeof(opcodes) / sizeof(opcodes[0]);
+  for (int i = 0; i < n; i++) {
+    if (!CotRegisterScriptOpcode(opcodes[i].opcode, opcodes[i].n_args, opcodes[i].handler)) {
+      while (--i >= 0) {
+        CotUnregisterScriptOpcode(opcodes[i].opcode);
+      }
+      return false;
+    }
+  }
+  return true;
+}
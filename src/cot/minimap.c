@@ -0,0 +1,98 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+static floor_bitset revealed;
+static floor_bitset hidden;
+static bool fully_revealed = false;
+static bool reset_hook_added = false;
+
+static void ResetOnNewFloor(struct floor_properties* props) {
+  (void) props;
+  CotResetMinimap();
+}
+
+// Overrides are reset on every new floor once the first one is set
+static void AddResetHook(void) {
+  if (!reset_hook_added) {
+    reset_hook_added = CotAddPreGenerateHook(ResetOnNewFloor);
+    if (!reset_hook_added) {
+      COT_WARN(COT_LOG_CAT_MINIMAP, "Can't add the minimap reset hook");
+    }
+  }
+}
+
+// The minimap only exists once the floor has been generated
+static void Redraw(int x, int y) {
+  if (DUNGEON_PTR != NULL && !CotIsGeneratingFloor()) {
+    DrawMinimapTile(x, y);
+  }
+}
+
+static void RedrawAll(void) {
+  for (int y = 0; y < COT_FLOOR_HEIGHT; y++) {
+    for (int x = 0; x < COT_FLOOR_WIDTH; x++) {
+      Redraw(x, y);
+    }
+  }
+}
+
+void CotRevealTile(int x, int y) {
+  if (CotTileInBounds(x, y)) {
+    AddResetHook();
+    CotBitsetSet(&revealed, x, y);
+    CotBitsetUnset(&hidden, x, y);
+    Redraw(x, y);
+  }
+}
+
+void CotHideTile(int x, int y) {
+  if (CotTileInBounds(x, y)) {
+    AddResetHook();
+    CotBitsetSet(&hidden, x, y);
+    CotBitsetUnset(&revealed, x, y);
+    Redraw(x, y);
+  }
+}
+
+void CotResetTileVisibility(int x, int y) {
+  if (CotTileInBounds(x, y)) {
+    CotBitsetUnset(&revealed, x, y);
+    CotBitsetUnset(&hidden, x, y);
+    Redraw(x, y);
+  }
+}
+
+void CotRevealArea(int x, int y, int w, int h) {
+  for (int j = y; j < y + h; j++) {
+    for (int i = x; i < x + w; i++) {
+      CotRevealTile(i, j);
+    }
+  }
+}
+
+void CotSetMapFullyRevealed(bool revealed) {
+  AddResetHook();
+  fully_revealed = revealed;
+  RedrawAll();
+}
+
+void CotResetMinimap(void) {
+  CotBitsetClear(&revealed);
+  CotBitsetClear(&hidden);
+  fully_revealed = false;
+}
+
+bool cotInternalIsTileRevealed(int x, int y, bool vanilla) {
+  if (CotBitsetTest(&hidden, x, y)) {
+    return false;
+  }
+  return vanilla || fully_revealed || CotBitsetTest(&revealed, x, y);
+}
+
+// Draws the tile with its revealed flag set to the overridden value
+void cotInternalDrawMinimapTile(int x, int y, void (*draw)(int x, int y)) {
+  bool vanilla = CotTileIsRevealed(x, y);
+  CotSetTileRevealed(x, y, cotInternalIsTileRevealed(x, y, vanilla));
+  draw(x, y);
+  CotSetTileRevealed(x, y, vanilla);
+}
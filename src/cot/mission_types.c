@@ -0,0 +1,228 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+#define MISSIONS_VERSION 2
+
+typedef struct tracked_mission {
+  bool in_use;
+  mission_progress progress;
+} tracked_mission;
+
+// Vanilla mission marked complete by CotCompleteMission
+typedef struct completed_mission {
+  bool in_use;
+  struct mission mission;
+} completed_mission;
+
+static const custom_mission_type* mission_types[COT_MAX_MISSION_TYPES];
+static int n_mission_types = 0;
+// Missions are stored as copies, since job list entries move when jobs are removed. Saved
+// through CotMissionsRegisterSaveBlock.
+static struct {
+  tracked_mission active[COT_MAX_ACTIVE_MISSIONS];
+  completed_mission completed[COT_MAX_ACTIVE_MISSIONS];
+} state;
+
+// Whether `a` and `b` are the same mission. The status changes over a mission's lifetime, so
+// it's ignored.
+static bool SameMission(const struct mission* a, const struct mission* b) {
+  struct mission a_copy = *a;
+  a_copy.status = b->status;
+  return memcmp(&a_copy, b, sizeof(a_copy)) == 0;
+}
+
+bool CotRegisterMissionType(const custom_mission_type* type) {
+  if (type->id < COT_N_VANILLA_MISSION_TYPES) {
+    COT_WARNFMT(COT_LOG_CAT_MISSIONS, "Mission type %d is a vanilla type", type->id);
+    return false;
+  }
+  if (CotGetMissionType(type->id) != NULL) {
+    COT_WARNFMT(COT_LOG_CAT_MISSIONS, "Mission type %d is already registered", type->id);
+    return false;
+  }
+  if (n_mission_types >= COT_MAX_MISSION_TYPES) {
+    COT_WARN(COT_LOG_CAT_MISSIONS, "Mission type table is full");
+    return false;
+  }
+  mission_types[n_mission_types++] = type;
+  return true;
+}
+
+const custom_mission_type* CotGetMissionType(int id) {
+  for (int i = 0; i < n_mission_types; i++) {
+    if (mission_types[i]->id == id) {
+      return mission_types[i];
+    }
+  }
+  return NULL;
+}
+
+static tracked_mission* FindTrackedMission(struct mission* mission) {
+  for (int i = 0; i < COT_MAX_ACTIVE_MISSIONS; i++) {
+    tracked_mission* tracked = &state.active[i];
+    if (tracked->in_use && SameMission(&tracked->progress.mission, mission)) {
+      return tracked;
+    }
+  }
+  return NULL;
+}
+
+mission_progress* CotGetMissionProgress(struct mission* mission) {
+  tracked_mission* tracked = FindTrackedMission(mission);
+  return tracked != NULL ? &tracked->progress : NULL;
+}
+
+static const custom_mission_type* GetType(mission_progress* progress) {
+  return CotGetMissionType(progress->type);
+}
+
+static tracked_mission* FindFreeSlot(void) {
+  for (int i = 0; i < COT_MAX_ACTIVE_MISSIONS; i++) {
+    if (!state.active[i].in_use) {
+      return &state.active[i];
+    }
+  }
+  return NULL;
+}
+
+bool cotInternalOnMissionAccepted(struct mission* mission) {
+  int type_id = mission->type.val;
+  if (type_id < COT_N_VANILLA_MISSION_TYPES) {
+    return true;
+  }
+  const custom_mission_type* type = CotGetMissionType(type_id);
+  if (type == NULL) {
+    COT_WARNFMT(COT_LOG_CAT_MISSIONS, "Rejecting mission of unknown type %d", type_id);
+    return false;
+  }
+  if (type->can_accept != NULL && !type->can_accept(mission)) {
+    return false;
+  }
+  tracked_mission* slot = FindFreeSlot();
+  if (slot == NULL) {
+    COT_WARN(COT_LOG_CAT_MISSIONS, "Too many custom missions accepted");
+    return false;
+  }
+  mission->type.val = type->vanilla_type;
+  *slot = (tracked_mission) {
+    .in_use = true,
+    .progress = { .mission = *mission, .type = type_id, .progress = 0, .complete = false },
+  };
+  return true;
+}
+
+void cotInternalOnMissionFloorStart(enum dungeon_id dungeon, int floor) {
+  for (int i = 0; i < COT_MAX_ACTIVE_MISSIONS; i++) {
+    if (!state.active[i].in_use) {
+      continue;
+    }
+    mission_progress* progress = &state.active[i].progress;
+    const custom_mission_type* type = GetType(progress);
+    if (type != NULL && type->on_floor_start != NULL) {
+      type->on_floor_start(progress, dungeon, floor);
+    }
+  }
+}
+
+void cotInternalOnMissionMonsterFainted(struct entity* monster) {
+  for (int i = 0; i < COT_MAX_ACTIVE_MISSIONS; i++) {
+    if (!state.active[i].in_use) {
+      continue;
+    }
+    mission_progress* progress = &state.active[i].progress;
+    const custom_mission_type* type = GetType(progress);
+    if (type != NULL && type->on_monster_fainted != NULL) {
+      type->on_monster_fainted(progress, monster);
+    }
+  }
+}
+
+static completed_mission* FindCompletedMission(struct mission* mission) {
+  for (int i = 0; i < COT_MAX_ACTIVE_MISSIONS; i++) {
+    completed_mission* completed = &state.completed[i];
+    if (completed->in_use && SameMission(&completed->mission, mission)) {
+      return completed;
+    }
+  }
+  return NULL;
+}
+
+static completed_mission* FindFreeCompletedSlot(void) {
+  for (int i = 0; i < COT_MAX_ACTIVE_MISSIONS; i++) {
+    if (!state.completed[i].in_use) {
+      return &state.completed[i];
+    }
+  }
+  return NULL;
+}
+
+// Marks the job list entry of `mission` as done, so the game hands out its reward when the
+// player reports back.
+static void SetJobDone(struct mission* mission) {
+  for (int i = 0; i < COT_JOB_LIST_SIZE; i++) {
+    struct mission* job = CotGetJob(i);
+    if (job != NULL && SameMission(job, mission)) {
+      job->status.val = MISSION_STATUS_DONE;
+      return;
+    }
+  }
+}
+
+bool CotCompleteMission(struct mission* mission) {
+  SetJobDone(mission);
+  mission_progress* progress = CotGetMissionProgress(mission);
+  if (progress != NULL) {
+    progress->complete = true;
+    return true;
+  }
+  if (FindCompletedMission(mission) != NULL) {
+    return true;
+  }
+  completed_mission* slot = FindFreeCompletedSlot();
+  if (slot == NULL) {
+    COT_WARN(COT_LOG_CAT_MISSIONS, "Too many missions marked complete");
+    return false;
+  }
+  *slot = (completed_mission) { .in_use = true, .mission = *mission };
+  return true;
+}
+
+bool CotIsMissionComplete(struct mission* mission) {
+  mission_progress* progress = CotGetMissionProgress(mission);
+  if (progress != NULL) {
+    return progress->complete;
+  }
+  return FindCompletedMission(mission) != NULL;
+}
+
+bool CotClaimMissionReward(struct mission* mission, int* money, enum item_id* item) {
+  if (!CotIsMissionComplete(mission)) {
+    return false;
+  }
+  completed_mission* completed = FindCompletedMission(mission);
+  if (completed != NULL) {
+    completed->in_use = false;
+  }
+  tracked_mission* tracked = FindTrackedMission(mission);
+  if (tracked == NULL) {
+    return true;
+  }
+  const custom_mission_type* type = GetType(&tracked->progress);
+  if (type != NULL && type->reward != NULL) {
+    type->reward(&tracked->progress, money, item);
+  }
+  // The mission is done once its reward has been handed out
+  tracked->in_use = false;
+  return true;
+}
+
+static const save_block missions_save_block = {
+  .tag = COT_SAVE_TAG('M', 'S', 'N', 'S'),
+  .version = MISSIONS_VERSION,
+  .size = sizeof(state),
+  .data = &state,
+};
+
+bool CotMissionsRegisterSaveBlock(void) {
+  return CotRegisterSaveBlock(&missions_save_block);
+}
@@ -0,0 +1,66 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+void CotMissionInit(mission_builder* builder, enum mission_type type, enum dungeon_id dungeon, int floor) {
+  memset(&builder->mission, 0, sizeof(builder->mission));
+  builder->mission.type.val = type;
+  builder->mission.dungeon_id.val = dungeon;
+  builder->mission.floor = floor;
+}
+
+void CotMissionSetClient(mission_builder* builder, enum monster_id client) {
+  builder->mission.client.val = client;
+}
+
+void CotMissionSetTarget(mission_builder* builder, enum monster_id target) {
+  builder->mission.target.val = target;
+}
+
+void CotMissionSetItemReward(mission_builder* builder, enum item_id item) {
+  builder->mission.reward_type.val = MISSION_REWARD_ITEM;
+  builder->mission.item_reward.val = item;
+}
+
+struct mission* CotAddMission(const mission_builder* builder) {
+  if (CotCountJobs() >= COT_JOB_LIST_SIZE) {
+    COT_WARN(COT_LOG_CAT_MISSIONS, "Job list is full");
+    return NULL;
+  }
+  bool occupied[COT_JOB_LIST_SIZE];
+  for (int i = 0; i < COT_JOB_LIST_SIZE; i++) {
+    occupied[i] = CotGetJob(i) != NULL;
+  }
+  struct mission mission = builder->mission;
+  AddMissionToJobList(&mission);
+
+  // The job list stores a copy, so find the slot it went into. There's none if the mission
+  // was rejected.
+  struct mission* job = NULL;
+  for (int i = 0; i < COT_JOB_LIST_SIZE && job == NULL; i++) {
+    if (!occupied[i]) {
+      job = CotGetJob(i);
+    }
+  }
+  return job;
+}
+
+int CotCountJobs(void) {
+  return CountJobListMissions();
+}
+
+struct mission* CotGetJob(int idx) {
+  if (idx < 0 || idx >= COT_JOB_LIST_SIZE) {
+    return NULL;
+  }
+  struct mission* mission = GetAcceptedMission(idx);
+  return mission->status.val != MISSION_STATUS_INVALID ? mission : NULL;
+}
+
+void CotForEachJob(void (*fn)(struct mission* mission, void* ctx), void* ctx) {
+  for (int i = 0; i < COT_JOB_LIST_SIZE; i++) {
+    struct mission* mission = CotGetJob(i);
+    if (mission != NULL) {
+      fn(mission, ctx);
+    }
+  }
+}
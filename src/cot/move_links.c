@@ -0,0 +1,87 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+static move_link_validator_fn link_validator = NULL;
+
+void CotSetMoveLinkValidator(move_link_validator_fn validator) {
+  link_validator = validator;
+}
+
+static bool IsValidRange(struct ground_monster* member, int first, int last) {
+  if (first < 0 || last >= COT_N_MOVE_SLOTS || first >= last) {
+    return false;
+  }
+  for (int i = first; i <= last; i++) {
+    if (!member->moves[i].f_exists) {
+      return false;
+    }
+  }
+  return true;
+}
+
+bool cotInternalValidateMoveLink(struct ground_monster* member, int first, int last) {
+  if (!IsValidRange(member, first, last)) {
+    return false;
+  }
+  return link_validator == NULL || link_validator(member, first, last);
+}
+
+bool CotGetMoveChain(struct ground_monster* member, int slot, int* first, int* last) {
+  if (slot < 0 || slot >= COT_N_MOVE_SLOTS) {
+    COT_WARNFMT(COT_LOG_CAT_MOVE_LINKS, "Invalid move slot %d", slot);
+    return false;
+  }
+  int start = slot;
+  while (start > 0 && member->moves[start].f_linked) {
+    start--;
+  }
+  int end = slot;
+  while (end + 1 < COT_N_MOVE_SLOTS && member->moves[end + 1].f_linked) {
+    end++;
+  }
+  *first = start;
+  *last = end;
+  return true;
+}
+
+bool CotLinkMoves(struct ground_monster* member, int first, int last) {
+  if (!cotInternalValidateMoveLink(member, first, last)) {
+    COT_WARNFMT(COT_LOG_CAT_MOVE_LINKS, "Can't link moves %d to %d", first, last);
+    return false;
+  }
+  for (int i = first + 1; i <= last; i++) {
+    member->moves[i].f_linked = true;
+  }
+  return true;
+}
+
+void CotDelinkMoves(struct ground_monster* member, int slot) {
+  int first, last;
+  if (!CotGetMoveChain(member, slot, &first, &last)) {
+    return;
+  }
+  for (int i = first; i <= last; i++) {
+    member->moves[i].f_linked = false;
+  }
+}
+
+void cotInternalOnMovesetToDungeon(struct ground_monster* member) {
+  int slot = 0;
+  while (slot < COT_N_MOVE_SLOTS) {
+    int first, last;
+    CotGetMoveChain(member, slot, &first, &last);
+    if (last > first && !cotInternalValidateMoveLink(member, first, last)) {
+      COT_LOGFMT(COT_LOG_CAT_MOVE_LINKS, "Breaking rejected link of moves %d to %d", first, last);
+      CotDelinkMoves(member, first);
+    }
+    slot = last + 1;
+  }
+}
+
+void CotLinkAllMoves(struct ground_monster* member) {
+  for (int i = 1; i < COT_N_MOVE_SLOTS; i++) {
+    if (member->moves[i].f_exists) {
+      member->moves[i].f_linked = true;
+    }
+  }
+}
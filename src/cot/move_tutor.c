@@ -0,0 +1,143 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+bool CotHasMove(struct ground_monster* member, enum move_id move) {
+  for (int i = 0; i < COT_N_MOVE_SLOTS; i++) {
+    if (member->moves[i].f_exists && member->moves[i].id.val == move) {
+      return true;
+    }
+  }
+  return false;
+}
+
+bool CotTeachMove(struct ground_monster* member, enum move_id move, int slot) {
+  if (CotHasMove(member, move)) {
+    return false;
+  }
+  if (slot == COT_FIRST_FREE_SLOT) {
+    for (int i = 0; i < COT_N_MOVE_SLOTS; i++) {
+      if (!member->moves[i].f_exists) {
+        slot = i;
+        break;
+      }
+    }
+  }
+  if (slot < 0 || slot >= COT_N_MOVE_SLOTS) {
+    COT_WARNFMT(COT_LOG_CAT_MOVE_TUTOR, "No slot to teach move %d", move);
+    return false;
+  }
+
+  // A replaced move must not stay part of a link chain
+  if (member->moves[slot].f_exists) {
+    CotDelinkMoves(member, slot);
+  }
+  memset(&member->moves[slot], 0, sizeof(member->moves[slot]));
+  member->moves[slot].f_exists = true;
+  member->moves[slot].id.val = move;
+  COT_LOGFMT(COT_LOG_CAT_MOVE_TUTOR, "Taught move %d in slot %d", move, slot);
+  return true;
+}
+
+bool CotForgetMove(struct ground_monster* member, int slot) {
+  if (slot < 0 || slot >= COT_N_MOVE_SLOTS || !member->moves[slot].f_exists) {
+    COT_WARNFMT(COT_LOG_CAT_MOVE_TUTOR, "No move to forget in slot %d", slot);
+    return false;
+  }
+  int n_moves = 0;
+  for (int i = 0; i < COT_N_MOVE_SLOTS; i++) {
+    n_moves += member->moves[i].f_exists;
+  }
+  if (n_moves == 1) {
+    COT_WARN(COT_LOG_CAT_MOVE_TUTOR, "Can't forget the only move");
+    return false;
+  }
+
+  CotDelinkMoves(member, slot);
+  for (int i = slot; i + 1 < COT_N_MOVE_SLOTS; i++) {
+    member->moves[i] = member->moves[i + 1];
+  }
+  memset(&member->moves[COT_N_MOVE_SLOTS - 1], 0, sizeof(member->moves[0]));
+  COT_LOGFMT(COT_LOG_CAT_MOVE_TUTOR, "Forgot the move in slot %d", slot);
+  return true;
+}
+
+int CotGetRemindableMoves(struct ground_monster* member, enum move_id* out, int max) {
+  enum move_id level_up_moves[COT_MAX_REMINDABLE_MOVES];
+  int n_level_up_moves = CotGetLevelUpMoves(
+    member->id.val, member->level, level_up_moves, COT_MAX_REMINDABLE_MOVES
+  );
+  int count = 0;
+  for (int i = 0; i < n_level_up_moves && count < max; i++) {
+    if (!CotHasMove(member, level_up_moves[i])) {
+      out[count++] = level_up_moves[i];
+    }
+  }
+  return count;
+}
+
+int CotGetTutorMoves(const move_tutor* tutor, struct ground_monster* member, enum move_id* out, int max) {
+  if (tutor->moves == NULL) {
+    return CotGetRemindableMoves(member, out, max);
+  }
+  int count = 0;
+  for (int i = 0; i < tutor->n_moves && count < max; i++) {
+    enum move_id move = tutor->moves[i];
+    if (CotHasMove(member, move)) {
+      continue;
+    }
+    if (tutor->can_learn == NULL || tutor->can_learn(member, move)) {
+      out[count++] = move;
+    }
+  }
+  return count;
+}
+
+static bool IsOffered(const move_tutor* tutor, struct ground_monster* member, enum move_id move) {
+  if (tutor->moves == NULL) {
+    enum move_id remindable[COT_MAX_REMINDABLE_MOVES];
+    int n = CotGetRemindableMoves(member, remindable, COT_MAX_REMINDABLE_MOVES);
+    for (int i = 0; i < n; i++) {
+      if (remindable[i] == move) {
+        return true;
+      }
+    }
+    return false;
+  }
+  for (int i = 0; i < tutor->n_moves; i++) {
+    if (tutor->moves[i] == move) {
+      return !CotHasMove(member, move)
+             && (tutor->can_learn == NULL || tutor->can_learn(member, move));
+    }
+  }
+  return false;
+}
+
+int CotGetTutorCost(const move_tutor* tutor, struct ground_monster* member, enum move_id move) {
+  return tutor->cost != NULL ? tutor->cost(member, move) : 0;
+}
+
+tutor_result CotRunTutor(const move_tutor* tutor, struct ground_monster* member, enum move_id move,
+                         int slot) {
+  if (!IsOffered(tutor, member, move)) {
+    return TUTOR_NOT_OFFERED;
+  }
+  int cost = CotGetTutorCost(tutor, member, move);
+  if (GetMoneyCarried() < cost) {
+    return TUTOR_NOT_ENOUGH_MONEY;
+  }
+  if (tutor->cost_item != ITEM_NOTHING && CotFindBagItem(tutor->cost_item) < 0) {
+    return TUTOR_MISSING_ITEM;
+  }
+  if (tutor->prompt_message_id != 0 && YesNoMenu(0, tutor->prompt_message_id, 0, 0) != 1) {
+    return TUTOR_DECLINED;
+  }
+  if (!CotTeachMove(member, move, slot)) {
+    return TUTOR_NO_SLOT;
+  }
+
+  SetMoneyCarried(GetMoneyCarried() - cost);
+  if (tutor->cost_item != ITEM_NOTHING) {
+    RemoveItemNoHole(CotFindBagItem(tutor->cost_item));
+  }
+  return TUTOR_TAUGHT;
+}
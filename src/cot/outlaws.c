@@ -0,0 +1,95 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+typedef struct active_outlaw {
+  struct entity* entity;
+  const outlaw_encounter* outlaw;
+} active_outlaw;
+
+static const outlaw_encounter* outlaws[COT_MAX_OUTLAWS];
+static int n_outlaws = 0;
+// Outlaws spawned on the current floor
+static active_outlaw active[COT_MAX_OUTLAWS];
+static int n_active = 0;
+// Announcements wait for the team, which is placed after the post-generate hooks ran
+static bool announce_pending = false;
+
+bool CotRegisterOutlaw(const outlaw_encounter* outlaw) {
+  if (n_outlaws >= COT_MAX_OUTLAWS) {
+    COT_WARN(COT_LOG_CAT_OUTLAWS, "Outlaw table is full");
+    return false;
+  }
+  outlaws[n_outlaws++] = outlaw;
+  return true;
+}
+
+static void SpawnOutlaws(struct floor_properties* props) {
+  enum dungeon_id dungeon = DUNGEON_PTR->id.val;
+  int floor = DUNGEON_PTR->floor;
+  n_active = 0;
+  for (int i = 0; i < n_outlaws; i++) {
+    const outlaw_encounter* outlaw = outlaws[i];
+    if (outlaw->dungeon != dungeon || outlaw->floor != floor) {
+      continue;
+    }
+    monster_spawn spawn;
+    CotSpawnInit(&spawn, outlaw->species);
+    CotSpawnSetLevel(&spawn, outlaw->level);
+    CotSpawnSetHeldItem(&spawn, outlaw->held_item);
+    CotSpawnSetBehavior(&spawn, outlaw->flees ? BEHAVIOR_FLEEING_OUTLAW : BEHAVIOR_OUTLAW);
+    CotSpawnSetCanBeAsleep(&spawn, false);
+    struct entity* entity = CotSpawn(&spawn);
+    if (entity == NULL) {
+      continue;
+    }
+    active[n_active++] = (active_outlaw) { .entity = entity, .outlaw = outlaw };
+  }
+  announce_pending = n_active > 0;
+}
+
+bool CotInstallOutlaws(void) {
+  return CotAddPostGenerateHook(SpawnOutlaws);
+}
+
+void cotInternalAnnounceOutlaws(void) {
+  if (!announce_pending) {
+    return;
+  }
+  announce_pending = false;
+  for (int i = 0; i < n_active; i++) {
+    if (active[i].outlaw->announcement != NULL) {
+      LogMessage(GetLeader(), active[i].outlaw->announcement, true);
+    }
+  }
+}
+
+bool CotIsOutlaw(struct entity* entity) {
+  for (int i = 0; i < n_active; i++) {
+    if (active[i].entity == entity) {
+      return true;
+    }
+  }
+  return false;
+}
+
+void cotInternalOnOutlawFainted(struct entity* entity, struct entity* killer) {
+  for (int i = 0; i < n_active; i++) {
+    if (active[i].entity != entity) {
+      continue;
+    }
+    const outlaw_encounter* outlaw = active[i].outlaw;
+    active[i] = active[--n_active];
+    if (!CotFaintedByTeam(killer)) {
+      COT_LOGFMT(COT_LOG_CAT_OUTLAWS, "Outlaw %d escaped", outlaw->species);
+      return;
+    }
+    COT_LOGFMT(COT_LOG_CAT_OUTLAWS, "Outlaw %d captured", outlaw->species);
+    if (outlaw->mission != NULL) {
+      CotCompleteMission(outlaw->mission);
+    }
+    if (outlaw->on_captured != NULL) {
+      outlaw->on_captured(outlaw);
+    }
+    return;
+  }
+}
@@ -0,0 +1,121 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+#define N_TILES (COT_FLOOR_WIDTH * COT_FLOOR_HEIGHT)
+#define NO_PARENT 0xFFFF
+
+// Too large for the stack
+static uint16_t parents[N_TILES];
+static uint16_t queue[N_TILES];
+// Number of tiles in `queue` after the last search
+static int n_visited;
+
+static bool IsFloor(int x, int y) {
+  return CotTileIsFloor(x, y);
+}
+
+static bool CanMove(path_walkable_fn walkable, int x, int y, int dx, int dy) {
+  if (!CotTileInBounds(x + dx, y + dy) || !walkable(x + dx, y + dy)) {
+    return false;
+  }
+  // Diagonal moves can't cut wall corners
+  return dx == 0 || dy == 0 || (!CotTileIsWall(x + dx, y) && !CotTileIsWall(x, y + dy));
+}
+
+// Breadth-first search from `from`. Stops early once `to` is reached, if it's in bounds.
+static bool Search(struct position from, struct position to, path_walkable_fn walkable) {
+  if (walkable == NULL) {
+    walkable = IsFloor;
+  }
+  for (int i = 0; i < N_TILES; i++) {
+    parents[i] = NO_PARENT;
+  }
+  n_visited = 0;
+  if (!CotTileInBounds(from.x, from.y)) {
+    return false;
+  }
+  int start = from.y * COT_FLOOR_WIDTH + from.x;
+  int goal = CotTileInBounds(to.x, to.y) ? to.y * COT_FLOOR_WIDTH + to.x : -1;
+  int head = 0;
+  int tail = 0;
+  parents[start] = start;
+  queue[tail++] = start;
+  while (head < tail) {
+    int idx = queue[head++];
+    n_visited = tail;
+    if (idx == goal) {
+      return true;
+    }
+    int x = idx % COT_FLOOR_WIDTH;
+    int y = idx / COT_FLOOR_WIDTH;
+    for (int d = 0; d < COT_N_DIRECTIONS; d++) {
+      // Straight directions first, so paths prefer straight moves over diagonal ones
+      struct position offset = CotDirectionOffset((enum direction_id) (d * 2 + d / 4));
+      int next = (y + offset.y) * COT_FLOOR_WIDTH + x + offset.x;
+      if (CanMove(walkable, x, y, offset.x, offset.y) && parents[next] == NO_PARENT) {
+        parents[next] = idx;
+        queue[tail++] = next;
+      }
+    }
+  }
+  n_visited = tail;
+  return false;
+}
+
+static int PathLength(int start, int goal) {
+  int n = 0;
+  for (int i = goal; i != start; i = parents[i]) {
+    n++;
+  }
+  return n;
+}
+
+bool CotFindPath(struct position from, struct position to, path_walkable_fn walkable, tile_path* path) {
+  COT_VEC_CLEAR(path);
+  if (!Search(from, to, walkable)) {
+    return false;
+  }
+  int start = from.y * COT_FLOOR_WIDTH + from.x;
+  int goal = to.y * COT_FLOOR_WIDTH + to.x;
+  int n = PathLength(start, goal);
+  if (n > COT_MAX_PATH_LENGTH) {
+    return false;
+  }
+  // Walk back from the goal, filling the path from its end
+  path->len = n;
+  for (int i = goal; i != start; i = parents[i]) {
+    path->items[--n] = (struct position) { .x = i % COT_FLOOR_WIDTH, .y = i / COT_FLOOR_WIDTH };
+  }
+  return true;
+}
+
+int CotPathDistance(struct position from, struct position to, path_walkable_fn walkable) {
+  if (!Search(from, to, walkable)) {
+    return COT_UNREACHABLE;
+  }
+  return PathLength(from.y * COT_FLOOR_WIDTH + from.x, to.y * COT_FLOOR_WIDTH + to.x);
+}
+
+void CotDistanceMap(struct position from, path_walkable_fn walkable,
+                    int16_t distances[COT_FLOOR_HEIGHT][COT_FLOOR_WIDTH]) {
+  struct position nowhere = { .x = -1, .y = -1 };
+  Search(from, nowhere, walkable);
+  for (int y = 0; y < COT_FLOOR_HEIGHT; y++) {
+    for (int x = 0; x < COT_FLOOR_WIDTH; x++) {
+      distances[y][x] = COT_UNREACHABLE;
+    }
+  }
+  // Tiles are dequeued in order of distance, so each one's parent already has its distance
+  int start = from.y * COT_FLOOR_WIDTH + from.x;
+  for (int i = 0; i < n_visited; i++) {
+    int idx = queue[i];
+    int parent = parents[idx];
+    distances[idx / COT_FLOOR_WIDTH][idx % COT_FLOOR_WIDTH] =
+      idx == start ? 0 : distances[parent / COT_FLOOR_WIDTH][parent % COT_FLOOR_WIDTH] + 1;
+  }
+}
+
+bool CotStairsReachable(bool mark_unreachable) {
+  struct position stairs = DUNGEON_PTR->gen_info.stairs_pos;
+  return StairsAlwaysReachable(stairs.x, stairs.y, mark_unreachable);
+}
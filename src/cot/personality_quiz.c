@@ -0,0 +1,84 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+static struct {
+  uint16_t hero_starters[COT_QUIZ_N_HERO_STARTERS];
+  uint16_t partner_starters[COT_QUIZ_N_PARTNER_STARTERS];
+  int16_t question_strings[COT_QUIZ_N_QUESTIONS];
+  struct {
+    int16_t answer;
+    uint8_t nature;
+    uint8_t points;
+  } points[COT_QUIZ_MAX_POINT_OVERRIDES];
+  int n_points;
+} overrides;
+
+// MONSTER_NONE and string 0 are never valid quiz values, so they mark entries without an override.
+#define NO_OVERRIDE 0
+
+void CotSetQuizHeroStarter(int nature, bool female, enum monster_id monster) {
+  if (nature >= 0 && nature < COT_QUIZ_N_NATURES) {
+    overrides.hero_starters[nature * 2 + (female ? 1 : 0)] = monster;
+  }
+}
+
+void CotSetQuizPartnerStarter(int index, enum monster_id monster) {
+  if (index >= 0 && index < COT_QUIZ_N_PARTNER_STARTERS) {
+    overrides.partner_starters[index] = monster;
+  }
+}
+
+void CotSetQuizQuestionString(int question, int string_id) {
+  if (question >= 0 && question < COT_QUIZ_N_QUESTIONS) {
+    overrides.question_strings[question] = string_id;
+  }
+}
+
+bool CotSetQuizAnswerPoints(int answer, int nature, uint8_t points) {
+  if (answer < 0 || answer >= COT_QUIZ_N_ANSWERS || nature < 0 || nature >= COT_QUIZ_N_NATURES) {
+    COT_WARNFMT(COT_LOG_CAT_QUIZ, "Invalid answer %d or nature %d", answer, nature);
+    return false;
+  }
+  for (int i = 0; i < overrides.n_points; i++) {
+    if (overrides.points[i].answer == answer && overrides.points[i].nature == nature) {
+      overrides.points[i].points = points;
+      return true;
+    }
+  }
+  if (overrides.n_points >= COT_QUIZ_MAX_POINT_OVERRIDES) {
+    COT_WARN(COT_LOG_CAT_QUIZ, "Answer point override table is full");
+    return false;
+  }
+  overrides.points[overrides.n_points].answer = answer;
+  overrides.points[overrides.n_points].nature = nature;
+  overrides.points[overrides.n_points].points = points;
+  overrides.n_points++;
+  return true;
+}
+
+void CotResetQuizOverrides(void) {
+  memset(&overrides, 0, sizeof(overrides));
+}
+
+void CotApplyQuizOverrides(void) {
+  for (int i = 0; i < COT_QUIZ_N_HERO_STARTERS; i++) {
+    if (overrides.hero_starters[i] != NO_OVERRIDE) {
+      STARTERS_HERO_IDS[i].val = overrides.hero_starters[i];
+    }
+  }
+  for (int i = 0; i < COT_QUIZ_N_PARTNER_STARTERS; i++) {
+    if (overrides.partner_starters[i] != NO_OVERRIDE) {
+      STARTERS_PARTNER_IDS[i].val = overrides.partner_starters[i];
+    }
+  }
+  for (int i = 0; i < COT_QUIZ_N_QUESTIONS; i++) {
+    if (overrides.question_strings[i] != NO_OVERRIDE) {
+      QUIZ_QUESTION_STRINGS[i] = overrides.question_strings[i];
+    }
+  }
+  for (int i = 0; i < overrides.n_points; i++) {
+    QUIZ_ANSWER_POINTS[overrides.points[i].answer][overrides.points[i].nature] =
+      overrides.points[i].points;
+  }
+  COT_LOG(COT_LOG_CAT_QUIZ, "Applied personality quiz overrides");
+}
@@ -0,0 +1,102 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+typedef struct puzzle_switch {
+  struct position pos;
+  puzzle_switch_kind kind;
+  uint8_t circuit;
+  bool on;
+  // Whether a monster stood on the switch during the last update.
+  bool occupied;
+} puzzle_switch;
+
+typedef struct puzzle_door {
+  struct position pos;
+  uint8_t circuit;
+  bool open;
+} puzzle_door;
+
+static puzzle_switch switches[COT_MAX_PUZZLE_SWITCHES];
+static int n_switches = 0;
+static puzzle_door doors[COT_MAX_PUZZLE_DOORS];
+static int n_doors = 0;
+static puzzle_door_fn door_callback = NULL;
+
+bool CotPlacePuzzleSwitch(int x, int y, puzzle_switch_kind kind, int circuit) {
+  if (n_switches >= COT_MAX_PUZZLE_SWITCHES || circuit < 0 || circuit >= COT_MAX_PUZZLE_CIRCUITS) {
+    COT_WARNFMT(COT_LOG_CAT_PUZZLES, "Can't place switch at (%d, %d)", x, y);
+    return false;
+  }
+  switches[n_switches++] = (puzzle_switch) {
+    .pos = { .x = x, .y = y },
+    .kind = kind,
+    .circuit = circuit,
+  };
+  return true;
+}
+
+static void SetDoorTerrain(puzzle_door* door, bool open) {
+  CotSetTileTerrain(door->pos.x, door->pos.y, open ? TERRAIN_NORMAL : TERRAIN_WALL, true);
+  door->open = open;
+}
+
+bool CotPlacePuzzleDoor(int x, int y, int circuit) {
+  if (n_doors >= COT_MAX_PUZZLE_DOORS || circuit < 0 || circuit >= COT_MAX_PUZZLE_CIRCUITS) {
+    COT_WARNFMT(COT_LOG_CAT_PUZZLES, "Can't place door at (%d, %d)", x, y);
+    return false;
+  }
+  puzzle_door* door = &doors[n_doors++];
+  door->pos = (struct position) { .x = x, .y = y };
+  door->circuit = circuit;
+  SetDoorTerrain(door, false);
+  return true;
+}
+
+void CotSetPuzzleDoorCallback(puzzle_door_fn on_door_changed) {
+  door_callback = on_door_changed;
+}
+
+void CotClearPuzzles(void) {
+  n_switches = 0;
+  n_doors = 0;
+}
+
+bool CotIsCircuitPowered(int circuit) {
+  for (int i = 0; i < n_switches; i++) {
+    if (switches[i].circuit == circuit && switches[i].on) {
+      return true;
+    }
+  }
+  return false;
+}
+
+void CotUpdatePuzzles(void) {
+  for (int i = 0; i < n_switches; i++) {
+    puzzle_switch* sw = &switches[i];
+    bool occupied = CotTileMonster(sw->pos.x, sw->pos.y) != NULL;
+    if (sw->kind == PUZZLE_SWITCH_PRESSURE_PLATE) {
+      sw->on = occupied;
+    } else if (occupied && !sw->occupied) {
+      sw->on = !sw->on;
+    }
+    sw->occupied = occupied;
+  }
+
+  for (int i = 0; i < n_doors; i++) {
+    puzzle_door* door = &doors[i];
+    bool open = CotIsCircuitPowered(door->circuit);
+    if (open == door->open) {
+      continue;
+    }
+    // Never close a door on top of a monster
+    if (!open && CotTileMonster(door->pos.x, door->pos.y) != NULL) {
+      continue;
+    }
+    COT_LOGFMT(COT_LOG_CAT_PUZZLES, "Door at (%d, %d) %s", door->pos.x, door->pos.y,
+               open ? "opened" : "closed");
+    SetDoorTerrain(door, open);
+    if (door_callback != NULL) {
+      door_callback(door->pos.x, door->pos.y, open);
+    }
+  }
+}
@@ -0,0 +1,70 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+static bool general_locked = false;
+// Dungeon the lock is limited to, or -1 if it applies everywhere
+static int locked_dungeon = -1;
+
+static bool UseDungeonRng(rng_source source) {
+  if (source == RNG_DUNGEON) {
+    return true;
+  }
+  return general_locked && (locked_dungeon < 0
+      || (DUNGEON_PTR != NULL && DUNGEON_PTR->id.val == locked_dungeon));
+}
+
+int CotRandInt(rng_source source, int n) {
+  return UseDungeonRng(source) ? DungeonRandInt(n) : RandInt(n);
+}
+
+int CotRandRange(rng_source source, int min, int max) {
+  return UseDungeonRng(source) ? DungeonRandRange(min, max) : RandRange(min, max);
+}
+
+bool CotRandChance(rng_source source, int percent) {
+  return CotRandInt(source, 100) < percent;
+}
+
+int CotRandWeighted(rng_source source, const int* weights, int n) {
+  int total = 0;
+  for (int i = 0; i < n; i++) {
+    total += weights[i];
+  }
+  if (total <= 0) {
+    return -1;
+  }
+  int roll = CotRandInt(source, total);
+  for (int i = 0; i < n; i++) {
+    if (roll < weights[i]) {
+      return i;
+    }
+    roll -= weights[i];
+  }
+  return n - 1;
+}
+
+void CotSaveDungeonRng(rng_snapshot* snapshot) {
+  snapshot->state = DUNGEON_PRNG_STATE;
+  memcpy(snapshot->secondary_values, DUNGEON_PRNG_STATE_SECONDARY_VALUES,
+         sizeof(snapshot->secondary_values));
+}
+
+void CotRestoreDungeonRng(const rng_snapshot* snapshot) {
+  DUNGEON_PRNG_STATE = snapshot->state;
+  memcpy(DUNGEON_PRNG_STATE_SECONDARY_VALUES, snapshot->secondary_values,
+         sizeof(snapshot->secondary_values));
+}
+
+void CotSeedDungeonRng(uint32_t seed) {
+  InitDungeonRng(seed);
+}
+
+void CotSetGeneralRngLocked(bool locked) {
+  general_locked = locked;
+  locked_dungeon = -1;
+}
+
+void CotLockGeneralRngToDungeon(enum dungeon_id dungeon) {
+  general_locked = true;
+  locked_dungeon = dungeon;
+}
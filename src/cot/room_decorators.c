@@ -0,0 +1,170 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+static const room_decorator* registered[COT_MAX_ROOM_DECORATORS];
+static int n_registered = 0;
+// Too large for the stack
+static room_info rooms[COT_MAX_DECORATED_ROOMS];
+
+static bool IsAt(struct position pos, int x, int y) {
+  return pos.x == x && pos.y == y;
+}
+
+static int CollectRooms(void) {
+  bool seen[COT_MAX_DECORATED_ROOMS] = { false };
+  for (int y = 0; y < COT_FLOOR_HEIGHT; y++) {
+    for (int x = 0; x < COT_FLOOR_WIDTH; x++) {
+      struct tile* tile = CotTileAt(x, y);
+      if (tile->room == COT_NO_ROOM || tile->room >= COT_MAX_DECORATED_ROOMS) {
+        continue;
+      }
+      room_info* room = &rooms[tile->room];
+      if (!seen[tile->room]) {
+        seen[tile->room] = true;
+        *room = (room_info) {
+          .room_id = tile->room,
+          .kind = ROOM_KIND_NORMAL,
+          .bounds = { .x0 = x, .y0 = y, .x1 = x, .y1 = y },
+        };
+      }
+      room->bounds.x0 = x < room->bounds.x0 ? x : room->bounds.x0;
+      room->bounds.x1 = x > room->bounds.x1 ? x : room->bounds.x1;
+      room->bounds.y1 = y;
+      if (tile->terrain_flags.f_in_monster_house) {
+        room->kind = ROOM_KIND_MONSTER_HOUSE;
+      } else if (tile->terrain_flags.f_in_kecleon_shop) {
+        room->kind = ROOM_KIND_KECLEON_SHOP;
+      }
+      room->has_stairs |= tile->terrain_flags.f_stairs
+                          || IsAt(DUNGEON_PTR->gen_info.stairs_pos, x, y);
+    }
+  }
+
+  // Compact the rooms that exist to the front
+  int n = 0;
+  for (int i = 0; i < COT_MAX_DECORATED_ROOMS; i++) {
+    if (seen[i]) {
+      rooms[n++] = rooms[i];
+    }
+  }
+  return n;
+}
+
+// The stairs and team spawn are checked by position too, since decorators may run before the
+// game marks the stairs tile
+static bool IsFreeRoomTile(const room_info* room, int x, int y) {
+  struct tile* tile = CotTileAt(x, y);
+  struct dungeon_generation_info* gen_info = &DUNGEON_PTR->gen_info;
+  return CotTileIsFloor(x, y) && tile->room == room->room_id && !tile->terrain_flags.f_stairs
+      && !IsAt(gen_info->stairs_pos, x, y) && !IsAt(gen_info->team_spawn_pos, x, y)
+      && tile->monster == NULL && tile->object == NULL;
+}
+
+static bool TouchesCorridor(int x, int y) {
+  for (int j = y - 1; j <= y + 1; j++) {
+    for (int i = x - 1; i <= x + 1; i++) {
+      if (CotTileIsFloor(i, j) && CotTileAt(i, j)->room == COT_NO_ROOM) {
+        return true;
+      }
+    }
+  }
+  return false;
+}
+
+static bool IsLargeRoom(const room_info* room) {
+  return room->bounds.x1 - room->bounds.x0 >= 4 && room->bounds.y1 - room->bounds.y0 >= 4;
+}
+
+void CotDecorateStatue(const room_info* room, int value) {
+  if (!IsLargeRoom(room)) {
+    return;
+  }
+  int x = (room->bounds.x0 + room->bounds.x1) / 2;
+  int y = (room->bounds.y0 + room->bounds.y1) / 2;
+  if (IsFreeRoomTile(room, x, y) && !TouchesCorridor(x, y)) {
+    CotSetTileTerrain(x, y, TERRAIN_WALL, true);
+  }
+}
+
+void CotDecorateCornerAccents(const room_info* room, int value) {
+  if (!IsLargeRoom(room)) {
+    return;
+  }
+  const int xs[2] = { room->bounds.x0, room->bounds.x1 };
+  const int ys[2] = { room->bounds.y0, room->bounds.y1 };
+  for (int j = 0; j < 2; j++) {
+    for (int i = 0; i < 2; i++) {
+      if (IsFreeRoomTile(room, xs[i], ys[j]) && !TouchesCorridor(xs[i], ys[j])) {
+        CotSetTileTerrain(xs[i], ys[j], TERRAIN_SECONDARY, false);
+      }
+    }
+  }
+}
+
+void CotDecorateItem(const room_info* room, int value) {
+  int w = room->bounds.x1 - room->bounds.x0 + 1;
+  int h = room->bounds.y1 - room->bounds.y0 + 1;
+  // Pick a random starting tile and take the first free one from there
+  int start = CotRandInt(RNG_DUNGEON, w * h);
+  for (int k = 0; k < w * h; k++) {
+    int i = (start + k) % (w * h);
+    int x = room->bounds.x0 + i % w;
+    int y = room->bounds.y0 + i / w;
+    if (IsFreeRoomTile(room, x, y)) {
+      item_spawn spawn;
+      CotItemSpawnInit(&spawn, value);
+      CotSpawnItemAt(&spawn, x, y);
+      return;
+    }
+  }
+}
+
+static bool ShouldDecorate(const room_decorator* decorator, const room_info* room) {
+  if (decorator->kinds != 0 && (decorator->kinds & COT_ROOM_KIND_MASK(room->kind)) == 0) {
+    return false;
+  }
+  return decorator->chance == 0 || CotRandChance(RNG_DUNGEON, decorator->chance);
+}
+
+void CotApplyRoomDecorators(const room_decorator* decorators, int n) {
+  int n_rooms = CollectRooms();
+  for (int i = 0; i < n; i++) {
+    for (int r = 0; r < n_rooms; r++) {
+      if (ShouldDecorate(&decorators[i], &rooms[r])) {
+        decorators[i].decorate(&rooms[r], decorators[i].value);
+      }
+    }
+  }
+}
+
+bool CotAddRoomDecorator(const room_decorator* decorator) {
+  if (n_registered >= COT_MAX_ROOM_DECORATORS) {
+    COT_WARN(COT_LOG_CAT_FLOOR_GEN, "Room decorator table is full");
+    return false;
+  }
+  registered[n_registered++] = decorator;
+  return true;
+}
+
+void CotRemoveRoomDecorator(const room_decorator* decorator) {
+  for (int i = 0; i < n_registered; i++) {
+    if (registered[i] == decorator) {
+      // Keep the order, decorators may build on each other
+      for (int j = i + 1; j < n_registered; j++) {
+        registered[j - 1] = registered[j];
+      }
+      n_registered--;
+      return;
+    }
+  }
+}
+
+static void RunRoomDecorators(struct floor_properties* props) {
+  for (int i = 0; i < n_registered; i++) {
+    CotApplyRoomDecorators(registered[i], 1);
+  }
+}
+
+bool CotInstallRoomDecorators(void) {
+  return CotAddPostGenerateHook(RunRoomDecorators);
+}
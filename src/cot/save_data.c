@@ -0,0 +1,115 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+#define SAVE_DATA_MAGIC COT_SAVE_TAG('C', 'O', 'T', 'S')
+
+static const save_block* blocks[COT_MAX_SAVE_BLOCKS];
+static int n_blocks = 0;
+
+static int FindSaveBlock(uint32_t tag) {
+  for (int i = 0; i < n_blocks; i++) {
+    if (blocks[i]->tag == tag) {
+      return i;
+    }
+  }
+  return -1;
+}
+
+bool CotRegisterSaveBlock(const save_block* block) {
+  if (FindSaveBlock(block->tag) >= 0) {
+    COT_WARNFMT(COT_LOG_CAT_SAVE_DATA, "Save block %08x is already registered", block->tag);
+    return false;
+  }
+  if (n_blocks >= COT_MAX_SAVE_BLOCKS) {
+    COT_WARN(COT_LOG_CAT_SAVE_DATA, "Save block table is full");
+    return false;
+  }
+  blocks[n_blocks++] = block;
+  return true;
+}
+
+void CotUnregisterSaveBlock(uint32_t tag) {
+  int i = FindSaveBlock(tag);
+  if (i >= 0) {
+    blocks[i] = blocks[--n_blocks];
+  }
+}
+
+int CotSaveDataSize(void) {
+  int size = COT_SAVE_DATA_HEADER_SIZE;
+  for (int i = 0; i < n_blocks; i++) {
+    size += COT_SAVE_BLOCK_HEADER_SIZE + blocks[i]->size;
+  }
+  return size;
+}
+
+int CotWriteSaveData(void* buf, int capacity) {
+  int size = CotSaveDataSize();
+  if (capacity < size) {
+    COT_ERRORFMT(COT_LOG_CAT_SAVE_DATA, "Save data needs %d bytes, but only %d are available",
+                 size, capacity);
+    return -1;
+  }
+
+  uint8_t* p = (uint8_t*) buf + COT_SAVE_DATA_HEADER_SIZE;
+  for (int i = 0; i < n_blocks; i++) {
+    CotWriteU32(p, blocks[i]->tag);
+    CotWriteU16(p + 4, blocks[i]->version);
+    CotWriteU16(p + 6, blocks[i]->size);
+    memcpy(p + COT_SAVE_BLOCK_HEADER_SIZE, blocks[i]->data, blocks[i]->size);
+    p += COT_SAVE_BLOCK_HEADER_SIZE + blocks[i]->size;
+  }
+
+  int payload_size = size - COT_SAVE_DATA_HEADER_SIZE;
+  CotWriteU32(buf, SAVE_DATA_MAGIC);
+  CotWriteU32((uint8_t*) buf + 4, payload_size);
+  CotWriteU32((uint8_t*) buf + 8, CotCrc32((uint8_t*) buf + COT_SAVE_DATA_HEADER_SIZE, payload_size));
+  return size;
+}
+
+static void RestoreBlock(const save_block* block, const uint8_t* data, uint16_t version, uint16_t size) {
+  if (version == block->version && size == block->size) {
+    memcpy(block->data, data, size);
+  } else if (block->migrate != NULL) {
+    COT_LOGFMT(COT_LOG_CAT_SAVE_DATA, "Migrating save block %08x from version %d", block->tag, version);
+    block->migrate(block->data, data, version, size);
+  } else {
+    COT_WARNFMT(COT_LOG_CAT_SAVE_DATA, "Discarding save block %08x with unknown version %d",
+                block->tag, version);
+  }
+}
+
+bool CotReadSaveData(const void* buf, int size) {
+  const uint8_t* header = buf;
+  if (size < COT_SAVE_DATA_HEADER_SIZE || CotReadU32(header) != SAVE_DATA_MAGIC) {
+    COT_LOG(COT_LOG_CAT_SAVE_DATA, "No custom save data found");
+    return false;
+  }
+  int payload_size = CotReadU32(header + 4);
+  if (payload_size < 0 || payload_size > size - COT_SAVE_DATA_HEADER_SIZE
+      || CotReadU32(header + 8) != CotCrc32(header + COT_SAVE_DATA_HEADER_SIZE, payload_size)) {
+    COT_ERROR(COT_LOG_CAT_SAVE_DATA, "Custom save data is damaged");
+    return false;
+  }
+
+  const uint8_t* p = header + COT_SAVE_DATA_HEADER_SIZE;
+  const uint8_t* end = p + payload_size;
+  while (end - p >= COT_SAVE_BLOCK_HEADER_SIZE) {
+    uint32_t tag = CotReadU32(p);
+    uint16_t version = CotReadU16(p + 4);
+    uint16_t block_size = CotReadU16(p + 6);
+    p += COT_SAVE_BLOCK_HEADER_SIZE;
+    if (block_size > end - p) {
+      COT_ERRORFMT(COT_LOG_CAT_SAVE_DATA, "Save block %08x is truncated", tag);
+      return false;
+    }
+    int i = FindSaveBlock(tag);
+    if (i >= 0) {
+      RestoreBlock(blocks[i], p, version, block_size);
+    } else {
+      COT_WARNFMT(COT_LOG_CAT_SAVE_DATA, "Ignoring unknown save block %08x", tag);
+    }
+    p += block_size;
+  }
+  return true;
+}
@@ -0,0 +1,106 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+typedef struct scratch_buffer {
+  const char* name;
+  void* data;
+  uint32_t size;
+  scratch_lifetime lifetime;
+} scratch_buffer;
+
+static cot_arena arena;
+static scratch_buffer buffers[COT_MAX_SCRATCH_BUFFERS];
+static int n_buffers = 0;
+// Floor generated last, to tell when a new dungeon was entered
+static int last_dungeon = -1;
+static int last_floor = 0;
+static bool hook_added = false;
+
+static bool NamesEqual(const char* a, const char* b) {
+  while (*a != '\0' && *a == *b) {
+    a++;
+    b++;
+  }
+  return *a == *b;
+}
+
+static int FindBuffer(const char* name) {
+  for (int i = 0; i < n_buffers; i++) {
+    if (NamesEqual(buffers[i].name, name)) {
+      return i;
+    }
+  }
+  return -1;
+}
+
+static void ReleaseAt(int i) {
+  CotArenaFree(&arena, buffers[i].data);
+  buffers[i] = buffers[--n_buffers];
+}
+
+// A different dungeon, or going back to an earlier floor, means the previous dungeon was left
+static void EndDungeonOnEntry(struct floor_properties* props) {
+  int dungeon = DUNGEON_PTR->id.val;
+  int floor = DUNGEON_PTR->floor;
+  if (last_dungeon >= 0 && (dungeon != last_dungeon || floor <= last_floor)) {
+    CotScratchEndDungeon();
+  }
+  last_dungeon = dungeon;
+  last_floor = floor;
+}
+
+void CotScratchInit(struct mem_arena* mem_arena, uint32_t budget) {
+  while (n_buffers > 0) {
+    ReleaseAt(n_buffers - 1);
+  }
+  CotArenaInit(&arena, mem_arena, budget);
+  if (!hook_added) {
+    hook_added = CotAddPreGenerateHook(EndDungeonOnEntry);
+  }
+}
+
+void* CotScratchGet(const char* name, uint32_t size, scratch_lifetime lifetime) {
+  int i = FindBuffer(name);
+  if (i >= 0) {
+    if (buffers[i].size != size) {
+      COT_WARNFMT(COT_LOG_CAT_SCRATCH, "Scratch buffer %s already exists with %d bytes", name,
+                  buffers[i].size);
+      return NULL;
+    }
+    return buffers[i].data;
+  }
+  if (n_buffers >= COT_MAX_SCRATCH_BUFFERS) {
+    COT_WARN(COT_LOG_CAT_SCRATCH, "Scratch buffer table is full");
+    return NULL;
+  }
+  void* data = CotArenaAlloc(&arena, size);
+  if (data == NULL) {
+    return NULL;
+  }
+  buffers[n_buffers++] = (scratch_buffer) { .name = name, .data = data, .size = size, .lifetime = lifetime };
+  return data;
+}
+
+void* CotScratchFind(const char* name) {
+  int i = FindBuffer(name);
+  return i >= 0 ? buffers[i].data : NULL;
+}
+
+void CotScratchRelease(const char* name) {
+  int i = FindBuffer(name);
+  if (i >= 0) {
+    ReleaseAt(i);
+  }
+}
+
+void CotScratchEndDungeon(void) {
+  for (int i = n_buffers - 1; i >= 0; i--) {
+    if (buffers[i].lifetime == SCRATCH_DUNGEON) {
+      ReleaseAt(i);
+    }
+  }
+}
+
+arena_stats CotScratchStats(void) {
+  return CotGetArenaStats(&arena);
+}
@@ -0,0 +1,75 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+static struct {
+  script_call_state state;
+  int routine_id;
+  int args[COT_SCRIPT_CALL_MAX_ARGS];
+  int result;
+  script_call_done_fn on_done;
+} mailbox;
+
+bool CotCallScriptRoutine(int routine_id, const int* args, int n_args, script_call_done_fn on_done) {
+  if (mailbox.state == SCRIPT_CALL_PENDING || mailbox.state == SCRIPT_CALL_RUNNING) {
+    COT_WARNFMT(COT_LOG_CAT_SCRIPT_CALLS, "Can't call routine %d: routine %d is still in progress",
+                routine_id, mailbox.routine_id);
+    return false;
+  }
+  COT_ASSERT(routine_id != 0);
+  COT_ASSERT(n_args <= COT_SCRIPT_CALL_MAX_ARGS);
+
+  mailbox.routine_id = routine_id;
+  for (int i = 0; i < COT_SCRIPT_CALL_MAX_ARGS; i++) {
+    mailbox.args[i] = i < n_args ? args[i] : 0;
+  }
+  mailbox.result = 0;
+  mailbox.on_done = on_done;
+  mailbox.state = SCRIPT_CALL_PENDING;
+  return true;
+}
+
+script_call_state CotGetScriptCallState(void) {
+  return mailbox.state;
+}
+
+int CotGetScriptCallResult(void) {
+  if (mailbox.state == SCRIPT_CALL_DONE) {
+    mailbox.state = SCRIPT_CALL_IDLE;
+  }
+  return mailbox.result;
+}
+
+bool cotInternalHandleScriptCallSpecialProcess(uint32_t special_process_id, short arg1, int* return_val) {
+  switch (special_process_id) {
+    case COT_SP_SCRIPT_CALL_POLL:
+      if (mailbox.state != SCRIPT_CALL_PENDING) {
+        *return_val = 0;
+        return true;
+      }
+      COT_LOGFMT(COT_LOG_CAT_SCRIPT_CALLS, "Running script routine %d", mailbox.routine_id);
+      mailbox.state = SCRIPT_CALL_RUNNING;
+      *return_val = mailbox.routine_id;
+      return true;
+
+    case COT_SP_SCRIPT_CALL_ARG:
+      *return_val = (arg1 >= 0 && arg1 < COT_SCRIPT_CALL_MAX_ARGS) ? mailbox.args[arg1] : 0;
+      return true;
+
+    case COT_SP_SCRIPT_CALL_DONE:
+      if (mailbox.state != SCRIPT_CALL_RUNNING) {
+        COT_WARN(COT_LOG_CAT_SCRIPT_CALLS, "Script reported completion without a running call");
+        *return_val = 0;
+        return true;
+      }
+      mailbox.result = arg1;
+      mailbox.state = SCRIPT_CALL_DONE;
+      if (mailbox.on_done != NULL) {
+        mailbox.on_done(mailbox.routine_id, mailbox.result);
+      }
+      *return_val = 0;
+      return true;
+
+    default:
+      return false;
+  }
+}
@@ -0,0 +1,64 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+static struct {
+  uint16_t opcode;
+  int n_args;
+  script_opcode_fn handler;
+} opcode_handlers[COT_MAX_SCRIPT_OPCODES];
+
+static int FindOpcode(uint16_t opcode) {
+  for (int i = 0; i < COT_MAX_SCRIPT_OPCODES; i++) {
+    if (opcode_handlers[i].handler != NULL && opcode_handlers[i].opcode == opcode) {
+      return i;
+    }
+  }
+  return -1;
+}
+
+bool CotRegisterScriptOpcode(uint16_t opcode, int n_args, script_opcode_fn handler) {
+  if (FindOpcode(opcode) >= 0) {
+    COT_WARNFMT(COT_LOG_CAT_SCRIPT_OPCODES, "Opcode 0x%x already has a handler", opcode);
+    return false;
+  }
+  for (int i = 0; i < COT_MAX_SCRIPT_OPCODES; i++) {
+    if (opcode_handlers[i].handler == NULL) {
+      opcode_handlers[i].opcode = opcode;
+      opcode_handlers[i].n_args = n_args;
+      opcode_handlers[i].handler = handler;
+      return true;
+    }
+  }
+  COT_WARN(COT_LOG_CAT_SCRIPT_OPCODES, "Script opcode table is full");
+  return false;
+}
+
+void CotUnregisterScriptOpcode(uint16_t opcode) {
+  int i = FindOpcode(opcode);
+  if (i >= 0) {
+    opcode_handlers[i].handler = NULL;
+  }
+}
+
+bool cotInternalDispatchScriptOpcode(struct script_routine* routine) {
+  // The active state of the routine points to the opcode it's about to run
+  uint16_t* next = routine->states[0].current_opcode;
+  int i = FindOpcode(*next);
+  if (i < 0) {
+    return false;
+  }
+
+  script_opcode_context ctx = {
+    .routine = routine,
+    .opcode = *next,
+    .args = next + 1,
+    .n_args = opcode_handlers[i].n_args,
+    .return_val = 0,
+    .jump_to = NULL,
+  };
+  opcode_handlers[i].handler(&ctx);
+  CotSetScriptVariableValue(VAR_CONDITION, ctx.return_val);
+  routine->states[0].current_opcode = ctx.jump_to != NULL
+      ? (uint16_t*) ctx.jump_to : next + 1 + ctx.n_args;
+  return true;
+}
@@ -0,0 +1,106 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+// Ring buffer; the command at `head` is the one being run while `routine` is set.
+static script_cmd queue[COT_SCRIPT_QUEUE_SIZE];
+static int head = 0;
+static int length = 0;
+// Routine running the command at `head`, and the queue opcode it returns to.
+static struct script_routine* routine = NULL;
+static const uint16_t* resume_at = NULL;
+// The running command followed by the resume opcode, run by the script engine.
+static uint16_t opcodes[1 + COT_SCRIPT_CMD_MAX_ARGS + 1];
+
+bool CotQueueScriptCommand(script_cmd cmd) {
+  if (cmd.n_args < 0 || cmd.n_args > COT_SCRIPT_CMD_MAX_ARGS) {
+    COT_WARNFMT(COT_LOG_CAT_SCRIPT_CALLS, "Opcode 0x%x has too many parameters", cmd.opcode);
+    return false;
+  }
+  if (length >= COT_SCRIPT_QUEUE_SIZE) {
+    COT_WARNFMT(COT_LOG_CAT_SCRIPT_CALLS, "Script queue is full, dropping opcode 0x%x", cmd.opcode);
+    return false;
+  }
+  queue[(head + length) % COT_SCRIPT_QUEUE_SIZE] = cmd;
+  length++;
+  return true;
+}
+
+bool CotQueueScriptOpcode(uint16_t opcode, const uint16_t* args, int n_args,
+                          script_cmd_done_fn on_done) {
+  script_cmd cmd = { .opcode = opcode, .n_args = n_args, .on_done = on_done };
+  for (int i = 0; i < n_args && i < COT_SCRIPT_CMD_MAX_ARGS; i++) {
+    cmd.args[i] = args[i];
+  }
+  return CotQueueScriptCommand(cmd);
+}
+
+const script_cmd* CotGetRunningScriptCommand(void) {
+  return routine != NULL ? &queue[head] : NULL;
+}
+
+struct script_routine* CotGetScriptQueueRoutine(void) {
+  return routine;
+}
+
+bool CotScriptQueueIdle(void) {
+  return length == 0;
+}
+
+int CotScriptQueueLength(void) {
+  return length;
+}
+
+void CotClearScriptQueue(void) {
+  length = routine != NULL ? 1 : 0;
+}
+
+static void RunQueue(script_opcode_context* ctx) {
+  if (routine != NULL && routine != ctx->routine) {
+    COT_WARN(COT_LOG_CAT_SCRIPT_CALLS, "The script queue is already run by another routine");
+    return;
+  }
+  // Keep the result of the last queued opcode
+  ctx->return_val = CotGetScriptVariableValue(VAR_CONDITION);
+  if (length == 0) {
+    return;
+  }
+
+  const script_cmd* cmd = &queue[head];
+  opcodes[0] = cmd->opcode;
+  for (int i = 0; i < cmd->n_args; i++) {
+    opcodes[1 + i] = cmd->args[i];
+  }
+  opcodes[1 + cmd->n_args] = COT_SCRIPT_OPCODE_QUEUE_RESUME;
+  routine = ctx->routine;
+  resume_at = ctx->args - 1;
+  ctx->jump_to = opcodes;
+}
+
+static void Resume(script_opcode_context* ctx) {
+  if (routine != ctx->routine) {
+    COT_WARN(COT_LOG_CAT_SCRIPT_CALLS, "Resume opcode reached outside of the script queue");
+    return;
+  }
+  // Copied, since the callback may queue new commands into the freed slot
+  script_cmd done = queue[head];
+  head = (head + 1) % COT_SCRIPT_QUEUE_SIZE;
+  length--;
+  routine = NULL;
+  ctx->return_val = CotGetScriptVariableValue(VAR_CONDITION);
+  // Back to the queue opcode, which runs the next command or lets the script carry on
+  ctx->jump_to = resume_at;
+  if (done.on_done != NULL) {
+    done.on_done(&done);
+  }
+}
+
+bool CotInstallScriptQueue(void) {
+  if (!CotRegisterScriptOpcode(COT_SCRIPT_OPCODE_QUEUE_RUN, 0, RunQueue)) {
+    return false;
+  }
+  if (!CotRegisterScriptOpcode(COT_SCRIPT_OPCODE_QUEUE_RESUME, 0, Resume)) {
+    CotUnregisterScriptOpcode(COT_SCRIPT_OPCODE_QUEUE_RUN);
+    return false;
+  }
+  return true;
+}
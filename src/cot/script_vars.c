@@ -0,0 +1,36 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+static bool IsGlobalVariable(enum script_var_id id) {
+  if (id >= VAR_LOCAL0) {
+    COT_ERRORFMT(COT_LOG_CAT_SCRIPT_VARS, "Script variable %d is not a global variable", id);
+    return false;
+  }
+  return true;
+}
+
+int CotGetScriptVariableValue(enum script_var_id id) {
+  if (!IsGlobalVariable(id)) {
+    return 0;
+  }
+  return LoadScriptVariableValue(NULL, id);
+}
+
+void CotSetScriptVariableValue(enum script_var_id id, int value) {
+  if (IsGlobalVariable(id)) {
+    SaveScriptVariableValue(NULL, id, value);
+  }
+}
+
+int CotGetScriptVariableValueAtIndex(enum script_var_id id, int index) {
+  if (!IsGlobalVariable(id)) {
+    return 0;
+  }
+  return LoadScriptVariableValueAtIndex(NULL, id, index);
+}
+
+void CotSetScriptVariableValueAtIndex(enum script_var_id id, int index, int value) {
+  if (IsGlobalVariable(id)) {
+    SaveScriptVariableValueAtIndex(NULL, id, index, value);
+  }
+}
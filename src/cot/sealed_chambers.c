@@ -0,0 +1,147 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+#define GATES_VERSION 1
+
+static gate gates[COT_MAX_GATES];
+static int n_gates = 0;
+// Bit i is set if piece i of the gate was collected. Saved through CotGatesRegisterSaveBlock.
+static uint16_t collected[COT_MAX_GATES];
+
+static bool IsValidGate(int gate_id) {
+  return gate_id >= 0 && gate_id < n_gates;
+}
+
+static bool IsValidPiece(int gate_id, int piece) {
+  return IsValidGate(gate_id) && piece >= 0 && piece < gates[gate_id].n_pieces;
+}
+
+static bool IsOnGateFloor(const gate* g) {
+  // DUNGEON_PTR is only allocated in dungeon mode; pieces can also be collected in ground mode.
+  return DUNGEON_PTR != NULL && DUNGEON_PTR->id.val == g->dungeon
+      && (g->floor == COT_ANY_FLOOR || DUNGEON_PTR->floor == g->floor);
+}
+
+static void SetPassageOpen(const gate* g, bool open) {
+  struct position pos;
+  COT_FOR_EACH_IN_AREA(g->passage.x, g->passage.y, g->passage_w, g->passage_h, pos) {
+    CotSetTileTerrain(pos.x, pos.y, open ? TERRAIN_NORMAL : TERRAIN_WALL, true);
+  }
+}
+
+static void CollectBagPieces(void) {
+  for (int gate_id = 0; gate_id < n_gates; gate_id++) {
+    gate* g = &gates[gate_id];
+    if (g->dungeon != DUNGEON_PTR->id.val || CotGateIsOpen(gate_id)) {
+      continue;
+    }
+    for (int piece = 0; piece < g->n_pieces; piece++) {
+      if (g->pieces[piece].item == ITEM_NOTHING || CotHasGatePiece(gate_id, piece)) {
+        continue;
+      }
+      int bag_idx = CotFindBagItem(g->pieces[piece].item);
+      if (bag_idx < 0) {
+        continue;
+      }
+      if (g->pieces[piece].consumed) {
+        RemoveItemNoHole(bag_idx);
+      }
+      CotCollectGatePiece(gate_id, piece);
+    }
+  }
+}
+
+static void ApplyPassages(struct floor_properties* props) {
+  (void) props;
+  for (int gate_id = 0; gate_id < n_gates; gate_id++) {
+    if (IsOnGateFloor(&gates[gate_id])) {
+      SetPassageOpen(&gates[gate_id], CotGateIsOpen(gate_id));
+    }
+  }
+}
+
+int CotRegisterGate(const gate* g) {
+  if (n_gates >= COT_MAX_GATES) {
+    COT_WARN(COT_LOG_CAT_SEALED_CHAMBERS, "Gate table is full");
+    return COT_GATE_INVALID;
+  }
+  if (g->n_pieces <= 0 || g->n_pieces > COT_MAX_GATE_PIECES || g->passage_w <= 0
+      || g->passage_h <= 0) {
+    COT_WARNFMT(COT_LOG_CAT_SEALED_CHAMBERS, "Invalid gate with %d pieces", g->n_pieces);
+    return COT_GATE_INVALID;
+  }
+  if (n_gates == 0
+      && (!CotAddTurnEndHook(CollectBagPieces) || !CotAddPostGenerateHook(ApplyPassages))) {
+    CotRemoveTurnHook(CollectBagPieces);
+    COT_WARN(COT_LOG_CAT_SEALED_CHAMBERS, "Can't add the gate hooks");
+    return COT_GATE_INVALID;
+  }
+  gates[n_gates] = *g;
+  collected[n_gates] = 0;
+  return n_gates++;
+}
+
+const gate* CotGetGate(int gate_id) {
+  return IsValidGate(gate_id) ? &gates[gate_id] : NULL;
+}
+
+bool CotCollectGatePiece(int gate_id, int piece) {
+  if (!IsValidPiece(gate_id, piece) || CotHasGatePiece(gate_id, piece)) {
+    return false;
+  }
+  collected[gate_id] |= 1u << piece;
+  COT_LOGFMT(COT_LOG_CAT_SEALED_CHAMBERS, "Collected piece %d of gate %d (%d/%d)", piece, gate_id,
+             CotGetGateProgress(gate_id), gates[gate_id].n_pieces);
+
+  if (CotGateIsOpen(gate_id)) {
+    gate* g = &gates[gate_id];
+    COT_LOGFMT(COT_LOG_CAT_SEALED_CHAMBERS, "Gate %d opened", gate_id);
+    if (IsOnGateFloor(g)) {
+      SetPassageOpen(g, true);
+    }
+    if (g->on_opened != NULL) {
+      g->on_opened(gate_id);
+    }
+  }
+  return true;
+}
+
+bool CotHasGatePiece(int gate_id, int piece) {
+  return IsValidPiece(gate_id, piece) && (collected[gate_id] & (1u << piece)) != 0;
+}
+
+int CotGetGateProgress(int gate_id) {
+  if (!IsValidGate(gate_id)) {
+    return 0;
+  }
+  int n_collected = 0;
+  for (int piece = 0; piece < gates[gate_id].n_pieces; piece++) {
+    n_collected += CotHasGatePiece(gate_id, piece);
+  }
+  return n_collected;
+}
+
+bool CotGateIsOpen(int gate_id) {
+  return IsValidGate(gate_id) && CotGetGateProgress(gate_id) == gates[gate_id].n_pieces;
+}
+
+void CotResetGate(int gate_id) {
+  if (!IsValidGate(gate_id)) {
+    return;
+  }
+  collected[gate_id] = 0;
+  if (IsOnGateFloor(&gates[gate_id])) {
+    SetPassageOpen(&gates[gate_id], false);
+  }
+}
+
+static const save_block gates_save_block = {
+  .tag = COT_SAVE_TAG('G', 'A', 'T', 'E'),
+  .version = GATES_VERSION,
+  .size = sizeof(collected),
+  .data = collected,
+};
+
+bool CotGatesRegisterSaveBlock(void) {
+  return CotRegisterSaveBlock(&gates_save_block);
+}
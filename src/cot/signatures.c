@@ -0,0 +1,46 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+// Defined by COT_SIGNATURE_TABLE, if it's used
+extern const address_signature cotSignatureTable[] __attribute__((weak));
+extern const int cotSignatureTableSize __attribute__((weak));
+
+static bool CheckSignature(const address_signature* signature) {
+  const uint8_t* code = signature->addr;
+  for (int i = 0; i < signature->n_bytes; i++) {
+    if (code[i] != signature->bytes[i]) {
+      COT_ERRORFMT(COT_LOG_CAT_SIGNATURES, "Signature mismatch for %s at %p+%d: expected %02x, got %02x",
+                   signature->name, signature->addr, i, signature->bytes[i], code[i]);
+      return false;
+    }
+  }
+  return true;
+}
+
+int CotCheckSignatures(const address_signature* signatures, int n) {
+  int mismatches = 0;
+  for (int i = 0; i < n; i++) {
+    if (!CheckSignature(&signatures[i])) {
+      mismatches++;
+    }
+  }
+  return mismatches;
+}
+
+void cotInternalRunBootSelfCheck(void) {
+  static bool checked = false;
+  if (checked || &cotSignatureTableSize == NULL) {
+    return;
+  }
+  checked = true;
+  int mismatches = CotCheckSignatures(cotSignatureTable, cotSignatureTableSize);
+  if (mismatches == 0) {
+    COT_LOGFMT(COT_LOG_CAT_SIGNATURES, "All %d signatures match", cotSignatureTableSize);
+    return;
+  }
+  // Calling into the wrong code corrupts the game in ways that are much harder to track down,
+  // so stop here regardless of NDEBUG
+  DebugPrint(2, "c-of-time: %d address signatures don't match. Is this the right ROM for the "
+                "REGION set in the Makefile?", mismatches);
+  WaitForever();
+}
@@ -0,0 +1,91 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+// Attempts at finding a random free tile before giving up.
+#define RANDOM_POSITION_ATTEMPTS 200
+
+void CotSpawnInit(monster_spawn* spawn, enum monster_id species) {
+  *spawn = (monster_spawn) {
+    .species = species,
+    .level = 1,
+    .behavior = BEHAVIOR_NORMAL_ENEMY_0x0,
+    .has_position = false,
+    .held_item = ITEM_NOTHING,
+    .can_be_asleep = true,
+  };
+}
+
+void CotSpawnSetLevel(monster_spawn* spawn, int level) {
+  spawn->level = level;
+}
+
+void CotSpawnSetBehavior(monster_spawn* spawn, enum monster_behavior behavior) {
+  spawn->behavior = behavior;
+}
+
+void CotSpawnSetPosition(monster_spawn* spawn, int x, int y) {
+  spawn->has_position = true;
+  spawn->pos = (struct position) { .x = x, .y = y };
+}
+
+void CotSpawnSetHeldItem(monster_spawn* spawn, enum item_id item) {
+  spawn->held_item = item;
+}
+
+void CotSpawnSetCanBeAsleep(monster_spawn* spawn, bool can_be_asleep) {
+  spawn->can_be_asleep = can_be_asleep;
+}
+
+// The team spawn is kept free for the team, which is placed after the post-generate hooks ran
+static bool IsFreeFloor(int x, int y) {
+  struct tile* tile = CotTileAt(x, y);
+  struct position team_spawn = DUNGEON_PTR->gen_info.team_spawn_pos;
+  return tile != NULL && CotTileIsFloor(x, y) && tile->monster == NULL
+      && !tile->terrain_flags.f_stairs && (team_spawn.x != x || team_spawn.y != y);
+}
+
+static bool FindRandomPosition(struct position* out) {
+  if (CotGetSpawnWeightMap(SPAWN_CATEGORY_MONSTER) != NULL) {
+    return CotPickSpawnTile(SPAWN_CATEGORY_MONSTER, IsFreeFloor, out);
+  }
+  for (int i = 0; i < RANDOM_POSITION_ATTEMPTS; i++) {
+    int x = CotRandInt(RNG_DUNGEON, COT_FLOOR_WIDTH);
+    int y = CotRandInt(RNG_DUNGEON, COT_FLOOR_HEIGHT);
+    if (IsFreeFloor(x, y)) {
+      *out = (struct position) { .x = x, .y = y };
+      return true;
+    }
+  }
+  return false;
+}
+
+struct entity* CotSpawn(const monster_spawn* spawn) {
+  struct position pos = spawn->pos;
+  if (!spawn->has_position && !FindRandomPosition(&pos)) {
+    COT_WARNFMT(COT_LOG_CAT_SPAWN_BUILDER, "No free tile to spawn monster %d", spawn->species);
+    return NULL;
+  }
+  if (!IsFreeFloor(pos.x, pos.y)) {
+    COT_WARNFMT(COT_LOG_CAT_SPAWN_BUILDER, "Can't spawn monster %d on (%d, %d)", spawn->species,
+                pos.x, pos.y);
+    return NULL;
+  }
+
+  struct spawned_monster_data data;
+  memset(&data, 0, sizeof(data));
+  data.monster_id.val = spawn->species;
+  data.monster_behavior.val = spawn->behavior;
+  data.level = spawn->level;
+  data.pos = pos;
+  struct entity* entity = SpawnMonster(&data, !spawn->can_be_asleep);
+  if (entity == NULL) {
+    COT_WARNFMT(COT_LOG_CAT_SPAWN_BUILDER, "Failed to spawn monster %d", spawn->species);
+    return NULL;
+  }
+
+  if (spawn->held_item != ITEM_NOTHING) {
+    struct monster* monster = (struct monster*) entity->info;
+    InitItem(&monster->held_item, spawn->held_item, 0, false);
+  }
+  return entity;
+}
@@ -0,0 +1,121 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+void CotConstraintInit(spawn_constraint* c) {
+  *c = (spawn_constraint) { .min_leader_distance = -1, .max_leader_distance = -1 };
+}
+
+void CotConstraintInRoom(spawn_constraint* c) {
+  c->in_room = true;
+}
+
+void CotConstraintInCorridor(spawn_constraint* c) {
+  c->in_corridor = true;
+}
+
+void CotConstraintNoObject(spawn_constraint* c) {
+  c->no_object = true;
+}
+
+void CotConstraintNotNearStairs(spawn_constraint* c) {
+  c->not_near_stairs = true;
+}
+
+void CotConstraintNotInShop(spawn_constraint* c) {
+  c->not_in_shop = true;
+}
+
+void CotConstraintNotInMonsterHouse(spawn_constraint* c) {
+  c->not_in_monster_house = true;
+}
+
+void CotConstraintMinLeaderDistance(spawn_constraint* c, int distance) {
+  c->min_leader_distance = distance;
+}
+
+void CotConstraintMaxLeaderDistance(spawn_constraint* c, int distance) {
+  c->max_leader_distance = distance;
+}
+
+void CotConstraintInArea(spawn_constraint* c, room_rect area) {
+  c->has_area = true;
+  c->area = area;
+}
+
+void CotConstraintCustom(spawn_constraint* c, spawn_tile_filter_fn filter) {
+  c->custom = filter;
+}
+
+static bool NearStairs(int x, int y) {
+  for (int j = y - 1; j <= y + 1; j++) {
+    for (int i = x - 1; i <= x + 1; i++) {
+      struct tile* tile = CotTileAt(i, j);
+      if (tile != NULL && tile->terrain_flags.f_stairs) {
+        return true;
+      }
+    }
+  }
+  return false;
+}
+
+static bool LeaderDistanceMatches(const spawn_constraint* c, int x, int y) {
+  if (c->min_leader_distance < 0 && c->max_leader_distance < 0) {
+    return true;
+  }
+  struct entity* leader = GetLeader();
+  if (leader == NULL) {
+    return true;
+  }
+  int dx = x > leader->pos.x ? x - leader->pos.x : leader->pos.x - x;
+  int dy = y > leader->pos.y ? y - leader->pos.y : leader->pos.y - y;
+  int distance = dx > dy ? dx : dy;
+  return (c->min_leader_distance < 0 || distance >= c->min_leader_distance)
+      && (c->max_leader_distance < 0 || distance <= c->max_leader_distance);
+}
+
+bool CotConstraintMatches(const spawn_constraint* c, int x, int y) {
+  if (!CotTileIsFloor(x, y) || CotTileMonster(x, y) != NULL) {
+    return false;
+  }
+  struct tile* tile = CotTileAt(x, y);
+  if ((c->in_room && tile->room == COT_NO_ROOM)
+      || (c->in_corridor && tile->room != COT_NO_ROOM)
+      || (c->no_object && tile->object != NULL)
+      || (c->not_near_stairs && NearStairs(x, y))
+      || (c->not_in_shop && tile->terrain_flags.f_in_kecleon_shop)
+      || (c->not_in_monster_house && tile->terrain_flags.f_in_monster_house)) {
+    return false;
+  }
+  if (c->has_area
+      && (x < c->area.x0 || x > c->area.x1 || y < c->area.y0 || y > c->area.y1)) {
+    return false;
+  }
+  return LeaderDistanceMatches(c, x, y) && (c->custom == NULL || c->custom(x, y));
+}
+
+int CotCountConstrainedTiles(const spawn_constraint* c) {
+  int n = 0;
+  for (int y = 0; y < COT_FLOOR_HEIGHT; y++) {
+    for (int x = 0; x < COT_FLOOR_WIDTH; x++) {
+      n += CotConstraintMatches(c, x, y);
+    }
+  }
+  return n;
+}
+
+bool CotPickConstrainedTile(const spawn_constraint* c, struct position* out) {
+  int n = CotCountConstrainedTiles(c);
+  if (n == 0) {
+    return false;
+  }
+  int pick = CotRandInt(RNG_DUNGEON, n);
+  for (int y = 0; y < COT_FLOOR_HEIGHT; y++) {
+    for (int x = 0; x < COT_FLOOR_WIDTH; x++) {
+      if (CotConstraintMatches(c, x, y) && pick-- == 0) {
+        *out = (struct position) { .x = x, .y = y };
+        return true;
+      }
+    }
+  }
+  return false;
+}
@@ -0,0 +1,110 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+static const spawn_weight_map* maps[COT_N_SPAWN_CATEGORIES];
+// Whether the game's own spawner is running
+static bool game_spawning = false;
+
+void CotSetSpawnWeightMap(spawn_category category, const spawn_weight_map* map) {
+  COT_ASSERT(category >= 0 && category < COT_N_SPAWN_CATEGORIES);
+  maps[category] = map;
+}
+
+const spawn_weight_map* CotGetSpawnWeightMap(spawn_category category) {
+  COT_ASSERT(category >= 0 && category < COT_N_SPAWN_CATEGORIES);
+  return maps[category];
+}
+
+static int TileWeight(const spawn_weight_map* map, spawn_tile_filter_fn filter, int x, int y) {
+  if (!filter(x, y)) {
+    return 0;
+  }
+  return map != NULL ? map->weights[y][x] : 1;
+}
+
+bool CotPickSpawnTile(spawn_category category, spawn_tile_filter_fn filter, struct position* out) {
+  const spawn_weight_map* map = CotGetSpawnWeightMap(category);
+  int total = 0;
+  for (int y = 0; y < COT_FLOOR_HEIGHT; y++) {
+    for (int x = 0; x < COT_FLOOR_WIDTH; x++) {
+      total += TileWeight(map, filter, x, y);
+    }
+  }
+  if (total == 0) {
+    return false;
+  }
+
+  int roll = CotRandInt(RNG_DUNGEON, total);
+  for (int y = 0; y < COT_FLOOR_HEIGHT; y++) {
+    for (int x = 0; x < COT_FLOOR_WIDTH; x++) {
+      roll -= TileWeight(map, filter, x, y);
+      if (roll < 0) {
+        *out = (struct position) { .x = x, .y = y };
+        return true;
+      }
+    }
+  }
+  return false;
+}
+
+void CotFillSpawnWeights(spawn_weight_map* map, uint8_t weight) {
+  for (int y = 0; y < COT_FLOOR_HEIGHT; y++) {
+    for (int x = 0; x < COT_FLOOR_WIDTH; x++) {
+      map->weights[y][x] = weight;
+    }
+  }
+}
+
+void CotRadialSpawnWeights(spawn_weight_map* map, int cx, int cy, int max_weight, int falloff) {
+  for (int y = 0; y < COT_FLOOR_HEIGHT; y++) {
+    for (int x = 0; x < COT_FLOOR_WIDTH; x++) {
+      int dx = x > cx ? x - cx : cx - x;
+      int dy = y > cy ? y - cy : cy - y;
+      // Distance in moves, since monsters can move diagonally
+      int weight = max_weight - falloff * (dx > dy ? dx : dy);
+      map->weights[y][x] = weight < 0 ? 0 : (weight > 0xFF ? 0xFF : weight);
+    }
+  }
+}
+
+static bool IsOnTeamSpawn(int x, int y) {
+  struct position spawn = DUNGEON_PTR->gen_info.team_spawn_pos;
+  return spawn.x == x && spawn.y == y;
+}
+
+static bool IsFreeGameSpawnTile(int x, int y) {
+  struct tile* tile = CotTileAt(x, y);
+  return tile != NULL && CotTileIsFloor(x, y) && !tile->terrain_flags.f_stairs
+         && !tile->terrain_flags.f_in_kecleon_shop && !tile->terrain_flags.f_in_monster_house
+         && !IsOnTeamSpawn(x, y);
+}
+
+static bool IsFreeObjectTile(int x, int y) {
+  return IsFreeGameSpawnTile(x, y) && CotTileAt(x, y)->object == NULL;
+}
+
+static bool IsFreeMonsterTile(int x, int y) {
+  return IsFreeGameSpawnTile(x, y) && CotTileAt(x, y)->monster == NULL;
+}
+
+void cotInternalSetGameSpawning(bool spawning) {
+  game_spawning = spawning;
+}
+
+void cotInternalPickGameSpawnPos(spawn_category category, struct position* pos) {
+  if (!game_spawning || CotGetSpawnWeightMap(category) == NULL) {
+    return;
+  }
+  // Shop stock, shopkeepers and Monster House contents stay where the game put them
+  struct tile* tile = CotTileAt(pos->x, pos->y);
+  if (tile == NULL || tile->terrain_flags.f_in_kecleon_shop
+      || tile->terrain_flags.f_in_monster_house) {
+    return;
+  }
+  spawn_tile_filter_fn filter = category == SPAWN_CATEGORY_MONSTER ? IsFreeMonsterTile
+                                                                   : IsFreeObjectTile;
+  struct position picked;
+  if (CotPickSpawnTile(category, filter, &picked)) {
+    *pos = picked;
+  }
+}
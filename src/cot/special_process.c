@@ -0,0 +1,77 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+static struct {
+  uint32_t id;
+  special_process_fn handler;
+} special_processes[COT_MAX_SPECIAL_PROCESSES];
+
+// Defined by COT_SPECIAL_PROCESS_TABLE, if it's used
+extern const special_process_entry cotSpecialProcessTable[] __attribute__((weak));
+extern const int cotSpecialProcessTableSize __attribute__((weak));
+
+static special_process_fn FindTableSpecialProcess(uint32_t id) {
+  if (&cotSpecialProcessTableSize == NULL) {
+    return NULL;
+  }
+  for (int i = 0; i < cotSpecialProcessTableSize; i++) {
+    if (cotSpecialProcessTable[i].id == id) {
+      return cotSpecialProcessTable[i].handler;
+    }
+  }
+  return NULL;
+}
+
+static int FindSpecialProcess(uint32_t id) {
+  for (int i = 0; i < COT_MAX_SPECIAL_PROCESSES; i++) {
+    if (special_processes[i].handler != NULL && special_processes[i].id == id) {
+      return i;
+    }
+  }
+  return -1;
+}
+
+bool CotRegisterSpecialProcess(uint32_t id, special_process_fn handler) {
+  if (id < 100) {
+    COT_WARNFMT(COT_LOG_CAT_SPECIAL_PROCESS, "Special process ID %d is reserved by the game", id);
+    return false;
+  }
+  if (id >= COT_SP_SCRIPT_CALL_POLL && id <= COT_SP_SCRIPT_CALL_DONE) {
+    COT_WARNFMT(COT_LOG_CAT_SPECIAL_PROCESS, "Special process ID %d is reserved by c-of-time", id);
+    return false;
+  }
+  if (FindSpecialProcess(id) >= 0) {
+    COT_WARNFMT(COT_LOG_CAT_SPECIAL_PROCESS, "Special process %d is already registered", id);
+    return false;
+  }
+  for (int i = 0; i < COT_MAX_SPECIAL_PROCESSES; i++) {
+    if (special_processes[i].handler == NULL) {
+      special_processes[i].id = id;
+      special_processes[i].handler = handler;
+      return true;
+    }
+  }
+  COT_WARN(COT_LOG_CAT_SPECIAL_PROCESS, "Special process table is full");
+  return false;
+}
+
+void CotUnregisterSpecialProcess(uint32_t id) {
+  int i = FindSpecialProcess(id);
+  if (i >= 0) {
+    special_processes[i].handler = NULL;
+  }
+}
+
+bool cotInternalDispatchRegisteredSpecialProcess(
+        undefined4* unknown, uint32_t special_process_id, short arg1, short arg2, int* return_val
+) {
+  int i = FindSpecialProcess(special_process_id);
+  special_process_fn handler = i >= 0 ? special_processes[i].handler
+                                      : FindTableSpecialProcess(special_process_id);
+  if (handler == NULL) {
+    return false;
+  }
+  special_process_args args = { .unknown = unknown, .arg1 = arg1, .arg2 = arg2 };
+  *return_val = handler(&args);
+  return true;
+}
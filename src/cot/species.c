@@ -0,0 +1,116 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+void CotGetSpeciesInfo(enum monster_id id, species_info* out) {
+  out->id = id;
+  out->dex_number = GetDexNumber(id);
+  out->gender = GetMonsterGender(id);
+  for (int i = 0; i < 2; i++) {
+    out->types[i] = GetType(id, i);
+    out->abilities[i] = GetAbility(id, i);
+    out->base_offensive_stats[i] = GetBaseOffensiveStat(id, i);
+    out->base_defensive_stats[i] = GetBaseDefensiveStat(id, i);
+  }
+  out->base_hp = GetBaseHp(id);
+  out->exp_yield = GetExpYield(id);
+  out->recruit_rate[0] = GetRecruitRate1(id);
+  out->recruit_rate[1] = GetRecruitRate2(id);
+  out->mobility = GetMobilityType(id);
+  out->body_size = GetBodySize(id);
+  out->pre_evolution = GetMonsterPreEvolution(id);
+  out->female_form = CotGetFemaleForm(id);
+}
+
+bool CotSpeciesHasType(enum monster_id id, enum type_id type) {
+  return GetType(id, 0) == type || GetType(id, 1) == type;
+}
+
+bool CotSpeciesHasAbility(enum monster_id id, enum ability_id ability) {
+  return GetAbility(id, 0) == ability || GetAbility(id, 1) == ability;
+}
+
+enum monster_id CotGetFemaleForm(enum monster_id id) {
+  if (id <= MONSTER_NONE || id >= COT_FEMALE_FORM_OFFSET) {
+    return MONSTER_NONE;
+  }
+  enum monster_id female = id + COT_FEMALE_FORM_OFFSET;
+  if (GetMonsterGender(female) != GENDER_FEMALE || GetDexNumber(female) != GetDexNumber(id)) {
+    return MONSTER_NONE;
+  }
+  return female;
+}
+
+enum monster_id CotRollGenderForm(enum monster_id id, int female_percent) {
+  enum monster_id female = CotGetFemaleForm(id);
+  if (female == MONSTER_NONE || !CotRandChance(RNG_DUNGEON, female_percent)) {
+    return id;
+  }
+  return female;
+}
+
+bool CotSetApparentSpecies(struct entity* entity, enum monster_id id) {
+  if (entity == NULL || !EntityIsValid(entity) || entity->type != ENTITY_MONSTER) {
+    return false;
+  }
+  ((struct monster*) entity->info)->apparent_id.val = id;
+  LoadMonsterSprite(id, 0);
+  return true;
+}
+
+#define N_MONSTER_DATA_ENTRIES \
+  ((int) (sizeof(MONSTER_DATA_TABLE_PTR->entries) / sizeof(MONSTER_DATA_TABLE_PTR->entries[0])))
+
+static bool IsKeptOrInRange(int value, int max) {
+  return value == COT_DATA_KEEP || (value >= 0 && value <= max);
+}
+
+static bool IsValidPatch(const species_patch* patch) {
+  for (int i = 0; i < 2; i++) {
+    if (!IsKeptOrInRange(patch->types[i], TYPE_NEUTRAL)
+        || !IsKeptOrInRange(patch->abilities[i], UINT8_MAX)
+        || !IsKeptOrInRange(patch->base_offensive_stats[i], UINT8_MAX)
+        || !IsKeptOrInRange(patch->base_defensive_stats[i], UINT8_MAX)) {
+      return false;
+    }
+  }
+  return IsKeptOrInRange(patch->base_hp, UINT16_MAX)
+      && IsKeptOrInRange(patch->exp_yield, UINT16_MAX);
+}
+
+static void PatchField8(uint8_t* field, int value) {
+  if (value != COT_DATA_KEEP) {
+    *field = value;
+  }
+}
+
+bool CotPatchSpeciesData(enum monster_id id, const species_patch* patch) {
+  if (MONSTER_DATA_TABLE_PTR == NULL || id < 0 || id >= N_MONSTER_DATA_ENTRIES) {
+    COT_WARNFMT(COT_LOG_CAT_DATA_TABLES, "Can't patch species %d", id);
+    return false;
+  }
+  if (!IsValidPatch(patch)) {
+    COT_WARNFMT(COT_LOG_CAT_DATA_TABLES, "Out-of-range patch for species %d", id);
+    return false;
+  }
+
+  struct monster_data_table_entry* entry = &MONSTER_DATA_TABLE_PTR->entries[id];
+  for (int i = 0; i < 2; i++) {
+    if (patch->types[i] != COT_DATA_KEEP) {
+      entry->types[i].val = patch->types[i];
+    }
+    if (patch->abilities[i] != COT_DATA_KEEP) {
+      entry->abilities[i].val = patch->abilities[i];
+    }
+  }
+  PatchField8(&entry->base_atk, patch->base_offensive_stats[0]);
+  PatchField8(&entry->base_sp_atk, patch->base_offensive_stats[1]);
+  PatchField8(&entry->base_def, patch->base_defensive_stats[0]);
+  PatchField8(&entry->base_sp_def, patch->base_defensive_stats[1]);
+  if (patch->base_hp != COT_DATA_KEEP) {
+    entry->base_hp = patch->base_hp;
+  }
+  if (patch->exp_yield != COT_DATA_KEEP) {
+    entry->exp_yield = patch->exp_yield;
+  }
+  return true;
+}
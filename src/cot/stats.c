@@ -0,0 +1,152 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+// Bumped whenever the layout of run_stats changes, so old data is discarded.
+#define STATS_VERSION 1
+
+static run_stats current_run;
+static run_stats lifetime;
+static bool run_active = false;
+
+static species_stats* GetOrAddSpecies(run_stats* stats, uint16_t species) {
+  for (int i = 0; i < stats->n_species; i++) {
+    if (stats->species[i].species == species) {
+      return &stats->species[i];
+    }
+  }
+  if (stats->n_species >= COT_STATS_MAX_SPECIES) {
+    return NULL;
+  }
+  species_stats* entry = &stats->species[stats->n_species++];
+  *entry = (species_stats) { .species = species };
+  return entry;
+}
+
+// Only the team's damage is counted, so enemies fighting each other don't show up in the stats.
+static uint16_t GetTeamMemberSpecies(struct entity* entity) {
+  if (entity == NULL || !EntityIsValid(entity) || entity->type != ENTITY_MONSTER) {
+    return MONSTER_NONE;
+  }
+  struct monster* monster = entity->info;
+  return monster->is_not_team_member ? MONSTER_NONE : monster->id.val;
+}
+
+void CotStatsBeginRun(void) {
+  memset(&current_run, 0, sizeof(current_run));
+  current_run.runs = 1;
+  run_active = true;
+}
+
+void CotStatsEndRun(void) {
+  if (!run_active) {
+    return;
+  }
+  run_active = false;
+
+  lifetime.runs += current_run.runs;
+  lifetime.floors_visited += current_run.floors_visited;
+  lifetime.items_used += current_run.items_used;
+  lifetime.damage_dealt += current_run.damage_dealt;
+  lifetime.damage_taken += current_run.damage_taken;
+  for (int i = 0; i < current_run.n_species; i++) {
+    species_stats* entry = GetOrAddSpecies(&lifetime, current_run.species[i].species);
+    if (entry != NULL) {
+      entry->damage_dealt += current_run.species[i].damage_dealt;
+      entry->damage_taken += current_run.species[i].damage_taken;
+    }
+  }
+  COT_LOGFMT(COT_LOG_CAT_STATS, "Run ended after %d floors", current_run.floors_visited);
+}
+
+void CotStatsRecordDamage(struct entity* attacker, struct entity* defender, int amount) {
+  if (!run_active || amount <= 0) {
+    return;
+  }
+  uint16_t attacker_species = GetTeamMemberSpecies(attacker);
+  if (attacker_species != MONSTER_NONE) {
+    current_run.damage_dealt += amount;
+    species_stats* entry = GetOrAddSpecies(&current_run, attacker_species);
+    if (entry != NULL) {
+      entry->damage_dealt += amount;
+    }
+  }
+  uint16_t defender_species = GetTeamMemberSpecies(defender);
+  if (defender_species != MONSTER_NONE) {
+    current_run.damage_taken += amount;
+    species_stats* entry = GetOrAddSpecies(&current_run, defender_species);
+    if (entry != NULL) {
+      entry->damage_taken += amount;
+    }
+  }
+}
+
+void CotStatsRecordItemUsed(struct entity* user, struct item* item) {
+  if (run_active && GetTeamMemberSpecies(user) != MONSTER_NONE) {
+    current_run.items_used++;
+  }
+}
+
+void CotStatsRecordFloor(void) {
+  if (run_active) {
+    current_run.floors_visited++;
+  }
+}
+
+const run_stats* CotGetRunStats(void) {
+  return &current_run;
+}
+
+const run_stats* CotGetLifetimeStats(void) {
+  return &lifetime;
+}
+
+const species_stats* CotGetSpeciesStats(const run_stats* stats, enum monster_id species) {
+  for (int i = 0; i < stats->n_species; i++) {
+    if (stats->species[i].species == species) {
+      return &stats->species[i];
+    }
+  }
+  return NULL;
+}
+
+int CotStatsSerializedSize(void) {
+  return sizeof(uint32_t) + sizeof(run_stats);
+}
+
+bool CotStatsSerialize(void* buf, int size) {
+  if (size < CotStatsSerializedSize()) {
+    return false;
+  }
+  uint32_t version = STATS_VERSION;
+  memcpy(buf, &version, sizeof(version));
+  memcpy((uint8_t*) buf + sizeof(version), &lifetime, sizeof(lifetime));
+  return true;
+}
+
+bool CotStatsDeserialize(const void* buf, int size) {
+  uint32_t version;
+  if (size < CotStatsSerializedSize()) {
+    return false;
+  }
+  memcpy(&version, buf, sizeof(version));
+  if (version != STATS_VERSION) {
+    COT_WARNFMT(COT_LOG_CAT_STATS, "Discarding statistics with unknown version %d", version);
+    return false;
+  }
+  memcpy(&lifetime, (const uint8_t*) buf + sizeof(version), sizeof(lifetime));
+  if (lifetime.n_species > COT_STATS_MAX_SPECIES) {
+    lifetime.n_species = COT_STATS_MAX_SPECIES;
+  }
+  return true;
+}
+
+static const save_block stats_save_block = {
+  .tag = COT_SAVE_TAG('S', 'T', 'A', 'T'),
+  .version = STATS_VERSION,
+  .size = sizeof(run_stats),
+  .data = &lifetime,
+};
+
+bool CotStatsRegisterSaveBlock(void) {
+  return CotRegisterSaveBlock(&stats_save_block);
+}
@@ -0,0 +1,122 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+typedef enum status_class {
+  STATUS_CLASS_SLEEP = 0,
+  STATUS_CLASS_BURN,
+  STATUS_CLASS_FROZEN,
+  STATUS_CLASS_CRINGE,
+} status_class;
+
+typedef struct status_info {
+  status_class class;
+  // Value of the class' status field
+  int id;
+} status_info;
+
+static const status_info STATUS_INFO[STATUS_EFFECT_COUNT] = {
+  [STATUS_EFFECT_SLEEP] = { STATUS_CLASS_SLEEP, STATUS_SLEEP_SLEEP },
+  [STATUS_EFFECT_NIGHTMARE] = { STATUS_CLASS_SLEEP, STATUS_SLEEP_NIGHTMARE },
+  [STATUS_EFFECT_YAWNING] = { STATUS_CLASS_SLEEP, STATUS_SLEEP_YAWNING },
+  [STATUS_EFFECT_NAPPING] = { STATUS_CLASS_SLEEP, STATUS_SLEEP_NAPPING },
+  [STATUS_EFFECT_BURN] = { STATUS_CLASS_BURN, STATUS_BURN_BURN },
+  [STATUS_EFFECT_POISONED] = { STATUS_CLASS_BURN, STATUS_BURN_POISONED },
+  [STATUS_EFFECT_BADLY_POISONED] = { STATUS_CLASS_BURN, STATUS_BURN_BADLY_POISONED },
+  [STATUS_EFFECT_PARALYSIS] = { STATUS_CLASS_BURN, STATUS_BURN_PARALYSIS },
+  [STATUS_EFFECT_FROZEN] = { STATUS_CLASS_FROZEN, STATUS_FROZEN_FROZEN },
+  [STATUS_EFFECT_CRINGE] = { STATUS_CLASS_CRINGE, STATUS_CRINGE_CRINGE },
+  [STATUS_EFFECT_CONFUSED] = { STATUS_CLASS_CRINGE, STATUS_CRINGE_CONFUSED },
+  [STATUS_EFFECT_PAUSED] = { STATUS_CLASS_CRINGE, STATUS_CRINGE_PAUSED },
+  [STATUS_EFFECT_INFATUATED] = { STATUS_CLASS_CRINGE, STATUS_CRINGE_INFATUATED },
+};
+
+static int GetClassStatus(struct monster* monster, status_class class) {
+  switch (class) {
+    case STATUS_CLASS_SLEEP:
+      return monster->statuses.sleep.val;
+    case STATUS_CLASS_BURN:
+      return monster->statuses.burn.val;
+    case STATUS_CLASS_FROZEN:
+      return monster->statuses.frozen.val;
+    case STATUS_CLASS_CRINGE:
+      return monster->statuses.cringe.val;
+  }
+  return 0;
+}
+
+bool CotHasStatus(struct entity* entity, status_effect status) {
+  if (status >= STATUS_EFFECT_COUNT) {
+    return false;
+  }
+  const status_info* info = &STATUS_INFO[status];
+  return GetClassStatus((struct monster*) entity->info, info->class) == info->id;
+}
+
+bool CotInflictStatus(struct entity* user, struct entity* target, status_effect status, int turns) {
+  switch (status) {
+    case STATUS_EFFECT_SLEEP:
+      TryInflictSleepStatus(user, target, turns, true);
+      break;
+    case STATUS_EFFECT_NIGHTMARE:
+      TryInflictNightmareStatus(user, target, turns);
+      break;
+    case STATUS_EFFECT_YAWNING:
+      TryInflictYawningStatus(user, target, turns);
+      break;
+    case STATUS_EFFECT_NAPPING:
+      TryInflictNappingStatus(user, target, turns);
+      break;
+    case STATUS_EFFECT_BURN:
+      TryInflictBurnStatus(user, target, false, true, false);
+      break;
+    case STATUS_EFFECT_POISONED:
+      TryInflictPoisonedStatus(user, target, true, false);
+      break;
+    case STATUS_EFFECT_BADLY_POISONED:
+      TryInflictBadlyPoisonedStatus(user, target, true, false);
+      break;
+    case STATUS_EFFECT_PARALYSIS:
+      TryInflictParalysisStatus(user, target, true, false);
+      break;
+    case STATUS_EFFECT_FROZEN:
+      TryInflictFrozenStatus(user, target, true);
+      break;
+    case STATUS_EFFECT_CRINGE:
+      TryInflictCringeStatus(user, target, true, false);
+      break;
+    case STATUS_EFFECT_CONFUSED:
+      TryInflictConfusedStatus(user, target, true, false);
+      break;
+    case STATUS_EFFECT_PAUSED:
+      TryInflictPausedStatus(user, target, 0, turns, true, false);
+      break;
+    case STATUS_EFFECT_INFATUATED:
+      TryInflictInfatuatedStatus(user, target, true, false);
+      break;
+    default:
+      COT_WARNFMT(COT_LOG_CAT_STATUSES, "Invalid status %d", status);
+      return false;
+  }
+  return CotHasStatus(target, status);
+}
+
+bool CotCureStatus(struct entity* user, struct entity* target, status_effect status) {
+  if (!CotHasStatus(target, status)) {
+    return false;
+  }
+  switch (STATUS_INFO[status].class) {
+    case STATUS_CLASS_SLEEP:
+      EndSleepClassStatus(user, target);
+      break;
+    case STATUS_CLASS_BURN:
+      EndBurnClassStatus(user, target);
+      break;
+    case STATUS_CLASS_FROZEN:
+      EndFrozenClassStatus(user, target, true);
+      break;
+    case STATUS_CLASS_CRINGE:
+      EndCringeClassStatus(user, target);
+      break;
+  }
+  return true;
+}
@@ -0,0 +1,105 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+str_buf CotStrBufInit(char* buf, int size) {
+  COT_ASSERT(size > 0);
+  buf[0] = '\0';
+  return (str_buf) { .buf = buf, .size = size, .len = 0, .truncated = false };
+}
+
+void CotStrBufClear(str_buf* sb) {
+  sb->len = 0;
+  sb->truncated = false;
+  sb->buf[0] = '\0';
+}
+
+void CotStrBufAppendChar(str_buf* sb, char c) {
+  if (sb->len + 1 >= sb->size) {
+    sb->truncated = true;
+    return;
+  }
+  sb->buf[sb->len++] = c;
+  sb->buf[sb->len] = '\0';
+}
+
+void CotStrBufAppend(str_buf* sb, const char* str) {
+  while (*str != '\0' && !sb->truncated) {
+    CotStrBufAppendChar(sb, *str++);
+  }
+}
+
+void CotStrBufAppendInt(str_buf* sb, int value) {
+  char digits[12];
+  int n = 0;
+  // Negate as unsigned, so INT_MIN works as well
+  unsigned int magnitude = value < 0 ? -(unsigned int) value : (unsigned int) value;
+  do {
+    digits[n++] = '0' + magnitude % 10;
+    magnitude /= 10;
+  } while (magnitude > 0);
+  if (value < 0) {
+    CotStrBufAppendChar(sb, '-');
+  }
+  while (n > 0) {
+    CotStrBufAppendChar(sb, digits[--n]);
+  }
+}
+
+// Characters of Windows-1252 from 0x80 to 0x9F, the rest of the encoding matches Latin-1.
+// 0 marks the unused bytes.
+static const uint16_t WINDOWS_1252_HIGH[32] = {
+  0x20AC, 0, 0x201A, 0x0192, 0x201E, 0x2026, 0x2020, 0x2021,
+  0x02C6, 0x2030, 0x0160, 0x2039, 0x0152, 0, 0x017D, 0,
+  0, 0x2018, 0x2019, 0x201C, 0x201D, 0x2022, 0x2013, 0x2014,
+  0x02DC, 0x2122, 0x0161, 0x203A, 0x0153, 0, 0x017E, 0x0178,
+};
+
+static char EncodeCodePoint(uint32_t code_point) {
+  if (code_point < 0x80 || (code_point >= 0xA0 && code_point <= 0xFF)) {
+    return (char) code_point;
+  }
+  for (int i = 0; i < 32; i++) {
+    if (WINDOWS_1252_HIGH[i] != 0 && WINDOWS_1252_HIGH[i] == code_point) {
+      return (char) (0x80 + i);
+    }
+  }
+  return '?';
+}
+
+// Decodes the code point at `*str` and advances past it. Returns 0xFFFFFFFF for invalid
+// sequences.
+static uint32_t DecodeUtf8(const uint8_t** str) {
+  uint8_t lead = *(*str)++;
+  if (lead < 0x80) {
+    return lead;
+  }
+  int n_continuation;
+  uint32_t code_point;
+  if ((lead & 0xE0) == 0xC0) {
+    n_continuation = 1;
+    code_point = lead & 0x1F;
+  } else if ((lead & 0xF0) == 0xE0) {
+    n_continuation = 2;
+    code_point = lead & 0x0F;
+  } else if ((lead & 0xF8) == 0xF0) {
+    n_continuation = 3;
+    code_point = lead & 0x07;
+  } else {
+    return 0xFFFFFFFF;
+  }
+  for (int i = 0; i < n_continuation; i++) {
+    // Stops at the terminator as well
+    if ((**str & 0xC0) != 0x80) {
+      return 0xFFFFFFFF;
+    }
+    code_point = (code_point << 6) | (*(*str)++ & 0x3F);
+  }
+  return code_point;
+}
+
+void CotStrBufAppendUtf8(str_buf* sb, const char* str) {
+  const uint8_t* ptr = (const uint8_t*) str;
+  while (*ptr != '\0' && !sb->truncated) {
+    CotStrBufAppendChar(sb, EncodeCodePoint(DecodeUtf8(&ptr)));
+  }
+}
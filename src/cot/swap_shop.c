@@ -0,0 +1,68 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+static swap_exchange exchanges[COT_MAX_SWAP_EXCHANGES];
+static int n_exchanges = 0;
+static struct {
+  const enum item_id* items;
+  int n;
+} prize_pools[COT_SWAP_N_TIERS];
+
+static int FindExchange(enum item_id receive) {
+  for (int i = 0; i < n_exchanges; i++) {
+    if (exchanges[i].receive == receive) {
+      return i;
+    }
+  }
+  return -1;
+}
+
+bool CotSetSwapExchange(swap_exchange exchange) {
+  if (exchange.n_give < 1 || exchange.n_give > COT_SWAP_MAX_INPUTS) {
+    COT_WARNFMT(COT_LOG_CAT_SWAP_SHOP, "Invalid number of inputs for item %d", exchange.receive);
+    return false;
+  }
+  int i = FindExchange(exchange.receive);
+  if (i >= 0) {
+    exchanges[i] = exchange;
+    return true;
+  }
+  if (n_exchanges >= COT_MAX_SWAP_EXCHANGES) {
+    COT_WARN(COT_LOG_CAT_SWAP_SHOP, "Swap exchange table is full");
+    return false;
+  }
+  exchanges[n_exchanges++] = exchange;
+  return true;
+}
+
+void CotRemoveSwapExchange(enum item_id receive) {
+  int i = FindExchange(receive);
+  if (i >= 0) {
+    exchanges[i] = exchanges[--n_exchanges];
+  }
+}
+
+void CotSetSwapPrizePool(int tier, const enum item_id* items, int n) {
+  if (tier >= 0 && tier < COT_SWAP_N_TIERS) {
+    prize_pools[tier].items = items;
+    prize_pools[tier].n = n;
+  }
+}
+
+bool CotGetSwapExchange(enum item_id receive, swap_exchange* out) {
+  int i = FindExchange(receive);
+  if (i < 0) {
+    return false;
+  }
+  *out = exchanges[i];
+  return true;
+}
+
+bool CotRollSwapPrize(int tier, enum item_id* out) {
+  if (tier < 0 || tier >= COT_SWAP_N_TIERS || prize_pools[tier].n == 0) {
+    return false;
+  }
+  *out = prize_pools[tier].items[CotRandInt(RNG_GENERAL, prize_pools[tier].n)];
+  COT_LOGFMT(COT_LOG_CAT_SWAP_SHOP, "Rolled prize %d from tier %d", *out, tier);
+  return true;
+}
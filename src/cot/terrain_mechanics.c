@@ -0,0 +1,122 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+typedef struct tracked_position {
+  struct entity* entity;
+  struct position pos;
+} tracked_position;
+
+static uint8_t mechanics[COT_FLOOR_HEIGHT][COT_FLOOR_WIDTH];
+static bool any_mechanics = false;
+// Positions of the monsters at the end of the last turn, to detect their steps
+static COT_VEC(tracked_position, COT_MAX_TERRAIN_MONSTERS) positions;
+
+void CotClearTerrainMechanics(void) {
+  memset(mechanics, TERRAIN_MECHANIC_NONE, sizeof(mechanics));
+  any_mechanics = false;
+  positions.len = 0;
+}
+
+void CotSetTerrainMechanic(int x, int y, terrain_mechanic mechanic) {
+  if (CotTileInBounds(x, y)) {
+    mechanics[y][x] = mechanic;
+    any_mechanics |= mechanic != TERRAIN_MECHANIC_NONE;
+  }
+}
+
+void CotFillTerrainMechanic(int x, int y, int w, int h, terrain_mechanic mechanic) {
+  for (int j = y; j < y + h; j++) {
+    for (int i = x; i < x + w; i++) {
+      CotSetTerrainMechanic(i, j, mechanic);
+    }
+  }
+}
+
+terrain_mechanic CotGetTerrainMechanic(int x, int y) {
+  if (!CotTileInBounds(x, y)) {
+    return TERRAIN_MECHANIC_NONE;
+  }
+  return mechanics[y][x];
+}
+
+static bool CanEnter(struct entity* entity, int x, int y) {
+  struct entity* occupant = CotTileMonster(x, y);
+  return CotTileIsFloor(x, y) && (occupant == NULL || occupant == entity);
+}
+
+static bool TryStep(struct entity* entity, enum direction_id direction) {
+  struct position pos = CotPosStep(entity->pos, direction);
+  if (!CanEnter(entity, pos.x, pos.y)) {
+    return false;
+  }
+  MoveMonsterToPos(entity, pos.x, pos.y, 0);
+  return true;
+}
+
+static enum direction_id ConveyorDirection(terrain_mechanic mechanic) {
+  switch (mechanic) {
+    case TERRAIN_MECHANIC_CONVEYOR_RIGHT:
+      return DIR_RIGHT;
+    case TERRAIN_MECHANIC_CONVEYOR_UP:
+      return DIR_UP;
+    case TERRAIN_MECHANIC_CONVEYOR_LEFT:
+      return DIR_LEFT;
+    default:
+      return DIR_DOWN;
+  }
+}
+
+bool CotApplyTerrainMechanics(struct entity* entity, enum direction_id direction) {
+  if (!EntityIsValid(entity) || entity->type != ENTITY_MONSTER) {
+    return false;
+  }
+
+  terrain_mechanic mechanic = CotGetTerrainMechanic(entity->pos.x, entity->pos.y);
+  if (mechanic == TERRAIN_MECHANIC_ICE) {
+    bool moved = false;
+    // Bounded by the floor size so a fully iced floor can't loop forever.
+    for (int i = 0; i < COT_FLOOR_WIDTH; i++) {
+      if (CotGetTerrainMechanic(entity->pos.x, entity->pos.y) != TERRAIN_MECHANIC_ICE
+          || !TryStep(entity, direction)) {
+        break;
+      }
+      moved = true;
+    }
+    return moved;
+  }
+  if (mechanic != TERRAIN_MECHANIC_NONE) {
+    return TryStep(entity, ConveyorDirection(mechanic));
+  }
+  return false;
+}
+
+static bool FindLastPosition(struct entity* entity, struct position* out) {
+  for (int i = 0; i < positions.len; i++) {
+    if (positions.items[i].entity == entity) {
+      *out = positions.items[i].pos;
+      return true;
+    }
+  }
+  return false;
+}
+
+void cotInternalUpdateTerrainMechanics(void) {
+  if (!any_mechanics) {
+    return;
+  }
+  __typeof__(positions) current = { .len = 0 };
+  struct entity* entity;
+  COT_FOR_EACH_ENTITY(ITER_MONSTERS, entity) {
+    struct position last;
+    enum direction_id direction;
+    if (FindLastPosition(entity, &last) && CotChebyshevDistance(last, entity->pos) == 1
+        && CotDirectionTowards(last, entity->pos, &direction)) {
+      CotApplyTerrainMechanics(entity, direction);
+    }
+    tracked_position tracked = { .entity = entity, .pos = entity->pos };
+    if (!COT_VEC_PUSH(&current, tracked)) {
+      break;
+    }
+  }
+  positions = current;
+}
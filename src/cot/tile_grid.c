@@ -0,0 +1,151 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+struct tile* CotTileAt(int x, int y) {
+  if (!CotTileInBounds(x, y)) {
+    return NULL;
+  }
+  return GetTileSafe(x, y);
+}
+
+bool CotTileIsFloor(int x, int y) {
+  struct tile* tile = CotTileAt(x, y);
+  return tile != NULL && tile->terrain_flags.terrain_type == TERRAIN_NORMAL;
+}
+
+bool CotTileIsWall(int x, int y) {
+  struct tile* tile = CotTileAt(x, y);
+  return tile != NULL && tile->terrain_flags.terrain_type == TERRAIN_WALL;
+}
+
+struct entity* CotTileMonster(int x, int y) {
+  struct tile* tile = CotTileAt(x, y);
+  return tile != NULL ? tile->monster : NULL;
+}
+
+void CotSetTileTerrain(int x, int y, enum terrain_type terrain, bool unbreakable) {
+  struct tile* tile = CotTileAt(x, y);
+  if (tile == NULL) {
+    COT_WARNFMT(COT_LOG_CAT_TILE_GRID, "Can't set terrain of out-of-bounds tile (%d, %d)", x, y);
+    return;
+  }
+  bool wall = terrain == TERRAIN_WALL;
+  tile->terrain_flags.terrain_type = terrain;
+  tile->terrain_flags.f_impassable_wall = wall && unbreakable;
+  tile->terrain_flags.f_unbreakable = wall && unbreakable;
+
+  // Walkable neighbor flags of the surrounding tiles depend on this tile
+  for (int j = y - 1; j <= y + 1; j++) {
+    for (int i = x - 1; i <= x + 1; i++) {
+      if (CotTileInBounds(i, j)) {
+        DetermineTileWalkableNeighbors(i, j);
+      }
+    }
+  }
+}
+
+int CotTileRoom(int x, int y) {
+  struct tile* tile = CotTileAt(x, y);
+  return tile != NULL ? tile->room : COT_NO_ROOM;
+}
+
+void CotSetTileRoom(int x, int y, int room) {
+  struct tile* tile = CotTileAt(x, y);
+  if (tile != NULL) {
+    tile->room = room;
+  }
+}
+
+bool CotTileIsJunction(int x, int y) {
+  struct tile* tile = CotTileAt(x, y);
+  return tile != NULL && tile->terrain_flags.f_natural_junction;
+}
+
+void CotSetTileJunction(int x, int y, bool junction) {
+  struct tile* tile = CotTileAt(x, y);
+  if (tile != NULL) {
+    tile->terrain_flags.f_natural_junction = junction;
+  }
+}
+
+bool CotTileIsStairs(int x, int y) {
+  struct tile* tile = CotTileAt(x, y);
+  return tile != NULL && tile->terrain_flags.f_stairs;
+}
+
+void CotSetStairs(int x, int y) {
+  struct tile* tile = CotTileAt(x, y);
+  if (tile == NULL) {
+    COT_WARNFMT(COT_LOG_CAT_TILE_GRID, "Can't place stairs on out-of-bounds tile (%d, %d)", x, y);
+    return;
+  }
+  struct position* stairs = &DUNGEON_PTR->gen_info.stairs_pos;
+  struct tile* old = CotTileAt(stairs->x, stairs->y);
+  if (old != NULL) {
+    old->terrain_flags.f_stairs = false;
+  }
+  tile->terrain_flags.f_stairs = true;
+  *stairs = (struct position) { .x = x, .y = y };
+}
+
+// The game packs the spawn and visibility bits into one byte, see tile_spawn_flag
+static uint8_t* SpawnOrVisibilityBits(struct tile* tile) {
+  return (uint8_t*) &tile->spawn_or_visibility_flags;
+}
+
+unsigned CotTileSpawnFlags(int x, int y) {
+  struct tile* tile = CotTileAt(x, y);
+  return tile != NULL ? *SpawnOrVisibilityBits(tile) & 0xF : 0;
+}
+
+void CotSetTileSpawnFlags(int x, int y, unsigned flags) {
+  struct tile* tile = CotTileAt(x, y);
+  if (tile != NULL) {
+    uint8_t* bits = SpawnOrVisibilityBits(tile);
+    *bits = (*bits & ~0xF) | (flags & 0xF);
+  }
+}
+
+bool CotTileIsRevealed(int x, int y) {
+  struct tile* tile = CotTileAt(x, y);
+  return tile != NULL && tile->spawn_or_visibility_flags.f_revealed;
+}
+
+void CotSetTileRevealed(int x, int y, bool revealed) {
+  struct tile* tile = CotTileAt(x, y);
+  if (tile != NULL) {
+    tile->spawn_or_visibility_flags.f_revealed = revealed;
+  }
+}
+
+room_iter CotIterRoom(int room) {
+  return (room_iter) { .room = room, .pos = { .x = 0, .y = 0 } };
+}
+
+bool CotRoomNext(room_iter* it, struct position* out) {
+  while (it->pos.y < COT_FLOOR_HEIGHT) {
+    struct position pos = it->pos;
+    if (++it->pos.x >= COT_FLOOR_WIDTH) {
+      it->pos.x = 0;
+      it->pos.y++;
+    }
+    if (CotTileRoom(pos.x, pos.y) == it->room) {
+      *out = pos;
+      return true;
+    }
+  }
+  return false;
+}
+
+void CotForEachTile(int x, int y, int w, int h, void (*fn)(struct tile* tile, int x, int y, void* ctx),
+                    void* ctx) {
+  int x0 = x < 0 ? 0 : x;
+  int y0 = y < 0 ? 0 : y;
+  int x1 = x + w > COT_FLOOR_WIDTH ? COT_FLOOR_WIDTH : x + w;
+  int y1 = y + h > COT_FLOOR_HEIGHT ? COT_FLOOR_HEIGHT : y + h;
+  for (int j = y0; j < y1; j++) {
+    for (int i = x0; i < x1; i++) {
+      fn(GetTileSafe(i, j), i, j, ctx);
+    }
+  }
+}
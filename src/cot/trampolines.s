@@ -76,3 +76,101 @@ move_effect_input:
   .word 0
 move_effect_input_out_dealt_damage:
   .word 0
+
+// Trampolines and original stubs of the game functions wrapped with cot_wrap in
+// patches/internal.asm. The trampoline jumps to the wrapper in hooks.c. The original stub runs
+// the game's implementation: the patch copies the function's first instruction into the
+// stub's first word, which continues with the rest of the function.
+.macro cot_wrapper func
+.align 4
+cotInternalTrampoline\func:
+  b cotInternalHook\func
+
+.global cotInternalOriginal\func
+cotInternalOriginal\func:
+  // First instruction of the game's function, written by cot_wrap in patches/internal.asm
+  .word 0
+  b \func+4
+  // Literal loaded by the first instruction, if it loads one
+  .word 0
+.endm
+
+cot_wrapper GenerateFloor
+cot_wrapper ApplyTrapEffect
+cot_wrapper RunFractionalTurn
+cot_wrapper RunMonsterAi
+cot_wrapper IqSkillIsEnabled
+cot_wrapper GenerateFixedRoom
+cot_wrapper HandleFaint
+cot_wrapper SpawnNonEnemies
+cot_wrapper GetActualBuyPrice
+cot_wrapper GetActualSellPrice
+cot_wrapper HiddenStairsTrigger
+cot_wrapper DrawMinimapTile
+cot_wrapper TryActivateWeather
+cot_wrapper DoMoveTrapbust
+cot_wrapper CanMonsterMoveInDirection
+cot_wrapper GetType
+cot_wrapper GetAbility
+cot_wrapper GetNameString
+cot_wrapper GetCategoryString
+cot_wrapper GetSpriteIndex
+cot_wrapper GetMonsterIdToSpawn
+cot_wrapper GetAiUseItemProbability
+cot_wrapper CalcDamage
+cot_wrapper GetLowKickMultiplier
+cot_wrapper SpawnMonster
+cot_wrapper GetMoveType
+cot_wrapper GetMoveBasePower
+cot_wrapper GetMoveBasePp
+cot_wrapper GetMoveAccuracyOrAiChance
+cot_wrapper GetMoveCategory
+cot_wrapper GetItemCategory
+cot_wrapper GetItemSpriteId
+cot_wrapper GetItemPaletteId
+cot_wrapper GetItemBuyPrice
+cot_wrapper GetItemSellPrice
+cot_wrapper GetKecleonIdToSpawnByFloor
+cot_wrapper RunNextOpcode
+cot_wrapper GetDexNumber
+cot_wrapper GetMonsterGender
+cot_wrapper GetBodySize
+cot_wrapper GetSpriteSize
+cot_wrapper GetShadowSize
+cot_wrapper GetSpeedStatus
+cot_wrapper GetMobilityType
+cot_wrapper GetRegenSpeed
+cot_wrapper GetCanMoveFlag
+cot_wrapper GetChanceAsleep
+cot_wrapper GetBaseHp
+cot_wrapper GetRecruitRate1
+cot_wrapper GetRecruitRate2
+cot_wrapper GetIqGroup
+cot_wrapper GetSpawnThreshold
+cot_wrapper NeedsItemToSpawn
+cot_wrapper GetBaseOffensiveStat
+cot_wrapper GetBaseDefensiveStat
+cot_wrapper GetExp
+cot_wrapper GetLvlUpEntry
+cot_wrapper GetNameRaw
+cot_wrapper GetMaxPp
+cot_wrapper GetMoveCritChance
+cot_wrapper GetMoveAnimation
+cot_wrapper GetMoveAnimationSpeed
+cot_wrapper StringFromId
+cot_wrapper GetItemName
+cot_wrapper IsThrownItem
+cot_wrapper IsEdible
+cot_wrapper AddMissionToJobList
+cot_wrapper GroundToDungeonMoveset
+cot_wrapper ApplyGummiBoostsDungeonMode
+cot_wrapper SubstitutePlaceholderStringTags
+cot_wrapper PickUpItem
+cot_wrapper SpawnInitialMonsters
+cot_wrapper SpawnItem
+cot_wrapper SpawnTrap
+cot_wrapper GetMovesetLevelUpPtr
+cot_wrapper GetMovesetHmTmPtr
+cot_wrapper GetMovesetEggPtr
+cot_wrapper CalcDamageFixed
+cot_wrapper IsMarowakTrainingMaze
@@ -0,0 +1,87 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+static transform_hook_fn transform_hook = NULL;
+
+void CotSetTransformHook(transform_hook_fn hook) {
+  transform_hook = hook;
+}
+
+static void TakeSnapshot(struct monster* monster, unsigned flags, transform_snapshot* snapshot) {
+  snapshot->flags = flags;
+  snapshot->apparent_id = monster->apparent_id.val;
+  for (int i = 0; i < 2; i++) {
+    snapshot->types[i] = monster->types[i].val;
+    snapshot->abilities[i] = monster->abilities[i].val;
+    snapshot->offensive_stats[i] = monster->offensive_stats[i];
+    snapshot->defensive_stats[i] = monster->defensive_stats[i];
+  }
+  memcpy(snapshot->moves, monster->moves, sizeof(snapshot->moves));
+}
+
+static bool IsMonster(struct entity* entity) {
+  return entity != NULL && EntityIsValid(entity) && entity->type == ENTITY_MONSTER;
+}
+
+bool CotTransform(struct entity* user, struct entity* target, unsigned flags,
+                  transform_snapshot* snapshot) {
+  if (!IsMonster(user) || !IsMonster(target)) {
+    return false;
+  }
+  if (transform_hook != NULL && !transform_hook(user, target, &flags)) {
+    return false;
+  }
+  struct monster* monster = (struct monster*) user->info;
+  struct monster* source = (struct monster*) target->info;
+  if (snapshot != NULL) {
+    TakeSnapshot(monster, flags, snapshot);
+  }
+
+  if (flags & TRANSFORM_APPEARANCE) {
+    CotSetApparentSpecies(user, source->apparent_id.val);
+  }
+  for (int i = 0; i < 2; i++) {
+    if (flags & TRANSFORM_TYPES) {
+      monster->types[i] = source->types[i];
+    }
+    if (flags & TRANSFORM_ABILITIES) {
+      monster->abilities[i] = source->abilities[i];
+    }
+    if (flags & TRANSFORM_STATS) {
+      monster->offensive_stats[i] = source->offensive_stats[i];
+      monster->defensive_stats[i] = source->defensive_stats[i];
+    }
+  }
+  if (flags & TRANSFORM_MOVES) {
+    for (int i = 0; i < 4; i++) {
+      monster->moves[i] = source->moves[i];
+      monster->moves[i].pp = COT_TRANSFORM_MOVE_PP;
+    }
+  }
+  return true;
+}
+
+void CotRevertTransform(struct entity* entity, const transform_snapshot* snapshot) {
+  if (!IsMonster(entity)) {
+    return;
+  }
+  struct monster* monster = (struct monster*) entity->info;
+  if (snapshot->flags & TRANSFORM_APPEARANCE) {
+    CotSetApparentSpecies(entity, snapshot->apparent_id);
+  }
+  for (int i = 0; i < 2; i++) {
+    if (snapshot->flags & TRANSFORM_TYPES) {
+      monster->types[i].val = snapshot->types[i];
+    }
+    if (snapshot->flags & TRANSFORM_ABILITIES) {
+      monster->abilities[i].val = snapshot->abilities[i];
+    }
+    if (snapshot->flags & TRANSFORM_STATS) {
+      monster->offensive_stats[i] = snapshot->offensive_stats[i];
+      monster->defensive_stats[i] = snapshot->defensive_stats[i];
+    }
+  }
+  if (snapshot->flags & TRANSFORM_MOVES) {
+    memcpy(monster->moves, snapshot->moves, sizeof(monster->moves));
+  }
+}
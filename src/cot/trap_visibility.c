@@ -0,0 +1,180 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+static uint32_t reveal_masks[TRAP_REVEAL_N_SOURCES] = { COT_TRAP_MASK_ALL, COT_TRAP_MASK_ALL };
+static uint32_t trapbust_mask = COT_TRAP_MASK_ALL;
+static bool trapbust_customized = false;
+static trap_reveal_policy_fn reveal_policy = NULL;
+static bool checking_game_reveals = false;
+// Bit i is set if the trap in trap slot i was visible at the last check
+static uint32_t known_visible[(COT_N_TRAP_SLOTS + 31) / 32];
+
+typedef struct trap_filter {
+  tile_area area;
+  uint32_t mask;
+} trap_filter;
+
+enum trap_id CotGetTrapId(struct entity* trap) {
+  struct trap* info = CotGetTrap(trap);
+  return info != NULL ? info->id.val : TRAP_NONE;
+}
+
+static bool InMask(struct entity* trap, uint32_t mask) {
+  enum trap_id id = CotGetTrapId(trap);
+  return CotGetTrap(trap) != NULL && id < 32 && (mask & COT_TRAP_BIT(id)) != 0;
+}
+
+bool CotIsTrapVisible(struct entity* trap) {
+  return trap->is_visible;
+}
+
+void CotSetTrapVisible(struct entity* trap, bool visible) {
+  trap->is_visible = visible;
+}
+
+static struct entity* GetTrapSlot(int slot) {
+  struct entity* trap = DUNGEON_PTR->entity_table.header.trap_ptrs[slot];
+  return CotGetTrap(trap) != NULL ? trap : NULL;
+}
+
+static bool WasVisible(int slot) {
+  return (known_visible[slot / 32] & (1u << (slot % 32))) != 0;
+}
+
+static void RememberVisibility(int slot, bool visible) {
+  if (visible) {
+    known_visible[slot / 32] |= 1u << (slot % 32);
+  } else {
+    known_visible[slot / 32] &= ~(1u << (slot % 32));
+  }
+}
+
+// Takes the current visibility of all traps as the baseline for the next check
+static void RememberAllVisibility(void) {
+  for (int slot = 0; slot < COT_N_TRAP_SLOTS; slot++) {
+    struct entity* trap = GetTrapSlot(slot);
+    RememberVisibility(slot, trap != NULL && trap->is_visible);
+  }
+}
+
+// Hides the traps the game revealed since the last check if they shouldn't have been
+static void CheckGameReveals(void) {
+  bool hidden_any = false;
+  for (int slot = 0; slot < COT_N_TRAP_SLOTS; slot++) {
+    struct entity* trap = GetTrapSlot(slot);
+    bool visible = trap != NULL && trap->is_visible;
+    if (visible && !WasVisible(slot)
+        && !cotInternalShouldRevealTrap(trap, TRAP_REVEAL_GAME, NULL, true)) {
+      trap->is_visible = false;
+      visible = false;
+      hidden_any = true;
+    }
+    RememberVisibility(slot, visible);
+  }
+  if (hidden_any) {
+    UpdateTrapsVisibility();
+  }
+}
+
+static void CheckGameRevealsFromNow(void) {
+  if (!checking_game_reveals) {
+    checking_game_reveals = CotAddTurnEndHook(CheckGameReveals);
+    if (DUNGEON_PTR != NULL) {
+      RememberAllVisibility();
+    }
+  }
+}
+
+void CotSetTrapRevealMask(trap_reveal_source source, uint32_t mask) {
+  if (source < 0 || source >= TRAP_REVEAL_N_SOURCES) {
+    COT_WARNFMT(COT_LOG_CAT_TRAPS, "Invalid trap reveal source %d", source);
+    return;
+  }
+  reveal_masks[source] = mask;
+  CheckGameRevealsFromNow();
+}
+
+uint32_t CotGetTrapRevealMask(trap_reveal_source source) {
+  return source >= 0 && source < TRAP_REVEAL_N_SOURCES ? reveal_masks[source] : 0;
+}
+
+void CotSetTrapRevealPolicy(trap_reveal_policy_fn policy) {
+  reveal_policy = policy;
+  CheckGameRevealsFromNow();
+}
+
+static bool InFilter(struct entity* trap, const trap_filter* filter) {
+  return CotAreaContains(filter->area, trap->pos) && InMask(trap, filter->mask);
+}
+
+int CotRevealTraps(tile_area area, uint32_t mask) {
+  trap_filter filter = { .area = area, .mask = mask };
+  int n_revealed = 0;
+  struct entity* trap;
+  COT_FOR_EACH_ENTITY(ITER_TRAPS, trap) {
+    if (!trap->is_visible && InFilter(trap, &filter)
+        && cotInternalShouldRevealTrap(trap, TRAP_REVEAL_CUSTOM, NULL, true)) {
+      trap->is_visible = true;
+      n_revealed++;
+    }
+  }
+  if (n_revealed > 0) {
+    UpdateTrapsVisibility();
+    // Don't let the next check take these for reveals by the game
+    RememberAllVisibility();
+  }
+  return n_revealed;
+}
+
+int CotHideTraps(uint32_t mask) {
+  int n_hidden = 0;
+  struct entity* trap;
+  COT_FOR_EACH_ENTITY(ITER_TRAPS, trap) {
+    if (trap->is_visible && InMask(trap, mask)) {
+      trap->is_visible = false;
+      n_hidden++;
+    }
+  }
+  if (n_hidden > 0) {
+    UpdateTrapsVisibility();
+    RememberAllVisibility();
+  }
+  return n_hidden;
+}
+
+void CotSetTrapbustMask(uint32_t mask) {
+  trapbust_mask = mask;
+  trapbust_customized = true;
+}
+
+static bool ShouldBust(struct entity* trap, void* ctx) {
+  return InFilter(trap, ctx) && InMask(trap, trapbust_mask);
+}
+
+int CotBustTraps(tile_area area, uint32_t mask) {
+  trap_filter filter = { .area = area, .mask = mask };
+  int n_busted = CotDespawnTraps(ShouldBust, &filter);
+  COT_LOGFMT(COT_LOG_CAT_TRAPS, "Busted %d traps", n_busted);
+  return n_busted;
+}
+
+bool cotInternalShouldRevealTrap(struct entity* trap, trap_reveal_source source,
+                                 struct entity* revealer, bool vanilla) {
+  bool reveal = vanilla && InMask(trap, CotGetTrapRevealMask(source));
+  if (reveal_policy != NULL) {
+    reveal = reveal_policy(trap, source, revealer, reveal);
+  }
+  return reveal;
+}
+
+bool cotInternalDoTrapbust(void) {
+  if (!trapbust_customized) {
+    return false;
+  }
+  CotBustTraps(COT_FLOOR_AREA, COT_TRAP_MASK_ALL);
+  return true;
+}
+
+void cotInternalOnTrapsSpawned(void) {
+  RememberAllVisibility();
+}
@@ -0,0 +1,57 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+typedef struct trap_handler {
+  enum trap_id id;
+  trap_effect_fn effect;
+} trap_handler;
+
+static trap_handler handlers[COT_MAX_CUSTOM_TRAPS];
+static int n_handlers = 0;
+
+static trap_handler* FindHandler(enum trap_id id) {
+  for (int i = 0; i < n_handlers; i++) {
+    if (handlers[i].id == id) {
+      return &handlers[i];
+    }
+  }
+  return NULL;
+}
+
+bool CotRegisterTrapHandler(enum trap_id id, trap_effect_fn effect) {
+  // Trap IDs are stored in 8 bits
+  if ((int) id < 0 || id > 0xFF || id == TRAP_NONE) {
+    COT_WARNFMT(COT_LOG_CAT_TRAPS, "Invalid trap ID %d", id);
+    return false;
+  }
+  trap_handler* handler = FindHandler(id);
+  if (handler != NULL) {
+    handler->effect = effect;
+    return true;
+  }
+  if (n_handlers >= COT_MAX_CUSTOM_TRAPS) {
+    COT_WARN(COT_LOG_CAT_TRAPS, "Trap handler table is full");
+    return false;
+  }
+  handlers[n_handlers++] = (trap_handler) { .id = id, .effect = effect };
+  return true;
+}
+
+void CotRemoveTrapHandler(enum trap_id id) {
+  trap_handler* handler = FindHandler(id);
+  if (handler != NULL) {
+    *handler = handlers[--n_handlers];
+  }
+}
+
+bool cotInternalDispatchApplyTrapEffect(struct entity* user, struct entity* target,
+                                        struct trap* trap, struct position* pos,
+                                        enum trap_id id, bool* remove_trap) {
+  trap_handler* handler = FindHandler(id);
+  if (handler == NULL) {
+    return false;
+  }
+  COT_LOGFMT(COT_LOG_CAT_TRAPS, "Running custom handler for trap %d", id);
+  *remove_trap = handler->effect(user, target, trap, pos);
+  return true;
+}
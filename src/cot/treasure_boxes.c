@@ -0,0 +1,33 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+static treasure_box_type box_types[COT_MAX_TREASURE_BOX_TYPES];
+static int n_box_types = 0;
+
+bool CotRegisterTreasureBoxType(treasure_box_type type) {
+  if (!IsTreasureBox(type.box_item)) {
+    COT_WARNFMT(COT_LOG_CAT_TREASURE_BOXES, "Item %d is not a treasure box", type.box_item);
+    return false;
+  }
+  if (n_box_types >= COT_MAX_TREASURE_BOX_TYPES) {
+    COT_WARN(COT_LOG_CAT_TREASURE_BOXES, "Treasure box type table is full");
+    return false;
+  }
+  box_types[n_box_types++] = type;
+  return true;
+}
+
+bool CotOpenTreasureBox(struct item* box, struct item* contents, treasure_box_location location) {
+  for (int i = 0; i < n_box_types; i++) {
+    treasure_box_type* type = &box_types[i];
+    if (type->box_item != box->id.val) {
+      continue;
+    }
+    COT_LOGFMT(COT_LOG_CAT_TREASURE_BOXES, "Opening treasure box %d", box->id.val);
+    if (type->animation != NULL) {
+      type->animation(box, location);
+    }
+    return type->contents != NULL && type->contents(box, contents, location);
+  }
+  return false;
+}
@@ -0,0 +1,62 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+static bool IsValidEpisode(cot_special_episode episode) {
+  return episode >= 0 && episode < COT_N_SPECIAL_EPISODES;
+}
+
+bool CotIsSpecialEpisodeUnlocked(cot_special_episode episode) {
+  return IsValidEpisode(episode)
+      && CotGetScriptVariableValueAtIndex(VAR_SPECIAL_EPISODE_OPEN, episode) != 0;
+}
+
+void CotSetSpecialEpisodeUnlocked(cot_special_episode episode, bool unlocked) {
+  if (IsValidEpisode(episode)) {
+    CotSetScriptVariableValueAtIndex(VAR_SPECIAL_EPISODE_OPEN, episode, unlocked);
+    // The game checks this list to decide whether to show the "new episode" notification
+    CotSetScriptVariableValueAtIndex(VAR_SPECIAL_EPISODE_OPEN_OLD, episode, unlocked);
+  }
+}
+
+bool CotIsSpecialEpisodeCleared(cot_special_episode episode) {
+  return IsValidEpisode(episode)
+      && CotGetScriptVariableValueAtIndex(VAR_SPECIAL_EPISODE_CONQUEST, episode) != 0;
+}
+
+void CotSetSpecialEpisodeCleared(cot_special_episode episode, bool cleared) {
+  if (IsValidEpisode(episode)) {
+    CotSetScriptVariableValueAtIndex(VAR_SPECIAL_EPISODE_CONQUEST, episode, cleared);
+  }
+}
+
+static bool IsValidDungeon(enum dungeon_id dungeon) {
+  if ((int) dungeon < 0 || dungeon >= COT_N_DUNGEON_FLAGS) {
+    COT_WARNFMT(COT_LOG_CAT_UNLOCKS, "Invalid dungeon %d", dungeon);
+    return false;
+  }
+  return true;
+}
+
+bool CotIsDungeonUnlocked(enum dungeon_id dungeon) {
+  return IsValidDungeon(dungeon)
+      && CotGetScriptVariableValueAtIndex(VAR_DUNGEON_OPEN_LIST, dungeon) != 0;
+}
+
+void CotSetDungeonUnlocked(enum dungeon_id dungeon, bool unlocked) {
+  if (!IsValidDungeon(dungeon)) {
+    return;
+  }
+  COT_LOGFMT(COT_LOG_CAT_UNLOCKS, "Setting dungeon %d unlocked=%d", dungeon, unlocked);
+  CotSetScriptVariableValueAtIndex(VAR_DUNGEON_OPEN_LIST, dungeon, unlocked);
+}
+
+bool CotIsDungeonCleared(enum dungeon_id dungeon) {
+  return IsValidDungeon(dungeon)
+      && CotGetScriptVariableValueAtIndex(VAR_DUNGEON_CONQUEST_LIST, dungeon) != 0;
+}
+
+void CotSetDungeonCleared(enum dungeon_id dungeon, bool cleared) {
+  if (IsValidDungeon(dungeon)) {
+    CotSetScriptVariableValueAtIndex(VAR_DUNGEON_CONQUEST_LIST, dungeon, cleared);
+  }
+}
@@ -0,0 +1,107 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+// Rooms never touch the outer two rows and columns of the floor.
+#define FLOOR_MARGIN 2
+#define N_ROOM_DATA ((int) (sizeof(DUNGEON_PTR->room_data) / sizeof(DUNGEON_PTR->room_data[0])))
+
+static bool has_vault = false;
+static room_rect vault;
+
+void CotClearVault(void) {
+  has_vault = false;
+}
+
+bool CotIsVaultTile(int x, int y) {
+  return has_vault && x >= vault.x0 && x <= vault.x1 && y >= vault.y0 && y <= vault.y1;
+}
+
+// The vault and its walls must not touch any open tile, or it wouldn't be sealed.
+static bool IsSolid(const room_rect* rect) {
+  for (int y = rect->y0 - 2; y <= rect->y1 + 2; y++) {
+    for (int x = rect->x0 - 2; x <= rect->x1 + 2; x++) {
+      if (!CotTileIsWall(x, y)) {
+        return false;
+      }
+    }
+  }
+  return true;
+}
+
+// Returns -1 if the game has no room data left for another room.
+static int NextRoomIndex(void) {
+  int max_room = -1;
+  for (int y = 0; y < COT_FLOOR_HEIGHT; y++) {
+    for (int x = 0; x < COT_FLOOR_WIDTH; x++) {
+      uint8_t room = CotTileAt(x, y)->room;
+      if (room != COT_NO_ROOM && room > max_room) {
+        max_room = room;
+      }
+    }
+  }
+  return max_room + 1 < N_ROOM_DATA ? max_room + 1 : -1;
+}
+
+static void Carve(const room_rect* rect, int room) {
+  for (int y = rect->y0 - 1; y <= rect->y1 + 1; y++) {
+    for (int x = rect->x0 - 1; x <= rect->x1 + 1; x++) {
+      bool inside = x >= rect->x0 && x <= rect->x1 && y >= rect->y0 && y <= rect->y1;
+      // Breakable walls, so the vault can be dug into
+      CotSetTileTerrain(x, y, inside ? TERRAIN_NORMAL : TERRAIN_WALL, false);
+      CotTileAt(x, y)->room = inside ? room : COT_NO_ROOM;
+    }
+  }
+  // Game code looks up the bounds of rooms by their index
+  struct room_data* data = &DUNGEON_PTR->room_data[room];
+  memset(data, 0, sizeof(*data));
+  data->top_left_corner = (struct position) { .x = rect->x0, .y = rect->y0 };
+  data->bottom_right_corner = (struct position) { .x = rect->x1, .y = rect->y1 };
+}
+
+static void PlaceLoot(const vault_config* config) {
+  if (config->n_loot <= 0) {
+    return;
+  }
+  int w = vault.x1 - vault.x0 + 1;
+  int h = vault.y1 - vault.y0 + 1;
+  int n_items = config->n_items < w * h ? config->n_items : w * h;
+  for (int i = 0; i < n_items; i++) {
+    item_spawn spawn;
+    CotItemSpawnInit(&spawn, COT_RAND_CHOOSE(RNG_DUNGEON, config->loot, config->n_loot));
+    CotSpawnItemAt(&spawn, vault.x0 + i % w, vault.y0 + i / w);
+  }
+}
+
+bool CotCarveVault(const vault_config* config) {
+  CotClearVault();
+  int margin = FLOOR_MARGIN + 2;
+  int range_x = COT_FLOOR_WIDTH - 2 * margin - config->width + 1;
+  int range_y = COT_FLOOR_HEIGHT - 2 * margin - config->height + 1;
+  if (config->width < 1 || config->height < 1 || range_x < 1 || range_y < 1) {
+    COT_WARNFMT(COT_LOG_CAT_FLOOR_GEN, "Invalid vault size %dx%d", config->width, config->height);
+    return false;
+  }
+  int room = NextRoomIndex();
+  if (room < 0) {
+    COT_WARN(COT_LOG_CAT_FLOOR_GEN, "No room index left for a vault");
+    return false;
+  }
+
+  for (int attempt = 0; attempt < config->max_attempts; attempt++) {
+    int x = margin + CotRandInt(RNG_DUNGEON, range_x);
+    int y = margin + CotRandInt(RNG_DUNGEON, range_y);
+    room_rect rect = {
+      .x0 = x, .y0 = y, .x1 = x + config->width - 1, .y1 = y + config->height - 1
+    };
+    if (IsSolid(&rect)) {
+      Carve(&rect, room);
+      vault = rect;
+      has_vault = true;
+      PlaceLoot(config);
+      COT_LOGFMT(COT_LOG_CAT_FLOOR_GEN, "Carved vault at (%d, %d)", x, y);
+      return true;
+    }
+  }
+  COT_LOG(COT_LOG_CAT_FLOOR_GEN, "No space for a vault");
+  return false;
+}
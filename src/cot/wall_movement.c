@@ -0,0 +1,103 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+static COT_MONSTER_MAP(wall_pass_mode) modes;
+static COT_VEC(enum item_id, COT_MAX_WALL_PASS_ITEMS) items;
+static bool reset_hook_added = false;
+
+static void ResetOnNewFloor(struct floor_properties* props) {
+  (void) props;
+  CotClearWallPass();
+}
+
+void CotSetWallPass(struct entity* entity, wall_pass_mode mode) {
+  // Modes are reset on every new floor once the first one is set
+  if (!reset_hook_added) {
+    reset_hook_added = CotAddPreGenerateHook(ResetOnNewFloor);
+    if (!reset_hook_added) {
+      COT_WARN(COT_LOG_CAT_WALL_MOVEMENT, "Can't add the wall passing reset hook");
+    }
+  }
+  if (mode == WALL_PASS_DEFAULT) {
+    COT_MONSTER_MAP_REMOVE(&modes, entity);
+  } else if (!COT_MONSTER_MAP_SET(&modes, entity, mode)) {
+    COT_WARN(COT_LOG_CAT_WALL_MOVEMENT, "Wall passing can only be set for monsters");
+  }
+}
+
+wall_pass_mode CotGetWallPass(struct entity* entity) {
+  wall_pass_mode* mode = COT_MONSTER_MAP_GET(&modes, entity);
+  return mode != NULL ? *mode : WALL_PASS_DEFAULT;
+}
+
+bool CotAddWallPassItem(enum item_id item) {
+  if (!COT_VEC_PUSH(&items, item)) {
+    COT_WARN(COT_LOG_CAT_WALL_MOVEMENT, "Wall pass item table is full");
+    return false;
+  }
+  return true;
+}
+
+void CotClearWallPass(void) {
+  COT_MONSTER_MAP_CLEAR(&modes);
+}
+
+bool CotIsWallPassBlocked(int x, int y) {
+  struct tile* tile = CotTileAt(x, y);
+  if (tile == NULL || tile->terrain_flags.f_impassable_wall) {
+    return true;
+  }
+  for (int j = y - 1; j <= y + 1; j++) {
+    for (int i = x - 1; i <= x + 1; i++) {
+      struct tile* neighbor = CotTileAt(i, j);
+      if (neighbor != NULL && neighbor->terrain_flags.f_in_kecleon_shop) {
+        return true;
+      }
+    }
+  }
+  return false;
+}
+
+static bool HoldsWallPassItem(struct entity* entity) {
+  struct monster* monster = entity->info;
+  if (!monster->held_item.f_exists) {
+    return false;
+  }
+  for (int i = 0; i < items.len; i++) {
+    if (monster->held_item.id.val == items.items[i]) {
+      return true;
+    }
+  }
+  return false;
+}
+
+static bool CanPassWall(struct entity* entity, int x, int y, bool vanilla) {
+  if (CotIsWallPassBlocked(x, y)) {
+    return false;
+  }
+  switch (CotGetWallPass(entity)) {
+    case WALL_PASS_GRANTED:
+      return true;
+    case WALL_PASS_REVOKED:
+      return false;
+    default:
+      return vanilla || HoldsWallPassItem(entity);
+  }
+}
+
+bool cotInternalCanMoveInDirection(struct entity* entity, enum direction_id direction,
+                                   bool vanilla) {
+  if (CotGetMonster(entity) == NULL) {
+    return vanilla;
+  }
+  struct position target = CotPosStep(entity->pos, direction);
+  if (!CotTileIsWall(target.x, target.y)) {
+    return vanilla;
+  }
+  if (!CanPassWall(entity, target.x, target.y, vanilla)) {
+    return false;
+  }
+  // The game already allowed the move, otherwise only the wall itself was in the way as long as
+  // nobody stands in it
+  return vanilla || CotTileAt(target.x, target.y)->monster == NULL;
+}
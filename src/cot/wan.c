@@ -0,0 +1,208 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+#define SIR0_HEADER_SIZE 0x10
+#define IMAGE_STRIP_SIZE 0xC
+#define META_FRAME_SIZE 0xA
+#define ANIM_GROUP_SIZE 0x8
+#define ANIM_FRAME_SIZE 0xC
+#define COLOR_SIZE 4
+// Up to and including the number of animation groups at +8
+#define ANIM_INFO_SIZE 0xA
+
+#define META_FRAME_LAST 0x800
+
+// Returns a pointer into the file, or NULL if `offset` doesn't leave room for `len` bytes.
+static const uint8_t* At(const wan_file* wan, uint32_t offset, int len) {
+  if (offset == 0 || offset + len > (uint32_t) wan->size) {
+    return NULL;
+  }
+  return wan->data + offset;
+}
+
+static const uint8_t* Deref(const wan_file* wan, const uint8_t* ptr, int len) {
+  return At(wan, CotReadU32(ptr), len);
+}
+
+static int SignExtend(int value, int bits) {
+  int sign = 1 << (bits - 1);
+  return (value ^ sign) - sign;
+}
+
+bool CotWanOpen(wan_file* wan, const void* data, int size) {
+  wan->data = data;
+  wan->size = size;
+  const uint8_t* header = At(wan, 0, SIR0_HEADER_SIZE);
+  if (header == NULL || header[0] != 'S' || header[1] != 'I' || header[2] != 'R'
+      || header[3] != '0') {
+    COT_WARN(COT_LOG_CAT_WAN, "Sprite is not an SIR0 container");
+    return false;
+  }
+  const uint8_t* content = Deref(wan, header + 4, 8);
+  if (content == NULL) {
+    COT_WARN(COT_LOG_CAT_WAN, "Invalid WAN header");
+    return false;
+  }
+  // Sprites without animations (e.g. effects with a single image) have no animation info
+  wan->anim_info = Deref(wan, content, ANIM_INFO_SIZE);
+  wan->image_info = Deref(wan, content + 4, 16);
+  return wan->image_info != NULL;
+}
+
+int CotWanImageCount(const wan_file* wan) {
+  return CotReadU16(wan->image_info + 14);
+}
+
+bool CotWanIs256Colors(const wan_file* wan) {
+  return CotReadU16(wan->image_info + 10) != 0;
+}
+
+int CotWanDecodeImage(const wan_file* wan, int idx, void* dst, int dst_capacity) {
+  if (idx < 0 || idx >= CotWanImageCount(wan)) {
+    return -1;
+  }
+  const uint8_t* table = Deref(wan, wan->image_info, 4 * (idx + 1));
+  const uint8_t* strip = table != NULL ? Deref(wan, table + 4 * idx, IMAGE_STRIP_SIZE) : NULL;
+  uint8_t* out = dst;
+  int len = 0;
+  // Strips either point to pixel data or, with a null pointer, stand for zeroed pixels
+  while (strip != NULL) {
+    uint32_t src_offset = CotReadU32(strip);
+    int strip_len = CotReadU16(strip + 4);
+    if (strip_len == 0) {
+      return len;
+    }
+    if (len + strip_len > dst_capacity) {
+      return -1;
+    }
+    if (src_offset == 0) {
+      memset(out + len, 0, strip_len);
+    } else {
+      const uint8_t* src = At(wan, src_offset, strip_len);
+      if (src == NULL) {
+        return -1;
+      }
+      memcpy(out + len, src, strip_len);
+    }
+    len += strip_len;
+    strip = At(wan, strip - wan->data + IMAGE_STRIP_SIZE, IMAGE_STRIP_SIZE);
+  }
+  return -1;
+}
+
+static const uint8_t* GetPaletteInfo(const wan_file* wan) {
+  return Deref(wan, wan->image_info + 4, 8);
+}
+
+// The color data is stored right before the palette info.
+int CotWanColorCount(const wan_file* wan) {
+  const uint8_t* palette_info = GetPaletteInfo(wan);
+  if (palette_info == NULL) {
+    return 0;
+  }
+  int colors_offset = CotReadU32(palette_info);
+  int info_offset = palette_info - wan->data;
+  return colors_offset < info_offset ? (info_offset - colors_offset) / COLOR_SIZE : 0;
+}
+
+int CotWanGetColors(const wan_file* wan, int first, uint16_t* out, int n) {
+  const uint8_t* palette_info = GetPaletteInfo(wan);
+  int n_colors = CotWanColorCount(wan);
+  if (palette_info == NULL || first < 0 || first >= n_colors) {
+    return 0;
+  }
+  if (first + n > n_colors) {
+    n = n_colors - first;
+  }
+  const uint8_t* colors = Deref(wan, palette_info, n_colors * COLOR_SIZE);
+  if (colors == NULL) {
+    return 0;
+  }
+  for (int i = 0; i < n; i++) {
+    const uint8_t* rgbx = colors + (first + i) * COLOR_SIZE;
+    out[i] = (rgbx[0] >> 3) | ((rgbx[1] >> 3) << 5) | ((rgbx[2] >> 3) << 10);
+  }
+  return n;
+}
+
+int CotWanGetMetaFrame(const wan_file* wan, int idx, wan_piece* out, int max) {
+  if (wan->anim_info == NULL || idx < 0) {
+    return -1;
+  }
+  const uint8_t* table = Deref(wan, wan->anim_info, 4 * (idx + 1));
+  const uint8_t* piece = table != NULL ? Deref(wan, table + 4 * idx, META_FRAME_SIZE) : NULL;
+  int n = 0;
+  while (piece != NULL && n < max) {
+    uint16_t attr0 = CotReadU16(piece + 4);
+    uint16_t attr1 = CotReadU16(piece + 6);
+    uint16_t attr2 = CotReadU16(piece + 8);
+    out[n++] = (wan_piece) {
+      .image_index = (int16_t) CotReadU16(piece),
+      .y = SignExtend(attr0 & 0x3FF, 10),
+      .shape = attr0 >> 14,
+      .x = SignExtend(attr1 & 0x1FF, 9),
+      .hflip = (attr1 & 0x1000) != 0,
+      .vflip = (attr1 & 0x2000) != 0,
+      .obj_size = attr1 >> 14,
+      .palette = attr2 >> 12,
+    };
+    if (attr1 & META_FRAME_LAST) {
+      return n;
+    }
+    piece = At(wan, piece - wan->data + META_FRAME_SIZE, META_FRAME_SIZE);
+  }
+  return piece != NULL ? n : -1;
+}
+
+int CotWanGetAnimation(const wan_file* wan, int group, int anim, wan_anim_frame* out, int max) {
+  if (wan->anim_info == NULL || group < 0 || anim < 0) {
+    return -1;
+  }
+  const uint8_t* groups = Deref(wan, wan->anim_info + 4, ANIM_GROUP_SIZE * (group + 1));
+  if (groups == NULL || group >= CotReadU16(wan->anim_info + 8)) {
+    return -1;
+  }
+  const uint8_t* group_entry = groups + ANIM_GROUP_SIZE * group;
+  if (anim >= CotReadU16(group_entry + 4)) {
+    return -1;
+  }
+  const uint8_t* anims = Deref(wan, group_entry, 4 * (anim + 1));
+  const uint8_t* frame = anims != NULL ? Deref(wan, anims + 4 * anim, ANIM_FRAME_SIZE) : NULL;
+  int n = 0;
+  // The frame list ends with a frame of duration 0
+  while (frame != NULL && frame[0] != 0 && n < max) {
+    out[n++] = (wan_anim_frame) {
+      .duration = frame[0],
+      .meta_frame = CotReadU16(frame + 2),
+      .offset_x = (int16_t) CotReadU16(frame + 4),
+      .offset_y = (int16_t) CotReadU16(frame + 6),
+      .shadow_x = (int16_t) CotReadU16(frame + 8),
+      .shadow_y = (int16_t) CotReadU16(frame + 10),
+    };
+    frame = At(wan, frame - wan->data + ANIM_FRAME_SIZE, ANIM_FRAME_SIZE);
+  }
+  return frame != NULL ? n : -1;
+}
+
+int CotWanLoadSprite(const char* path, uint32_t flags) {
+  // The game trusts sprite files, so catch broken ones before it parses them
+  int size;
+  void* data = CotLoadFile(path, &size);
+  if (data == NULL) {
+    return -1;
+  }
+  wan_file wan;
+  bool valid = CotWanOpen(&wan, data, size);
+  MemFree(data);
+  if (!valid) {
+    COT_WARNFMT(COT_LOG_CAT_WAN, "Not loading invalid sprite %s", path);
+    return -1;
+  }
+  return LoadWanTableEntry(WAN_TABLE, (char*) path, flags);
+}
+
+void CotWanUnloadSprite(int sprite_id) {
+  if (sprite_id >= 0) {
+    DeleteWanTableEntry(WAN_TABLE, sprite_id);
+  }
+}
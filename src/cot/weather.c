@@ -0,0 +1,64 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+static weather_turn_fn weather_hooks[COT_MAX_WEATHER_HOOKS];
+// CotSetWeather updates the weather through the game too, which isn't a new turn
+static bool setting_weather = false;
+
+enum weather_id CotGetWeather(void) {
+  return DUNGEON_PTR->weather.val;
+}
+
+enum weather_id CotGetApparentWeather(struct entity* entity) {
+  return GetApparentWeather(entity);
+}
+
+enum weather_id CotGetNaturalWeather(void) {
+  return DUNGEON_PTR->weather_natural.val;
+}
+
+void CotSetWeather(enum weather_id weather, int turns) {
+  if (weather >= WEATHER_RANDOM) {
+    COT_WARNFMT(COT_LOG_CAT_WEATHER, "Invalid weather %d", weather);
+    return;
+  }
+  // Temporary weather has priority over natural weather while it has turns left
+  for (int i = 0; i < WEATHER_RANDOM; i++) {
+    DUNGEON_PTR->weather_turns[i] = 0;
+  }
+  DUNGEON_PTR->weather_turns[weather] = turns;
+  setting_weather = true;
+  TryActivateWeather(true, true);
+  setting_weather = false;
+}
+
+bool CotAddWeatherTurnHook(weather_turn_fn hook) {
+  for (int i = 0; i < COT_MAX_WEATHER_HOOKS; i++) {
+    if (weather_hooks[i] == NULL) {
+      weather_hooks[i] = hook;
+      return true;
+    }
+  }
+  COT_WARN(COT_LOG_CAT_WEATHER, "Weather hook table is full");
+  return false;
+}
+
+void CotRemoveWeatherTurnHook(weather_turn_fn hook) {
+  for (int i = 0; i < COT_MAX_WEATHER_HOOKS; i++) {
+    if (weather_hooks[i] == hook) {
+      weather_hooks[i] = NULL;
+    }
+  }
+}
+
+void cotInternalOnWeatherTurn(void) {
+  if (setting_weather) {
+    return;
+  }
+  enum weather_id weather = CotGetWeather();
+  for (int i = 0; i < COT_MAX_WEATHER_HOOKS; i++) {
+    if (weather_hooks[i] != NULL) {
+      weather_hooks[i](weather);
+    }
+  }
+}
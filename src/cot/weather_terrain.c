@@ -0,0 +1,154 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+static COT_VEC(weather_terrain_rule, COT_MAX_WEATHER_TERRAIN_RULES) rules;
+static COT_VEC(enum dungeon_id, COT_MAX_WEATHER_TERRAIN_DUNGEONS) enabled_dungeons;
+static weather_terrain_redraw_fn redraw_fn = NULL;
+
+// Bounding rectangle of the tiles changed during the current call
+static room_rect changed_area;
+static int n_changed;
+
+bool CotAddWeatherTerrainRule(weather_terrain_rule rule) {
+  if (!COT_VEC_PUSH(&rules, rule)) {
+    COT_WARN(COT_LOG_CAT_WEATHER_TERRAIN, "Weather terrain rule table is full");
+    return false;
+  }
+  return true;
+}
+
+void CotClearWeatherTerrainRules(void) {
+  COT_VEC_CLEAR(&rules);
+}
+
+bool CotWeatherTerrainEnabled(enum dungeon_id dungeon) {
+  for (int i = 0; i < enabled_dungeons.len; i++) {
+    if (enabled_dungeons.items[i] == dungeon) {
+      return true;
+    }
+  }
+  return false;
+}
+
+bool CotSetWeatherTerrainEnabled(enum dungeon_id dungeon, bool enabled) {
+  for (int i = 0; i < enabled_dungeons.len; i++) {
+    if (enabled_dungeons.items[i] == dungeon) {
+      if (!enabled) {
+        COT_VEC_SWAP_REMOVE(&enabled_dungeons, i);
+      }
+      return true;
+    }
+  }
+  if (enabled && !COT_VEC_PUSH(&enabled_dungeons, dungeon)) {
+    COT_WARN(COT_LOG_CAT_WEATHER_TERRAIN, "Weather terrain dungeon table is full");
+    return false;
+  }
+  return true;
+}
+
+void CotSetWeatherTerrainRedraw(weather_terrain_redraw_fn redraw) {
+  redraw_fn = redraw;
+}
+
+static bool CanChange(int x, int y, const weather_terrain_rule* rule) {
+  struct tile* tile = CotTileAt(x, y);
+  if (tile == NULL || tile->terrain_flags.terrain_type != rule->from) {
+    return false;
+  }
+  if (tile->terrain_flags.f_stairs || tile->terrain_flags.f_in_kecleon_shop
+      || tile->terrain_flags.f_impassable_wall || tile->object != NULL) {
+    return false;
+  }
+  // Don't drop monsters into chasms or walls
+  return tile->monster == NULL || rule->to == TERRAIN_NORMAL;
+}
+
+static void Change(int x, int y, const weather_terrain_rule* rule) {
+  CotSetTileTerrain(x, y, rule->to, false);
+  if (n_changed == 0) {
+    changed_area = (room_rect) { .x0 = x, .y0 = y, .x1 = x, .y1 = y };
+  } else {
+    changed_area.x0 = x < changed_area.x0 ? x : changed_area.x0;
+    changed_area.y0 = y < changed_area.y0 ? y : changed_area.y0;
+    changed_area.x1 = x > changed_area.x1 ? x : changed_area.x1;
+    changed_area.y1 = y > changed_area.y1 ? y : changed_area.y1;
+  }
+  n_changed++;
+}
+
+static int CountCandidates(const weather_terrain_rule* rule) {
+  int count = 0;
+  for (int y = 0; y < COT_FLOOR_HEIGHT; y++) {
+    for (int x = 0; x < COT_FLOOR_WIDTH; x++) {
+      count += CanChange(x, y, rule);
+    }
+  }
+  return count;
+}
+
+// Changes the `n`-th tile the rule can change, in row order
+static void ChangeNth(const weather_terrain_rule* rule, int n) {
+  for (int y = 0; y < COT_FLOOR_HEIGHT; y++) {
+    for (int x = 0; x < COT_FLOOR_WIDTH; x++) {
+      if (CanChange(x, y, rule) && n-- == 0) {
+        Change(x, y, rule);
+        return;
+      }
+    }
+  }
+}
+
+static void ApplyRule(const weather_terrain_rule* rule) {
+  if (rule->tiles_per_turn <= 0) {
+    for (int y = 0; y < COT_FLOOR_HEIGHT; y++) {
+      for (int x = 0; x < COT_FLOOR_WIDTH; x++) {
+        if (CanChange(x, y, rule)) {
+          Change(x, y, rule);
+        }
+      }
+    }
+    return;
+  }
+  for (int i = 0; i < rule->tiles_per_turn; i++) {
+    int candidates = CountCandidates(rule);
+    if (candidates == 0) {
+      return;
+    }
+    ChangeNth(rule, CotRandInt(RNG_DUNGEON, candidates));
+  }
+}
+
+int CotApplyWeatherTerrain(enum weather_id weather) {
+  enum dungeon_id dungeon = DUNGEON_PTR->id.val;
+  n_changed = 0;
+  if (!CotWeatherTerrainEnabled(dungeon)) {
+    return 0;
+  }
+  for (int i = 0; i < rules.len; i++) {
+    const weather_terrain_rule* rule = &rules.items[i];
+    bool dungeon_matches = rule->dungeon == dungeon || rule->dungeon == COT_ANY_DUNGEON;
+    if (dungeon_matches && rule->weather == weather) {
+      ApplyRule(rule);
+    }
+  }
+  if (n_changed == 0) {
+    return 0;
+  }
+  for (int y = changed_area.y0; y <= changed_area.y1; y++) {
+    for (int x = changed_area.x0; x <= changed_area.x1; x++) {
+      DrawMinimapTile(x, y);
+    }
+  }
+  if (redraw_fn != NULL) {
+    redraw_fn(changed_area);
+  }
+  return n_changed;
+}
+
+static void OnWeatherTurn(enum weather_id weather) {
+  CotApplyWeatherTerrain(weather);
+}
+
+bool CotInstallWeatherTerrain(void) {
+  return CotAddWeatherTurnHook(OnWeatherTurn);
+}
@@ -0,0 +1,70 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+static struct {
+  uint16_t species;
+  int16_t weight;
+} weight_overrides[COT_MAX_WEIGHT_OVERRIDES];
+static int n_weight_overrides = 0;
+
+static struct {
+  uint16_t move;
+  weight_move_fn fn;
+} weight_moves[COT_MAX_WEIGHT_MOVES];
+static int n_weight_moves = 0;
+
+int CotGetSpeciesWeight(enum monster_id species) {
+  // Goes through the wrapper in hooks.c, which applies the overrides
+  return GetLowKickMultiplier(species);
+}
+
+bool CotSetSpeciesWeight(enum monster_id species, int weight) {
+  for (int i = 0; i < n_weight_overrides; i++) {
+    if (weight_overrides[i].species == species) {
+      weight_overrides[i].weight = weight;
+      return true;
+    }
+  }
+  if (n_weight_overrides >= COT_MAX_WEIGHT_OVERRIDES) {
+    COT_WARN(COT_LOG_CAT_WEIGHTS, "Weight override table is full");
+    return false;
+  }
+  weight_overrides[n_weight_overrides].species = species;
+  weight_overrides[n_weight_overrides].weight = weight;
+  n_weight_overrides++;
+  return true;
+}
+
+int CotGetSpeciesBodySize(enum monster_id species) {
+  return GetBodySize(species);
+}
+
+bool CotRegisterWeightMove(enum move_id move, weight_move_fn fn) {
+  if (n_weight_moves >= COT_MAX_WEIGHT_MOVES) {
+    COT_WARN(COT_LOG_CAT_WEIGHTS, "Weight-based move table is full");
+    return false;
+  }
+  weight_moves[n_weight_moves].move = move;
+  weight_moves[n_weight_moves].fn = fn;
+  n_weight_moves++;
+  return true;
+}
+
+int CotGetWeightMultiplier(enum move_id move, struct entity* user, struct entity* target) {
+  int weight = CotGetSpeciesWeight(((struct monster*) target->info)->apparent_id.val);
+  for (int i = 0; i < n_weight_moves; i++) {
+    if (weight_moves[i].move == move) {
+      return weight_moves[i].fn(user, target, weight);
+    }
+  }
+  return weight;
+}
+
+int cotInternalGetSpeciesWeight(enum monster_id species, int vanilla) {
+  for (int i = 0; i < n_weight_overrides; i++) {
+    if (weight_overrides[i].species == species) {
+      return weight_overrides[i].weight;
+    }
+  }
+  return vanilla;
+}
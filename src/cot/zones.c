@@ -0,0 +1,192 @@
+#include <pmdsky.h>
+#include <cot.h>
+
+static zone zones[COT_MAX_ZONES];
+// Zone the leader was in during the last update, used for the enter/leave indicator.
+static int leader_zone = COT_ZONE_INVALID;
+// Text box showing the name of the leader's zone, or -1 if it's closed.
+static int indicator_window = -1;
+
+static void CloseIndicator(void) {
+  if (indicator_window >= 0) {
+    CloseTextBox(indicator_window);
+    indicator_window = -1;
+  }
+}
+
+static void ShowIndicator(const char* name) {
+  CloseIndicator();
+  struct window_params params = {
+    .x_offset = COT_ZONE_INDICATOR_X,
+    .y_offset = COT_ZONE_INDICATOR_Y,
+    .width = COT_ZONE_INDICATOR_WIDTH,
+    .height = 2,
+  };
+  indicator_window = CreateTextBox(&params, NULL);
+  if (indicator_window < 0) {
+    COT_WARN(COT_LOG_CAT_ZONES, "Can't open the zone indicator");
+    return;
+  }
+  DrawTextInWindow(indicator_window, 4, 2, (char*) name);
+  UpdateWindow(indicator_window);
+}
+
+static int AllocZone(const char* name, zone_effect effect) {
+  for (int i = 0; i < COT_MAX_ZONES; i++) {
+    if (!zones[i].active) {
+      zones[i] = (zone) { .active = true, .name = name, .effect = effect };
+      return i;
+    }
+  }
+  COT_WARN(COT_LOG_CAT_ZONES, "Zone table is full");
+  return COT_ZONE_INVALID;
+}
+
+int CotAddRectZone(const char* name, int x, int y, int w, int h, zone_effect effect) {
+  int id = AllocZone(name, effect);
+  if (id != COT_ZONE_INVALID) {
+    zones[id].top_left = (struct position) { .x = x, .y = y };
+    zones[id].bottom_right = (struct position) { .x = x + w - 1, .y = y + h - 1 };
+  }
+  return id;
+}
+
+int CotAddTileZone(const char* name, const struct position* tiles, int n_tiles, zone_effect effect) {
+  int id = AllocZone(name, effect);
+  if (id != COT_ZONE_INVALID) {
+    zones[id].tiles = tiles;
+    zones[id].n_tiles = n_tiles;
+  }
+  return id;
+}
+
+void CotRemoveZone(int zone_id) {
+  if (zone_id >= 0 && zone_id < COT_MAX_ZONES) {
+    zones[zone_id].active = false;
+    if (leader_zone == zone_id) {
+      leader_zone = COT_ZONE_INVALID;
+      CloseIndicator();
+    }
+  }
+}
+
+void CotClearZones(void) {
+  for (int i = 0; i < COT_MAX_ZONES; i++) {
+    zones[i].active = false;
+  }
+  leader_zone = COT_ZONE_INVALID;
+  CloseIndicator();
+}
+
+static void ClearZonesOnNewFloor(struct floor_properties* props) {
+  CotClearZones();
+}
+
+bool CotInstallZones(void) {
+  return CotAddPreGenerateHook(ClearZonesOnNewFloor);
+}
+
+zone* CotGetZone(int zone_id) {
+  if (zone_id < 0 || zone_id >= COT_MAX_ZONES || !zones[zone_id].active) {
+    return NULL;
+  }
+  return &zones[zone_id];
+}
+
+static bool ZoneContains(const zone* z, int x, int y) {
+  if (z->tiles != NULL) {
+    for (int i = 0; i < z->n_tiles; i++) {
+      if (z->tiles[i].x == x && z->tiles[i].y == y) {
+        return true;
+      }
+    }
+    return false;
+  }
+  return x >= z->top_left.x && x <= z->bottom_right.x
+      && y >= z->top_left.y && y <= z->bottom_right.y;
+}
+
+int CotGetZoneAt(int x, int y) {
+  for (int i = 0; i < COT_MAX_ZONES; i++) {
+    if (zones[i].active && ZoneContains(&zones[i], x, y)) {
+      return i;
+    }
+  }
+  return COT_ZONE_INVALID;
+}
+
+static bool ZoneTargets(const zone* z, struct entity* entity) {
+  bool is_team = !((struct monster*) entity->info)->is_not_team_member;
+  switch (z->effect.target) {
+    case ZONE_TARGET_TEAM:
+      return is_team;
+    case ZONE_TARGET_ENEMIES:
+      return !is_team;
+    default:
+      return true;
+  }
+}
+
+static void ApplyZoneEffect(zone* z, struct entity* entity) {
+  struct monster* monster = (struct monster*) entity->info;
+  switch (z->effect.kind) {
+    case ZONE_EFFECT_DAMAGE: {
+      int new_hp = monster->hp - z->effect.amount;
+      monster->hp = new_hp < 1 ? 1 : new_hp;
+      break;
+    }
+    case ZONE_EFFECT_HEAL:
+      TryIncreaseHp(entity, entity, z->effect.amount, 0, false);
+      break;
+    case ZONE_EFFECT_OFFENSIVE_STAT:
+      if (z->effect.amount > 0) {
+        BoostOffensiveStat(entity, entity, z->effect.stat_idx, z->effect.amount);
+      } else if (z->effect.amount < 0) {
+        LowerOffensiveStat(entity, entity, z->effect.stat_idx, -z->effect.amount, 0, 0);
+      }
+      break;
+    case ZONE_EFFECT_DEFENSIVE_STAT:
+      if (z->effect.amount > 0) {
+        BoostDefensiveStat(entity, entity, z->effect.stat_idx, z->effect.amount);
+      } else if (z->effect.amount < 0) {
+        LowerDefensiveStat(entity, entity, z->effect.stat_idx, -z->effect.amount, 0, 0);
+      }
+      break;
+    case ZONE_EFFECT_CUSTOM:
+      if (z->effect.custom != NULL) {
+        z->effect.custom(z, entity);
+      }
+      break;
+    default:
+      break;
+  }
+}
+
+static void UpdateLeaderIndicator(void) {
+  struct entity* leader = GetLeader();
+  if (leader == NULL) {
+    return;
+  }
+  int current = CotGetZoneAt(leader->pos.x, leader->pos.y);
+  if (current == leader_zone) {
+    return;
+  }
+  leader_zone = current;
+  if (current != COT_ZONE_INVALID && zones[current].name != NULL) {
+    COT_LOG_MSG(leader, "Entered {}.", MSG_STRING(zones[current].name));
+    ShowIndicator(zones[current].name);
+  } else {
+    CloseIndicator();
+  }
+}
+
+void cotInternalUpdateZones(void) {
+  struct entity* entity;
+  COT_FOR_EACH_ENTITY(ITER_MONSTERS, entity) {
+    int zone_id = CotGetZoneAt(entity->pos.x, entity->pos.y);
+    if (zone_id != COT_ZONE_INVALID && ZoneTargets(&zones[zone_id], entity)) {
+      ApplyZoneEffect(&zones[zone_id], entity);
+    }
+  }
+  UpdateLeaderIndicator();
+}
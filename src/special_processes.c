@@ -7,24 +7,20 @@ extern void ChangeGlobalBorderColor(int color_type);
 
 // Special process 100: Change border color
 // Based on https://github.com/SkyTemple/eos-move-effects/blob/master/example/process/set_frame_color.asm
-static int SpChangeBorderColor(short arg1) {
-  ChangeGlobalBorderColor(arg1);
+COT_SPECIAL_PROCESS(SpChangeBorderColor, int, color, int, unused) {
+  ChangeGlobalBorderColor(color);
   return 0;
 }
 
-// Called for special process IDs 100 and greater.
+// Special processes with IDs 100 and greater. Add your own SP's here...
+COT_SPECIAL_PROCESS_TABLE(
+  { 100, SpChangeBorderColor },
+);
+
+// Called for special process IDs 100 and greater that aren't in the table above.
 //
 // Set return_val to the return value that should be passed back to the game's script engine. Return true,
 // if the special process was handled.
 bool CustomScriptSpecialProcessCall(undefined4* unknown, uint32_t special_process_id, short arg1, short arg2, int* return_val) {
-  switch (special_process_id) {
-    case 100:
-      *return_val = SpChangeBorderColor(arg1);
-      return true;
-
-    // Add your own SP's here...
-
-    default:
-      return false;
-  }
+  return false;
 }
@@ -0,0 +1,15 @@
+#pragma once
+
+// Stand-in for include/cot.h, including only the headers of the tested modules.
+
+#include <cot/logging.h>
+#include <cot/bytes.h>
+#include <cot/compression.h>
+#include <cot/fixed_bin.h>
+#include <cot/str_buf.h>
+#include <cot/fixed_point.h>
+#include <cot/tile_grid.h>
+#include <cot/geometry.h>
+#include <cot/entity_slots.h>
+#include <cot/collections.h>
+#include <cot/pathfinding.h>
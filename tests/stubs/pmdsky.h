@@ -0,0 +1,59 @@
+#pragma once
+
+// Minimal stand-in for pmdsky-debug's headers, declaring only what the tested modules use.
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <string.h>
+
+void DebugPrint(int level, const char* fmt, ...);
+void WaitForever(void);
+
+typedef int32_t fx32_8;
+
+struct position {
+  int16_t x;
+  int16_t y;
+};
+
+enum direction_id {
+  DIR_DOWN = 0,
+  DIR_DOWN_RIGHT = 1,
+  DIR_RIGHT = 2,
+  DIR_UP_RIGHT = 3,
+  DIR_UP = 4,
+  DIR_UP_LEFT = 5,
+  DIR_LEFT = 6,
+  DIR_DOWN_LEFT = 7,
+};
+
+enum terrain_type {
+  TERRAIN_WALL = 0,
+  TERRAIN_NORMAL = 1,
+  TERRAIN_SECONDARY = 2,
+  TERRAIN_CHASM = 3,
+};
+
+struct tile;
+
+struct entity {
+  int type;
+};
+
+struct entity_table {
+  struct entity entities[64];
+};
+
+struct dungeon_generation_info {
+  struct position stairs_pos;
+};
+
+struct dungeon {
+  struct dungeon_generation_info gen_info;
+  struct entity_table entity_table;
+};
+
+extern struct dungeon* DUNGEON_PTR;
+
+bool StairsAlwaysReachable(int x_stairs, int y_stairs, bool mark_unreachable);
@@ -0,0 +1,26 @@
+#include <stdarg.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <pmdsky.h>
+
+void DebugPrint(int level, const char* fmt, ...) {
+  va_list args;
+  va_start(args, fmt);
+  vfprintf(stderr, fmt, args);
+  fputc('\n', stderr);
+  va_end(args);
+}
+
+void WaitForever(void) {
+  abort();
+}
+
+static struct dungeon dungeon;
+struct dungeon* DUNGEON_PTR = &dungeon;
+
+bool StairsAlwaysReachable(int x_stairs, int y_stairs, bool mark_unreachable) {
+  (void) x_stairs;
+  (void) y_stairs;
+  (void) mark_unreachable;
+  return true;
+}
@@ -0,0 +1,18 @@
+#pragma once
+
+// Helpers for the host-compiled tests. Every test file is its own program; main returns the
+// number of failed checks.
+
+#include <stdio.h>
+
+static int test_failures = 0;
+
+#define CHECK(expr) \
+  do { \
+    if (!(expr)) { \
+      fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__, __LINE__, #expr); \
+      test_failures++; \
+    } \
+  } while (0)
+
+#define TEST_RESULT() (test_failures != 0)
@@ -0,0 +1,79 @@
+#include <pmdsky.h>
+#include <cot.h>
+#include "test.h"
+
+static void TestVec(void) {
+  COT_VEC(int, 4) vec = { 0 };
+  CHECK(COT_VEC_CAPACITY(&vec) == 4);
+  for (int i = 0; i < 4; i++) {
+    CHECK(COT_VEC_PUSH(&vec, i * 10));
+  }
+  CHECK(COT_VEC_IS_FULL(&vec));
+  CHECK(!COT_VEC_PUSH(&vec, 40));
+  CHECK(vec.len == 4);
+
+  // 0 10 20 30 -> 0 20 30
+  COT_VEC_REMOVE(&vec, 1);
+  CHECK(vec.len == 3 && vec.items[0] == 0 && vec.items[1] == 20 && vec.items[2] == 30);
+  // 0 20 30 -> 30 20
+  COT_VEC_SWAP_REMOVE(&vec, 0);
+  CHECK(vec.len == 2 && vec.items[0] == 30 && vec.items[1] == 20);
+  CHECK(COT_VEC_POP(&vec) == 20);
+  CHECK(vec.len == 1);
+  COT_VEC_CLEAR(&vec);
+  CHECK(vec.len == 0);
+}
+
+static void TestBitset(void) {
+  floor_bitset set;
+  CotBitsetClear(&set);
+  CHECK(CotBitsetCount(&set) == 0);
+  CotBitsetSet(&set, 0, 0);
+  CotBitsetSet(&set, 31, 0);
+  CotBitsetSet(&set, 32, 0);
+  CotBitsetSet(&set, COT_FLOOR_WIDTH - 1, COT_FLOOR_HEIGHT - 1);
+  CHECK(CotBitsetCount(&set) == 4);
+  CHECK(CotBitsetTest(&set, 31, 0) && CotBitsetTest(&set, 32, 0));
+  CHECK(CotBitsetTest(&set, COT_FLOOR_WIDTH - 1, COT_FLOOR_HEIGHT - 1));
+  CHECK(!CotBitsetTest(&set, 1, 0));
+  CHECK(!CotBitsetTest(&set, -1, 0) && !CotBitsetTest(&set, 0, COT_FLOOR_HEIGHT));
+  // Setting a tile twice counts it once
+  CotBitsetSet(&set, 31, 0);
+  CotBitsetUnset(&set, 0, 0);
+  CHECK(CotBitsetCount(&set) == 3);
+  CHECK(!CotBitsetTest(&set, 0, 0));
+}
+
+static void TestMonsterMap(void) {
+  struct entity* entities = DUNGEON_PTR->entity_table.entities;
+  struct entity* first = &entities[0];
+  struct entity* last = &entities[COT_N_MONSTER_SLOTS - 1];
+  // Past the monster slots, e.g. an item
+  struct entity* item = &entities[COT_N_MONSTER_SLOTS];
+  struct entity outside;
+  CHECK(CotMonsterSlot(last) == COT_N_MONSTER_SLOTS - 1);
+  CHECK(CotMonsterSlot(item) == -1 && CotMonsterSlot(&outside) == -1);
+
+  COT_MONSTER_MAP(int) map = { 0 };
+  CHECK(COT_MONSTER_MAP_GET(&map, first) == NULL);
+  CHECK(COT_MONSTER_MAP_SET(&map, first, 5));
+  CHECK(COT_MONSTER_MAP_SET(&map, last, 7));
+  CHECK(!COT_MONSTER_MAP_SET(&map, item, 9));
+  CHECK(*COT_MONSTER_MAP_GET(&map, first) == 5);
+  CHECK(*COT_MONSTER_MAP_GET(&map, last) == 7);
+  CHECK(COT_MONSTER_MAP_GET(&map, item) == NULL);
+
+  COT_MONSTER_MAP_REMOVE(&map, first);
+  COT_MONSTER_MAP_REMOVE(&map, item);
+  CHECK(COT_MONSTER_MAP_GET(&map, first) == NULL);
+  CHECK(*COT_MONSTER_MAP_GET(&map, last) == 7);
+  COT_MONSTER_MAP_CLEAR(&map);
+  CHECK(COT_MONSTER_MAP_GET(&map, last) == NULL);
+}
+
+int main(void) {
+  TestVec();
+  TestBitset();
+  TestMonsterMap();
+  return TEST_RESULT();
+}
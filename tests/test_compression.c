@@ -0,0 +1,99 @@
+#include <stdlib.h>
+#include <pmdsky.h>
+#include <cot.h>
+#include "test.h"
+
+#define MAX_SIZE 5000
+#define PX_HEADER_SIZE 0x12
+
+// Random bytes, sparse data with many zeros and short repeating patterns, to exercise
+// literals, runs and back-references.
+static void FillTestData(uint8_t* data, int size, int kind) {
+  for (int i = 0; i < size; i++) {
+    switch (kind) {
+      case 0:
+        data[i] = rand();
+        break;
+      case 1:
+        data[i] = rand() % 4 == 0 ? 0 : rand() % 3;
+        break;
+      default:
+        data[i] = (i / 7) % 5;
+        break;
+    }
+  }
+}
+
+static void TestRoundTrips(void) {
+  static uint8_t data[MAX_SIZE];
+  static uint8_t compressed[MAX_SIZE * 2 + 64];
+  static uint8_t decompressed[MAX_SIZE];
+  srand(1);
+  for (int i = 0; i < 150; i++) {
+    int size = rand() % MAX_SIZE;
+    FillTestData(data, size, i % 3);
+
+    px_format format = i % 2 ? PX_FORMAT_PKDPX : PX_FORMAT_AT4PX;
+    int px_size = CotPxCompress(data, size, compressed, sizeof(compressed), format);
+    CHECK(px_size > 0);
+    CHECK(CotPxGetDecompressedSize(compressed) == size);
+    CHECK(CotPxDecompress(compressed, decompressed, size) == size);
+    CHECK(memcmp(data, decompressed, size) == 0);
+
+    int nrl_size = CotNrlCompress(data, size, compressed, sizeof(compressed));
+    CHECK(nrl_size >= 0);
+    CHECK(CotNrlDecompress(compressed, nrl_size, decompressed, size) == size);
+    CHECK(memcmp(data, decompressed, size) == 0);
+  }
+}
+
+static void TestPxTooSmall(void) {
+  static const uint8_t data[] = { 1, 2, 3, 4, 5, 6, 7, 8 };
+  uint8_t compressed[64];
+  uint8_t decompressed[sizeof(data)];
+  int size = CotPxCompress(data, sizeof(data), compressed, sizeof(compressed), PX_FORMAT_AT4PX);
+  CHECK(size > 0);
+  CHECK(CotPxDecompress(compressed, decompressed, sizeof(data) - 1) == -1);
+  CHECK(CotPxCompress(data, sizeof(data), compressed, 8, PX_FORMAT_AT4PX) == -1);
+}
+
+// A back-reference whose second byte is cut off by the container size. The byte after the
+// container would make a valid back-reference, so it must not be read.
+static void TestPxTruncatedBackReference(void) {
+  uint8_t buffer[PX_HEADER_SIZE + 4];
+  uint16_t container_size = PX_HEADER_SIZE + 3;
+  memcpy(buffer, "AT4PX", 5);
+  CotWriteU16(buffer + 5, container_size);
+  memset(buffer + 7, 0xF, 9);
+  CotWriteU16(buffer + 0x10, 4);
+  // A literal, then a back-reference (high nibble 0 matches none of the flags)
+  buffer[PX_HEADER_SIZE] = 0x80;
+  buffer[PX_HEADER_SIZE + 1] = 'a';
+  buffer[PX_HEADER_SIZE + 2] = 0x0F;
+  buffer[PX_HEADER_SIZE + 3] = 0xFF;
+
+  uint8_t decompressed[4];
+  CHECK(CotPxDecompress(buffer, decompressed, sizeof(decompressed)) == -1);
+  // With the whole back-reference, it repeats the literal
+  CotWriteU16(buffer + 5, container_size + 1);
+  CHECK(CotPxDecompress(buffer, decompressed, sizeof(decompressed)) == 4);
+  CHECK(memcmp(decompressed, "aaaa", 4) == 0);
+}
+
+static void TestNrlInvalid(void) {
+  uint8_t out[4];
+  // A repeat command without the repeated byte
+  static const uint8_t repeat[] = { 0x81 };
+  CHECK(CotNrlDecompress(repeat, sizeof(repeat), out, sizeof(out)) == -1);
+  // 5 zeros don't fit
+  static const uint8_t zeros[] = { 0x04 };
+  CHECK(CotNrlDecompress(zeros, sizeof(zeros), out, sizeof(out)) == -1);
+}
+
+int main(void) {
+  TestRoundTrips();
+  TestPxTooSmall();
+  TestPxTruncatedBackReference();
+  TestNrlInvalid();
+  return TEST_RESULT();
+}
@@ -0,0 +1,68 @@
+#include <string.h>
+#include <pmdsky.h>
+#include <cot.h>
+#include "test.h"
+
+// Two rooms followed by the room list and the SIR0 padding:
+// room 0 is 3x2, room 1 is 2x2 with actions that don't cover the room.
+static const uint8_t FILE_DATA[] = {
+  'S', 'I', 'R', '0', 0x30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+  // 0x10: room 0
+  3, 0, 2, 0, 0x42, 0,
+  0x01, 0x20,  // action 1, repeated twice more
+  0x05, 0x00,  // action 5
+  0xFF, 0x10,  // action 0xFF, repeated once more
+  0, 0, 0, 0,
+  // 0x20: room 1
+  2, 0, 2, 0, 0, 0,
+  0x02, 0x10,  // only covers 2 of 4 tiles
+  0, 0, 0, 0, 0, 0, 0, 0,
+  // 0x30: room list
+  0x10, 0, 0, 0,
+  0x20, 0, 0, 0,
+  0xAA, 0xAA, 0xAA, 0xAA,
+};
+
+static void TestOpen(void) {
+  fixed_bin bin;
+  CHECK(CotFixedBinOpen(&bin, FILE_DATA, sizeof(FILE_DATA)));
+  CHECK(bin.n_rooms == 2);
+
+  uint8_t bad[sizeof(FILE_DATA)];
+  memcpy(bad, FILE_DATA, sizeof(bad));
+  bad[0] = 'X';
+  CHECK(!CotFixedBinOpen(&bin, bad, sizeof(bad)));
+  memcpy(bad, FILE_DATA, sizeof(bad));
+  bad[4] = 0xFF;
+  CHECK(!CotFixedBinOpen(&bin, bad, sizeof(bad)));
+  CHECK(!CotFixedBinOpen(&bin, FILE_DATA, 8));
+}
+
+static void TestRooms(void) {
+  fixed_bin bin;
+  CHECK(CotFixedBinOpen(&bin, FILE_DATA, sizeof(FILE_DATA)));
+  fixed_bin_room room;
+  CHECK(!CotFixedBinGetRoom(&bin, 2, &room));
+  CHECK(!CotFixedBinGetRoom(&bin, -1, &room));
+
+  CHECK(CotFixedBinGetRoom(&bin, 0, &room));
+  CHECK(room.width == 3 && room.height == 2 && room.properties == 0x42);
+  uint16_t tiles[6];
+  CHECK(CotFixedBinDecodeRoom(&room, tiles, 6) == 6);
+  CHECK(tiles[0] == 1 && tiles[1] == 1 && tiles[2] == 1);
+  CHECK(tiles[3] == 5 && tiles[4] == 0xFF && tiles[5] == 0xFF);
+  CHECK(CotFixedBinDecodeRoom(&room, tiles, 5) == -1);
+
+  CHECK(CotFixedBinGetRoom(&bin, 1, &room));
+  // Decoding doesn't stop at the room's end, so the padding after it is read as action 0
+  CHECK(CotFixedBinDecodeRoom(&room, tiles, 6) == 4);
+  CHECK(tiles[0] == 2 && tiles[1] == 2 && tiles[2] == 0 && tiles[3] == 0);
+  room.actions_size = 2;
+  CHECK(CotFixedBinDecodeRoom(&room, tiles, 6) == -1);
+}
+
+int main(void) {
+  TestOpen();
+  TestRooms();
+  return TEST_RESULT();
+}
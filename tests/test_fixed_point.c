@@ -0,0 +1,70 @@
+#include <pmdsky.h>
+#include <cot.h>
+#include "test.h"
+
+static void TestConversions(void) {
+  CHECK(CotFx32FromInt(3) == 3 * COT_FX32_ONE);
+  CHECK(CotFx32ToInt(CotFx32FromInt(-7)) == -7);
+  // Conversions to integers round towards negative infinity, like the game
+  CHECK(CotFx32ToInt(CotFx32FromRatio(3, 2)) == 1);
+  CHECK(CotFx32ToInt(CotFx32FromRatio(-3, 2)) == -2);
+  CHECK(CotFx32Round(CotFx32FromRatio(3, 2)) == 2);
+  CHECK(CotFx32Round(CotFx32FromRatio(-3, 2)) == -2);
+  CHECK(CotFx32Round(CotFx32FromRatio(5, 4)) == 1);
+
+  CHECK(CotFx32FromInt(COT_FX32_INT_MAX) == COT_FX32_INT_MAX * COT_FX32_ONE);
+  CHECK(CotFx32FromInt(COT_FX32_INT_MIN) == INT32_MIN);
+  // 2^23 doesn't fit
+  CHECK(CotFx32FromInt(COT_FX32_INT_MAX + 1) == INT32_MAX);
+  CHECK(CotFx32FromInt(COT_FX32_INT_MIN - 1) == INT32_MIN);
+}
+
+static void TestArithmetic(void) {
+  cot_fx32 half = CotFx32FromRatio(1, 2);
+  CHECK(half == COT_FX32_ONE / 2);
+  CHECK(CotFx32Mul(CotFx32FromInt(6), half) == CotFx32FromInt(3));
+  CHECK(CotFx32Mul(CotFx32FromInt(-3), half) == CotFx32FromRatio(-3, 2));
+  CHECK(CotFx32Div(CotFx32FromInt(3), CotFx32FromInt(2)) == CotFx32FromRatio(3, 2));
+  // Divisions round towards zero
+  CHECK(CotFx32Div(1, 3 * COT_FX32_ONE) == 0);
+  CHECK(CotFx32Div(-1, 3 * COT_FX32_ONE) == 0);
+  CHECK(CotFx32FromRatio(1, 3) == 85);
+  CHECK(CotFx32FromRatio(-1, 3) == -85);
+  CHECK(CotFx32Scale(100, CotFx32FromRatio(3, 2)) == 150);
+  CHECK(CotFx32Scale(-5, half) == -3);
+}
+
+static void TestOverflow(void) {
+  cot_fx32 big = CotFx32FromInt(1 << 20);
+  CHECK(CotFx32Mul(big, big) == INT32_MAX);
+  CHECK(CotFx32Mul(big, -big) == INT32_MIN);
+  CHECK(CotFx32Div(big, 1) == INT32_MAX);
+  CHECK(CotFx32Div(-big, 1) == INT32_MIN);
+  CHECK(CotFx32Scale(INT32_MAX, CotFx32FromInt(2)) == INT32_MAX);
+  // The most negative value has no positive counterpart, but fits as a result
+  CHECK(CotFx32Div(INT32_MIN, COT_FX32_ONE) == INT32_MIN);
+  CHECK(CotFx32Div(INT32_MIN, -COT_FX32_ONE) == INT32_MAX);
+
+  CHECK(CotFx32Div(COT_FX32_ONE, 0) == INT32_MAX);
+  CHECK(CotFx32Div(-COT_FX32_ONE, 0) == INT32_MIN);
+  CHECK(CotFx32Div(0, 0) == 0);
+  CHECK(CotFx32FromRatio(1, 0) == INT32_MAX);
+}
+
+static void TestFx16(void) {
+  cot_fx32 value = CotFx32FromRatio(-5, 4);
+  cot_fx16 narrow = CotFx32ToFx16(value);
+  CHECK(sizeof(narrow) == 2);
+  CHECK(CotFx16ToFx32(narrow) == value);
+  CHECK(CotFx32ToFx16(CotFx32FromInt(127)) == 127 * COT_FX16_ONE);
+  CHECK(CotFx32ToFx16(CotFx32FromInt(128)) == INT16_MAX);
+  CHECK(CotFx32ToFx16(CotFx32FromInt(-129)) == INT16_MIN);
+}
+
+int main(void) {
+  TestConversions();
+  TestArithmetic();
+  TestOverflow();
+  TestFx16();
+  return TEST_RESULT();
+}
@@ -0,0 +1,117 @@
+#include <pmdsky.h>
+#include <cot.h>
+#include "test.h"
+
+static struct position Pos(int x, int y) {
+  return (struct position) { .x = x, .y = y };
+}
+
+static void TestDirections(void) {
+  CHECK(CotPosEqual(CotDirectionOffset(DIR_DOWN), Pos(0, 1)));
+  CHECK(CotPosEqual(CotDirectionOffset(DIR_UP_RIGHT), Pos(1, -1)));
+  CHECK(CotDirectionOpposite(DIR_DOWN_LEFT) == DIR_UP_RIGHT);
+  CHECK(CotDirectionRotate(DIR_DOWN, 2) == DIR_RIGHT);
+  CHECK(CotDirectionRotate(DIR_DOWN, -1) == DIR_DOWN_LEFT);
+  CHECK(CotDirectionIsDiagonal(DIR_UP_LEFT) && !CotDirectionIsDiagonal(DIR_LEFT));
+  for (int d = 0; d < COT_N_DIRECTIONS; d++) {
+    struct position back = CotPosStep(CotPosStep(Pos(5, 5), d), CotDirectionOpposite(d));
+    CHECK(CotPosEqual(back, Pos(5, 5)));
+  }
+
+  enum direction_id dir;
+  CHECK(!CotDirectionTowards(Pos(3, 3), Pos(3, 3), &dir));
+  CHECK(CotDirectionTowards(Pos(3, 3), Pos(10, 4), &dir) && dir == DIR_RIGHT);
+  CHECK(CotDirectionTowards(Pos(3, 3), Pos(0, 0), &dir) && dir == DIR_UP_LEFT);
+  CHECK(CotDirectionTowards(Pos(3, 3), Pos(4, 5), &dir) && dir == DIR_DOWN_RIGHT);
+}
+
+static void TestDistances(void) {
+  CHECK(CotChebyshevDistance(Pos(1, 1), Pos(4, -1)) == 3);
+  CHECK(CotManhattanDistance(Pos(1, 1), Pos(4, -1)) == 5);
+}
+
+static void TestPixels(void) {
+  pixel_pos pixel = CotTileToPixel(Pos(2, 3));
+  CHECK(pixel.x == 2 * COT_TILE_PIXELS && pixel.y == 3 * COT_TILE_PIXELS);
+  pixel = CotTileCenterToPixel(Pos(0, 0));
+  CHECK(pixel.x == COT_TILE_PIXELS / 2 && pixel.y == COT_TILE_PIXELS / 2);
+  CHECK(CotPosEqual(CotPixelToTile((pixel_pos) { .x = 47, .y = 48 }), Pos(1, 2)));
+  // Pixels left of or above the floor stay out of bounds
+  CHECK(CotPosEqual(CotPixelToTile((pixel_pos) { .x = -1, .y = -24 }), Pos(-1, -1)));
+  CHECK(CotPosEqual(CotPixelToTile((pixel_pos) { .x = -25, .y = 0 }), Pos(-2, 0)));
+}
+
+static void TestNeighbors(void) {
+  struct position pos;
+  int n = 0;
+  COT_FOR_EACH_NEIGHBOR(Pos(5, 5), pos) {
+    CHECK(CotChebyshevDistance(pos, Pos(5, 5)) == 1);
+    n++;
+  }
+  CHECK(n == 8);
+  n = 0;
+  COT_FOR_EACH_NEIGHBOR(Pos(0, 0), pos) {
+    n++;
+  }
+  CHECK(n == 3);
+}
+
+static void TestLines(void) {
+  struct position pos;
+  struct position last = Pos(-1, -1);
+  int n = 0;
+  COT_FOR_EACH_ON_LINE(Pos(1, 1), Pos(7, 4), pos) {
+    if (n == 0) {
+      CHECK(CotPosEqual(pos, Pos(1, 1)));
+    } else {
+      CHECK(CotChebyshevDistance(pos, last) == 1);
+    }
+    last = pos;
+    n++;
+  }
+  CHECK(CotPosEqual(last, Pos(7, 4)));
+  CHECK(n == 7);
+
+  n = 0;
+  COT_FOR_EACH_ON_LINE(Pos(3, 3), Pos(3, 3), pos) {
+    n++;
+  }
+  CHECK(n == 1);
+}
+
+static void TestAreas(void) {
+  struct position pos;
+  int n = 0;
+  COT_FOR_EACH_IN_AREA(2, 3, 4, 2, pos) {
+    CHECK(pos.x >= 2 && pos.x < 6 && pos.y >= 3 && pos.y < 5);
+    n++;
+  }
+  CHECK(n == 8);
+  // Clipped to the floor
+  n = 0;
+  COT_FOR_EACH_IN_AREA(-2, COT_FLOOR_HEIGHT - 1, 4, 5, pos) {
+    CHECK(CotPosInBounds(pos));
+    n++;
+  }
+  CHECK(n == 2);
+  n = 0;
+  COT_FOR_EACH_IN_AREA(0, 0, 0, 5, pos) {
+    n++;
+  }
+  CHECK(n == 0);
+
+  tile_area area = { .pos = Pos(2, 3), .w = 4, .h = 2 };
+  CHECK(CotAreaContains(area, Pos(2, 3)) && CotAreaContains(area, Pos(5, 4)));
+  CHECK(!CotAreaContains(area, Pos(6, 4)) && !CotAreaContains(area, Pos(2, 5)));
+  CHECK(CotAreaContains(COT_FLOOR_AREA, Pos(COT_FLOOR_WIDTH - 1, COT_FLOOR_HEIGHT - 1)));
+}
+
+int main(void) {
+  TestDirections();
+  TestDistances();
+  TestPixels();
+  TestNeighbors();
+  TestLines();
+  TestAreas();
+  return TEST_RESULT();
+}
@@ -0,0 +1,135 @@
+#include <pmdsky.h>
+#include <cot.h>
+#include "test.h"
+
+// The floor the tile grid functions below report, '#' for walls and '~' for water. Tiles
+// outside of the map are walls.
+static const char* const* map;
+static int map_height;
+
+static char TileAt(int x, int y) {
+  if (y < 0 || y >= map_height || x < 0 || x >= (int) strlen(map[y])) {
+    return '#';
+  }
+  return map[y][x];
+}
+
+bool CotTileIsFloor(int x, int y) {
+  return TileAt(x, y) == '.';
+}
+
+bool CotTileIsWall(int x, int y) {
+  return TileAt(x, y) == '#';
+}
+
+static void SetMap(const char* const* rows, int n_rows) {
+  map = rows;
+  map_height = n_rows;
+}
+
+static struct position Pos(int x, int y) {
+  return (struct position) { .x = x, .y = y };
+}
+
+static void TestStraightPath(void) {
+  static const char* const rows[] = {
+    "#######",
+    "#.....#",
+    "#######",
+  };
+  SetMap(rows, 3);
+  tile_path path;
+  CHECK(CotFindPath(Pos(1, 1), Pos(5, 1), NULL, &path));
+  CHECK(path.len == 4);
+  CHECK(CotPosEqual(path.items[0], Pos(2, 1)) && CotPosEqual(path.items[3], Pos(5, 1)));
+  CHECK(CotPathDistance(Pos(1, 1), Pos(5, 1), NULL) == 4);
+  CHECK(CotFindPath(Pos(1, 1), Pos(1, 1), NULL, &path) && path.len == 0);
+}
+
+static void TestCorners(void) {
+  // The diagonal from (1, 1) to (2, 2) would cut the corner of the wall at (2, 1)
+  static const char* const rows[] = {
+    "#####",
+    "#.#.#",
+    "#...#",
+    "#####",
+  };
+  SetMap(rows, 4);
+  CHECK(CotPathDistance(Pos(1, 1), Pos(3, 1), NULL) == 4);
+  // Water doesn't block diagonals, but isn't walkable by default
+  static const char* const water[] = {
+    "#####",
+    "#.~.#",
+    "#...#",
+    "#####",
+  };
+  SetMap(water, 4);
+  CHECK(CotPathDistance(Pos(1, 1), Pos(3, 1), NULL) == 2);
+}
+
+static bool IsFloorOrWater(int x, int y) {
+  return CotTileIsFloor(x, y) || TileAt(x, y) == '~';
+}
+
+static void TestUnreachable(void) {
+  static const char* const rows[] = {
+    "#######",
+    "#..~..#",
+    "#######",
+  };
+  SetMap(rows, 3);
+  tile_path path;
+  CHECK(!CotFindPath(Pos(1, 1), Pos(5, 1), NULL, &path));
+  CHECK(path.len == 0);
+  CHECK(CotPathDistance(Pos(1, 1), Pos(5, 1), NULL) == COT_UNREACHABLE);
+  CHECK(CotPathDistance(Pos(1, 1), Pos(5, 1), IsFloorOrWater) == 4);
+  CHECK(CotPathDistance(Pos(-1, 1), Pos(5, 1), NULL) == COT_UNREACHABLE);
+}
+
+static void TestDistanceMap(void) {
+  static const char* const rows[] = {
+    "######",
+    "#....#",
+    "#.##.#",
+    "#....#",
+    "######",
+  };
+  SetMap(rows, 5);
+  static int16_t distances[COT_FLOOR_HEIGHT][COT_FLOOR_WIDTH];
+  CotDistanceMap(Pos(1, 1), NULL, distances);
+  CHECK(distances[1][1] == 0);
+  CHECK(distances[1][4] == 3);
+  CHECK(distances[3][4] == 5);
+  CHECK(distances[2][2] == COT_UNREACHABLE);
+  CHECK(distances[10][10] == COT_UNREACHABLE);
+}
+
+static void TestMaxLength(void) {
+  // A hallway zigzagging across the whole floor is longer than COT_MAX_PATH_LENGTH
+  static char rows[COT_FLOOR_HEIGHT][COT_FLOOR_WIDTH + 1];
+  static const char* row_ptrs[COT_FLOOR_HEIGHT];
+  for (int y = 0; y < COT_FLOOR_HEIGHT; y++) {
+    for (int x = 0; x < COT_FLOOR_WIDTH; x++) {
+      bool open = y % 2 == 0 || (y % 4 == 1 ? x == COT_FLOOR_WIDTH - 1 : x == 0);
+      rows[y][x] = open ? '.' : '#';
+    }
+    rows[y][COT_FLOOR_WIDTH] = '\0';
+    row_ptrs[y] = rows[y];
+  }
+  SetMap(row_ptrs, COT_FLOOR_HEIGHT);
+  tile_path path;
+  struct position goal = Pos(COT_FLOOR_WIDTH - 1, COT_FLOOR_HEIGHT - 2);
+  CHECK(CotPathDistance(Pos(0, 0), goal, NULL) > COT_MAX_PATH_LENGTH);
+  CHECK(!CotFindPath(Pos(0, 0), goal, NULL, &path));
+  CHECK(CotFindPath(Pos(0, 0), Pos(COT_FLOOR_WIDTH - 1, 0), NULL, &path));
+  CHECK(path.len == COT_FLOOR_WIDTH - 1);
+}
+
+int main(void) {
+  TestStraightPath();
+  TestCorners();
+  TestUnreachable();
+  TestDistanceMap();
+  TestMaxLength();
+  return TEST_RESULT();
+}
@@ -0,0 +1,52 @@
+#include <limits.h>
+#include <pmdsky.h>
+#include <cot.h>
+#include "test.h"
+
+static void TestAppend(void) {
+  COT_STR_BUF(sb, 32);
+  CHECK(sb.len == 0 && sb.buf[0] == '\0');
+  CotStrBufAppend(&sb, "Floor ");
+  CotStrBufAppendInt(&sb, 12);
+  CotStrBufAppendChar(&sb, 'F');
+  CHECK(strcmp(sb.buf, "Floor 12F") == 0);
+  CHECK(sb.len == 9 && !sb.truncated);
+
+  CotStrBufClear(&sb);
+  CotStrBufAppendInt(&sb, 0);
+  CotStrBufAppendChar(&sb, ' ');
+  CotStrBufAppendInt(&sb, INT_MIN);
+  CHECK(strcmp(sb.buf, "0 -2147483648") == 0);
+}
+
+static void TestTruncation(void) {
+  COT_STR_BUF(sb, 8);
+  CotStrBufAppend(&sb, "Explorers of Sky");
+  CHECK(sb.truncated);
+  CHECK(sb.len == 7);
+  CHECK(strcmp(sb.buf, "Explore") == 0);
+  // Nothing is appended once the buffer is full
+  CotStrBufAppendInt(&sb, 1);
+  CHECK(strcmp(sb.buf, "Explore") == 0);
+
+  CotStrBufClear(&sb);
+  CHECK(!sb.truncated && sb.len == 0 && sb.buf[0] == '\0');
+}
+
+static void TestUtf8(void) {
+  COT_STR_BUF(sb, 32);
+  CotStrBufAppendUtf8(&sb, "Pok\xC3\xA9mon \xE2\x80\x9CSky\xE2\x80\x9D \xE2\x82\xAC");
+  CHECK(strcmp(sb.buf, "Pok\xE9mon \x93Sky\x94 \x80") == 0);
+
+  // Characters missing from the encoding and broken sequences
+  CotStrBufClear(&sb);
+  CotStrBufAppendUtf8(&sb, "\xE2\x98\x83 \xF0\x9F\x98\x80 \xC3 \x80");
+  CHECK(strcmp(sb.buf, "? ? ? ?") == 0);
+}
+
+int main(void) {
+  TestAppend();
+  TestTruncation();
+  TestUtf8();
+  return TEST_RESULT();
+}